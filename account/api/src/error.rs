@@ -24,6 +24,10 @@ pub enum AccountError {
     TransactionSignError(anyhow::Error),
     // #[error("decrypt private key error, {0:?}")]
     // DecryptPrivateKeyError(anyhow::Error),
+    #[error("account {0} is not a multisig account")]
+    NotMultisigAccount(AccountAddress),
+    #[error("invalid multisig account, {0:?}")]
+    InvalidMultisigAccount(anyhow::Error),
     #[error("no private key data associate with address {0}")]
     AccountPrivateKeyMissing(AccountAddress),
     #[error("account vault store error, {0:?}")]
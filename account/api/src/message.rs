@@ -1,14 +1,17 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::AccountInfo;
+use crate::{AccountInfo, PartiallySignedTransaction};
 use anyhow::Result;
+use starcoin_crypto::ed25519::Ed25519PublicKey;
 use starcoin_service_registry::ServiceRequest;
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::account_config::token_code::TokenCode;
 use starcoin_types::sign_message::SigningMessage;
 use starcoin_types::transaction::authenticator::AccountSignature;
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -26,6 +29,18 @@ pub enum AccountRequest {
         signer: AccountAddress,
         message: SigningMessage,
     },
+    SignFeePayerTxn {
+        txn: Box<FeePayerRawTransaction>,
+        signer: AccountAddress,
+    },
+    CreateMultisigAccount {
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    },
+    CreateMultisigTxn(Box<RawUserTransaction>),
+    SignMultisigTxn(Box<PartiallySignedTransaction>),
     AccountAcceptedTokens {
         address: AccountAddress,
     },
@@ -60,5 +75,6 @@ pub enum AccountResponse {
     ExportAccountResponse(Vec<u8>),
     AcceptedTokens(Vec<TokenCode>),
     MessageSignature(Box<AccountSignature>),
+    PartiallySignedTxn(Box<PartiallySignedTransaction>),
     None,
 }
@@ -2,15 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::message::{AccountRequest, AccountResponse};
-use crate::AccountInfo;
+use crate::{AccountInfo, PartiallySignedTransaction};
 use anyhow::Result;
+use starcoin_crypto::ed25519::Ed25519PublicKey;
 use starcoin_crypto::multi_ed25519::MultiEd25519Signature;
 use starcoin_service_registry::{ActorService, ServiceHandler, ServiceRef};
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::account_config::token_code::TokenCode;
 use starcoin_types::sign_message::SigningMessage;
 use starcoin_types::transaction::authenticator::AccountSignature;
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 
 #[async_trait::async_trait]
 pub trait AccountAsyncService:
@@ -36,6 +39,35 @@ pub trait AccountAsyncService:
         raw_txn: RawUserTransaction,
         signer_address: AccountAddress,
     ) -> Result<SignedUserTransaction>;
+
+    /// Sign a sponsored transaction as the sender or the fee payer.
+    async fn sign_fee_payer_txn(
+        &self,
+        txn: FeePayerRawTransaction,
+        signer_address: AccountAddress,
+    ) -> Result<AccountSignature>;
+
+    /// Create a multisig account, `private_keys` are the local shards of the account.
+    async fn create_multisig_account(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    ) -> Result<AccountInfo>;
+
+    /// Create a multisig txn of the txn sender, and sign it with the local shards.
+    async fn create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> Result<PartiallySignedTransaction>;
+
+    /// Co-sign the multisig txn with the local shards of the txn sender.
+    async fn sign_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction>;
+
     async fn unlock_account(
         &self,
         address: AccountAddress,
@@ -138,6 +170,11 @@ where
                     bytes.extend(Into::<MultiEd25519Signature>::into(s).to_bytes().to_vec());
                     bytes
                 }
+                AccountSignature::Secp256k1(p, s) => {
+                    let mut bytes = p.to_bytes();
+                    bytes.extend(s.to_bytes());
+                    bytes
+                }
             })
         } else {
             panic!("Unexpected response type.")
@@ -162,6 +199,74 @@ where
         }
     }
 
+    async fn sign_fee_payer_txn(
+        &self,
+        txn: FeePayerRawTransaction,
+        signer_address: AccountAddress,
+    ) -> Result<AccountSignature> {
+        let response = self
+            .send(AccountRequest::SignFeePayerTxn {
+                txn: Box::new(txn),
+                signer: signer_address,
+            })
+            .await??;
+        if let AccountResponse::MessageSignature(signature) = response {
+            Ok(*signature)
+        } else {
+            panic!("Unexpect response type.")
+        }
+    }
+
+    async fn create_multisig_account(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    ) -> Result<AccountInfo> {
+        let response = self
+            .send(AccountRequest::CreateMultisigAccount {
+                public_keys,
+                threshold,
+                private_keys,
+                password,
+            })
+            .await??;
+        if let AccountResponse::AccountInfo(account) = response {
+            Ok(*account)
+        } else {
+            panic!("Unexpect response type.")
+        }
+    }
+
+    async fn create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> Result<PartiallySignedTransaction> {
+        let response = self
+            .send(AccountRequest::CreateMultisigTxn(Box::new(raw_txn)))
+            .await??;
+        if let AccountResponse::PartiallySignedTxn(txn) = response {
+            Ok(*txn)
+        } else {
+            panic!("Unexpect response type.")
+        }
+    }
+
+    async fn sign_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction> {
+        let response = self
+            .send(AccountRequest::SignMultisigTxn(Box::new(txn)))
+            .await??;
+        if let AccountResponse::PartiallySignedTxn(txn) = response {
+            Ok(*txn)
+        } else {
+            panic!("Unexpect response type.")
+        }
+    }
+
     async fn unlock_account(
         &self,
         address: AccountAddress,
@@ -1,12 +1,18 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::{bail, ensure, Result};
 use serde::{Deserialize, Serialize};
+use starcoin_crypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
 use starcoin_crypto::keygen::KeyGen;
+use starcoin_crypto::multi_ed25519::multi_shard::MultiEd25519SignatureShard;
+use starcoin_crypto::multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature};
 use starcoin_types::{
     account_address::{self, AccountAddress},
     transaction::authenticator::AuthenticationKey,
+    transaction::{RawUserTransaction, SignedUserTransaction},
 };
+use std::collections::BTreeMap;
 
 pub use starcoin_types::transaction::authenticator::{
     AccountPrivateKey, AccountPublicKey, AccountSignature,
@@ -51,3 +57,123 @@ impl AccountInfo {
         }
     }
 }
+
+/// A multisig transaction which is collecting signatures from the signers of a multisig account.
+/// It is the standard format for passing a multisig transaction between co-signers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartiallySignedTransaction {
+    raw_txn: RawUserTransaction,
+    /// public key of the multisig account.
+    public_key: MultiEd25519PublicKey,
+    /// collected signatures, None if nobody has signed.
+    signature: Option<MultiEd25519SignatureShard>,
+}
+
+impl PartiallySignedTransaction {
+    pub fn new(raw_txn: RawUserTransaction, public_key: MultiEd25519PublicKey) -> Result<Self> {
+        let address = AccountPublicKey::Multi(public_key.clone()).derived_address();
+        ensure!(
+            raw_txn.sender() == address,
+            "txn sender {} is not the multisig account {}",
+            raw_txn.sender(),
+            address
+        );
+        Ok(Self {
+            raw_txn,
+            public_key,
+            signature: None,
+        })
+    }
+
+    pub fn raw_txn(&self) -> &RawUserTransaction {
+        &self.raw_txn
+    }
+
+    pub fn public_key(&self) -> &MultiEd25519PublicKey {
+        &self.public_key
+    }
+
+    pub fn signature(&self) -> Option<&MultiEd25519SignatureShard> {
+        self.signature.as_ref()
+    }
+
+    pub fn threshold(&self) -> u8 {
+        *self.public_key.threshold()
+    }
+
+    /// Add a signature shard, the shard is verified before merged into the collected signatures.
+    /// A signer who has already signed is ignored.
+    pub fn add_signature(&mut self, shard: MultiEd25519SignatureShard) -> Result<()> {
+        shard.verify(&self.raw_txn, &self.public_key)?;
+        let mut signatures: BTreeMap<u8, Ed25519Signature> = self
+            .signature
+            .as_ref()
+            .map(|s| s.signatures().into_iter().map(|(s, i)| (i, s)).collect())
+            .unwrap_or_default();
+        for (signature, index) in shard.signatures() {
+            signatures.entry(index).or_insert(signature);
+        }
+        let signature = MultiEd25519Signature::new(
+            signatures
+                .into_iter()
+                .map(|(index, signature)| (signature, index))
+                .collect(),
+        )?;
+        self.signature = Some(MultiEd25519SignatureShard::new(signature, self.threshold()));
+        Ok(())
+    }
+
+    /// Public keys of the signers who have signed the txn.
+    pub fn signers(&self) -> Vec<Ed25519PublicKey> {
+        let signed = self.signed_indexes();
+        self.indexed_public_keys()
+            .filter(|(i, _)| signed.contains(i))
+            .map(|(_, key)| key.clone())
+            .collect()
+    }
+
+    /// Public keys of the signers who have not signed the txn yet.
+    pub fn missing_signers(&self) -> Vec<Ed25519PublicKey> {
+        let signed = self.signed_indexes();
+        self.indexed_public_keys()
+            .filter(|(i, _)| !signed.contains(i))
+            .map(|(_, key)| key.clone())
+            .collect()
+    }
+
+    /// Whether the collected signatures reach the threshold.
+    pub fn is_enough(&self) -> bool {
+        self.signature
+            .as_ref()
+            .map(|s| s.is_enough())
+            .unwrap_or(false)
+    }
+
+    pub fn into_signed_txn(self) -> Result<SignedUserTransaction> {
+        match self.signature {
+            Some(signature) if signature.is_enough() => {
+                AccountSignature::Multi(self.public_key, signature).build_transaction(self.raw_txn)
+            }
+            _ => bail!(
+                "multisig txn needs {} signatures, but only {} collected",
+                self.threshold(),
+                self.signed_indexes().len()
+            ),
+        }
+    }
+
+    fn signed_indexes(&self) -> Vec<u8> {
+        self.signature
+            .as_ref()
+            .map(|s| s.signatures().into_iter().map(|(_, i)| i).collect())
+            .unwrap_or_default()
+    }
+
+    fn indexed_public_keys(&self) -> impl Iterator<Item = (u8, &Ed25519PublicKey)> {
+        self.public_key
+            .public_keys()
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (i as u8, key))
+    }
+}
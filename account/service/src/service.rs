@@ -118,6 +118,29 @@ impl ServiceHandler<AccountService, AccountRequest> for AccountService {
             AccountRequest::SignMessage { message, signer } => AccountResponse::MessageSignature(
                 Box::new(self.manager.sign_message(signer, message)?),
             ),
+            AccountRequest::SignFeePayerTxn { txn, signer } => AccountResponse::MessageSignature(
+                Box::new(self.manager.sign_fee_payer_txn(signer, *txn)?),
+            ),
+            AccountRequest::CreateMultisigAccount {
+                public_keys,
+                threshold,
+                private_keys,
+                password,
+            } => {
+                let account = self.manager.create_multisig_account(
+                    public_keys,
+                    threshold,
+                    private_keys,
+                    password.as_str(),
+                )?;
+                AccountResponse::AccountInfo(Box::new(account.info()))
+            }
+            AccountRequest::CreateMultisigTxn(raw_txn) => AccountResponse::PartiallySignedTxn(
+                Box::new(self.manager.create_multisig_txn(*raw_txn)?),
+            ),
+            AccountRequest::SignMultisigTxn(txn) => {
+                AccountResponse::PartiallySignedTxn(Box::new(self.manager.sign_multisig_txn(*txn)?))
+            }
             AccountRequest::UnlockAccount(address, password, duration) => {
                 self.manager
                     .unlock_account(address, password.as_str(), duration)?;
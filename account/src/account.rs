@@ -5,14 +5,18 @@ use crate::account_manager::gen_private_key;
 use crate::account_storage::AccountStorage;
 use anyhow::{format_err, Result};
 use starcoin_account_api::error::AccountError;
-use starcoin_account_api::{AccountInfo, AccountPrivateKey, AccountPublicKey, AccountResult};
+use starcoin_account_api::{
+    AccountInfo, AccountPrivateKey, AccountPublicKey, AccountResult, PartiallySignedTransaction,
+};
 use starcoin_crypto::{PrivateKey, ValidCryptoMaterial};
 use starcoin_storage::storage::StorageInstance;
 use starcoin_types::account_address;
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::sign_message::SigningMessage;
 use starcoin_types::transaction::authenticator::{AccountSignature, AuthenticationKey};
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 
 pub struct Account {
     addr: AccountAddress,
@@ -71,16 +75,60 @@ impl Account {
         AccountInfo::new(self.addr, self.private_key.public_key(), false)
     }
 
-    pub fn sign_message(&self, message: SigningMessage) -> AccountSignature {
-        self.private_key.sign_message(message)
+    pub fn sign_message(&self, message: SigningMessage) -> Result<AccountSignature> {
+        self.ensure_can_sign()?;
+        Ok(self.private_key.sign_message(message))
     }
 
     pub fn sign_txn(&self, raw_txn: RawUserTransaction) -> Result<SignedUserTransaction> {
         //TODO handle multi signature
+        self.ensure_can_sign()?;
         let signature = self.private_key.sign(&raw_txn);
         signature.build_transaction(raw_txn)
     }
 
+    /// Sign the sponsored transaction as the sender or the fee payer.
+    pub fn sign_fee_payer_txn(&self, txn: &FeePayerRawTransaction) -> Result<AccountSignature> {
+        self.ensure_can_sign()?;
+        Ok(self.private_key.sign(txn))
+    }
+
+    /// A multisig account may hold no local shard, it can not sign anything.
+    fn ensure_can_sign(&self) -> Result<()> {
+        match &self.private_key {
+            AccountPrivateKey::Multi(shard) if shard.is_empty() => Err(format_err!(
+                "multisig account {} has no local private key",
+                self.addr
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Co-sign the multisig txn with the local shards of this multisig account.
+    pub fn sign_multisig_txn(
+        &self,
+        mut txn: PartiallySignedTransaction,
+    ) -> AccountResult<PartiallySignedTransaction> {
+        match &self.private_key {
+            AccountPrivateKey::Multi(shard) => {
+                if &shard.public_key() != txn.public_key() {
+                    return Err(AccountError::TransactionSignError(format_err!(
+                        "multisig txn public key mismatch with account {}",
+                        self.addr
+                    )));
+                }
+                // the account without local shard leaves the txn to other signers.
+                if shard.is_empty() {
+                    return Ok(txn);
+                }
+                txn.add_signature(shard.sign(txn.raw_txn()))
+                    .map_err(AccountError::TransactionSignError)?;
+                Ok(txn)
+            }
+            _ => Err(AccountError::NotMultisigAccount(self.addr)),
+        }
+    }
+
     pub fn destroy(self) -> Result<()> {
         self.store.destroy_account(self.addr)
     }
@@ -7,15 +7,18 @@ use crate::account_storage::AccountStorage;
 use parking_lot::RwLock;
 use rand::prelude::*;
 use starcoin_account_api::error::AccountError;
-use starcoin_account_api::{AccountInfo, AccountPrivateKey, AccountResult};
-use starcoin_crypto::ed25519::Ed25519PrivateKey;
+use starcoin_account_api::{
+    AccountInfo, AccountPrivateKey, AccountPublicKey, AccountResult, PartiallySignedTransaction,
+};
+use starcoin_crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey};
+use starcoin_crypto::multi_ed25519::multi_shard::MultiEd25519KeyShard;
 use starcoin_crypto::{Uniform, ValidCryptoMaterial};
 use starcoin_types::sign_message::SigningMessage;
 use starcoin_types::transaction::authenticator::AccountSignature;
 use starcoin_types::{
     account_address::AccountAddress,
     account_config::token_code::TokenCode,
-    transaction::{RawUserTransaction, SignedUserTransaction},
+    transaction::{FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction},
 };
 use std::collections::HashMap;
 use std::convert::TryFrom;
@@ -108,6 +111,28 @@ impl AccountManager {
         self.save_account(address, private_key, password.to_string())
     }
 
+    /// Create a multisig account with the public keys of all signers and the threshold,
+    /// `private_keys` are the local shards held by this wallet.
+    pub fn create_multisig_account(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: &str,
+    ) -> AccountResult<Account> {
+        let private_keys = private_keys
+            .iter()
+            .map(|key| Ed25519PrivateKey::try_from(key.as_slice()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| AccountError::InvalidPrivateKey)?;
+        // the private keys can be of any public keys, or none of them.
+        let shard = MultiEd25519KeyShard::from_private_keys(public_keys, threshold, private_keys)
+            .map_err(AccountError::InvalidMultisigAccount)?;
+        let private_key = AccountPrivateKey::Multi(shard);
+        let address = private_key.public_key().derived_address();
+        self.save_account(address, private_key, password.to_string())
+    }
+
     fn save_account(
         &self,
         address: AccountAddress,
@@ -196,7 +221,9 @@ impl AccountManager {
             Some(p) => {
                 let account = Account::load(signer_address, p.as_str(), self.store.clone())?
                     .ok_or(AccountError::AccountNotExist(signer_address))?;
-                Ok(account.sign_message(message))
+                account
+                    .sign_message(message)
+                    .map_err(AccountError::TransactionSignError)
             }
         }
     }
@@ -219,6 +246,57 @@ impl AccountManager {
         }
     }
 
+    pub fn sign_fee_payer_txn(
+        &self,
+        signer_address: AccountAddress,
+        txn: FeePayerRawTransaction,
+    ) -> AccountResult<AccountSignature> {
+        let pass = self.key_cache.write().get_pass(&signer_address);
+        match pass {
+            None => Err(AccountError::AccountLocked(signer_address)),
+            Some(p) => {
+                let account = Account::load(signer_address, p.as_str(), self.store.clone())?
+                    .ok_or(AccountError::AccountNotExist(signer_address))?;
+                account
+                    .sign_fee_payer_txn(&txn)
+                    .map_err(AccountError::TransactionSignError)
+            }
+        }
+    }
+
+    /// Create a multisig txn of the txn sender, and sign it with the local shards.
+    pub fn create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> AccountResult<PartiallySignedTransaction> {
+        let sender = raw_txn.sender();
+        let public_key = match self.store.public_key(sender)? {
+            Some(AccountPublicKey::Multi(public_key)) => public_key,
+            Some(_) => return Err(AccountError::NotMultisigAccount(sender)),
+            None => return Err(AccountError::AccountNotExist(sender)),
+        };
+        let txn = PartiallySignedTransaction::new(raw_txn, public_key)
+            .map_err(AccountError::TransactionSignError)?;
+        self.sign_multisig_txn(txn)
+    }
+
+    /// Co-sign the multisig txn with the local shards of the txn sender.
+    pub fn sign_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> AccountResult<PartiallySignedTransaction> {
+        let signer_address = txn.raw_txn().sender();
+        let pass = self.key_cache.write().get_pass(&signer_address);
+        match pass {
+            None => Err(AccountError::AccountLocked(signer_address)),
+            Some(p) => {
+                let account = Account::load(signer_address, p.as_str(), self.store.clone())?
+                    .ok_or(AccountError::AccountNotExist(signer_address))?;
+                account.sign_multisig_txn(txn)
+            }
+        }
+    }
+
     pub fn set_default_account(&self, address: AccountAddress) -> AccountResult<()> {
         self.store
             .set_default_address(Some(address))
@@ -111,6 +111,157 @@ pub fn test_wallet_unlock() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_multisig_account() -> Result<()> {
+    use starcoin_crypto::PrivateKey;
+
+    let private_keys = (0..3)
+        .map(|_| super::account_manager::gen_private_key())
+        .collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|k| k.public_key())
+        .collect::<Vec<_>>();
+
+    // every wallet holds one shard of the 2-of-3 multisig account.
+    let tempdirs = (0..2)
+        .map(|_| tempfile::tempdir())
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let mut managers = vec![];
+    for (tempdir, private_key) in tempdirs.iter().zip(private_keys.iter()) {
+        let storage = AccountStorage::create_from_path(tempdir.path(), RocksdbConfig::default())?;
+        let manager = AccountManager::new(storage)?;
+        let account = manager.create_multisig_account(
+            public_keys.clone(),
+            2,
+            vec![private_key.to_bytes().to_vec()],
+            "hello",
+        )?;
+        manager.unlock_account(*account.address(), "hello", Duration::from_secs(10))?;
+        managers.push((manager, *account.address()));
+    }
+    let address = managers[0].1;
+    assert_eq!(address, managers[1].1);
+
+    let raw_txn = RawUserTransaction::new_with_default_gas_token(
+        address,
+        1,
+        TransactionPayload::Script(Script::new(vec![], vec![], vec![])),
+        1000,
+        1,
+        100000,
+        ChainId::new(1),
+    );
+    let txn = managers[0].0.create_multisig_txn(raw_txn)?;
+    assert!(!txn.is_enough());
+    assert_eq!(txn.signers(), vec![public_keys[0].clone()]);
+    assert_eq!(
+        txn.missing_signers(),
+        vec![public_keys[1].clone(), public_keys[2].clone()]
+    );
+    assert!(txn.clone().into_signed_txn().is_err());
+
+    // sign twice by the same signer should not add a signature.
+    let txn = managers[0].0.sign_multisig_txn(txn)?;
+    assert_eq!(txn.missing_signers().len(), 2);
+
+    let txn = managers[1].0.sign_multisig_txn(txn)?;
+    assert!(txn.is_enough());
+    assert_eq!(txn.missing_signers(), vec![public_keys[2].clone()]);
+    let signed_txn = txn.into_signed_txn()?;
+    assert!(signed_txn.check_signature().is_ok());
+
+    // an account which is not multisig can not sign the multisig txn.
+    let account = managers[0].0.create_account("hello")?;
+    let raw_txn = RawUserTransaction::new_with_default_gas_token(
+        *account.address(),
+        1,
+        TransactionPayload::Script(Script::new(vec![], vec![], vec![])),
+        1000,
+        1,
+        100000,
+        ChainId::new(1),
+    );
+    assert!(matches!(
+        managers[0].0.create_multisig_txn(raw_txn),
+        Err(AccountError::NotMultisigAccount(_))
+    ));
+    Ok(())
+}
+
+#[test]
+pub fn test_multisig_account_of_any_shards() -> Result<()> {
+    use starcoin_crypto::PrivateKey;
+
+    let private_keys = (0..3)
+        .map(|_| super::account_manager::gen_private_key())
+        .collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|k| k.public_key())
+        .collect::<Vec<_>>();
+
+    // one wallet holds the unsequential shards, the other holds no shard.
+    let tempdirs = (0..2)
+        .map(|_| tempfile::tempdir())
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let shards = vec![
+        vec![
+            private_keys[2].to_bytes().to_vec(),
+            private_keys[0].to_bytes().to_vec(),
+        ],
+        vec![],
+    ];
+    let mut managers = vec![];
+    for (tempdir, shard) in tempdirs.iter().zip(shards) {
+        let storage = AccountStorage::create_from_path(tempdir.path(), RocksdbConfig::default())?;
+        let manager = AccountManager::new(storage)?;
+        let account = manager.create_multisig_account(public_keys.clone(), 2, shard, "hello")?;
+        manager.unlock_account(*account.address(), "hello", Duration::from_secs(10))?;
+        managers.push((manager, *account.address()));
+    }
+    let address = managers[0].1;
+    assert_eq!(address, managers[1].1);
+
+    let raw_txn = RawUserTransaction::new_with_default_gas_token(
+        address,
+        1,
+        TransactionPayload::Script(Script::new(vec![], vec![], vec![])),
+        1000,
+        1,
+        100000,
+        ChainId::new(1),
+    );
+    // the wallet without shard creates the txn, but can not sign it.
+    let txn = managers[1].0.create_multisig_txn(raw_txn.clone())?;
+    assert!(txn.signers().is_empty());
+    assert!(managers[1].0.sign_txn(address, raw_txn).is_err());
+
+    let txn = managers[0].0.sign_multisig_txn(txn)?;
+    assert!(txn.is_enough());
+    assert_eq!(
+        txn.signers(),
+        vec![public_keys[0].clone(), public_keys[2].clone()]
+    );
+    assert!(txn.into_signed_txn()?.check_signature().is_ok());
+
+    // the private key must be one of the public keys.
+    let tempdir = tempfile::tempdir()?;
+    let storage = AccountStorage::create_from_path(tempdir.path(), RocksdbConfig::default())?;
+    let manager = AccountManager::new(storage)?;
+    let other_key = super::account_manager::gen_private_key();
+    assert!(matches!(
+        manager.create_multisig_account(
+            public_keys,
+            2,
+            vec![other_key.to_bytes().to_vec()],
+            "hello"
+        ),
+        Err(AccountError::InvalidMultisigAccount(_))
+    ));
+    Ok(())
+}
+
 // ignore for now.
 #[ignore]
 #[test]
@@ -11,7 +11,9 @@ use network_api::messages::{CompactBlockMessage, NotificationMessage, PeerCompac
 use network_api::{NetworkService, PeerProvider, PeerSelector, PeerStrategy};
 use starcoin_network::NetworkServiceRef;
 use starcoin_network_rpc_api::GetTxnsWithHash;
-use starcoin_service_registry::{ActorService, EventHandler, ServiceContext, ServiceFactory};
+use starcoin_service_registry::{
+    ActorService, EventHandler, ServiceContext, ServiceFactory, SupervisionPolicy,
+};
 use starcoin_sync::block_connector::BlockConnectorService;
 use starcoin_sync::verified_rpc_client::VerifiedRpcClient;
 use starcoin_sync_api::PeerNewBlock;
@@ -197,6 +199,14 @@ impl BlockRelayer {
 }
 
 impl ActorService for BlockRelayer {
+    fn supervision_policy() -> SupervisionPolicy {
+        SupervisionPolicy::restart(10)
+    }
+
+    fn dependencies() -> Vec<&'static str> {
+        vec![BlockConnectorService::service_name()]
+    }
+
     fn started(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
         ctx.subscribe::<SyncStatusChangeEvent>();
         ctx.subscribe::<NewHeadBlock>();
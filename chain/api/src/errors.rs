@@ -39,6 +39,8 @@ pub enum VerifyBlockField {
     Consensus,
     // block field verified base on block executed result.
     State,
+    // block conflicts with a checkpoint.
+    Checkpoint,
 }
 
 impl Display for VerifyBlockField {
@@ -49,6 +51,7 @@ impl Display for VerifyBlockField {
             VerifyBlockField::Uncle => write!(f, "uncle"),
             VerifyBlockField::Consensus => write!(f, "consensus"),
             VerifyBlockField::State => write!(f, "state"),
+            VerifyBlockField::Checkpoint => write!(f, "checkpoint"),
         }
     }
 }
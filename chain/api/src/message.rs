@@ -9,6 +9,7 @@ use starcoin_types::block::EpochUncleSummary;
 use starcoin_types::stress_test::TPS;
 use starcoin_types::transaction::BlockTransactionInfo;
 use starcoin_types::{
+    account_address::AccountAddress,
     block::{Block, BlockHeader, BlockInfo, BlockNumber, BlockTemplate},
     contract_event::ContractEventInfo,
     filter::Filter,
@@ -44,6 +45,12 @@ pub enum ChainRequest {
     GetEventsByTxnHash {
         txn_hash: HashValue,
     },
+    GetTransactionInfosByAddress {
+        address: AccountAddress,
+        start_index: Option<u64>,
+        reverse: bool,
+        max_size: u64,
+    },
     GetBlocksByNumber(Option<BlockNumber>, u64),
     MainEvents(Filter),
     GetBlockIds {
@@ -80,6 +87,7 @@ pub enum ChainResponse {
     BlockHeaderVec(Vec<BlockHeader>),
     TransactionInfos(Vec<BlockTransactionInfo>),
     TransactionInfo(Option<BlockTransactionInfo>),
+    AddressTransactionInfos(Vec<(u64, BlockTransactionInfo)>),
     Events(Vec<ContractEventInfo>),
     MainEvents(Vec<ContractEventInfo>),
     None,
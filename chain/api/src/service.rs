@@ -11,6 +11,7 @@ use starcoin_types::filter::Filter;
 use starcoin_types::startup_info::ChainStatus;
 use starcoin_types::transaction::{BlockTransactionInfo, Transaction};
 use starcoin_types::{
+    account_address::AccountAddress,
     block::{Block, BlockHeader, BlockInfo, BlockNumber},
     startup_info::StartupInfo,
 };
@@ -35,6 +36,15 @@ pub trait ReadableChainService {
         &self,
         txn_info_id: HashValue,
     ) -> Result<Option<Vec<ContractEvent>>>;
+    /// Get the main chain txn infos of `address` from the address txn index, with their index.
+    /// The txn infos of the fork blocks are skipped, so the result may be less than `max_size`.
+    fn get_transaction_infos_by_address(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        reverse: bool,
+        max_size: u64,
+    ) -> Result<Vec<(u64, BlockTransactionInfo)>>;
     /// for main
     fn main_head_header(&self) -> BlockHeader;
     fn main_head_block(&self) -> Block;
@@ -95,6 +105,13 @@ pub trait ChainAsyncService:
         idx: u64,
     ) -> Result<Option<BlockTransactionInfo>>;
     async fn get_events_by_txn_hash(&self, txn_hash: HashValue) -> Result<Vec<ContractEventInfo>>;
+    async fn get_transaction_infos_by_address(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        reverse: bool,
+        max_size: u64,
+    ) -> Result<Vec<(u64, BlockTransactionInfo)>>;
     /// for main
     async fn main_head_header(&self) -> Result<BlockHeader>;
     async fn main_head_block(&self) -> Result<Block>;
@@ -272,6 +289,28 @@ where
         }
     }
 
+    async fn get_transaction_infos_by_address(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        reverse: bool,
+        max_size: u64,
+    ) -> Result<Vec<(u64, BlockTransactionInfo)>> {
+        let response = self
+            .send(ChainRequest::GetTransactionInfosByAddress {
+                address,
+                start_index,
+                reverse,
+                max_size,
+            })
+            .await??;
+        if let ChainResponse::AddressTransactionInfos(txn_infos) = response {
+            Ok(txn_infos)
+        } else {
+            bail!("get transaction infos by address error:{}", address)
+        }
+    }
+
     async fn main_head_header(&self) -> Result<BlockHeader> {
         if let ChainResponse::BlockHeader(header) =
             self.send(ChainRequest::CurrentHeader()).await??
@@ -1,7 +1,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{format_err, Error, Result};
+use anyhow::{bail, format_err, Error, Result};
 use starcoin_chain::BlockChain;
 use starcoin_chain_api::message::{ChainRequest, ChainResponse};
 use starcoin_chain_api::{ChainReader, ChainWriter, ReadableChainService};
@@ -18,6 +18,7 @@ use starcoin_types::filter::Filter;
 use starcoin_types::system_events::NewHeadBlock;
 use starcoin_types::transaction::BlockTransactionInfo;
 use starcoin_types::{
+    account_address::AccountAddress,
     block::{Block, BlockHeader, BlockInfo, BlockNumber},
     contract_event::ContractEvent,
     startup_info::StartupInfo,
@@ -159,6 +160,19 @@ impl ServiceHandler<Self, ChainRequest> for ChainReaderService {
                 self.inner
                     .get_txn_info_by_block_and_index(block_id, txn_idx)?,
             )),
+            ChainRequest::GetTransactionInfosByAddress {
+                address,
+                start_index,
+                reverse,
+                max_size,
+            } => Ok(ChainResponse::AddressTransactionInfos(
+                self.inner.get_transaction_infos_by_address(
+                    address,
+                    start_index,
+                    reverse,
+                    max_size,
+                )?,
+            )),
             ChainRequest::GetEventsByTxnHash { txn_hash } => {
                 let txn_info = self
                     .inner
@@ -356,6 +370,32 @@ impl ReadableChainService for ChainReaderServiceInner {
         self.storage.get_contract_events(txn_info_id)
     }
 
+    fn get_transaction_infos_by_address(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        reverse: bool,
+        max_size: u64,
+    ) -> Result<Vec<(u64, BlockTransactionInfo)>> {
+        if !self.storage.address_txn_index_enabled() {
+            bail!("The address txn index is not enabled, please restart the node with `--address-txn-index true`.");
+        }
+        let mut txn_infos = vec![];
+        for (index, txn_info_id) in
+            self.storage
+                .get_address_txn_info_ids(address, start_index, max_size, reverse)?
+        {
+            let txn_info = self
+                .storage
+                .get_transaction_info(txn_info_id)?
+                .ok_or_else(|| format_err!("cannot find txn info {}", txn_info_id))?;
+            if self.main.get_header(txn_info.block_id())?.is_some() {
+                txn_infos.push((index, txn_info));
+            }
+        }
+        Ok(txn_infos)
+    }
+
     fn main_head_header(&self) -> BlockHeader {
         self.main.current_header()
     }
@@ -0,0 +1,187 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Result};
+use crypto::HashValue;
+use logger::prelude::*;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::account_config::{DepositEvent, WithdrawEvent};
+use starcoin_types::block::BlockNumber;
+use starcoin_types::contract_event::ContractEvent;
+use starcoin_types::language_storage::TypeTag;
+use starcoin_types::transaction::Transaction;
+use starcoin_vm_types::move_resource::MoveResource;
+use std::collections::{BTreeMap, HashMap};
+use storage::Store;
+
+/// Collect the address txn index entries of a block's transactions:
+/// the sender of every user transaction, and the addresses whose balance is changed by
+/// the deposit or withdraw events of the transaction, each address once per transaction.
+pub fn address_txn_index_entries(
+    transactions: &[Transaction],
+    txn_info_ids: &[HashValue],
+    txn_events: &[Vec<ContractEvent>],
+) -> Vec<(AccountAddress, HashValue)> {
+    let balance_event_tags = [
+        TypeTag::Struct(DepositEvent::struct_tag()),
+        TypeTag::Struct(WithdrawEvent::struct_tag()),
+    ];
+    let mut entries = vec![];
+    for ((txn, txn_info_id), events) in transactions
+        .iter()
+        .zip(txn_info_ids.iter())
+        .zip(txn_events.iter())
+    {
+        let mut addresses = vec![];
+        if let Transaction::UserTransaction(user_txn) = txn {
+            addresses.push(user_txn.sender());
+        }
+        for event in events {
+            if balance_event_tags.contains(event.type_tag()) {
+                let address = event.key().get_creator_address();
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+            }
+        }
+        entries.extend(addresses.into_iter().map(|address| (address, *txn_info_id)));
+    }
+    entries
+}
+
+/// The address txn index entries of a saved block, read from `storage`.
+fn block_address_txn_index_entries(
+    storage: &dyn Store,
+    block_id: HashValue,
+) -> Result<Vec<(AccountAddress, HashValue)>> {
+    let txn_info_ids = storage.get_block_txn_info_ids(block_id)?;
+    let mut transactions = Vec::with_capacity(txn_info_ids.len());
+    let mut txn_events = Vec::with_capacity(txn_info_ids.len());
+    for txn_info_id in &txn_info_ids {
+        let txn_info = storage
+            .get_transaction_info(*txn_info_id)?
+            .ok_or_else(|| format_err!("Txn info {} is missing.", txn_info_id))?;
+        let txn_hash = txn_info.transaction_hash();
+        transactions.push(
+            storage
+                .get_transaction(txn_hash)?
+                .ok_or_else(|| format_err!("Transaction {} is missing.", txn_hash))?,
+        );
+        txn_events.push(
+            storage
+                .get_contract_events(*txn_info_id)?
+                .ok_or_else(|| format_err!("Events of txn info {} is missing.", txn_info_id))?,
+        );
+    }
+    Ok(address_txn_index_entries(
+        &transactions,
+        &txn_info_ids,
+        &txn_events,
+    ))
+}
+
+/// Move the address txn index from the main head `old_head_id` to `new_head_id`:
+/// remove the entries of the blocks retracted from the main chain, from the old head back to
+/// the common ancestor, then append the entries of the enacted blocks in order, so the index
+/// always matches `rebuild_address_txn_index` of the new main chain.
+/// `old_head_id` is `None` if there is no main chain yet, all blocks up to genesis are enacted.
+/// Do nothing if the index is not enabled.
+pub fn move_address_txn_index(
+    storage: &dyn Store,
+    old_head_id: Option<HashValue>,
+    new_head_id: HashValue,
+) -> Result<()> {
+    if !storage.address_txn_index_enabled() || old_head_id == Some(new_head_id) {
+        return Ok(());
+    }
+    let get_header = |block_id: HashValue| {
+        storage
+            .get_block_header_by_hash(block_id)?
+            .ok_or_else(|| format_err!("Block header {} is missing.", block_id))
+    };
+    let mut retracted = vec![];
+    let mut enacted = vec![];
+    let mut new_header = Some(get_header(new_head_id)?);
+    let mut old_header = old_head_id.map(get_header).transpose()?;
+    loop {
+        match (new_header.take(), old_header.take()) {
+            (Some(new), Some(old)) if new.id() == old.id() => break,
+            (Some(new), old)
+                if old
+                    .as_ref()
+                    .map(|old| new.number() >= old.number())
+                    .unwrap_or(true) =>
+            {
+                enacted.push(new.id());
+                new_header = if new.is_genesis() {
+                    None
+                } else {
+                    Some(get_header(new.parent_hash())?)
+                };
+                old_header = old;
+            }
+            (new, Some(old)) => {
+                retracted.push(old.id());
+                old_header = if old.is_genesis() {
+                    None
+                } else {
+                    Some(get_header(old.parent_hash())?)
+                };
+                new_header = new;
+            }
+            (_, None) => break,
+        }
+    }
+    for block_id in retracted {
+        storage.remove_address_txn_index(block_address_txn_index_entries(storage, block_id)?)?;
+    }
+    for block_id in enacted.into_iter().rev() {
+        storage.append_address_txn_index(block_address_txn_index_entries(storage, block_id)?)?;
+    }
+    Ok(())
+}
+
+/// Rebuild the address txn index from the main chain blocks of `storage`, from genesis to
+/// the startup head, used to backfill the index of the db saved without it.
+/// The node should be stopped when rebuilding. Return the number of indexed blocks.
+pub fn rebuild_address_txn_index(storage: &dyn Store) -> Result<BlockNumber> {
+    let startup_info = storage
+        .get_startup_info()?
+        .ok_or_else(|| format_err!("Startup info is missing, the db is not initialized."))?;
+    let mut block_ids = vec![];
+    let mut block_id = startup_info.main;
+    loop {
+        let header = storage
+            .get_block_header_by_hash(block_id)?
+            .ok_or_else(|| format_err!("Block header {} is missing.", block_id))?;
+        block_ids.push(block_id);
+        if header.is_genesis() {
+            break;
+        }
+        block_id = header.parent_hash();
+    }
+    block_ids.reverse();
+
+    let mut counts: HashMap<AccountAddress, u64> = HashMap::new();
+    let mut indexed_blocks: BlockNumber = 0;
+    for block_id in block_ids {
+        let mut address_txn_info_ids: BTreeMap<AccountAddress, Vec<HashValue>> = BTreeMap::new();
+        for (address, txn_info_id) in block_address_txn_index_entries(storage, block_id)? {
+            address_txn_info_ids
+                .entry(address)
+                .or_default()
+                .push(txn_info_id);
+        }
+        for (address, txn_info_ids) in address_txn_info_ids {
+            let count = counts.entry(address).or_default();
+            let start_index = *count;
+            *count = count.saturating_add(txn_info_ids.len() as u64);
+            storage.put_address_txn_index(address, start_index, txn_info_ids)?;
+        }
+        indexed_blocks = indexed_blocks.saturating_add(1);
+        if indexed_blocks.checked_rem(1000) == Some(0) {
+            info!("Address txn index rebuilt for {} blocks", indexed_blocks);
+        }
+    }
+    Ok(indexed_blocks)
+}
@@ -35,6 +35,7 @@ use starcoin_vm_types::genesis_config::ConsensusStrategy;
 use starcoin_vm_types::on_chain_resource::{Epoch, EpochData, EpochInfo, GlobalTimeOnChain};
 use starcoin_vm_types::time::TimeService;
 use starcoin_vm_types::transaction::authenticator::AuthenticationKey;
+use starcoin_vm_types::write_set::WriteSet;
 use std::cmp::min;
 use std::iter::Extend;
 use std::option::Option::{None, Some};
@@ -125,6 +126,36 @@ impl BlockChain {
         storage: Arc<dyn Store>,
         genesis_epoch: Epoch,
         genesis_block: Block,
+    ) -> Result<Self> {
+        let statedb = ChainStateDB::new(storage.clone().into_super_arc(), None);
+        Self::init_with_genesis(time_service, storage, genesis_epoch, genesis_block, statedb)
+    }
+
+    /// Init the chain with the genesis block of a fork, the block has no transaction,
+    /// and its state is already saved in the storage.
+    pub fn new_with_fork_genesis(
+        time_service: Arc<dyn TimeService>,
+        storage: Arc<dyn Store>,
+        genesis_epoch: Epoch,
+        genesis_block: Block,
+    ) -> Result<Self> {
+        ensure!(
+            genesis_block.transactions().is_empty(),
+            "The genesis block of a fork should not contain transactions."
+        );
+        let statedb = ChainStateDB::new(
+            storage.clone().into_super_arc(),
+            Some(genesis_block.header().state_root()),
+        );
+        Self::init_with_genesis(time_service, storage, genesis_epoch, genesis_block, statedb)
+    }
+
+    fn init_with_genesis(
+        time_service: Arc<dyn TimeService>,
+        storage: Arc<dyn Store>,
+        genesis_epoch: Epoch,
+        genesis_block: Block,
+        statedb: ChainStateDB,
     ) -> Result<Self> {
         debug_assert!(genesis_block.header().is_genesis());
         let txn_accumulator = MerkleAccumulator::new_empty(
@@ -133,7 +164,6 @@ impl BlockChain {
         let block_accumulator = MerkleAccumulator::new_empty(
             storage.get_accumulator_store(AccumulatorStoreType::Block),
         );
-        let statedb = ChainStateDB::new(storage.clone().into_super_arc(), None);
         let executed_block = Self::execute_block_and_save(
             storage.as_ref(),
             statedb,
@@ -348,6 +378,77 @@ impl BlockChain {
         self.connect(ExecutedBlock { block, block_info })
     }
 
+    /// Replace the head block by a block with the same parent and transactions, whose state is the
+    /// head state changed by the `write_set`, and connect it as the new head.
+    /// The new block can not be verified by executing its transactions, so only for dev and test network.
+    pub fn apply_write_set_to_head(&mut self, write_set: WriteSet) -> Result<ExecutedBlock> {
+        let head = self.head_block();
+        let head_header = head.header().clone();
+        ensure!(
+            !head_header.is_genesis(),
+            "Can not change the state of genesis block, please mine a block first."
+        );
+        let statedb = ChainStateDB::new(
+            self.storage.clone().into_super_arc(),
+            Some(head_header.state_root()),
+        );
+        statedb.apply_write_set(write_set)?;
+        let state_root = statedb.commit()?;
+        statedb.flush()?;
+        if state_root == head_header.state_root() {
+            return Ok(ExecutedBlock::new(head, self.status.status.info().clone()));
+        }
+
+        let mut new_chain = self.fork(head_header.parent_hash())?;
+        let header = head_header.as_builder().with_state_root(state_root).build();
+        let block_id = header.id();
+        let block = Block::new(header, head.body.clone());
+        let block_accumulator = info_2_accumulator(
+            new_chain
+                .status
+                .status
+                .info()
+                .get_block_accumulator_info()
+                .clone(),
+            AccumulatorStoreType::Block,
+            self.storage.as_ref(),
+        );
+        block_accumulator.append(&[block_id])?;
+        block_accumulator.flush()?;
+        let head_info = self.status.status.info();
+        let block_info = BlockInfo::new(
+            block_id,
+            head_info.total_difficulty,
+            head_info.get_txn_accumulator_info().clone(),
+            block_accumulator.get_info(),
+        );
+
+        // the new block shares the transactions, txn infos and events with the replaced head.
+        let metadata = block.to_metadata(new_chain.current_header().gas_used());
+        let mut txn_ids = vec![Transaction::BlockMetadata(metadata).id()];
+        txn_ids.extend(block.transactions().iter().map(|txn| txn.id()));
+        let txn_info_ids = self.storage.get_block_txn_info_ids(head_header.id())?;
+        let mut txn_infos = Vec::with_capacity(txn_info_ids.len());
+        for txn_info_id in &txn_info_ids {
+            let (_, txn_info): (HashValue, TransactionInfo) = self
+                .storage
+                .get_transaction_info(*txn_info_id)?
+                .ok_or_else(|| format_err!("Can not find txn info by id {}", txn_info_id))?
+                .into();
+            txn_infos.push(BlockTransactionInfo::new(block_id, txn_info));
+        }
+        self.storage
+            .save_block_txn_info_ids(block_id, txn_info_ids)?;
+        self.storage.save_transaction_infos(txn_infos)?;
+        self.storage.save_block_transaction_ids(block_id, txn_ids)?;
+        self.storage.commit_block(block.clone())?;
+        self.storage.save_block_info(block_info.clone())?;
+
+        let executed_block = new_chain.connect(ExecutedBlock { block, block_info })?;
+        *self = new_chain;
+        Ok(executed_block)
+    }
+
     //TODO consider move this logic to BlockExecutor
     fn execute_block_and_save(
         storage: &dyn Store,
@@ -0,0 +1,102 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Result};
+use crypto::HashValue;
+use starcoin_chain_api::{ChainReader, ConnectBlockError, VerifyBlockField};
+use starcoin_config::{Checkpoint, NodeConfig};
+use starcoin_types::block::{BlockHeader, BlockNumber};
+use std::collections::BTreeMap;
+
+/// The finality rules of the chain: the checkpoints the chain must contain,
+/// and the max number of main chain blocks a fork can retract.
+#[derive(Clone, Debug, Default)]
+pub struct ChainFinality {
+    checkpoints: BTreeMap<BlockNumber, HashValue>,
+    max_reorg_depth: Option<u64>,
+}
+
+impl ChainFinality {
+    pub fn new(checkpoints: Vec<Checkpoint>, max_reorg_depth: Option<u64>) -> Self {
+        Self {
+            checkpoints: checkpoints
+                .into_iter()
+                .map(|checkpoint| (checkpoint.number, checkpoint.block_id))
+                .collect(),
+            max_reorg_depth,
+        }
+    }
+
+    pub fn new_with_config(config: &NodeConfig) -> Self {
+        Self::new(config.sync.checkpoints(), config.sync.max_reorg_depth())
+    }
+
+    pub fn max_reorg_depth(&self) -> Option<u64> {
+        self.max_reorg_depth
+    }
+
+    /// The checkpoint with the max block number which is not greater than `number`.
+    pub fn latest_checkpoint(&self, number: BlockNumber) -> Option<Checkpoint> {
+        self.checkpoints
+            .range(..=number)
+            .next_back()
+            .map(|(number, block_id)| Checkpoint::new(*number, *block_id))
+    }
+
+    /// Refuse the block if the chain it connects to does not contain the checkpoint block at
+    /// every checkpoint number not greater than the block number, a chain which conflicts with a
+    /// checkpoint is refused at any height above it.
+    /// `chain` contains the parent of the block, and may contain the block itself.
+    pub fn verify_checkpoints(&self, chain: &dyn ChainReader, header: &BlockHeader) -> Result<()> {
+        for (number, block_id) in self.checkpoints.range(..=header.number()) {
+            let actual_id = if *number == header.number() {
+                Some(header.id())
+            } else {
+                chain.get_hash_by_number(*number)?
+            };
+            if actual_id != Some(*block_id) {
+                return Err(ConnectBlockError::VerifyBlockFailed(
+                    VerifyBlockField::Checkpoint,
+                    format_err!(
+                        "Block {} at number {} conflicts with checkpoint {} at number {}, the block of its chain at the checkpoint number is {:?}",
+                        header.id(),
+                        header.number(),
+                        block_id,
+                        number,
+                        actual_id
+                    ),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse the reorg from `main_head_number` to a fork whose common ancestor with main chain
+    /// is at `ancestor_number`, if it retracts more blocks than max reorg depth.
+    pub fn verify_reorg(
+        &self,
+        main_head_number: BlockNumber,
+        ancestor_number: BlockNumber,
+    ) -> Result<(), ReorgRefused> {
+        let depth = main_head_number.saturating_sub(ancestor_number);
+        match self.max_reorg_depth {
+            Some(max_reorg_depth) if depth > max_reorg_depth => Err(ReorgRefused {
+                depth,
+                max_reorg_depth,
+                ancestor_number,
+                main_head_number,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("Refuse reorg from main head {main_head_number} to fork at ancestor {ancestor_number}, reorg depth {depth} exceeds max reorg depth {max_reorg_depth}")]
+pub struct ReorgRefused {
+    pub depth: u64,
+    pub max_reorg_depth: u64,
+    pub ancestor_number: BlockNumber,
+    pub main_head_number: BlockNumber,
+}
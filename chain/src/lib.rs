@@ -1,7 +1,9 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 #![deny(clippy::integer_arithmetic)]
+pub mod address_txn_index;
 mod chain;
+pub mod finality;
 pub mod verifier;
 pub use chain::BlockChain;
 pub use starcoin_chain_api::{ChainReader, ChainWriter};
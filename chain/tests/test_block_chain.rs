@@ -30,6 +30,8 @@ fn test_chain_filter_events() {
             from_block: 1,
             to_block: 5,
             event_keys: vec![evt_key],
+            addrs: vec![],
+            type_tags: vec![],
             limit: None,
             reverse: false,
         };
@@ -46,6 +48,8 @@ fn test_chain_filter_events() {
             from_block: 1,
             to_block: 10,
             event_keys: vec![EventKey::new_from_address(&genesis_address(), 4)],
+            addrs: vec![],
+            type_tags: vec![],
             limit: Some(5),
             reverse: false,
         };
@@ -60,6 +64,8 @@ fn test_chain_filter_events() {
             from_block: 1,
             to_block: 10,
             event_keys: vec![EventKey::new_from_address(&genesis_address(), 4)],
+            addrs: vec![],
+            type_tags: vec![],
             limit: Some(5),
             reverse: true,
         };
@@ -76,6 +82,8 @@ fn test_chain_filter_events() {
             from_block: 0,
             to_block: 10,
             event_keys: vec![EventKey::new_from_address(&genesis_address(), 4)],
+            addrs: vec![],
+            type_tags: vec![],
             limit: Some(20),
             reverse: true,
         };
@@ -92,6 +100,8 @@ fn test_chain_filter_events() {
             from_block: 0,
             to_block: 20,
             event_keys: vec![EventKey::new_from_address(&genesis_address(), 4)],
+            addrs: vec![],
+            type_tags: vec![],
             limit: Some(20),
             reverse: true,
         };
@@ -3,10 +3,15 @@ use anyhow::Result;
 use jsonrpc_core_client::RpcError;
 use starcoin_rpc_api::chain::ChainClient;
 use starcoin_rpc_api::types::{
-    BlockHeaderView, BlockTransactionsView, BlockView, ChainInfoView, TransactionEventView,
-    TransactionInfoView, TransactionView,
+    BlockHeaderView, BlockTransactionsView, BlockView, ChainInfoView, GetEventOption,
+    TransactionEventView, TransactionInfoView, TransactionView,
 };
 
+/// Events are indexed with the decoded event data, so that the fields can be queried directly.
+fn decode_option() -> GetEventOption {
+    GetEventOption { decode: true }
+}
+
 pub struct BlockClient {
     node_client: ChainClient,
 }
@@ -42,7 +47,7 @@ impl BlockClient {
 
             let events: Vec<TransactionEventView> = self
                 .node_client
-                .get_events_by_txn_hash(txn_info.transaction_hash)
+                .get_events_by_txn_hash(txn_info.transaction_hash, Some(decode_option()))
                 .await?;
             txns_data.push(TransactionData {
                 info: txn_info,
@@ -59,7 +64,10 @@ impl BlockClient {
         let fetch_events_tasks = txn_infos
             .iter()
             .map(|txn_info| txn_info.transaction_hash)
-            .map(|txn_hash| self.node_client.get_events_by_txn_hash(txn_hash));
+            .map(|txn_hash| {
+                self.node_client
+                    .get_events_by_txn_hash(txn_hash, Some(decode_option()))
+            });
 
         let events = futures_util::future::try_join_all(fetch_events_tasks).await?;
 
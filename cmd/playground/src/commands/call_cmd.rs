@@ -0,0 +1,75 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_dev::playground::call_contract;
+use starcoin_rpc_api::types::{
+    AnnotatedMoveValueView, FunctionIdView, TransactionArgumentView, TypeTagView,
+};
+use structopt::StructOpt;
+
+/// Call a readonly function on the playground state.
+///  Some examples:
+///  ``` shell
+///  call --function 0x1::Block::get_current_block_number
+///  call --function 0x1::Account::balance -t 0x1::STC::STC --arg 0x726098b70ba8aa2cc172af19af8804
+///  ```
+#[derive(Debug, StructOpt)]
+#[structopt(name = "call")]
+pub struct CallOpt {
+    #[structopt(long)]
+    /// function to call, example: 0x1::Block::get_current_block_number
+    function: FunctionIdView,
+    #[structopt(
+        short = "t",
+        long = "type_tag",
+        name = "type-tag",
+        help = "can specify multi type_tag"
+    )]
+    type_tags: Option<Vec<TypeTagView>>,
+
+    #[structopt(
+        long = "arg",
+        name = "transaction-args",
+        help = "can specify multi arg"
+    )]
+    args: Option<Vec<TransactionArgumentView>>,
+}
+
+pub struct CallCommand;
+
+impl CommandAction for CallCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = CallOpt;
+    type ReturnItem = Vec<AnnotatedMoveValueView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let function_id = opt.function.0.clone();
+        let chain_state = ctx.state().chain_state();
+        let result = call_contract(
+            &chain_state,
+            function_id.module,
+            function_id.function.as_str(),
+            opt.type_tags
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|t| t.0)
+                .collect(),
+            opt.args
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| a.0)
+                .collect(),
+        )?;
+        Ok(result.into_iter().map(Into::into).collect())
+    }
+}
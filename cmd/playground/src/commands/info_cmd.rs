@@ -0,0 +1,54 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use serde::{Deserialize, Serialize};
+use starcoin_crypto::HashValue;
+use structopt::StructOpt;
+
+/// Show the info of the playground state.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "info")]
+pub struct InfoOpt {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoView {
+    pub chain_id: u8,
+    pub state_root: HashValue,
+    pub block_number: u64,
+    /// The on chain time in milliseconds.
+    pub timestamp: u64,
+    /// How many state changes can be reverted by undo.
+    pub undo_depth: usize,
+}
+
+impl InfoView {
+    pub fn new(state: &PlaygroundState) -> Result<Self> {
+        let (block_number, timestamp) = state.block_number_and_timestamp()?;
+        Ok(Self {
+            chain_id: state.chain_id().id(),
+            state_root: state.state_root(),
+            block_number,
+            timestamp,
+            undo_depth: state.undo_depth(),
+        })
+    }
+}
+
+pub struct InfoCommand;
+
+impl CommandAction for InfoCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = InfoOpt;
+    type ReturnItem = InfoView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        InfoView::new(ctx.state())
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use starcoin_crypto::HashValue;
+use starcoin_rpc_api::types::TransactionOutputView;
+
+mod call_cmd;
+mod info_cmd;
+mod publish_cmd;
+mod resource_cmd;
+mod run_cmd;
+mod step_cmd;
+mod undo_cmd;
+
+pub use call_cmd::*;
+pub use info_cmd::*;
+pub use publish_cmd::*;
+pub use resource_cmd::*;
+pub use run_cmd::*;
+pub use step_cmd::*;
+pub use undo_cmd::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecuteResultView {
+    /// The state root after execution, keep unchanged if the execution failed.
+    pub state_root: HashValue,
+    pub output: TransactionOutputView,
+}
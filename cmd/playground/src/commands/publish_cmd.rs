@@ -0,0 +1,115 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::ExecuteResultView;
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::{bail, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::temp_path;
+use starcoin_move_compiler::{
+    compile_source_string_no_report, errors, load_bytecode_file, CompiledUnit, MOVE_EXTENSION,
+};
+use starcoin_types::transaction::{Module, Package, TransactionPayload};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::association_address;
+use std::path::PathBuf;
+use stdlib::restore_stdlib_in_dir;
+use structopt::StructOpt;
+
+/// Publish a module to the playground state, the module can be a move source file or
+/// a compiled bytecode file.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "publish")]
+pub struct PublishOpt {
+    #[structopt(short = "s", long)]
+    /// hex encoded string, like 0x1, 0x12, default is the association address.
+    sender: Option<AccountAddress>,
+
+    #[structopt(
+        short = "d",
+        name = "dependency_path",
+        long = "dep",
+        help = "path of dependency used to build, support multi deps"
+    )]
+    deps: Option<Vec<String>>,
+
+    #[structopt(
+        short = "g",
+        long = "max-gas",
+        name = "max-gas-amount",
+        default_value = "10000000",
+        help = "max gas used to publish the module"
+    )]
+    max_gas_amount: u64,
+
+    #[structopt(name = "module_file", parse(from_os_str))]
+    /// move module source file or bytecode file
+    module_file: PathBuf,
+}
+
+pub struct PublishCommand;
+
+impl CommandAction for PublishCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = PublishOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let sender = opt.sender.unwrap_or_else(association_address);
+        let ext = opt
+            .module_file
+            .as_path()
+            .extension()
+            .map(|os_str| os_str.to_str().expect("file extension should is utf8 str"))
+            .unwrap_or_else(|| "");
+        let (bytecode, is_script) = if ext == MOVE_EXTENSION {
+            let temp_path = temp_path();
+            let mut deps = restore_stdlib_in_dir(temp_path.path())?;
+            // add extra deps
+            deps.append(&mut opt.deps.clone().unwrap_or_default());
+            let (sources, compile_result) = compile_source_string_no_report(
+                std::fs::read_to_string(opt.module_file.as_path())?.as_str(),
+                &deps,
+                sender,
+            )?;
+            let mut compile_units = match compile_result {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        String::from_utf8_lossy(
+                            errors::report_errors_to_color_buffer(sources, e).as_slice()
+                        )
+                    );
+                    bail!("compile error")
+                }
+            };
+            let compile_unit = compile_units
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("file should at least contain one compile unit"))?;
+            let is_script = matches!(compile_unit, CompiledUnit::Script { .. });
+            (compile_unit.serialize(), is_script)
+        } else {
+            load_bytecode_file(opt.module_file.as_path())?
+        };
+        if is_script {
+            bail!("Only support publish module, please use the run command to execute script function.");
+        }
+        let package = Package::new(vec![Module::new(bytecode)], None)?;
+        let state = ctx.state();
+        let (_, output) = state.execute_as(
+            sender,
+            &TransactionPayload::Package(package),
+            opt.max_gas_amount,
+        )?;
+        Ok(ExecuteResultView {
+            state_root: state.state_root(),
+            output: output.into(),
+        })
+    }
+}
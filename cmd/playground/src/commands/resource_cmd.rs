@@ -0,0 +1,79 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::{format_err, Result};
+use bcs_ext::BCSCodec;
+use scmd::{CommandAction, ExecContext};
+use starcoin_resource_viewer::MoveValueAnnotator;
+use starcoin_rpc_api::types::{AnnotatedMoveStructView, StrView, StructTagView};
+use starcoin_state_api::ChainStateReader;
+use starcoin_vm_types::access_path::AccessPath;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::language_storage::StructTag;
+use starcoin_vm_types::state_view::StateView;
+use std::collections::BTreeMap;
+use structopt::StructOpt;
+
+/// Show the resources of an account in the playground state.
+///  Some examples:
+///  ``` shell
+///  resource 0x1
+///  resource 0x1 0x1::Block::BlockMetadata
+///  ```
+#[derive(Debug, StructOpt)]
+#[structopt(name = "resource")]
+pub struct ResourceOpt {
+    #[structopt(name = "address")]
+    /// hex encoded account address, like 0x1
+    address: AccountAddress,
+
+    #[structopt(name = "struct-tag")]
+    /// the resource struct tag, show all resources of the account if not present.
+    struct_tag: Option<StructTagView>,
+}
+
+pub struct ResourceCommand;
+
+impl CommandAction for ResourceCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = ResourceOpt;
+    type ReturnItem = BTreeMap<StructTagView, AnnotatedMoveStructView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let chain_state = ctx.state().chain_state();
+        let annotator = MoveValueAnnotator::new(&chain_state);
+        match opt.struct_tag.as_ref() {
+            Some(struct_tag) => {
+                let data = chain_state
+                    .get(&AccessPath::resource_access_path(
+                        opt.address,
+                        struct_tag.0.clone(),
+                    ))?
+                    .ok_or_else(|| {
+                        format_err!("Can not find resource {} at {}", struct_tag, opt.address)
+                    })?;
+                let value = annotator.view_struct(struct_tag.0.clone(), data.as_slice())?;
+                let mut resources = BTreeMap::new();
+                resources.insert(struct_tag.clone(), value.into());
+                Ok(resources)
+            }
+            None => chain_state
+                .get_account_state_set(&opt.address)?
+                .and_then(|s| s.resource_set().cloned())
+                .unwrap_or_default()
+                .iter()
+                .map(|(k, v)| {
+                    let struct_tag = StructTag::decode(k.as_slice())?;
+                    let value = annotator.view_struct(struct_tag.clone(), v.as_slice())?;
+                    Ok((StrView(struct_tag), value.into()))
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::ExecuteResultView;
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::FunctionIdView;
+use starcoin_types::transaction::{
+    parse_transaction_argument, ScriptFunction, TransactionArgument, TransactionPayload,
+};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::association_address;
+use starcoin_vm_types::transaction_argument::convert_txn_args;
+use starcoin_vm_types::{language_storage::TypeTag, parser::parse_type_tag};
+use structopt::StructOpt;
+
+/// Run a script function as any sender, the signature and balance of the sender are not checked.
+///  Some examples:
+///  ``` shell
+///  run --function 0x1::TransferScripts::peer_to_peer_v2 -t 0x1::STC::STC --arg 0x726098b70ba8aa2cc172af19af8804 --arg 10000u128
+///  ```
+#[derive(Debug, StructOpt)]
+#[structopt(name = "run")]
+pub struct RunOpt {
+    #[structopt(short = "s", long)]
+    /// hex encoded string, like 0x1, 0x12, default is the association address.
+    sender: Option<AccountAddress>,
+
+    #[structopt(long = "function", name = "script-function")]
+    /// script function to execute, example: 0x1::TransferScripts::peer_to_peer_v2
+    script_function: FunctionIdView,
+
+    #[structopt(
+    short = "t",
+    long = "type_tag",
+    name = "type-tag",
+    help = "can specify multi type_tag",
+    parse(try_from_str = parse_type_tag)
+    )]
+    type_tags: Option<Vec<TypeTag>>,
+
+    #[structopt(long = "arg", name = "transaction-args", help = "can specify multi arg", parse(try_from_str = parse_transaction_argument))]
+    args: Option<Vec<TransactionArgument>>,
+
+    #[structopt(
+        short = "g",
+        long = "max-gas",
+        name = "max-gas-amount",
+        default_value = "10000000",
+        help = "max gas used to execute the script function"
+    )]
+    max_gas_amount: u64,
+}
+
+pub struct RunCommand;
+
+impl CommandAction for RunCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = RunOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let function_id = opt.script_function.0.clone();
+        let script_function = ScriptFunction::new(
+            function_id.module,
+            function_id.function,
+            opt.type_tags.clone().unwrap_or_default(),
+            convert_txn_args(&opt.args.clone().unwrap_or_default()),
+        );
+        let state = ctx.state();
+        let (_, output) = state.execute_as(
+            opt.sender.unwrap_or_else(association_address),
+            &TransactionPayload::ScriptFunction(script_function),
+            opt.max_gas_amount,
+        )?;
+        Ok(ExecuteResultView {
+            state_root: state.state_root(),
+            output: output.into(),
+        })
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::InfoView;
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use structopt::StructOpt;
+
+/// Step the playground state to new blocks, the block metadata transactions are executed
+/// like the chain, so the block number and the on chain time are advanced.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "step")]
+pub struct StepOpt {
+    #[structopt(long = "blocks", short = "b", default_value = "1")]
+    /// how many blocks to step.
+    blocks: u64,
+
+    #[structopt(long = "time", short = "t", default_value = "1")]
+    /// how long(in seconds) the time advanced per block.
+    time: u64,
+}
+
+pub struct StepCommand;
+
+impl CommandAction for StepCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = StepOpt;
+    type ReturnItem = InfoView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let state = ctx.state();
+        for _ in 0..opt.blocks {
+            state.step_block(opt.time * 1000)?;
+        }
+        InfoView::new(state)
+    }
+}
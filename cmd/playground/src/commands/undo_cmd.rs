@@ -0,0 +1,35 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::InfoView;
+use crate::{PlaygroundOpt, PlaygroundState};
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use structopt::StructOpt;
+
+/// Revert the last state changes of the playground.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "undo")]
+pub struct UndoOpt {
+    #[structopt(name = "steps", default_value = "1")]
+    /// how many state changes to revert.
+    steps: usize,
+}
+
+pub struct UndoCommand;
+
+impl CommandAction for UndoCommand {
+    type State = PlaygroundState;
+    type GlobalOpt = PlaygroundOpt;
+    type Opt = UndoOpt;
+    type ReturnItem = InfoView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let state = ctx.state();
+        state.undo(ctx.opt().steps)?;
+        InfoView::new(state)
+    }
+}
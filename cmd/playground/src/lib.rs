@@ -0,0 +1,30 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use starcoin_config::BuiltinNetworkID;
+use structopt::StructOpt;
+
+pub mod commands;
+pub mod playground_state;
+
+pub use playground_state::PlaygroundState;
+
+#[cfg(test)]
+mod tests;
+
+/// A interactive Move playground, execute transactions by the real VM on a local state.
+#[derive(Debug, Clone, StructOpt)]
+#[structopt(name = "starcoin_playground")]
+pub struct PlaygroundOpt {
+    #[structopt(long, short = "n", default_value = "dev")]
+    /// The builtin network of the local genesis state, ignored when fork from a node.
+    pub net: BuiltinNetworkID,
+
+    #[structopt(long = "fork", name = "rpc-address")]
+    /// Fork the state from a node by the websocket rpc address, example: ws://127.0.0.1:9870
+    pub fork: Option<String>,
+
+    #[structopt(long = "fork-block", name = "block-number", requires = "rpc-address")]
+    /// The block number to fork, default is the head block of the node.
+    pub fork_block: Option<u64>,
+}
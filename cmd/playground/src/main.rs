@@ -0,0 +1,37 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use scmd::CmdContext;
+use starcoin_config::{APP_VERSION, CRATE_VERSION};
+use starcoin_logger::prelude::*;
+use starcoin_playground::commands::{
+    CallCommand, InfoCommand, PublishCommand, ResourceCommand, RunCommand, StepCommand, UndoCommand,
+};
+use starcoin_playground::{PlaygroundOpt, PlaygroundState};
+
+fn run() -> Result<()> {
+    let context = CmdContext::<PlaygroundState, PlaygroundOpt>::with_initializer(
+        CRATE_VERSION,
+        Some(APP_VERSION.as_str()),
+        PlaygroundState::new,
+    );
+    context
+        .with_console_support_default()
+        .command(PublishCommand)
+        .command(RunCommand)
+        .command(CallCommand)
+        .command(ResourceCommand)
+        .command(StepCommand)
+        .command(UndoCommand)
+        .command(InfoCommand)
+        .exec()
+}
+
+/// A interactive Move playground, run `starcoin_playground console` to start the console.
+fn main() {
+    let _logger_handle = starcoin_logger::init();
+    if let Err(e) = run() {
+        error!("error: {:?}", e);
+    }
+}
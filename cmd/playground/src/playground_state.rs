@@ -0,0 +1,223 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::PlaygroundOpt;
+use anyhow::{bail, format_err, Result};
+use starcoin_config::ChainNetwork;
+use starcoin_crypto::HashValue;
+use starcoin_executor::execute_transactions;
+use starcoin_genesis::Genesis;
+use starcoin_logger::prelude::*;
+use starcoin_rpc_client::RpcClient;
+use starcoin_state_api::{AccountStateReader, ChainStateReader, ChainStateWriter};
+use starcoin_state_tree::mock::MockStateNodeStore;
+use starcoin_state_tree::{StateNode, StateNodeStore};
+use starcoin_statedb::ChainStateDB;
+use starcoin_types::block_metadata::BlockMetadata;
+use starcoin_types::transaction::{Transaction, TransactionOutput, TransactionStatus};
+use starcoin_vm_runtime::starcoin_vm::StarcoinVM;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::{association_address, genesis_address};
+use starcoin_vm_types::genesis_config::ChainId;
+use starcoin_vm_types::on_chain_resource;
+use starcoin_vm_types::transaction::TransactionPayload;
+use starcoin_vm_types::vm_status::{KeptVMStatus, VMStatus};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// A local store forked from a remote node, the state nodes missing in the local store are fetched
+/// from the remote node, verified by their hashes and saved in the local store.
+struct ForkedStateNodeStore {
+    store: MockStateNodeStore,
+    client: RpcClient,
+}
+
+impl StateNodeStore for ForkedStateNodeStore {
+    fn get(&self, hash: &HashValue) -> Result<Option<StateNode>> {
+        if let Some(node) = self.store.get(hash)? {
+            return Ok(Some(node));
+        }
+        match self.client.state_get_state_node_by_node_hash(*hash)? {
+            Some(node) => {
+                let node = StateNode(node);
+                node.verify(hash)?;
+                self.store.put(*hash, node.clone())?;
+                Ok(Some(node))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn put(&self, key: HashValue, node: StateNode) -> Result<()> {
+        self.store.put(key, node)
+    }
+
+    fn write_nodes(&self, nodes: BTreeMap<HashValue, StateNode>) -> Result<()> {
+        self.store.write_nodes(nodes)
+    }
+}
+
+/// The state of the playground, a local state forked from local genesis or a remote node.
+/// Every change of the state is committed as a new state root, and the previous roots are kept
+/// in an undo stack.
+pub struct PlaygroundState {
+    chain_id: ChainId,
+    store: Arc<dyn StateNodeStore>,
+    roots: Mutex<Vec<HashValue>>,
+}
+
+impl PlaygroundState {
+    pub fn new(opt: &PlaygroundOpt) -> Result<Self> {
+        match opt.fork.as_ref() {
+            Some(address) => Self::fork(address.as_str(), opt.fork_block),
+            None => Self::local(ChainNetwork::new_builtin(opt.net)),
+        }
+    }
+
+    /// Init the state with the genesis of the builtin network.
+    pub fn local(net: ChainNetwork) -> Result<Self> {
+        let store: Arc<dyn StateNodeStore> = Arc::new(MockStateNodeStore::new());
+        let chain_state = ChainStateDB::new(store.clone(), None);
+        let genesis_txn = Genesis::build_genesis_transaction(&net)?;
+        let txn_info = Genesis::execute_genesis_txn(&chain_state, genesis_txn)?;
+        Ok(Self {
+            chain_id: net.chain_id(),
+            store,
+            roots: Mutex::new(vec![txn_info.state_root_hash()]),
+        })
+    }
+
+    /// Fork the state of the node at `address` at the block `number`, default is the head block.
+    pub fn fork(address: &str, number: Option<u64>) -> Result<Self> {
+        let client = RpcClient::connect_websocket(address)?;
+        let chain_info = client.chain_info()?;
+        let remote_state_root = match number {
+            Some(number) => {
+                client
+                    .chain_get_block_by_number(number)?
+                    .ok_or_else(|| format_err!("Can not find block by number {}", number))?
+                    .header
+                    .state_root
+            }
+            None => chain_info.head.state_root,
+        };
+        info!("Fork state {} from {}", remote_state_root, address);
+        let store: Arc<dyn StateNodeStore> = Arc::new(ForkedStateNodeStore {
+            store: MockStateNodeStore::new(),
+            client,
+        });
+        Ok(Self {
+            chain_id: ChainId::new(chain_info.chain_id),
+            store,
+            roots: Mutex::new(vec![remote_state_root]),
+        })
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    pub fn state_root(&self) -> HashValue {
+        *self
+            .roots
+            .lock()
+            .expect("lock roots should success.")
+            .last()
+            .expect("roots should not empty.")
+    }
+
+    /// The depth of the undo stack.
+    pub fn undo_depth(&self) -> usize {
+        self.roots.lock().expect("lock roots should success.").len() - 1
+    }
+
+    pub fn chain_state(&self) -> ChainStateDB {
+        ChainStateDB::new(self.store.clone(), Some(self.state_root()))
+    }
+
+    /// Apply the output to the current state if it is executed, returns the new state root.
+    pub fn apply(&self, output: &TransactionOutput) -> Result<Option<HashValue>> {
+        if output.status() != &TransactionStatus::Keep(KeptVMStatus::Executed) {
+            return Ok(None);
+        }
+        let chain_state = self.chain_state();
+        chain_state.apply_write_set(output.write_set().clone())?;
+        let state_root = chain_state.commit()?;
+        chain_state.flush()?;
+        self.roots
+            .lock()
+            .expect("lock roots should success.")
+            .push(state_root);
+        Ok(Some(state_root))
+    }
+
+    /// Revert the last `steps` changes, returns the state root after undo.
+    pub fn undo(&self, steps: usize) -> Result<HashValue> {
+        let mut roots = self.roots.lock().expect("lock roots should success.");
+        if steps >= roots.len() {
+            bail!(
+                "Can not undo {} steps, only {} steps in history",
+                steps,
+                roots.len() - 1
+            );
+        }
+        let len = roots.len() - steps;
+        roots.truncate(len);
+        Ok(*roots.last().expect("roots should not empty."))
+    }
+
+    /// Execute the payload as `sender` without signature and balance check, and apply the output.
+    pub fn execute_as(
+        &self,
+        sender: AccountAddress,
+        payload: &TransactionPayload,
+        max_gas_amount: u64,
+    ) -> Result<(VMStatus, TransactionOutput)> {
+        let chain_state = self.chain_state();
+        let mut vm = StarcoinVM::new();
+        let (status, output) =
+            vm.execute_payload_as(&chain_state, sender, payload, max_gas_amount)?;
+        self.apply(&output)?;
+        Ok((status, output))
+    }
+
+    /// Returns the current block number and timestamp in milliseconds.
+    pub fn block_number_and_timestamp(&self) -> Result<(u64, u64)> {
+        let chain_state = self.chain_state();
+        let reader = AccountStateReader::new(&chain_state);
+        let block_metadata = reader
+            .get_resource::<on_chain_resource::BlockMetadata>(genesis_address())?
+            .ok_or_else(|| format_err!("Can not find the block metadata resource"))?;
+        let timestamp = reader.get_timestamp()?;
+        Ok((block_metadata.number, timestamp.milliseconds))
+    }
+
+    /// Execute a block metadata transaction to step to the next block, with the time advanced
+    /// by `milliseconds`.
+    pub fn step_block(&self, milliseconds: u64) -> Result<TransactionOutput> {
+        if milliseconds == 0 {
+            bail!("The time of a new block must be advanced");
+        }
+        let (number, timestamp) = self.block_number_and_timestamp()?;
+        let block_metadata = BlockMetadata::new(
+            self.state_root(),
+            timestamp + milliseconds,
+            association_address(),
+            None,
+            0,
+            number + 1,
+            self.chain_id,
+            0,
+        );
+        let output = execute_transactions(
+            &self.chain_state(),
+            vec![Transaction::BlockMetadata(block_metadata)],
+        )?
+        .pop()
+        .expect("Execute output must exist.");
+        if self.apply(&output)?.is_none() {
+            bail!("Execute block metadata failed: {:?}", output.status());
+        }
+        Ok(output)
+    }
+}
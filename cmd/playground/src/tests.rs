@@ -0,0 +1,120 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::PlaygroundState;
+use anyhow::Result;
+use starcoin_config::{BuiltinNetworkID, ChainNetwork, NodeConfig};
+use starcoin_rpc_client::RpcClient;
+use starcoin_state_api::StateReaderExt;
+use starcoin_transaction_builder::{encode_transfer_script_function, StdlibVersion};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::association_address;
+use starcoin_vm_types::transaction::authenticator::AuthenticationKey;
+use starcoin_vm_types::transaction::TransactionPayload;
+use starcoin_vm_types::vm_status::VMStatus;
+use std::sync::Arc;
+
+const MAX_GAS_AMOUNT: u64 = 10_000_000;
+
+fn local_state() -> Result<PlaygroundState> {
+    PlaygroundState::local(ChainNetwork::new_builtin(BuiltinNetworkID::Test))
+}
+
+fn transfer_payload(amount: u128) -> (AccountAddress, TransactionPayload) {
+    let auth_key = AuthenticationKey::random();
+    let receiver = auth_key.derived_address();
+    let payload = TransactionPayload::ScriptFunction(encode_transfer_script_function(
+        StdlibVersion::Latest,
+        receiver,
+        Some(auth_key),
+        amount,
+    ));
+    (receiver, payload)
+}
+
+#[stest::test]
+fn test_step_block_and_undo() -> Result<()> {
+    let state = local_state()?;
+    let genesis_root = state.state_root();
+    assert_eq!(state.undo_depth(), 0);
+    let (number, timestamp) = state.block_number_and_timestamp()?;
+    assert_eq!(number, 0);
+
+    assert!(state.step_block(0).is_err());
+    assert_eq!(state.undo_depth(), 0);
+
+    state.step_block(1000)?;
+    state.step_block(2000)?;
+    assert_eq!(state.undo_depth(), 2);
+    assert_eq!(state.block_number_and_timestamp()?, (2, timestamp + 3000));
+
+    assert!(state.undo(3).is_err());
+    assert_eq!(state.undo_depth(), 2);
+    state.undo(1)?;
+    assert_eq!(state.block_number_and_timestamp()?, (1, timestamp + 1000));
+    assert_eq!(state.undo(1)?, genesis_root);
+    assert_eq!(state.undo_depth(), 0);
+    assert_eq!(state.block_number_and_timestamp()?, (0, timestamp));
+    Ok(())
+}
+
+#[stest::test]
+fn test_execute_as() -> Result<()> {
+    let state = local_state()?;
+    let (receiver, payload) = transfer_payload(1000);
+    let (status, output) = state.execute_as(association_address(), &payload, MAX_GAS_AMOUNT)?;
+    assert_eq!(status, VMStatus::Executed);
+    assert!(output.gas_used() > 0);
+    assert_eq!(state.undo_depth(), 1);
+    assert_eq!(state.chain_state().get_balance(receiver)?, Some(1000));
+
+    // the sender has no account, the failed output is not applied.
+    let (_, payload) = transfer_payload(1000);
+    let (status, _) = state.execute_as(AccountAddress::random(), &payload, MAX_GAS_AMOUNT)?;
+    assert_ne!(status, VMStatus::Executed);
+    assert_eq!(state.undo_depth(), 1);
+
+    state.undo(1)?;
+    assert_eq!(state.chain_state().get_balance(receiver)?, None);
+    Ok(())
+}
+
+#[stest::test(timeout = 120)]
+fn test_fork() -> Result<()> {
+    let config = Arc::new(NodeConfig::random_for_test());
+    let ws_address = config
+        .rpc
+        .get_ws_address()
+        .expect("websocket rpc should be enabled");
+    let node_handle = test_helper::run_node_by_config(config)?;
+    let remote_client = RpcClient::connect_local(node_handle.rpc_service()?)?;
+    let remote_head = remote_client.chain_info()?.head;
+
+    let state = PlaygroundState::fork(ws_address.to_string().as_str(), None)?;
+    let fork_root = state.state_root();
+    assert_eq!(fork_root, remote_head.state_root);
+    assert_eq!(state.chain_id(), remote_client.chain_id()?);
+    assert_eq!(state.block_number_and_timestamp()?.0, remote_head.number.0);
+
+    let (receiver, payload) = transfer_payload(1000);
+    let (status, _) = state.execute_as(association_address(), &payload, MAX_GAS_AMOUNT)?;
+    assert_eq!(status, VMStatus::Executed);
+    assert_eq!(state.chain_state().get_balance(receiver)?, Some(1000));
+    state.step_block(1000)?;
+    assert_eq!(
+        state.block_number_and_timestamp()?.0,
+        remote_head.number.0 + 1
+    );
+
+    // the remote node is not changed.
+    assert_eq!(
+        remote_client.chain_info()?.head.block_hash,
+        remote_head.block_hash
+    );
+    assert_eq!(state.undo(2)?, fork_root);
+    assert_eq!(state.chain_state().get_balance(receiver)?, None);
+
+    remote_client.close();
+    node_handle.stop()?;
+    Ok(())
+}
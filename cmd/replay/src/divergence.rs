@@ -0,0 +1,361 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, format_err, Result};
+use forkable_jellyfish_merkle::RawKey;
+use starcoin_chain::{BlockChain, ChainReader};
+use starcoin_crypto::HashValue;
+use starcoin_state_api::{ChainStateReader, ChainStateWriter, StateView};
+use starcoin_statedb::ChainStateDB;
+use starcoin_storage::{BlockStore, BlockTransactionInfoStore, Storage};
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::block::Block;
+use starcoin_types::language_storage::StructTag;
+use starcoin_types::state_set::AccountStateSet;
+use starcoin_types::transaction::{Transaction, TransactionInfo, TransactionStatus};
+use starcoin_vm_types::access_path::{AccessPath, DataPath, ModuleName};
+use starcoin_vm_types::write_set::{WriteOp, WriteSet};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// The first transaction whose re-executed result differs from the source chain.
+pub struct Divergence {
+    pub block_number: u64,
+    pub block_id: HashValue,
+    pub txn_index: usize,
+    pub txn_hash: HashValue,
+    /// The TransactionInfo saved in the source chain.
+    pub expect: TransactionInfo,
+    /// The re-executed TransactionInfo, None if the txn is discarded by the vm.
+    pub actual: Option<TransactionInfo>,
+    pub discard_reason: Option<String>,
+    pub write_set: Vec<WriteSetDiff>,
+}
+
+/// The value at an access path written by the divergent txn, in the source chain or in the
+/// re-execution.
+pub struct WriteSetDiff {
+    pub access_path: AccessPath,
+    /// Value at the parent state root of the txn.
+    pub parent: Option<Vec<u8>>,
+    /// Value at the state root of the source txn info.
+    pub expect: Option<Vec<u8>>,
+    /// Value after the re-executed txn.
+    pub actual: Option<Vec<u8>>,
+}
+
+impl WriteSetDiff {
+    pub fn is_diff(&self) -> bool {
+        self.expect != self.actual
+    }
+}
+
+fn hex_value(value: &Option<Vec<u8>>) -> String {
+    match value {
+        Some(v) => format!("0x{}", hex::encode(v)),
+        None => "None".to_string(),
+    }
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "state divergence at block {}({}), txn index: {}, txn hash: {}",
+            self.block_number, self.block_id, self.txn_index, self.txn_hash
+        )?;
+        writeln!(
+            f,
+            "expect state_root: {}, event_root: {}, gas_used: {}, status: {:?}",
+            self.expect.state_root_hash(),
+            self.expect.event_root_hash(),
+            self.expect.gas_used(),
+            self.expect.status()
+        )?;
+        match (&self.actual, &self.discard_reason) {
+            (Some(actual), _) => writeln!(
+                f,
+                "actual state_root: {}, event_root: {}, gas_used: {}, status: {:?}",
+                actual.state_root_hash(),
+                actual.event_root_hash(),
+                actual.gas_used(),
+                actual.status()
+            )?,
+            (None, Some(reason)) => writeln!(f, "actual: txn discarded, {}", reason)?,
+            (None, None) => writeln!(f, "actual: txn is not executed")?,
+        }
+        writeln!(f, "write set:")?;
+        for diff in &self.write_set {
+            writeln!(
+                f,
+                "{} {}\n    parent: {}\n    expect: {}\n    actual: {}",
+                if diff.is_diff() { "[DIFF]" } else { "[SAME]" },
+                diff.access_path,
+                hex_value(&diff.parent),
+                hex_value(&diff.expect),
+                hex_value(&diff.actual),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Re-execute the blocks of a chain against the chain's own historical state,
+/// and compare the result of every transaction with the saved `TransactionInfo`.
+pub struct DivergenceChecker {
+    storage: Arc<Storage>,
+}
+
+impl DivergenceChecker {
+    pub fn new(storage: Arc<Storage>) -> Self {
+        Self { storage }
+    }
+
+    /// Check blocks in range [`from`, `to`] of the `chain`, return the first divergence.
+    /// The range is bisected by the state root of re-executed blocks, then the first divergent
+    /// block is checked transaction by transaction.
+    pub fn check_range(
+        &self,
+        chain: &BlockChain,
+        from: u64,
+        to: u64,
+    ) -> Result<Option<Divergence>> {
+        let mut low = from.max(1);
+        let mut high = to.min(chain.current_header().number());
+        if low > high {
+            return Ok(None);
+        }
+        // the invariant: the state after block `low - 1` is same as the source chain, and the
+        // first divergent block, if any, is in [`low`, `high`].
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.is_range_diverged(chain, low, mid)? {
+                println!("divergence found in block [{}, {}]", low, mid);
+                high = mid;
+            } else {
+                println!("block [{}, {}] checked, no divergence found.", low, mid);
+                low = mid + 1;
+            }
+        }
+        let block = block_by_number(chain, low)?;
+        self.check_block(&block)
+    }
+
+    /// Re-execute blocks in range [`from`, `to`] on the state of the source chain after block
+    /// `from - 1`, returns whether the state root after block `to` is not same as the source.
+    fn is_range_diverged(&self, chain: &BlockChain, from: u64, to: u64) -> Result<bool> {
+        let parent = block_by_number(chain, from - 1)?;
+        let statedb = ChainStateDB::new(self.storage.clone(), Some(parent.header().state_root()));
+        let mut parent_gas_used = parent.header().gas_used();
+        let mut state_root = parent.header().state_root();
+        for number in from..=to {
+            let block = block_by_number(chain, number)?;
+            let txns = block_transactions(&block, parent_gas_used);
+            for output in starcoin_executor::execute_transactions(&statedb, txns)? {
+                if let TransactionStatus::Discard(_) = output.status() {
+                    return Ok(true);
+                }
+                statedb.apply_write_set(output.write_set().clone())?;
+            }
+            state_root = statedb.commit()?;
+            parent_gas_used = block.header().gas_used();
+        }
+        Ok(state_root != block_by_number(chain, to)?.header().state_root())
+    }
+
+    /// Re-execute the `block` at its parent state root, and find the first transaction
+    /// whose state_root or event_root is not same as the source chain.
+    pub fn check_block(&self, block: &Block) -> Result<Option<Divergence>> {
+        let block_id = block.id();
+        let parent = self
+            .storage
+            .get_block_header_by_hash(block.header().parent_hash())?
+            .ok_or_else(|| format_err!("Can not find parent of block {}", block_id))?;
+        let txns = block_transactions(block, parent.gas_used());
+        let expect_infos = self.source_txn_infos(block_id)?;
+        if expect_infos.len() != txns.len() {
+            bail!(
+                "txn info num {} mismatch with txn num {} of block {}",
+                expect_infos.len(),
+                txns.len(),
+                block_id
+            );
+        }
+
+        let statedb = ChainStateDB::new(self.storage.clone(), Some(parent.state_root()));
+        let outputs = starcoin_executor::execute_transactions(&statedb, txns.clone())?;
+        let mut parent_state_root = parent.state_root();
+        for (txn_index, ((txn, output), expect)) in txns
+            .iter()
+            .zip(outputs.into_iter())
+            .zip(expect_infos.into_iter())
+            .enumerate()
+        {
+            let txn_hash = txn.id();
+            let (write_set, events, gas_used, status) = output.into_inner();
+            let divergence = |actual: Option<TransactionInfo>,
+                              discard_reason: Option<String>|
+             -> Result<Option<Divergence>> {
+                Ok(Some(Divergence {
+                    block_number: block.header().number(),
+                    block_id,
+                    txn_index,
+                    txn_hash,
+                    expect: expect.clone(),
+                    actual,
+                    discard_reason,
+                    write_set: self.diff_write_set(
+                        parent_state_root,
+                        expect.state_root_hash(),
+                        &write_set,
+                    )?,
+                }))
+            };
+            let status = match status {
+                TransactionStatus::Discard(status) => {
+                    return divergence(None, Some(format!("{:?}", status)));
+                }
+                TransactionStatus::Keep(status) => status,
+            };
+            statedb.apply_write_set(write_set.clone())?;
+            let state_root = statedb.commit()?;
+            let actual = TransactionInfo::new(txn_hash, state_root, &events, gas_used, status);
+            if actual.state_root_hash() != expect.state_root_hash()
+                || actual.event_root_hash() != expect.event_root_hash()
+            {
+                return divergence(Some(actual), None);
+            }
+            parent_state_root = state_root;
+        }
+        Ok(None)
+    }
+
+    fn source_txn_infos(&self, block_id: HashValue) -> Result<Vec<TransactionInfo>> {
+        self.storage
+            .get_block_txn_info_ids(block_id)?
+            .into_iter()
+            .map(|id| {
+                self.storage
+                    .get_transaction_info(id)?
+                    .map(|info| Into::<(_, TransactionInfo)>::into(info).1)
+                    .ok_or_else(|| format_err!("Can not find txn info {}", id))
+            })
+            .collect()
+    }
+
+    /// Diff the values of the access paths written by the re-executed txn, and the access paths
+    /// changed by the source txn in the accounts of the write set. The paths changed by the source
+    /// txn are the paths whose value changed from the parent state to the expected state.
+    fn diff_write_set(
+        &self,
+        parent_state_root: HashValue,
+        expect_state_root: HashValue,
+        write_set: &WriteSet,
+    ) -> Result<Vec<WriteSetDiff>> {
+        let parent_state = ChainStateDB::new(self.storage.clone(), Some(parent_state_root));
+        let expect_state = ChainStateDB::new(self.storage.clone(), Some(expect_state_root));
+        let mut actual_values: BTreeMap<AccessPath, Option<Vec<u8>>> = write_set
+            .iter()
+            .map(|(access_path, op)| {
+                let value = match op {
+                    WriteOp::Value(v) => Some(v.clone()),
+                    WriteOp::Deletion => None,
+                };
+                (access_path.clone(), value)
+            })
+            .collect();
+        let addresses: BTreeSet<AccountAddress> = actual_values
+            .keys()
+            .map(|access_path| access_path.address)
+            .collect();
+        for address in addresses {
+            for access_path in changed_access_paths(&parent_state, &expect_state, address)? {
+                if !actual_values.contains_key(&access_path) {
+                    let value = parent_state.get(&access_path)?;
+                    actual_values.insert(access_path, value);
+                }
+            }
+        }
+        actual_values
+            .into_iter()
+            .map(|(access_path, actual)| {
+                Ok(WriteSetDiff {
+                    parent: parent_state.get(&access_path)?,
+                    expect: expect_state.get(&access_path)?,
+                    access_path,
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The access paths of the account at `address` whose value is not same in the two states.
+fn changed_access_paths(
+    left: &ChainStateDB,
+    right: &ChainStateDB,
+    address: AccountAddress,
+) -> Result<BTreeSet<AccessPath>> {
+    let mut changed = BTreeSet::new();
+    if left.get_account_state(&address)? == right.get_account_state(&address)? {
+        return Ok(changed);
+    }
+    let mut access_paths = BTreeSet::new();
+    for state in [left, right].iter() {
+        if let Some(account_state_set) = state.get_account_state_set(&address)? {
+            access_paths.extend(account_access_paths(address, &account_state_set)?);
+        }
+    }
+    for access_path in access_paths {
+        if left.get(&access_path)? != right.get(&access_path)? {
+            changed.insert(access_path);
+        }
+    }
+    Ok(changed)
+}
+
+fn account_access_paths(
+    address: AccountAddress,
+    account_state_set: &AccountStateSet,
+) -> Result<Vec<AccessPath>> {
+    let mut access_paths = vec![];
+    if let Some(code_set) = account_state_set.code_set() {
+        for (key, _) in code_set.iter() {
+            access_paths.push(AccessPath::new(
+                address,
+                DataPath::Code(ModuleName::decode_key(key.as_slice())?),
+            ));
+        }
+    }
+    if let Some(resource_set) = account_state_set.resource_set() {
+        for (key, _) in resource_set.iter() {
+            access_paths.push(AccessPath::new(
+                address,
+                DataPath::Resource(StructTag::decode_key(key.as_slice())?),
+            ));
+        }
+    }
+    Ok(access_paths)
+}
+
+fn block_by_number(chain: &BlockChain, number: u64) -> Result<Block> {
+    chain
+        .get_block_by_number(number)?
+        .ok_or_else(|| format_err!("Can not find block by number {}", number))
+}
+
+/// The transactions of the `block`, include the block metadata transaction.
+fn block_transactions(block: &Block, parent_gas_used: u64) -> Vec<Transaction> {
+    let mut txns = vec![Transaction::BlockMetadata(
+        block.to_metadata(parent_gas_used),
+    )];
+    txns.extend(
+        block
+            .transactions()
+            .iter()
+            .cloned()
+            .map(Transaction::UserTransaction),
+    );
+    txns
+}
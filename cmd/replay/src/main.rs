@@ -1,6 +1,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::divergence::DivergenceChecker;
 use sp_utils::stop_watch::start_watch;
 use starcoin_chain::verifier::Verifier;
 use starcoin_chain::verifier::{BasicVerifier, ConsensusVerifier, FullVerifier, NoneVerifier};
@@ -17,6 +18,10 @@ use std::sync::Arc;
 use std::time::SystemTime;
 use structopt::StructOpt;
 
+mod divergence;
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "replay")]
 pub struct ReplayOpt {
@@ -32,12 +37,25 @@ pub struct ReplayOpt {
     #[structopt(long, short = "c", default_value = "20000")]
     /// Number of block.
     pub block_num: u64,
-    #[structopt(possible_values = &Verifier::variants(), case_insensitive = true)]
-    /// Verify type:  Basic, Consensus, Full, None, eg.
-    pub verifier: Verifier,
+    #[structopt(
+        possible_values = &Verifier::variants(),
+        case_insensitive = true,
+        required_unless = "compare"
+    )]
+    /// Verify type:  Basic, Consensus, Full, None, eg. Not used in compare mode.
+    pub verifier: Option<Verifier>,
     #[structopt(long, short = "w")]
     /// Watch metrics logs.
     pub watch: bool,
+    #[structopt(long)]
+    /// Compare mode: re-execute blocks of the `from` chain, bisect the blocks by the state root
+    /// to find the first divergent block, compare the state_root and event_root of every txn of
+    /// the block with the source chain's txn info, and dump the write set of the first divergent
+    /// txn. The target dir is not used in this mode.
+    pub compare: bool,
+    #[structopt(long, default_value = "1")]
+    /// The block number to start comparing from, only used in compare mode.
+    pub start_block: u64,
 }
 
 fn main() {
@@ -70,8 +88,25 @@ fn main() {
     );
     let (chain_info, _) = Genesis::init_and_check_storage(&net, storage.clone(), from_dir.as_ref())
         .expect("init storage by genesis fail.");
-    let chain = BlockChain::new(net.time_service(), chain_info.head().id(), storage)
+    let chain = BlockChain::new(net.time_service(), chain_info.head().id(), storage.clone())
         .expect("create block chain should success.");
+    if opts.compare {
+        let begin = SystemTime::now();
+        let checker = DivergenceChecker::new(storage);
+        match checker
+            .check_range(&chain, opts.start_block, block_num)
+            .expect("compare blocks should success.")
+        {
+            Some(divergence) => println!("{}", divergence),
+            None => println!(
+                "no divergence found from block {} to {}",
+                opts.start_block, block_num
+            ),
+        }
+        let use_time = SystemTime::now().duration_since(begin).unwrap();
+        println!("compare use time: {:?}", use_time.as_nanos());
+        return;
+    }
     //read from first chain
     let begin = SystemTime::now();
     let mut block_vec = vec![];
@@ -102,9 +137,12 @@ fn main() {
         storage2,
     )
     .expect("create block chain should success.");
+    let verifier = opts
+        .verifier
+        .expect("verifier is required when not in compare mode.");
     let begin = SystemTime::now();
     for block in block_vec {
-        match opts.verifier {
+        match verifier {
             Verifier::Basic => {
                 chain2.apply_with_verifier::<BasicVerifier>(block).unwrap();
             }
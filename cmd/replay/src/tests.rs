@@ -0,0 +1,94 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::divergence::DivergenceChecker;
+use anyhow::Result;
+use starcoin_account_api::AccountInfo;
+use starcoin_chain::ChainReader;
+use starcoin_chain_mock::MockChain;
+use starcoin_config::ChainNetwork;
+use starcoin_genesis::Genesis;
+use starcoin_storage::{BlockStore, BlockTransactionInfoStore, Storage};
+use starcoin_types::transaction::{BlockTransactionInfo, TransactionInfo};
+use std::sync::Arc;
+
+fn mock_chain(blocks: u64) -> Result<(MockChain, Arc<Storage>)> {
+    let net = ChainNetwork::new_test();
+    let (storage, chain_info, _) = Genesis::init_storage_for_test(&net)?;
+    let mut mock_chain = MockChain::new_with_storage(
+        net,
+        storage.clone(),
+        chain_info.head().id(),
+        AccountInfo::random(),
+    )?;
+    mock_chain.produce_and_apply_times(blocks)?;
+    Ok((mock_chain, storage))
+}
+
+#[test]
+fn test_check_range_without_divergence() -> Result<()> {
+    let (mock_chain, storage) = mock_chain(5)?;
+    let checker = DivergenceChecker::new(storage);
+    assert!(checker.check_range(mock_chain.head(), 1, 5)?.is_none());
+    // the range is clamped to the head of the chain.
+    assert!(checker.check_range(mock_chain.head(), 0, 100)?.is_none());
+    assert!(checker.check_range(mock_chain.head(), 4, 2)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_check_block_divergence() -> Result<()> {
+    let (mock_chain, storage) = mock_chain(3)?;
+    let chain = mock_chain.head();
+    let block = chain.get_block_by_number(2)?.expect("block 2 should exist");
+    let real_state_root = block.header().state_root();
+    let other_state_root = chain
+        .get_block_by_number(3)?
+        .expect("block 3 should exist")
+        .header()
+        .state_root();
+
+    // replace the txn info of the block metadata txn of block 2 with a wrong state root.
+    let block_id = block.id();
+    let mut txn_info_ids = storage.get_block_txn_info_ids(block_id)?;
+    let source_info = storage
+        .get_transaction_info(txn_info_ids[0])?
+        .expect("txn info should exist");
+    let wrong_info = TransactionInfo::new(
+        source_info.transaction_hash(),
+        other_state_root,
+        &[],
+        source_info.gas_used(),
+        source_info.status().clone(),
+    );
+    txn_info_ids[0] = wrong_info.id();
+    storage.save_transaction_infos(vec![BlockTransactionInfo::new(block_id, wrong_info)])?;
+    storage.save_block_txn_info_ids(block_id, txn_info_ids)?;
+
+    let checker = DivergenceChecker::new(storage.clone());
+    let divergence = checker
+        .check_block(&block)?
+        .expect("divergence should be found");
+    assert_eq!(divergence.block_number, 2);
+    assert_eq!(divergence.block_id, block_id);
+    assert_eq!(divergence.txn_index, 0);
+    assert_eq!(divergence.txn_hash, source_info.transaction_hash());
+    assert_eq!(divergence.expect.state_root_hash(), other_state_root);
+    let actual = divergence.actual.as_ref().expect("txn should be executed");
+    assert_eq!(actual.state_root_hash(), real_state_root);
+    assert!(divergence.discard_reason.is_none());
+
+    // every access path is in the accounts written by the block metadata txn, and the block
+    // metadata resource written by block 2 is not same as the one of block 3.
+    assert!(!divergence.write_set.is_empty());
+    assert!(divergence.write_set.iter().any(|diff| diff.is_diff()));
+    let report = divergence.to_string();
+    assert!(report.contains("[DIFF]"));
+    assert!(report.contains(&format!("{}", block_id)));
+
+    // the other blocks are not affected.
+    let block3 = chain.get_block_by_number(3)?.expect("block 3 should exist");
+    assert!(checker.check_block(&block3)?.is_none());
+    assert!(storage.get_block_header_by_hash(block_id)?.is_some());
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::format_err;
+use resource_exporter::parse_struct_tag;
+use resource_exporter::state_export::{ExportFilter, ExportFormat, StateExporter};
+use starcoin_crypto::HashValue;
+use starcoin_resource_viewer::MoveValueAnnotator;
+use starcoin_statedb::ChainStateDB;
+use starcoin_storage::db_storage::DBStorage;
+use starcoin_storage::storage::StorageInstance;
+use starcoin_storage::{BlockStore, Storage};
+use starcoin_types::language_storage::StructTag;
+use starcoin_vm_types::identifier::Identifier;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, StructOpt)]
+#[structopt(name = "state-exporter", about = "onchain state exporter")]
+pub struct StateExporterOptions {
+    #[structopt(long, short = "o", parse(from_os_str))]
+    /// output file, like state.jsonl
+    pub output: PathBuf,
+    #[structopt(long, short = "i", parse(from_os_str))]
+    /// starcoin node db path. like ~/.starcoin/barnard/starcoindb/db
+    pub db_path: PathBuf,
+
+    #[structopt(long)]
+    /// block id which snapshot at, use the head block if absent.
+    pub block_id: Option<HashValue>,
+
+    #[structopt(
+        long,
+        short = "f",
+        default_value = "jsonl",
+        possible_values = &ExportFormat::variants(),
+        case_insensitive = true
+    )]
+    /// output format.
+    pub format: ExportFormat,
+
+    #[structopt(long = "resource", short = "r", parse(try_from_str = parse_struct_tag))]
+    /// resource struct tags to export, a struct tag without type params matches all its instantiations.
+    /// export all resources and modules if both resource and module are absent.
+    pub resource_types: Vec<StructTag>,
+
+    #[structopt(long = "module", short = "m")]
+    /// module names to export.
+    pub modules: Vec<Identifier>,
+
+    #[structopt(long, parse(from_os_str))]
+    /// output the proof of every account chunk to this file, one json per line,
+    /// every proof can be verified on its own, and in order with `ChunkProofVerifier`.
+    pub proof_output: Option<PathBuf>,
+
+    #[structopt(long, default_value = "1000")]
+    /// account number of a chunk.
+    pub chunk_size: usize,
+}
+
+fn main() -> anyhow::Result<()> {
+    let option: StateExporterOptions = StateExporterOptions::from_args();
+    let storage = Storage::new(StorageInstance::new_db_instance(DBStorage::new(
+        option.db_path.display().to_string().as_str(),
+        Default::default(),
+    )?))?;
+    let storage = Arc::new(storage);
+    let block_id = match option.block_id {
+        Some(block_id) => block_id,
+        None => {
+            storage
+                .get_startup_info()?
+                .ok_or_else(|| format_err!("startup info not exist"))?
+                .main
+        }
+    };
+    let block = storage
+        .get_block(block_id)?
+        .ok_or_else(|| format_err!("block {} not exist", block_id))?;
+    let state_root = block.header.state_root();
+
+    let statedb = ChainStateDB::new(storage.clone(), Some(state_root));
+    let annotator = MoveValueAnnotator::new(&statedb);
+    let exporter = StateExporter::new(
+        storage,
+        state_root,
+        ExportFilter {
+            resource_types: option.resource_types,
+            modules: option.modules,
+        },
+        option.chunk_size,
+    );
+    let output = BufWriter::new(File::create(option.output.as_path())?);
+    let mut proof_output = option
+        .proof_output
+        .as_ref()
+        .map(|path| File::create(path).map(BufWriter::new))
+        .transpose()?;
+    let summary = exporter.export(
+        &annotator,
+        option.format,
+        output,
+        proof_output.as_mut().map(|w| w as &mut dyn Write),
+    )?;
+    if let Some(mut proof_output) = proof_output {
+        proof_output.flush()?;
+    }
+    for skipped in &summary.skipped_resources {
+        eprintln!("skip undecodable resource {}", skipped);
+    }
+    println!(
+        "export {} accounts at block {}, state root: {}, skip {} undecodable resources",
+        summary.account_count,
+        block_id,
+        state_root,
+        summary.skipped_resources.len()
+    );
+    Ok(())
+}
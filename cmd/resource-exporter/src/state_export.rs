@@ -0,0 +1,420 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export the whole state, or a filtered set of resources and modules, of all accounts
+//! at a block, with the decoded resource values.
+
+use crate::MoveStruct;
+use anyhow::{bail, ensure, format_err, Result};
+use bcs_ext::BCSCodec;
+use forkable_jellyfish_merkle::blob::Blob;
+use forkable_jellyfish_merkle::proof::{SparseMerkleProof, SparseMerkleRangeProof};
+use forkable_jellyfish_merkle::RawKey;
+use serde::{Deserialize, Serialize};
+use starcoin_crypto::{hash::PlainCryptoHash, HashValue};
+use starcoin_resource_viewer::MoveValueAnnotator;
+use starcoin_state_tree::{StateNodeStore, StateTree};
+use starcoin_types::access_path::DataType;
+use starcoin_types::account_state::AccountState;
+use starcoin_types::language_storage::StructTag;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::identifier::Identifier;
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Jsonl,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn variants() -> [&'static str; 2] {
+        ["jsonl", "csv"]
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportFormat::Jsonl => write!(f, "jsonl"),
+            ExportFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jsonl" => Ok(ExportFormat::Jsonl),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => bail!("Unsupported export format: {}", s),
+        }
+    }
+}
+
+/// Which resources and modules of an account to export.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+    /// Resource types to export, a struct tag without type params matches all its instantiations.
+    /// Export all resources if empty and `modules` is empty too.
+    pub resource_types: Vec<StructTag>,
+    /// Module names to export.
+    /// Export all modules if empty and `resource_types` is empty too.
+    pub modules: Vec<Identifier>,
+}
+
+impl ExportFilter {
+    fn is_empty(&self) -> bool {
+        self.resource_types.is_empty() && self.modules.is_empty()
+    }
+
+    pub fn match_resource(&self, struct_tag: &StructTag) -> bool {
+        self.is_empty()
+            || self.resource_types.iter().any(|filter| {
+                filter == struct_tag
+                    || (filter.type_params.is_empty()
+                        && filter.address == struct_tag.address
+                        && filter.module == struct_tag.module
+                        && filter.name == struct_tag.name)
+            })
+    }
+
+    pub fn match_module(&self, name: &Identifier) -> bool {
+        self.is_empty() || self.modules.contains(name)
+    }
+}
+
+/// The exported state of an account.
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountStateRecord {
+    pub address: AccountAddress,
+    /// resource struct tag -> decoded resource value.
+    pub resources: BTreeMap<String, serde_json::Value>,
+    /// module name -> hex encoded bytecode.
+    pub modules: BTreeMap<String, String>,
+}
+
+/// A resource which can not be decoded, it is skipped in the export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedResource {
+    pub address: AccountAddress,
+    pub resource_type: String,
+    pub error: String,
+}
+
+impl Display for SkippedResource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of account {}: {}",
+            self.resource_type, self.address, self.error
+        )
+    }
+}
+
+/// The result of an export.
+#[derive(Debug, Clone, Default)]
+pub struct ExportSummary {
+    /// The number of exported accounts.
+    pub account_count: u64,
+    pub skipped_resources: Vec<SkippedResource>,
+}
+
+/// The proof of a chunk of exported accounts, it shows the accounts from `first_address` to
+/// `last_address` are all the accounts after the last account of the previous chunk, up to
+/// `last_address`, in the state tree of `state_root`.
+/// A chunk proof can be verified on its own, without the chunks before it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkProof {
+    pub chunk_index: u64,
+    pub state_root: HashValue,
+    pub first_address: AccountAddress,
+    pub last_address: AccountAddress,
+    /// The key hash of `last_address` in the state tree.
+    pub last_key_hash: HashValue,
+    /// The hash of the raw account state blob of every account in the chunk, in the order of the
+    /// state tree, including the accounts filtered out or without anything to export.
+    pub account_state_hashes: Vec<(AccountAddress, HashValue)>,
+    /// The inclusion proof of the last account of the previous chunk, it covers the accounts
+    /// before the chunk. `None` for the first chunk.
+    pub previous_proof: Option<SparseMerkleProof>,
+    /// The range proof of `last_address`, it covers the accounts after the chunk.
+    pub proof: SparseMerkleRangeProof,
+}
+
+impl ChunkProof {
+    pub fn verify(&self) -> Result<()> {
+        ensure!(
+            self.account_state_hashes
+                .first()
+                .map(|(address, _)| address)
+                == Some(&self.first_address)
+                && self.account_state_hashes.last().map(|(address, _)| address)
+                    == Some(&self.last_address)
+                && self.last_address.key_hash() == self.last_key_hash,
+            "Accounts of chunk {} mismatch with its first and last address",
+            self.chunk_index
+        );
+        let mut leaves = vec![];
+        if let Some(previous_proof) = self.previous_proof.as_ref() {
+            leaves.push(previous_proof.leaf().ok_or_else(|| {
+                format_err!(
+                    "Proof of the previous account of chunk {} is not an inclusion proof",
+                    self.chunk_index
+                )
+            })?);
+        }
+        leaves.extend(
+            self.account_state_hashes
+                .iter()
+                .map(|(address, hash)| (address.key_hash(), *hash)),
+        );
+        match self.previous_proof.as_ref() {
+            Some(previous_proof) => {
+                self.proof
+                    .verify_chunk(self.state_root, previous_proof, leaves.as_slice())
+            }
+            None => self.proof.verify(self.state_root, leaves.as_slice()),
+        }
+    }
+
+    /// The key hash of the last account of the previous chunk.
+    pub fn previous_key_hash(&self) -> Option<HashValue> {
+        self.previous_proof
+            .as_ref()
+            .and_then(|proof| proof.leaf())
+            .map(|(key, _)| key)
+    }
+}
+
+/// Verify the chunk proofs of an export in order, every chunk is verified on its own and must
+/// follow the previous chunk at the same state root, so only the last chunk is kept.
+#[derive(Debug, Default)]
+pub struct ChunkProofVerifier {
+    next_chunk_index: u64,
+    state_root: Option<HashValue>,
+    last_key_hash: Option<HashValue>,
+}
+
+impl ChunkProofVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verify(&mut self, chunk_proof: &ChunkProof) -> Result<()> {
+        ensure!(
+            chunk_proof.chunk_index == self.next_chunk_index,
+            "Expect chunk {}, but got chunk {}",
+            self.next_chunk_index,
+            chunk_proof.chunk_index
+        );
+        ensure!(
+            self.state_root.is_none() || self.state_root == Some(chunk_proof.state_root),
+            "State root of chunk {} mismatch with the previous chunks",
+            chunk_proof.chunk_index
+        );
+        ensure!(
+            chunk_proof.previous_key_hash() == self.last_key_hash,
+            "Chunk {} does not follow the previous chunk",
+            chunk_proof.chunk_index
+        );
+        chunk_proof.verify()?;
+        self.state_root = Some(chunk_proof.state_root);
+        self.last_key_hash = Some(chunk_proof.last_key_hash);
+        self.next_chunk_index += 1;
+        Ok(())
+    }
+}
+
+pub struct StateExporter {
+    storage: Arc<dyn StateNodeStore>,
+    state_root: HashValue,
+    filter: ExportFilter,
+    chunk_size: usize,
+}
+
+impl StateExporter {
+    pub fn new(
+        storage: Arc<dyn StateNodeStore>,
+        state_root: HashValue,
+        filter: ExportFilter,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            storage,
+            state_root,
+            filter,
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    /// Export the accounts state to `output` in `format`,
+    /// and the proof of every account chunk to `proof_output` if present.
+    /// The resources which can not be decoded are skipped and reported in the summary.
+    pub fn export<W: Write>(
+        &self,
+        annotator: &MoveValueAnnotator,
+        format: ExportFormat,
+        output: W,
+        mut proof_output: Option<&mut dyn Write>,
+    ) -> Result<ExportSummary> {
+        let state_tree =
+            StateTree::<AccountAddress>::new(self.storage.clone(), Some(self.state_root));
+        let mut writer = RecordWriter::new(format, output)?;
+        let mut start_after = None;
+        let mut chunk_index = 0u64;
+        let mut summary = ExportSummary::default();
+        let mut previous_address = None;
+        loop {
+            let chunk = state_tree.dump_range(start_after, self.chunk_size)?;
+            let (first_address, last_address) = match (chunk.first(), chunk.last()) {
+                (Some((first, _)), Some((last, _))) => (*first, *last),
+                _ => break,
+            };
+            let mut account_state_hashes = Vec::with_capacity(chunk.len());
+            for (address, account_state_bytes) in chunk.iter() {
+                account_state_hashes.push((
+                    *address,
+                    Blob::from(account_state_bytes.clone()).crypto_hash(),
+                ));
+                let account_state: AccountState = account_state_bytes.as_slice().try_into()?;
+                let record = self.export_account(
+                    annotator,
+                    *address,
+                    &account_state,
+                    &mut summary.skipped_resources,
+                )?;
+                if !record.resources.is_empty() || !record.modules.is_empty() {
+                    writer.write(&record)?;
+                    summary.account_count += 1;
+                }
+            }
+            if let Some(proof_output) = proof_output.as_mut() {
+                let chunk_proof = ChunkProof {
+                    chunk_index,
+                    state_root: self.state_root,
+                    first_address,
+                    last_address,
+                    last_key_hash: last_address.key_hash(),
+                    account_state_hashes,
+                    previous_proof: previous_address
+                        .map(|address| state_tree.get_with_proof(&address).map(|(_, proof)| proof))
+                        .transpose()?,
+                    proof: state_tree.get_range_proof(&last_address)?,
+                };
+                writeln!(proof_output, "{}", serde_json::to_string(&chunk_proof)?)?;
+            }
+            start_after = Some(last_address.key_hash());
+            previous_address = Some(last_address);
+            chunk_index += 1;
+        }
+        writer.flush()?;
+        Ok(summary)
+    }
+
+    fn export_account(
+        &self,
+        annotator: &MoveValueAnnotator,
+        address: AccountAddress,
+        account_state: &AccountState,
+        skipped_resources: &mut Vec<SkippedResource>,
+    ) -> Result<AccountStateRecord> {
+        let mut resources = BTreeMap::new();
+        if let Some(root) = account_state.storage_roots()[DataType::RESOURCE.storage_index()] {
+            let resource_tree = StateTree::<StructTag>::new(self.storage.clone(), Some(root));
+            for (key, value) in resource_tree.dump()?.iter() {
+                let struct_tag = StructTag::decode(key.as_slice())?;
+                if !self.filter.match_resource(&struct_tag) {
+                    continue;
+                }
+                let annotated = match annotator.view_struct(struct_tag.clone(), value.as_slice()) {
+                    Ok(annotated) => annotated,
+                    Err(e) => {
+                        skipped_resources.push(SkippedResource {
+                            address,
+                            resource_type: struct_tag.to_string(),
+                            error: e.to_string(),
+                        });
+                        continue;
+                    }
+                };
+                resources.insert(
+                    struct_tag.to_string(),
+                    serde_json::to_value(MoveStruct(annotated))?,
+                );
+            }
+        }
+        let mut modules = BTreeMap::new();
+        if let Some(root) = account_state.storage_roots()[DataType::CODE.storage_index()] {
+            let code_tree = StateTree::<Identifier>::new(self.storage.clone(), Some(root));
+            for (key, value) in code_tree.dump()?.iter() {
+                let name = Identifier::decode(key.as_slice())?;
+                if !self.filter.match_module(&name) {
+                    continue;
+                }
+                modules.insert(name.to_string(), hex::encode(value));
+            }
+        }
+        Ok(AccountStateRecord {
+            address,
+            resources,
+            modules,
+        })
+    }
+}
+
+enum RecordWriter<W: Write> {
+    Jsonl(W),
+    Csv(csv::Writer<W>),
+}
+
+impl<W: Write> RecordWriter<W> {
+    fn new(format: ExportFormat, output: W) -> Result<Self> {
+        Ok(match format {
+            ExportFormat::Jsonl => RecordWriter::Jsonl(output),
+            ExportFormat::Csv => {
+                let mut writer = csv::WriterBuilder::new().from_writer(output);
+                writer.write_record(&["address", "kind", "name", "value"])?;
+                RecordWriter::Csv(writer)
+            }
+        })
+    }
+
+    fn write(&mut self, record: &AccountStateRecord) -> Result<()> {
+        match self {
+            RecordWriter::Jsonl(w) => {
+                writeln!(w, "{}", serde_json::to_string(record)?)?;
+            }
+            RecordWriter::Csv(w) => {
+                let address = record.address.to_string();
+                for (name, value) in &record.resources {
+                    w.write_record(&[
+                        address.as_str(),
+                        "resource",
+                        name.as_str(),
+                        serde_json::to_string(value)?.as_str(),
+                    ])?;
+                }
+                for (name, code) in &record.modules {
+                    w.write_record(&[address.as_str(), "module", name.as_str(), code.as_str()])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            RecordWriter::Jsonl(w) => w.flush()?,
+            RecordWriter::Csv(w) => w.flush()?,
+        }
+        Ok(())
+    }
+}
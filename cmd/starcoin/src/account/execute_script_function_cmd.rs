@@ -6,11 +6,12 @@ use crate::StarcoinOpt;
 use anyhow::{format_err, Result};
 use scmd::{CommandAction, ExecContext};
 use starcoin_crypto::hash::HashValue;
+use starcoin_resource_viewer::json_arg::JsonArgEncoder;
 use starcoin_rpc_api::types::FunctionIdView;
 use starcoin_rpc_client::RemoteStateReader;
 use starcoin_state_api::AccountStateReader;
 use starcoin_types::transaction::{
-    parse_transaction_argument, RawUserTransaction, TransactionArgument,
+    parse_transaction_argument, FeePayerRawTransaction, RawUserTransaction, TransactionArgument,
 };
 use starcoin_vm_types::account_address::AccountAddress;
 use starcoin_vm_types::transaction::ScriptFunction;
@@ -42,6 +43,16 @@ pub struct ExecuteScriptFunctionOpt {
     /// args for the script.
     args: Option<Vec<TransactionArgument>>,
 
+    #[structopt(
+        long = "json-arg",
+        name = "json-args",
+        parse(try_from_str = serde_json::from_str),
+        conflicts_with = "transaction-args"
+    )]
+    /// args for the script in JSON, encoded by the function signature on chain,
+    /// like '"0x1"', '[1, 2]', '{"value": 1}'.
+    json_args: Option<Vec<serde_json::Value>>,
+
     #[structopt(
         name = "expiration_time",
         long = "timeout",
@@ -66,6 +77,10 @@ pub struct ExecuteScriptFunctionOpt {
     )]
     gas_price: u64,
 
+    #[structopt(long = "fee-payer")]
+    /// the account which pays the gas, both the sender and the fee payer should be unlocked in the wallet.
+    fee_payer: Option<AccountAddress>,
+
     #[structopt(
         short = "b",
         name = "blocking-mode",
@@ -101,8 +116,16 @@ impl CommandAction for ExecuteScriptFunctionCmd {
         let expiration_time = opt.expiration_time + node_info.now_seconds;
 
         let type_tags = opt.type_tags.clone().unwrap_or_default();
-        let args = opt.args.clone().unwrap_or_default();
         let script_function = opt.script_function.clone().0;
+        let args = match opt.json_args.as_ref() {
+            Some(json_args) => JsonArgEncoder::new(&chain_state_reader).encode_function_args(
+                &script_function.module,
+                script_function.function.as_ident_str(),
+                type_tags.as_slice(),
+                json_args.as_slice(),
+            )?,
+            None => convert_txn_args(&opt.args.clone().unwrap_or_default()),
+        };
         let script_txn = RawUserTransaction::new_script_function(
             sender.address,
             account_resource.sequence_number(),
@@ -110,7 +133,7 @@ impl CommandAction for ExecuteScriptFunctionCmd {
                 script_function.module,
                 script_function.function,
                 type_tags,
-                convert_txn_args(&args),
+                args,
             ),
             opt.max_gas_amount,
             opt.gas_price,
@@ -118,7 +141,17 @@ impl CommandAction for ExecuteScriptFunctionCmd {
             ctx.state().net().chain_id(),
         );
 
-        let signed_txn = client.account_sign_txn(script_txn)?;
+        let signed_txn = match opt.fee_payer {
+            Some(fee_payer) => {
+                let txn = FeePayerRawTransaction::new(script_txn, fee_payer);
+                let sender_signature =
+                    client.account_sign_fee_payer_txn(txn.clone(), sender.address)?;
+                let fee_payer_signature =
+                    client.account_sign_fee_payer_txn(txn.clone(), fee_payer)?;
+                sender_signature.build_fee_payer_transaction(txn, fee_payer_signature)?
+            }
+            None => client.account_sign_txn(script_txn)?,
+        };
         let txn_hash = signed_txn.id();
         client.submit_transaction(signed_txn)?;
         println!("txn {:#x} submitted.", txn_hash);
@@ -6,6 +6,7 @@ use crate::StarcoinOpt;
 use anyhow::{bail, Result};
 use scmd::{CommandAction, ExecContext};
 use starcoin_account_api::{AccountInfo, AccountPrivateKey};
+use starcoin_crypto::secp256k1::Secp256k1PrivateKey;
 use starcoin_crypto::{ValidCryptoMaterial, ValidCryptoMaterialStringExt};
 use starcoin_vm_types::account_address::AccountAddress;
 use std::path::PathBuf;
@@ -28,6 +29,10 @@ pub struct ImportOpt {
     )]
     from_file: Option<PathBuf>,
 
+    /// the private key is a raw secp256k1 private key, such as an ethereum private key.
+    #[structopt(long = "secp256k1")]
+    secp256k1: bool,
+
     /// if account_address is absent, generate address by public_key.
     #[structopt(name = "account_address")]
     account_address: Option<AccountAddress>,
@@ -48,16 +53,19 @@ impl CommandAction for ImportCommand {
         let client = ctx.state().client();
         let opt: &ImportOpt = ctx.opt();
 
-        let private_key = match (opt.from_input.as_ref(), opt.from_file.as_ref()) {
-            (Some(p), _) => AccountPrivateKey::from_encoded_string(p)?,
-            (None, Some(p)) => {
-                let data = std::fs::read_to_string(p)?;
-                AccountPrivateKey::from_encoded_string(data.as_str())?
-            }
+        let encoded_key = match (opt.from_input.as_ref(), opt.from_file.as_ref()) {
+            (Some(p), _) => p.clone(),
+            (None, Some(p)) => std::fs::read_to_string(p)?,
             (None, None) => {
                 bail!("private key should be specified, use one of <input>, <from-file>")
             }
         };
+        let encoded_key = encoded_key.trim();
+        let private_key: AccountPrivateKey = if opt.secp256k1 {
+            Secp256k1PrivateKey::from_encoded_string(encoded_key)?.into()
+        } else {
+            AccountPrivateKey::from_encoded_string(encoded_key)?
+        };
 
         let address = opt
             .account_address
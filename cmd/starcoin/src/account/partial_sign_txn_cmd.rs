@@ -4,7 +4,7 @@
 use crate::cli_state::CliState;
 use crate::mutlisig_transaction::MultisigTransaction;
 use crate::StarcoinOpt;
-use anyhow::{ensure, format_err, Result};
+use anyhow::{bail, ensure, format_err, Result};
 use scmd::{CommandAction, ExecContext};
 use short_hex_str::AsShortHexStr;
 use starcoin_crypto::hash::PlainCryptoHash;
@@ -83,8 +83,10 @@ impl CommandAction for PartialSignTxnCommand {
                 public_key,
                 signature,
             } => (public_key, signature),
-            transaction::authenticator::TransactionAuthenticator::MultiEd25519 { .. } => {
-                unreachable!()
+            transaction::authenticator::TransactionAuthenticator::MultiEd25519 { .. }
+            | transaction::authenticator::TransactionAuthenticator::Secp256k1 { .. }
+            | transaction::authenticator::TransactionAuthenticator::FeePayer { .. } => {
+                bail!("partial sign only supports multisig accounts")
             }
         };
         txn.collect_signature(signer_public_key, signer_signature);
@@ -31,7 +31,7 @@ impl CommandAction for GetEventsCommand {
     ) -> Result<Self::ReturnItem> {
         let client = ctx.state().client();
         let opt = ctx.opt();
-        let events = client.chain_get_events_by_txn_hash(opt.hash)?;
+        let events = client.chain_get_events_by_txn_hash(opt.hash, None)?;
         let events = events.into_iter().map(|e| e.into()).collect::<Vec<_>>();
         Ok(events)
     }
@@ -0,0 +1,52 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::StarcoinOpt;
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::{AddressTransactionInfoView, GetTransactionsByAddressOption};
+use starcoin_types::account_address::AccountAddress;
+use structopt::StructOpt;
+
+/// Get the transaction infos sent by or affecting the address, require the node enables the address txn index.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "get_txn_by_address")]
+pub struct GetTxnByAddressOpt {
+    #[structopt(name = "address")]
+    address: AccountAddress,
+    #[structopt(long = "start-index")]
+    /// The index of the address transaction to start from.
+    start_index: Option<u64>,
+    #[structopt(long, short = "r")]
+    /// Query from the latest transaction to the older ones.
+    reverse: bool,
+    #[structopt(long = "max-size", short = "n")]
+    /// The max number of transactions to return.
+    max_size: Option<u64>,
+}
+
+pub struct GetTxnByAddressCommand;
+
+impl CommandAction for GetTxnByAddressCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = GetTxnByAddressOpt;
+    type ReturnItem = Vec<AddressTransactionInfoView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let client = ctx.state().client();
+        let opt = ctx.opt();
+        client.chain_get_transactions_by_address(
+            opt.address,
+            Some(GetTransactionsByAddressOption {
+                start_index: opt.start_index,
+                reverse: opt.reverse,
+                max_size: opt.max_size,
+            }),
+        )
+    }
+}
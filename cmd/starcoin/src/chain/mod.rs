@@ -7,6 +7,7 @@ mod get_block_cmd;
 mod get_epoch_info_by_number;
 mod get_events_cmd;
 mod get_global_time_by_number;
+mod get_txn_by_address_cmd;
 mod get_txn_by_block_cmd;
 mod get_txn_cmd;
 mod get_txn_info_cmd;
@@ -23,6 +24,7 @@ pub use get_block_cmd::*;
 pub use get_epoch_info_by_number::*;
 pub use get_events_cmd::*;
 pub use get_global_time_by_number::*;
+pub use get_txn_by_address_cmd::*;
 pub use get_txn_by_block_cmd::*;
 pub use get_txn_cmd::*;
 pub use get_txn_info_cmd::*;
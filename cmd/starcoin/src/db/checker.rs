@@ -0,0 +1,445 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Result};
+use serde::Serialize;
+use starcoin_accumulator::accumulator_info::AccumulatorInfo;
+use starcoin_accumulator::inmemory::InMemoryAccumulator;
+use starcoin_accumulator::node::AccumulatorStoreType;
+use starcoin_accumulator::{Accumulator, MerkleAccumulator};
+use starcoin_crypto::hash::CryptoHash;
+use starcoin_crypto::HashValue;
+use starcoin_logger::prelude::*;
+use starcoin_state_tree::{StateNodeStore, StateTree};
+use starcoin_storage::block_info::BlockInfoStore;
+use starcoin_storage::{BlockStore, BlockTransactionInfoStore, ContractEventStore, Storage, Store};
+use starcoin_types::access_path::DataType;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::account_state::AccountState;
+use starcoin_types::block::{Block, BlockHeader, BlockIdAndNumber, BlockInfo, BlockNumber};
+use starcoin_types::language_storage::StructTag;
+use starcoin_vm_types::identifier::Identifier;
+use std::convert::TryInto;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    MissingHeader,
+    MissingBlock,
+    MissingBlockInfo,
+    BlockAccumulator,
+    TxnAccumulator,
+    MissingTxnInfo,
+    MissingEvents,
+    EventRoot,
+    MissingStateNode,
+}
+
+/// An inconsistency found in the database.
+#[derive(Clone, Debug, Serialize)]
+pub struct Issue {
+    pub number: BlockNumber,
+    pub block_id: HashValue,
+    pub kind: IssueKind,
+    pub message: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "block {}({}) {:?}: {}",
+            self.number, self.block_id, self.kind, self.message
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckReport {
+    pub head_number: BlockNumber,
+    pub head_id: HashValue,
+    pub start_number: BlockNumber,
+    pub end_number: BlockNumber,
+    pub checked_blocks: u64,
+    pub checked_txns: u64,
+    /// Number of blocks whose whole state tree is walked.
+    pub walked_states: u64,
+    /// The last block before the first issue, the chain can be repaired from it.
+    pub last_good_number: Option<BlockNumber>,
+    pub last_good_id: Option<HashValue>,
+    pub issues: Vec<Issue>,
+}
+
+impl CheckReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CheckOptions {
+    pub start_number: BlockNumber,
+    /// Check to the main head if absent.
+    pub end_number: Option<BlockNumber>,
+    /// Walk the whole state tree of every `state_sample` blocks, 0 means never.
+    pub state_sample: u64,
+    /// Walk the whole state tree of the last checked block.
+    pub full_state: bool,
+    /// Stop checking once so many issues found.
+    pub max_issues: usize,
+}
+
+/// Check the main chain of a database from the `StartupInfo`: the block and txn accumulators,
+/// the txn infos and events referenced by every block, and the state trees.
+pub struct DbChecker {
+    storage: Arc<Storage>,
+    options: CheckOptions,
+}
+
+impl DbChecker {
+    pub fn new(storage: Arc<Storage>, options: CheckOptions) -> Self {
+        Self { storage, options }
+    }
+
+    /// The main chain blocks in range [start_number, end_number], walked back from the head,
+    /// and the issue if the chain is broken by a missing header.
+    fn main_chain_blocks(
+        &self,
+        head: &BlockHeader,
+        end_number: BlockNumber,
+    ) -> Result<(Vec<BlockIdAndNumber>, Option<Issue>)> {
+        let mut blocks = vec![];
+        let mut block_id = head.id();
+        let mut number = head.number();
+        let mut header_issue = None;
+        loop {
+            let header = match self.storage.get_block_header_by_hash(block_id)? {
+                Some(header) => header,
+                None => {
+                    header_issue = Some(Issue {
+                        number,
+                        block_id,
+                        kind: IssueKind::MissingHeader,
+                        message: "block header is missing, blocks before it can not be checked"
+                            .to_string(),
+                    });
+                    break;
+                }
+            };
+            if header.number() <= end_number {
+                blocks.push(BlockIdAndNumber::new(block_id, header.number()));
+            }
+            if header.number() <= self.options.start_number || header.is_genesis() {
+                break;
+            }
+            block_id = header.parent_hash();
+            number = header.number().saturating_sub(1);
+        }
+        blocks.reverse();
+        Ok((blocks, header_issue))
+    }
+
+    pub fn check(&self) -> Result<CheckReport> {
+        let startup_info = self
+            .storage
+            .get_startup_info()?
+            .ok_or_else(|| format_err!("Startup info is missing, the db is not initialized."))?;
+        let head_id = startup_info.main;
+        let head = self
+            .storage
+            .get_block_header_by_hash(head_id)?
+            .ok_or_else(|| format_err!("Main head block header {} is missing.", head_id))?;
+        let head_info = self
+            .storage
+            .get_block_info(head_id)?
+            .ok_or_else(|| format_err!("Main head block info {} is missing.", head_id))?;
+        let end_number = self
+            .options
+            .end_number
+            .map(|number| number.min(head.number()))
+            .unwrap_or_else(|| head.number());
+        let mut report = CheckReport {
+            head_number: head.number(),
+            head_id,
+            start_number: self.options.start_number,
+            end_number,
+            checked_blocks: 0,
+            checked_txns: 0,
+            walked_states: 0,
+            last_good_number: None,
+            last_good_id: None,
+            issues: vec![],
+        };
+
+        let (blocks, header_issue) = self.main_chain_blocks(&head, end_number)?;
+        if let Some(issue) = header_issue {
+            report.issues.push(issue);
+        }
+        let block_accumulator = MerkleAccumulator::new_with_info(
+            head_info.block_accumulator_info,
+            self.storage
+                .get_accumulator_store(AccumulatorStoreType::Block),
+        );
+        let mut parent_info = match blocks.first() {
+            Some(first) if first.number > 0 => {
+                match self.storage.get_block_header_by_hash(first.id)? {
+                    Some(header) => self.storage.get_block_info(header.parent_hash())?,
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+        let last_index = blocks.len().saturating_sub(1);
+        for (index, block_id_and_number) in blocks.iter().enumerate() {
+            if report.issues.len() >= self.options.max_issues {
+                break;
+            }
+            let block = match self.storage.get_block(block_id_and_number.id)? {
+                Some(block) => block,
+                None => {
+                    report.issues.push(Issue {
+                        number: block_id_and_number.number,
+                        block_id: block_id_and_number.id,
+                        kind: IssueKind::MissingBlock,
+                        message: "block body is missing".to_string(),
+                    });
+                    parent_info = None;
+                    continue;
+                }
+            };
+            let walk_state = (self.options.state_sample > 0
+                && block_id_and_number.number % self.options.state_sample == 0)
+                || (self.options.full_state && index == last_index);
+            let (block_info, issues) =
+                self.check_block(&block, parent_info.as_ref(), &block_accumulator, walk_state)?;
+            if issues.is_empty() && report.issues.is_empty() {
+                report.last_good_number = Some(block_id_and_number.number);
+                report.last_good_id = Some(block_id_and_number.id);
+            }
+            report.issues.extend(issues);
+            report.checked_txns = report
+                .checked_txns
+                .saturating_add(block.transactions().len() as u64);
+            if walk_state {
+                report.walked_states += 1;
+            }
+            report.checked_blocks += 1;
+            if report.checked_blocks % 10000 == 0 {
+                info!(
+                    "checked {} blocks, found {} issues.",
+                    report.checked_blocks,
+                    report.issues.len()
+                );
+            }
+            parent_info = block_info;
+        }
+        Ok(report)
+    }
+
+    fn check_block(
+        &self,
+        block: &Block,
+        parent_info: Option<&BlockInfo>,
+        block_accumulator: &MerkleAccumulator,
+        walk_state: bool,
+    ) -> Result<(Option<BlockInfo>, Vec<Issue>)> {
+        let header = block.header();
+        let number = header.number();
+        let block_id = header.id();
+        let mut issues = vec![];
+        let mut issue = |kind: IssueKind, message: String| {
+            issues.push(Issue {
+                number,
+                block_id,
+                kind,
+                message,
+            })
+        };
+
+        match block_accumulator.get_leaf(number) {
+            Ok(Some(leaf)) if leaf == block_id => {}
+            Ok(Some(leaf)) => issue(
+                IssueKind::BlockAccumulator,
+                format!("block accumulator leaf {} is {}", number, leaf),
+            ),
+            Ok(None) => issue(
+                IssueKind::BlockAccumulator,
+                format!("block accumulator leaf {} is missing", number),
+            ),
+            Err(e) => issue(
+                IssueKind::BlockAccumulator,
+                format!("read block accumulator leaf {} error: {}", number, e),
+            ),
+        }
+        if let Some(parent_info) = parent_info {
+            if header.block_accumulator_root()
+                != parent_info.block_accumulator_info.accumulator_root
+            {
+                issue(
+                    IssueKind::BlockAccumulator,
+                    format!(
+                        "block accumulator root {} mismatch with parent block info's {}",
+                        header.block_accumulator_root(),
+                        parent_info.block_accumulator_info.accumulator_root
+                    ),
+                );
+            }
+        }
+
+        let block_info = match self.storage.get_block_info(block_id)? {
+            Some(block_info) => block_info,
+            None => {
+                issue(
+                    IssueKind::MissingBlockInfo,
+                    "block info is missing".to_string(),
+                );
+                return Ok((None, issues));
+            }
+        };
+        if block_info.block_accumulator_info.num_leaves != number.saturating_add(1) {
+            issue(
+                IssueKind::BlockAccumulator,
+                format!(
+                    "block accumulator leaves {} of block info mismatch with block number",
+                    block_info.block_accumulator_info.num_leaves
+                ),
+            );
+        }
+        if header.txn_accumulator_root() != block_info.txn_accumulator_info.accumulator_root {
+            issue(
+                IssueKind::TxnAccumulator,
+                format!(
+                    "txn accumulator root {} mismatch with block info's {}",
+                    header.txn_accumulator_root(),
+                    block_info.txn_accumulator_info.accumulator_root
+                ),
+            );
+        }
+
+        let txn_info_ids = self.storage.get_block_txn_info_ids(block_id)?;
+        // genesis block has no BlockMetadata txn.
+        let txn_count = if header.is_genesis() {
+            block.transactions().len()
+        } else {
+            block.transactions().len().saturating_add(1)
+        };
+        if txn_info_ids.len() != txn_count {
+            issue(
+                IssueKind::MissingTxnInfo,
+                format!(
+                    "txn info num {} mismatch with txn num {}",
+                    txn_info_ids.len(),
+                    txn_count
+                ),
+            );
+        }
+        if let Err(e) = self.check_txn_accumulator(&block_info.txn_accumulator_info, &txn_info_ids)
+        {
+            issue(IssueKind::TxnAccumulator, e.to_string());
+        }
+        for txn_info_id in &txn_info_ids {
+            let txn_info = match self.storage.get_transaction_info(*txn_info_id)? {
+                Some(txn_info) => txn_info,
+                None => {
+                    issue(
+                        IssueKind::MissingTxnInfo,
+                        format!("txn info {} is missing", txn_info_id),
+                    );
+                    continue;
+                }
+            };
+            match self.storage.get_contract_events(*txn_info_id)? {
+                Some(events) => {
+                    let event_hashes: Vec<_> = events.iter().map(|e| e.crypto_hash()).collect();
+                    let event_root =
+                        InMemoryAccumulator::from_leaves(event_hashes.as_slice()).root_hash();
+                    if event_root != txn_info.event_root_hash() {
+                        issue(
+                            IssueKind::EventRoot,
+                            format!(
+                                "events root {} of txn info {} mismatch with {}",
+                                event_root,
+                                txn_info_id,
+                                txn_info.event_root_hash()
+                            ),
+                        );
+                    }
+                }
+                None => issue(
+                    IssueKind::MissingEvents,
+                    format!("events of txn info {} are missing", txn_info_id),
+                ),
+            }
+        }
+
+        let state_root = header.state_root();
+        if walk_state {
+            if let Err(e) = self.walk_state(state_root) {
+                issue(
+                    IssueKind::MissingStateNode,
+                    format!("walk state tree {} error: {}", state_root, e),
+                );
+            }
+        } else if StateNodeStore::get(self.storage.as_ref(), &state_root)?.is_none() {
+            issue(
+                IssueKind::MissingStateNode,
+                format!("state root node {} is missing", state_root),
+            );
+        }
+        Ok((Some(block_info), issues))
+    }
+
+    /// Check the txn infos of a block are the last leaves of the txn accumulator.
+    fn check_txn_accumulator(
+        &self,
+        accumulator_info: &AccumulatorInfo,
+        txn_info_ids: &[HashValue],
+    ) -> Result<()> {
+        let txn_accumulator = MerkleAccumulator::new_with_info(
+            accumulator_info.clone(),
+            self.storage
+                .get_accumulator_store(AccumulatorStoreType::Transaction),
+        );
+        let first_index = accumulator_info
+            .num_leaves
+            .checked_sub(txn_info_ids.len() as u64)
+            .ok_or_else(|| format_err!("txn accumulator has less leaves than the txn infos"))?;
+        for (offset, txn_info_id) in txn_info_ids.iter().enumerate() {
+            let leaf_index = first_index.saturating_add(offset as u64);
+            match txn_accumulator.get_leaf(leaf_index)? {
+                Some(leaf) if leaf == *txn_info_id => {}
+                leaf => {
+                    return Err(format_err!(
+                        "txn accumulator leaf {} is {:?}, expect txn info {}",
+                        leaf_index,
+                        leaf,
+                        txn_info_id
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Walk all the nodes of the account state tree and the account storage trees.
+    fn walk_state(&self, state_root: HashValue) -> Result<()> {
+        let state_tree = StateTree::<AccountAddress>::new(self.storage.clone(), Some(state_root));
+        for (address, account_state_bytes) in state_tree.dump()?.iter() {
+            let account_state: AccountState = account_state_bytes.as_slice().try_into()?;
+            let storage_roots = account_state.storage_roots();
+            if let Some(root) = storage_roots[DataType::RESOURCE.storage_index()] {
+                StateTree::<StructTag>::new(self.storage.clone(), Some(root))
+                    .dump()
+                    .map_err(|e| format_err!("resources of account {}: {}", address, e))?;
+            }
+            if let Some(root) = storage_roots[DataType::CODE.storage_index()] {
+                StateTree::<Identifier>::new(self.storage.clone(), Some(root))
+                    .dump()
+                    .map_err(|e| format_err!("modules of account {}: {}", address, e))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `starcoin db` commands check whether the db of a stopped node is internally consistent,
+//! after a crash or disk issue, and repair it if possible.
+//! They open the db directly, so they never start or connect a node. The db of the node's data
+//! dir of the global options is used if the db path is absent, for example:
+//!
+//! ```shell
+//! starcoin -n main db check --state-sample 10000 --full-state
+//! starcoin db repair -i ~/.starcoin/main/starcoindb/db
+//! starcoin -n main db index-address-txn
+//! ```
+
+use crate::db::checker::{CheckOptions, CheckReport, DbChecker};
+use anyhow::{format_err, Result};
+use starcoin_chain::address_txn_index::rebuild_address_txn_index;
+use starcoin_config::{NodeConfig, StarcoinOpt};
+use starcoin_storage::db_storage::DBStorage;
+use starcoin_storage::storage::StorageInstance;
+use starcoin_storage::Storage;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use structopt::StructOpt;
+
+pub mod checker;
+pub mod repair;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct CheckOpt {
+    #[structopt(long, short = "i", parse(from_os_str))]
+    /// starcoin node db path. like ~/.starcoin/main/starcoindb/db,
+    /// use the db of the node's data dir if absent.
+    pub db_path: Option<PathBuf>,
+    #[structopt(long, default_value = "0")]
+    /// The block number to start checking from.
+    pub start_block: u64,
+    #[structopt(long)]
+    /// The block number to check to, check to the main head if absent.
+    pub end_block: Option<u64>,
+    #[structopt(long, default_value = "0")]
+    /// Walk the whole state tree of every N blocks to find missing state nodes, 0 means never.
+    /// Only the state root node is checked for other blocks.
+    pub state_sample: u64,
+    #[structopt(long)]
+    /// Walk the whole state tree of the last checked block.
+    pub full_state: bool,
+    #[structopt(long, default_value = "100")]
+    /// Stop checking once so many issues found.
+    pub max_issues: usize,
+}
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct RepairOpt {
+    #[structopt(flatten)]
+    pub check: CheckOpt,
+    #[structopt(long)]
+    /// Reset the main head to the last re-executed block if the repair stops before the main
+    /// head, the main chain blocks after it are dropped from the main chain.
+    /// Without it, the main head is kept and the repair fails in this case.
+    pub reset_head: bool,
+}
+
+#[derive(Debug, Clone, StructOpt)]
+#[structopt(name = "db", about = "Check and repair the db of a stopped node.")]
+pub enum DbOpt {
+    /// Walk the main chain from the startup info, check the accumulators, txn infos, events and
+    /// state nodes, and print the report in json.
+    Check(CheckOpt),
+    /// Check the db, then re-execute the main chain blocks after the last good block,
+    /// up to the main head.
+    Repair(RepairOpt),
+    /// Rebuild the address txn index from the main chain blocks, to backfill the index of
+    /// the db saved before the node enables `--address-txn-index`. The node should be stopped.
+    IndexAddressTxn(IndexOpt),
+}
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct IndexOpt {
+    #[structopt(long, short = "i", parse(from_os_str))]
+    /// starcoin node db path. like ~/.starcoin/main/starcoindb/db,
+    /// use the db of the node's data dir if absent.
+    pub db_path: Option<PathBuf>,
+}
+
+fn open_storage(global_opt: &StarcoinOpt, db_path: Option<&Path>) -> Result<Arc<Storage>> {
+    let db_path = match db_path {
+        Some(db_path) => db_path.to_path_buf(),
+        None => NodeConfig::load_with_opt(global_opt)?.storage.dir(),
+    };
+    if !db_path.exists() {
+        return Err(format_err!("The db {} does not exist.", db_path.display()));
+    }
+    let db_storage = DBStorage::new(db_path, Default::default())?;
+    Ok(Arc::new(Storage::new(StorageInstance::new_db_instance(
+        db_storage,
+    ))?))
+}
+
+fn check(global_opt: &StarcoinOpt, opt: &CheckOpt) -> Result<(Arc<Storage>, CheckReport)> {
+    let storage = open_storage(global_opt, opt.db_path.as_deref())?;
+    let checker = DbChecker::new(
+        storage.clone(),
+        CheckOptions {
+            start_number: opt.start_block,
+            end_number: opt.end_block,
+            state_sample: opt.state_sample,
+            full_state: opt.full_state,
+            max_issues: opt.max_issues.max(1),
+        },
+    );
+    let report = checker.check()?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok((storage, report))
+}
+
+/// Run the `starcoin db` command, return error if the check finds any issue,
+/// or the repair can not repair up to the main head.
+pub fn run(global_opt: &StarcoinOpt, opt: DbOpt) -> Result<()> {
+    match opt {
+        DbOpt::Check(opt) => {
+            let (_, report) = check(global_opt, &opt)?;
+            if !report.is_ok() {
+                return Err(format_err!(
+                    "{} issues found in the db",
+                    report.issues.len()
+                ));
+            }
+        }
+        DbOpt::Repair(opt) => {
+            let (storage, report) = check(global_opt, &opt.check)?;
+            if report.is_ok() {
+                println!("No issue found, nothing to repair.");
+                return Ok(());
+            }
+            let repair_report = repair::repair(storage, &report, opt.reset_head)?;
+            println!("{}", serde_json::to_string_pretty(&repair_report)?);
+            if let Some(stop_reason) = repair_report.stop_reason.as_ref() {
+                if repair_report.head_reset {
+                    println!(
+                        "Main head is reset from block {}({}) to block {}({}), as the repair stopped: {}",
+                        repair_report.old_head_number,
+                        repair_report.old_head_id,
+                        repair_report.head_number,
+                        repair_report.head_id,
+                        stop_reason
+                    );
+                } else {
+                    return Err(format_err!(
+                        "The repair stopped at block {}({}) before the main head {}({}): {}, the main head is kept, run with --reset-head to reset the main head to block {}.",
+                        repair_report.head_number,
+                        repair_report.head_id,
+                        repair_report.old_head_number,
+                        repair_report.old_head_id,
+                        stop_reason,
+                        repair_report.head_number
+                    ));
+                }
+            }
+        }
+        DbOpt::IndexAddressTxn(opt) => {
+            let storage = open_storage(global_opt, opt.db_path.as_deref())?;
+            let indexed_blocks = rebuild_address_txn_index(storage.as_ref())?;
+            println!("Address txn index rebuilt for {} blocks.", indexed_blocks);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::db::checker::CheckReport;
+use anyhow::{format_err, Result};
+use serde::Serialize;
+use starcoin_chain::verifier::NoneVerifier;
+use starcoin_chain::{BlockChain, ChainReader};
+use starcoin_crypto::HashValue;
+use starcoin_logger::prelude::*;
+use starcoin_storage::{BlockStore, Storage};
+use starcoin_types::block::BlockNumber;
+use starcoin_types::startup_info::StartupInfo;
+use starcoin_vm_types::time::RealTimeService;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RepairReport {
+    /// The block the repair re-executed from.
+    pub from_number: BlockNumber,
+    pub from_id: HashValue,
+    /// The main head before repair.
+    pub old_head_number: BlockNumber,
+    pub old_head_id: HashValue,
+    /// The last re-executed block.
+    pub head_number: BlockNumber,
+    pub head_id: HashValue,
+    pub repaired_blocks: u64,
+    /// The error which stopped the repair before the old main head, if any.
+    pub stop_reason: Option<String>,
+    /// Whether the main head is reset to the last re-executed block.
+    pub head_reset: bool,
+}
+
+/// Re-execute the main chain blocks after the last good block of the `report`,
+/// which rewrites their txn infos, events, accumulators and state nodes.
+/// If the repair stops before the main head, the main head is reset to the last re-executed
+/// block only if `reset_head` is true, otherwise the main head is kept.
+pub fn repair(
+    storage: Arc<Storage>,
+    report: &CheckReport,
+    reset_head: bool,
+) -> Result<RepairReport> {
+    let (from_number, from_id) = match (report.last_good_number, report.last_good_id) {
+        (Some(number), Some(id)) => (number, id),
+        _ => {
+            return Err(format_err!(
+                "No good block found before the first issue, can not repair, please resync."
+            ))
+        }
+    };
+    let main_head = storage
+        .get_block_header_by_hash(report.head_id)?
+        .ok_or_else(|| format_err!("Main head block header {} is missing.", report.head_id))?;
+    let block_ids = main_block_ids(storage.as_ref(), main_head.id(), from_id)?;
+
+    let mut chain = BlockChain::new(Arc::new(RealTimeService::new()), from_id, storage.clone())?;
+    let mut repaired_blocks = 0u64;
+    let mut stop_reason = None;
+    for block_id in block_ids {
+        let block = match storage.get_block(block_id)? {
+            Some(block) => block,
+            None => {
+                stop_reason = Some(format!("block {} is missing", block_id));
+                break;
+            }
+        };
+        let number = block.header().number();
+        if let Err(e) = chain.apply_with_verifier::<NoneVerifier>(block) {
+            stop_reason = Some(format!(
+                "re-execute block {}({}) error: {}",
+                number, block_id, e
+            ));
+            break;
+        }
+        repaired_blocks += 1;
+        if repaired_blocks % 1000 == 0 {
+            info!("repaired {} blocks, current: {}", repaired_blocks, number);
+        }
+    }
+    let head = chain.current_header();
+    let head_reset = head.id() != main_head.id() && reset_head;
+    if head_reset {
+        warn!(
+            "reset main head from block {}({}) to block {}({})",
+            main_head.number(),
+            main_head.id(),
+            head.number(),
+            head.id()
+        );
+        storage.save_startup_info(StartupInfo::new(head.id()))?;
+    }
+    Ok(RepairReport {
+        from_number,
+        from_id,
+        old_head_number: main_head.number(),
+        old_head_id: main_head.id(),
+        head_number: head.number(),
+        head_id: head.id(),
+        repaired_blocks,
+        stop_reason,
+        head_reset,
+    })
+}
+
+/// The main chain block ids after `from_id` up to `head_id`, walked back by block headers.
+/// The headers are sure to exist, as the checker has walked them.
+fn main_block_ids(
+    storage: &Storage,
+    head_id: HashValue,
+    from_id: HashValue,
+) -> Result<Vec<HashValue>> {
+    let mut block_ids = vec![];
+    let mut block_id = head_id;
+    while block_id != from_id {
+        let header = storage
+            .get_block_header_by_hash(block_id)?
+            .ok_or_else(|| format_err!("Block header {} is missing.", block_id))?;
+        block_ids.push(block_id);
+        block_id = header.parent_hash();
+    }
+    block_ids.reverse();
+    Ok(block_ids)
+}
@@ -0,0 +1,151 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::db::checker::{CheckOptions, CheckReport, DbChecker, IssueKind};
+use crate::db::repair::repair;
+use anyhow::Result;
+use starcoin_account_api::AccountInfo;
+use starcoin_chain::ChainReader;
+use starcoin_chain_mock::MockChain;
+use starcoin_config::ChainNetwork;
+use starcoin_crypto::HashValue;
+use starcoin_genesis::Genesis;
+use starcoin_storage::storage::{InnerStore, StorageInstance};
+use starcoin_storage::{
+    BlockStore, BlockTransactionInfoStore, ContractEventStore, Storage, BLOCK_PREFIX_NAME,
+};
+use starcoin_types::startup_info::StartupInfo;
+use std::sync::Arc;
+
+fn mock_db(blocks: u64) -> Result<(StorageInstance, Arc<Storage>, MockChain)> {
+    let net = ChainNetwork::new_test();
+    let instance = StorageInstance::new_cache_instance();
+    let storage = Arc::new(Storage::new(instance.clone())?);
+    let genesis = Genesis::load(&net)?;
+    let chain_info = genesis.execute_genesis_block(&net, storage.clone())?;
+    let mut mock_chain = MockChain::new_with_storage(
+        net,
+        storage.clone(),
+        chain_info.head().id(),
+        AccountInfo::random(),
+    )?;
+    mock_chain.produce_and_apply_times(blocks)?;
+    storage.save_startup_info(StartupInfo::new(mock_chain.head().current_header().id()))?;
+    Ok((instance, storage, mock_chain))
+}
+
+fn check(storage: Arc<Storage>) -> Result<CheckReport> {
+    DbChecker::new(
+        storage,
+        CheckOptions {
+            start_number: 0,
+            end_number: None,
+            state_sample: 2,
+            full_state: true,
+            max_issues: 100,
+        },
+    )
+    .check()
+}
+
+fn block_id(mock_chain: &MockChain, number: u64) -> Result<HashValue> {
+    Ok(mock_chain
+        .head()
+        .get_header_by_number(number)?
+        .expect("block should exist")
+        .id())
+}
+
+/// Drop the events of the block metadata txn of the block.
+fn drop_events(storage: &Storage, block_id: HashValue) -> Result<()> {
+    let txn_info_id = storage.get_block_txn_info_ids(block_id)?[0];
+    let events = storage
+        .get_contract_events(txn_info_id)?
+        .expect("events should exist");
+    assert!(!events.is_empty());
+    storage.save_contract_events(txn_info_id, vec![])
+}
+
+fn main_head(storage: &Storage) -> Result<HashValue> {
+    Ok(storage
+        .get_startup_info()?
+        .expect("startup info should exist")
+        .main)
+}
+
+#[test]
+fn test_check_db() -> Result<()> {
+    let (_, storage, mock_chain) = mock_db(5)?;
+    let report = check(storage)?;
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.head_number, 5);
+    assert_eq!(report.checked_blocks, 6);
+    assert_eq!(report.walked_states, 4);
+    assert_eq!(report.last_good_number, Some(5));
+    assert_eq!(report.last_good_id, Some(block_id(&mock_chain, 5)?));
+    Ok(())
+}
+
+#[test]
+fn test_check_and_repair_db() -> Result<()> {
+    let (_, storage, mock_chain) = mock_db(5)?;
+    let head_id = main_head(storage.as_ref())?;
+    drop_events(storage.as_ref(), block_id(&mock_chain, 3)?)?;
+
+    let report = check(storage.clone())?;
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].number, 3);
+    assert_eq!(report.issues[0].kind, IssueKind::EventRoot);
+    assert_eq!(report.last_good_number, Some(2));
+
+    let repair_report = repair(storage.clone(), &report, false)?;
+    assert_eq!(repair_report.from_number, 2);
+    assert_eq!(repair_report.repaired_blocks, 3);
+    assert!(repair_report.stop_reason.is_none());
+    assert!(!repair_report.head_reset);
+    assert_eq!(repair_report.head_id, head_id);
+    assert_eq!(main_head(storage.as_ref())?, head_id);
+
+    let report = check(storage)?;
+    assert!(report.is_ok(), "{:?}", report.issues);
+    Ok(())
+}
+
+#[test]
+fn test_repair_db_reset_head() -> Result<()> {
+    let (instance, storage, mock_chain) = mock_db(5)?;
+    let head_id = main_head(storage.as_ref())?;
+    drop_events(storage.as_ref(), block_id(&mock_chain, 2)?)?;
+    let missing_block_id = block_id(&mock_chain, 4)?;
+    instance.remove(BLOCK_PREFIX_NAME, missing_block_id.to_vec())?;
+
+    let report = check(storage.clone())?;
+    let kinds: Vec<_> = report
+        .issues
+        .iter()
+        .map(|issue| (issue.number, issue.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![(2, IssueKind::EventRoot), (4, IssueKind::MissingBlock)]
+    );
+    assert_eq!(report.last_good_number, Some(1));
+
+    // the repair stops at the missing block, the main head is kept without reset.
+    let repair_report = repair(storage.clone(), &report, false)?;
+    assert!(repair_report.stop_reason.is_some());
+    assert!(!repair_report.head_reset);
+    assert_eq!(repair_report.head_number, 3);
+    assert_eq!(repair_report.old_head_id, head_id);
+    assert_eq!(main_head(storage.as_ref())?, head_id);
+
+    let repair_report = repair(storage.clone(), &report, true)?;
+    assert!(repair_report.head_reset);
+    assert_eq!(repair_report.head_id, block_id(&mock_chain, 3)?);
+    assert_eq!(main_head(storage.as_ref())?, repair_report.head_id);
+
+    let report = check(storage)?;
+    assert!(report.is_ok(), "{:?}", report.issues);
+    assert_eq!(report.head_number, 3);
+    Ok(())
+}
@@ -6,9 +6,13 @@ use crate::view::MoveExplainView;
 use crate::StarcoinOpt;
 use anyhow::{format_err, Result};
 use scmd::{CommandAction, ExecContext};
-use starcoin_move_explain::get_explanation;
+use starcoin_move_explain::{
+    decode_error_mapping, get_explanation, register_error_mapping, register_error_mapping_file,
+};
 use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::{access_path_for_error_map, ErrorMapResource};
 use starcoin_vm_types::{identifier::Identifier, language_storage::ModuleId};
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 ///Explain Move abort codes. Errors are defined as
@@ -22,6 +26,10 @@ pub struct MoveExplainOpt {
     /// The abort code returned with a `MoveAbort` error
     #[structopt(short = "a")]
     abort_code: u64,
+    /// The error map file of a third-party module, generated by errmapgen,
+    /// the error map published on chain with the module is preferred.
+    #[structopt(long = "error-map", parse(from_os_str))]
+    error_map: Option<PathBuf>,
 }
 
 pub struct MoveExplain;
@@ -37,6 +45,9 @@ impl CommandAction for MoveExplain {
         ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
     ) -> Result<Self::ReturnItem> {
         let opt = ctx.opt();
+        if let Some(error_map) = opt.error_map.as_ref() {
+            register_error_mapping_file(error_map)?;
+        }
         let category = opt.abort_code & 0xFFu64;
         let reason_code = opt.abort_code >> 8;
         match opt.location {
@@ -57,6 +68,15 @@ impl CommandAction for MoveExplain {
                     AccountAddress::from_hex_literal(&address_literal)?,
                     Identifier::new(module_name)?,
                 );
+                // the error map published on chain under the module address.
+                if let Some(bytes) = ctx
+                    .state()
+                    .client()
+                    .state_get(access_path_for_error_map(*module_id.address()))?
+                {
+                    let resource: ErrorMapResource = bcs_ext::from_bytes(bytes.as_slice())?;
+                    register_error_mapping(decode_error_mapping(resource.bytes())?);
+                }
 
                 let error_ctx = get_explanation(&module_id, opt.abort_code);
 
@@ -6,7 +6,8 @@ use crate::StarcoinOpt;
 use anyhow::Result;
 use scmd::{CommandAction, ExecContext};
 use starcoin_rpc_api::types::{
-    AnnotatedMoveValueView, ContractCall, FunctionIdView, TransactionArgumentView, TypeTagView,
+    AnnotatedMoveValueView, ContractCall, FunctionIdView, StateOverrideView,
+    TransactionArgumentView, TypeTagView,
 };
 use structopt::StructOpt;
 
@@ -17,6 +18,10 @@ use structopt::StructOpt;
 ///  dev call --function 0x1::Block::current_block_number
 ///  # 0x1::Account::balance<0x1::STC::STC>(0x726098b70ba8aa2cc172af19af8804)
 ///  dev call --function 0x1::Account::balance -t 0x1::STC::STC --arg 0x726098b70ba8aa2cc172af19af8804
+///  # args in JSON, encoded by the function signature on chain
+///  dev call --function 0x1::Account::balance -t 0x1::STC::STC --json-arg '"0x726098b70ba8aa2cc172af19af8804"'
+///  # call with the balance of 0x726098b70ba8aa2cc172af19af8804 overridden
+///  dev call --function 0x1::Account::balance -t 0x1::STC::STC --arg 0x726098b70ba8aa2cc172af19af8804 --state-override '{"balance": {"address": "0x726098b70ba8aa2cc172af19af8804", "amount": "100"}}'
 ///  ```
 #[derive(Debug, StructOpt)]
 #[structopt(name = "call")]
@@ -38,6 +43,23 @@ pub struct CallContractOpt {
         help = "can specify multi arg"
     )]
     args: Option<Vec<TransactionArgumentView>>,
+
+    #[structopt(
+        long = "json-arg",
+        name = "json-args",
+        help = "can specify multi arg in JSON, like '[1, 2]', '{\"value\": 1}'",
+        parse(try_from_str = serde_json::from_str),
+        conflicts_with = "transaction-args"
+    )]
+    json_args: Option<Vec<serde_json::Value>>,
+
+    #[structopt(
+        long = "state-override",
+        name = "state-overrides",
+        help = "can specify multi state override in JSON, applied in order before the call",
+        parse(try_from_str = serde_json::from_str)
+    )]
+    state_overrides: Option<Vec<StateOverrideView>>,
 }
 
 pub struct CallContractCommand;
@@ -58,6 +80,8 @@ impl CommandAction for CallContractCommand {
             function_id: opt.function.clone(),
             type_args: opt.type_tags.clone().unwrap_or_default(),
             args: opt.args.clone().unwrap_or_default(),
+            json_args: opt.json_args.clone(),
+            state_overrides: opt.state_overrides.clone().unwrap_or_default(),
         };
 
         let result = ctx.state().client().contract_call(call)?;
@@ -0,0 +1,100 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use serde::{Deserialize, Serialize};
+use starcoin_move_compiler::check_module_compat_report;
+use starcoin_move_compiler::compatibility::CompatibilityReport;
+use starcoin_types::transaction::Package;
+use starcoin_vm_types::access::ModuleAccess;
+use starcoin_vm_types::file_format::CompiledModule;
+use starcoin_vm_types::language_storage::ModuleId;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Check the module upgrade compatibility between local bytecode and the on chain module.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "check-upgrade")]
+pub struct CheckUpgradeOpt {
+    #[structopt(
+        name = "module-or-package-file",
+        help = "module bytecode file or package file path",
+        parse(from_os_str)
+    )]
+    file: PathBuf,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModuleUpgradeView {
+    pub module_id: ModuleId,
+    /// Whether the module has been published on chain.
+    pub published: bool,
+    /// The compatibility report, None if the module is not published.
+    pub report: Option<CompatibilityReport>,
+}
+
+pub struct CheckUpgradeCommand;
+
+impl CommandAction for CheckUpgradeCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = CheckUpgradeOpt;
+    type ReturnItem = Vec<ModuleUpgradeView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let mut bytes = vec![];
+        File::open(&ctx.opt().file)?.read_to_end(&mut bytes)?;
+        let modules = match CompiledModule::deserialize(bytes.as_slice()) {
+            Ok(_) => vec![bytes],
+            Err(_) => {
+                let package: Package = bcs_ext::from_bytes(&bytes).map_err(|e| {
+                    format_err!(
+                        "invalid file, cannot deserialize as module or package, {}",
+                        e
+                    )
+                })?;
+                package
+                    .modules()
+                    .iter()
+                    .map(|module| module.code().to_vec())
+                    .collect()
+            }
+        };
+        let client = ctx.state().client();
+        let mut views = vec![];
+        for code in modules {
+            let module_id = CompiledModule::deserialize(code.as_slice())
+                .map_err(|e| format_err!("invalid module bytecode, {}", e))?
+                .self_id();
+            let view = match client.get_code(module_id.clone())? {
+                Some(on_chain_code) => {
+                    let on_chain_code =
+                        hex::decode(on_chain_code.strip_prefix("0x").unwrap_or(&on_chain_code))?;
+                    let report =
+                        check_module_compat_report(on_chain_code.as_slice(), code.as_slice())
+                            .map_err(|e| format_err!("check module {} error: {}", module_id, e))?;
+                    ModuleUpgradeView {
+                        module_id,
+                        published: true,
+                        report: Some(report),
+                    }
+                }
+                None => ModuleUpgradeView {
+                    module_id,
+                    published: false,
+                    report: None,
+                },
+            };
+            views.push(view);
+        }
+        Ok(views)
+    }
+}
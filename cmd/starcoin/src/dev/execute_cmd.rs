@@ -1,12 +1,15 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use super::profile_gas_cmd::write_gas_profile;
 use crate::cli_state::CliState;
 use crate::view::{ExecuteResultView, ExecutionOutputView};
 use crate::StarcoinOpt;
 use anyhow::{bail, Result};
+use move_coverage::trace::{enable_vm_trace, vm_trace_path};
 use scmd::{CommandAction, ExecContext};
 use starcoin_config::temp_path;
+use starcoin_dev::gas_profiler::profile_dry_run;
 use starcoin_dev::playground;
 use starcoin_move_compiler::{
     compile_source_string_no_report, errors, load_bytecode_file, CompiledUnit, MOVE_EXTENSION,
@@ -85,6 +88,15 @@ pub struct ExecuteOpt {
     /// Whether dry-run in local cli or remote node.
     local_mode: bool,
 
+    #[structopt(long = "coverage", name = "trace-file")]
+    /// Write the Move VM execution trace of the local dry-run to the file for coverage,
+    /// only works with a debug build of the cli.
+    coverage: Option<PathBuf>,
+
+    #[structopt(long = "profile-gas", name = "profile-dir", parse(from_os_str))]
+    /// Profile the gas used of the local dry-run, and write the report to the dir.
+    profile_gas: Option<PathBuf>,
+
     #[structopt(long = "function", name = "script-function")]
     /// script function to execute, example: 0x1::TransferScripts::peer_to_peer
     script_function: Option<FunctionIdView>,
@@ -115,6 +127,24 @@ impl CommandAction for ExecuteCommand {
         ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
     ) -> Result<Self::ReturnItem> {
         let opt = ctx.opt();
+        if opt.profile_gas.is_some() && !opt.local_mode {
+            bail!("--profile-gas only works with --local");
+        }
+        if let Some(trace_file) = opt.coverage.as_ref() {
+            if !opt.local_mode {
+                bail!("--coverage only works with --local");
+            }
+            if !cfg!(debug_assertions) {
+                bail!("--coverage only works with a debug build of the cli, the Move VM of a release build does not write the execution trace");
+            }
+            // The VM reads the trace file only once in a process.
+            match vm_trace_path() {
+                Some(trace_path) if PathBuf::from(trace_path.as_str()) != *trace_file => {
+                    bail!("execution trace is already written to {}", trace_path)
+                }
+                _ => enable_vm_trace(trace_file.as_path()),
+            }
+        }
         let client = ctx.state().client();
         let sender = if let Some(sender) = ctx.opt().sender {
             sender
@@ -231,18 +261,28 @@ impl CommandAction for ExecuteCommand {
         let txn_hash = signed_txn.id();
         let output = if opt.local_mode {
             let state_view = RemoteStateReader::new(client)?;
-            playground::dry_run(
-                &state_view,
-                DryRunTransaction {
-                    public_key: signed_txn.authenticator().public_key(),
-                    raw_txn: signed_txn.raw_txn().clone(),
-                },
-            )
-            .map(|(_, b)| b.into())?
+            let dry_run_txn = DryRunTransaction {
+                public_key: signed_txn.authenticator().public_key(),
+                raw_txn: signed_txn.raw_txn().clone(),
+            };
+            match opt.profile_gas.as_ref() {
+                Some(profile_dir) => {
+                    let (_, output, profile) = profile_dry_run(&state_view, dry_run_txn)?;
+                    let view = write_gas_profile(profile, profile_dir.as_path())?;
+                    println!(
+                        "gas profile written to {} and {}",
+                        view.report_file.display(),
+                        view.folded_file.display()
+                    );
+                    output.into()
+                }
+                None => playground::dry_run(&state_view, dry_run_txn).map(|(_, b)| b.into())?,
+            }
         } else {
             client.dry_run(DryRunTransactionRequest {
                 sender_public_key: Some(StrView(signed_txn.authenticator().public_key())),
                 transaction: signed_txn.raw_txn().clone().into(),
+                state_overrides: vec![],
             })?
         };
         match output.status {
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod call_contract_cmd;
+mod check_upgrade_cmd;
 mod compile_cmd;
 mod deploy_cmd;
 mod derive_account_address_cmd;
 mod execute_cmd;
 mod generate_multisig_txn_cmd;
 mod get_coin_cmd;
+mod package_cmd;
+mod profile_gas_cmd;
 pub(crate) mod sign_txn_helper;
 mod submit_multisig_txn_cmd;
 mod subscribe_cmd;
@@ -19,12 +22,15 @@ mod upgrade_module_proposal_cmd;
 mod upgrade_module_queue_cmd;
 
 pub use call_contract_cmd::*;
+pub use check_upgrade_cmd::*;
 pub use compile_cmd::*;
 pub use deploy_cmd::*;
 pub use derive_account_address_cmd::*;
 pub use execute_cmd::*;
 pub use generate_multisig_txn_cmd::*;
 pub use get_coin_cmd::*;
+pub use package_cmd::*;
+pub use profile_gas_cmd::*;
 pub use sign_txn_helper::sign_txn_with_account_by_rpc_client;
 pub use submit_multisig_txn_cmd::*;
 pub use subscribe_cmd::*;
@@ -0,0 +1,122 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::StarcoinOpt;
+use anyhow::{bail, Result};
+use scmd::{CommandAction, ExecContext};
+use serde::{Deserialize, Serialize};
+use starcoin_config::temp_path;
+use starcoin_crypto::hash::{HashValue, PlainCryptoHash};
+use starcoin_move_compiler::errors;
+use starcoin_move_compiler::package::{build_package, CompiledPackage, PackageManifest};
+use starcoin_vm_types::transaction::ScriptABI;
+use std::path::PathBuf;
+use stdlib::restore_stdlib_in_dir;
+use structopt::StructOpt;
+
+/// Build a Move package with a `Package.toml` manifest to a deployable package blob.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "package")]
+pub struct PackageOpt {
+    #[structopt(short = "o", name = "out_dir", help = "out dir", parse(from_os_str))]
+    out_dir: Option<PathBuf>,
+
+    #[structopt(
+        name = "package_dir",
+        help = "package dir which contains Package.toml",
+        parse(from_os_str)
+    )]
+    package_dir: PathBuf,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PackageView {
+    pub package_file: PathBuf,
+    pub package_hash: HashValue,
+    pub abi_dir: PathBuf,
+    pub modules: usize,
+    pub script_functions: Vec<String>,
+}
+
+pub struct PackageCommand;
+
+impl CommandAction for PackageCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = PackageOpt;
+    type ReturnItem = PackageView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let manifest = PackageManifest::load(opt.package_dir.as_path())?;
+        let temp_path = temp_path();
+        let deps = restore_stdlib_in_dir(temp_path.path())?;
+        let work_dir = temp_path.path().join("build");
+        std::fs::create_dir_all(work_dir.as_path())?;
+
+        let client = ctx.state().client();
+        let (sources, compile_result) = build_package(
+            opt.package_dir.as_path(),
+            &deps,
+            work_dir.as_path(),
+            |module_id| {
+                client
+                    .get_code(module_id.clone())?
+                    .map(|code| {
+                        hex::decode(code.strip_prefix("0x").unwrap_or(&code)).map_err(Into::into)
+                    })
+                    .transpose()
+            },
+        )?;
+        let units = match compile_result {
+            Ok(units) => units,
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    String::from_utf8_lossy(
+                        errors::report_errors_to_color_buffer(sources, e).as_slice()
+                    )
+                );
+                bail!("compile error")
+            }
+        };
+        let compiled_package = CompiledPackage::new(units)?;
+
+        let out_dir = opt
+            .out_dir
+            .clone()
+            .unwrap_or_else(|| ctx.state().temp_dir().to_path_buf());
+        let package_file = out_dir
+            .join(manifest.package.name.as_str())
+            .with_extension("blob");
+        std::fs::write(
+            package_file.as_path(),
+            bcs_ext::to_bytes(&compiled_package.package)?,
+        )?;
+        let abi_dir = out_dir.join("abi");
+        std::fs::create_dir_all(abi_dir.as_path())?;
+        let mut script_functions = vec![];
+        for abi in compiled_package.abis {
+            let name = format!("{}::{}", abi.module_name().name(), abi.name());
+            let abi_file = abi_dir
+                .join(format!("{}_{}", abi.module_name().name(), abi.name()))
+                .with_extension("abi");
+            std::fs::write(
+                abi_file.as_path(),
+                bcs_ext::to_bytes(&ScriptABI::ScriptFunction(abi))?,
+            )?;
+            script_functions.push(name);
+        }
+        Ok(PackageView {
+            package_file,
+            package_hash: compiled_package.package.crypto_hash(),
+            abi_dir,
+            modules: compiled_package.package.modules().len(),
+            script_functions,
+        })
+    }
+}
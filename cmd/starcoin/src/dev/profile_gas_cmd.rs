@@ -0,0 +1,104 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use serde::{Deserialize, Serialize};
+use starcoin_crypto::HashValue;
+use starcoin_dev::gas_profiler::{profile_dry_run, GasProfile};
+use starcoin_rpc_client::RemoteStateReader;
+use starcoin_types::transaction::{DryRunTransaction, RawUserTransaction};
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+/// Re-execute a historical user transaction on the state before it, and profile the gas used.
+/// The profile is written as a json report and a flamegraph compatible folded stacks file.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "profile-gas")]
+pub struct ProfileGasOpt {
+    #[structopt(short = "o", name = "out_dir", help = "out dir", parse(from_os_str))]
+    out_dir: Option<PathBuf>,
+
+    #[structopt(name = "txn_hash")]
+    /// hash of the user transaction to profile
+    txn_hash: HashValue,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GasProfileView {
+    pub report_file: PathBuf,
+    pub folded_file: PathBuf,
+    pub profile: GasProfile,
+}
+
+/// Writes `gas_profile.json` and `gas_profile.folded` to the out dir.
+pub(crate) fn write_gas_profile(profile: GasProfile, out_dir: &Path) -> Result<GasProfileView> {
+    std::fs::create_dir_all(out_dir)?;
+    let report_file = out_dir.join("gas_profile.json");
+    std::fs::write(
+        report_file.as_path(),
+        serde_json::to_string_pretty(&profile)?,
+    )?;
+    let folded_file = out_dir.join("gas_profile.folded");
+    std::fs::write(folded_file.as_path(), profile.to_folded())?;
+    Ok(GasProfileView {
+        report_file,
+        folded_file,
+        profile,
+    })
+}
+
+pub struct ProfileGasCommand;
+
+impl CommandAction for ProfileGasCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ProfileGasOpt;
+    type ReturnItem = GasProfileView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let client = ctx.state().client();
+        let txn = client
+            .chain_get_transaction(opt.txn_hash)?
+            .ok_or_else(|| format_err!("transaction {} not found", opt.txn_hash))?;
+        let user_txn = txn
+            .user_transaction
+            .ok_or_else(|| format_err!("transaction {} is not a user transaction", opt.txn_hash))?;
+        // The block metadata is the first transaction of a block, so a user transaction
+        // always has a previous transaction in the block.
+        let pre_state_root = client
+            .chain_get_txn_info_by_block_and_index(
+                txn.block_hash,
+                txn.transaction_index as u64 - 1,
+            )?
+            .ok_or_else(|| {
+                format_err!(
+                    "transaction info of block {} index {} not found",
+                    txn.block_hash,
+                    txn.transaction_index - 1
+                )
+            })?
+            .state_root_hash;
+
+        let state_view = RemoteStateReader::new_with_root(client, pre_state_root);
+        let (_, _, profile) = profile_dry_run(
+            &state_view,
+            DryRunTransaction {
+                public_key: user_txn.authenticator.public_key(),
+                raw_txn: RawUserTransaction::try_from(user_txn.raw_txn)?,
+            },
+        )?;
+        let out_dir = opt
+            .out_dir
+            .clone()
+            .unwrap_or_else(|| ctx.state().temp_dir().to_path_buf());
+        write_gas_profile(profile, out_dir.as_path())
+    }
+}
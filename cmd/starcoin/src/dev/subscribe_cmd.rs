@@ -4,6 +4,8 @@ use anyhow::Result;
 use futures::{StreamExt, TryStream, TryStreamExt};
 use scmd::{CommandAction, ExecContext};
 use starcoin_rpc_api::types::pubsub::EventFilter;
+use starcoin_rpc_api::types::TypeTagView;
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::event::EventKey;
 use structopt::StructOpt;
 use tokio::io::AsyncBufReadExt;
@@ -28,6 +30,21 @@ pub struct SubscribeEventOpt {
         multiple = true
     )]
     event_key: Option<Vec<EventKey>>,
+    #[structopt(
+        short = "a",
+        long = "addr",
+        name = "addr",
+        help = "address which creates the event key",
+        multiple = true
+    )]
+    addr: Option<Vec<AccountAddress>>,
+    #[structopt(
+        long = "type-tag",
+        name = "type_tag",
+        help = "event type tag, such as 0x1::Account::DepositEvent, a struct without type params matches all its instantiations",
+        multiple = true
+    )]
+    type_tag: Option<Vec<TypeTagView>>,
     #[structopt(
         short = "l",
         long = "limit",
@@ -35,6 +52,8 @@ pub struct SubscribeEventOpt {
         help = "limit return size"
     )]
     limit: Option<usize>,
+    #[structopt(long = "decode", help = "decode the event data into move value")]
+    decode: bool,
 }
 
 pub struct SubscribeEventCommand;
@@ -51,7 +70,10 @@ impl CommandAction for SubscribeEventCommand {
             from_block: ctx.opt().from_block,
             to_block: ctx.opt().to_block,
             event_keys: ctx.opt().event_key.clone().unwrap_or_default(),
+            addrs: ctx.opt().addr.clone().unwrap_or_default(),
+            type_tags: ctx.opt().type_tag.clone().unwrap_or_default(),
             limit: ctx.opt().limit,
+            decode: ctx.opt().decode,
         };
 
         let event_stream = ctx.state().client().subscribe_events(filter)?;
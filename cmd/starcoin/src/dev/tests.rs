@@ -359,6 +359,8 @@ fn test_upgrade_module() {
         function_id: FunctionIdView::from_str("0x1::TestModule::is_test").unwrap(),
         type_args: Vec::new(),
         args: Vec::new(),
+        json_args: None,
+        state_overrides: vec![],
     };
     let result = cli_state.client().contract_call(call).unwrap();
     assert!(!result.is_empty());
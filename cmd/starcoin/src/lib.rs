@@ -8,6 +8,7 @@ pub mod account;
 pub mod chain;
 pub mod cli_state;
 pub mod contract;
+pub mod db;
 pub mod debug;
 pub mod dev;
 pub mod helper;
@@ -88,6 +89,7 @@ pub fn add_command(
                 .subcommand(chain::ListBlockCommand)
                 .subcommand(chain::GetTransactionCommand)
                 .subcommand(chain::GetTxnByBlockCommand)
+                .subcommand(chain::GetTxnByAddressCommand)
                 .subcommand(chain::GetTransactionInfoCommand)
                 .subcommand(chain::GetEventsCommand)
                 .subcommand(chain::GetBlockCommand)
@@ -124,8 +126,11 @@ pub fn add_command(
             Command::with_name("dev")
                 .subcommand(dev::GetCoinCommand)
                 .subcommand(dev::CompileCommand)
+                .subcommand(dev::PackageCommand)
                 .subcommand(dev::DeployCommand)
+                .subcommand(dev::CheckUpgradeCommand)
                 .subcommand(dev::ExecuteCommand)
+                .subcommand(dev::ProfileGasCommand)
                 .subcommand(dev::DeriveAddressCommand)
                 .subcommand(dev::GenerateMultisigTxnCommand)
                 .subcommand(dev::ExecuteMultiSignedTxnCommand)
@@ -157,4 +162,6 @@ pub fn add_command(
                 .subcommand(GenBlockCommand)
                 .subcommand(debug::MoveExplain),
         )
+        // The db commands work on the db of a stopped node, run them without starting a node.
+        .stateless_command(db::run)
 }
@@ -16,17 +16,21 @@ use std::fmt;
 
 const MAX_NUM_OF_KEYS: usize = 32;
 const BITMAP_NUM_OF_BYTES: usize = 4;
+/// The index byte of the serialized shard whose private key indexes are not sequential,
+/// the indexes of the private keys follow the header.
+const UNSEQUENTIAL_INDEXES_FLAG: u8 = u8::MAX;
 
 /// Part of private keys in the multi-key Ed25519 structure along with the threshold.
-/// note: the private keys must be a sequential part of the MultiEd25519PrivateKey
+/// note: the private keys can be any part of the MultiEd25519PrivateKey, even none of them.
 #[derive(Eq, PartialEq, Serialize, Deserialize)]
 pub struct MultiEd25519KeyShard {
     /// Public keys must contains all public key of the MultiEd25519PrivateKey
     public_keys: Vec<Ed25519PublicKey>,
     threshold: u8,
+    /// The private keys, sorted by the index in MultiEd25519PrivateKey.
     private_keys: Vec<Ed25519PrivateKey>,
-    /// The private_key index in MultiEd25519PrivateKey, if has multi private_keys, use the first key index.
-    index: u8,
+    /// The index of every private key in MultiEd25519PrivateKey.
+    indexes: Vec<u8>,
 }
 
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -45,37 +49,106 @@ impl MultiEd25519KeyShard {
         Self::new_multi(public_keys, threshold, vec![private_key], index)
     }
 
+    /// Create a shard with the sequential `private_keys`, `index` is the index of the first key.
     pub fn new_multi(
         public_keys: Vec<Ed25519PublicKey>,
         threshold: u8,
         private_keys: Vec<Ed25519PrivateKey>,
         index: u8,
+    ) -> Result<Self, CryptoMaterialError> {
+        if private_keys.is_empty() {
+            return Err(CryptoMaterialError::ValidationError);
+        }
+        if private_keys.len() > MAX_NUM_OF_KEYS || index as usize >= MAX_NUM_OF_KEYS {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        let indexes = (0..private_keys.len())
+            .map(|i| index as usize + i)
+            .map(|i| u8::try_from(i).map_err(|_| CryptoMaterialError::WrongLengthError))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new_with_indexes(public_keys, threshold, private_keys, indexes)
+    }
+
+    /// Create a shard with the `private_keys` at the given `indexes`, the `indexes` must be
+    /// strictly increasing, and the `private_keys` may be empty.
+    pub fn new_with_indexes(
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Ed25519PrivateKey>,
+        indexes: Vec<u8>,
     ) -> Result<Self, CryptoMaterialError> {
         let num_of_public_keys = public_keys.len();
-        let num_of_private_keys = private_keys.len();
-        if threshold == 0 || num_of_private_keys == 0 || num_of_public_keys < threshold as usize {
-            Err(CryptoMaterialError::ValidationError)
-        } else if num_of_private_keys > MAX_NUM_OF_KEYS
-            || num_of_public_keys > MAX_NUM_OF_KEYS
-            || (index as usize) >= num_of_public_keys
-        {
-            Err(CryptoMaterialError::WrongLengthError)
-        } else {
-            for (i, private_key) in private_keys.iter().enumerate() {
-                let public_key_idx = (index as usize) + i;
-                let public_key = &public_keys[public_key_idx];
-                let private_key_public_key = private_key.public_key();
-                if public_key != &private_key_public_key {
-                    return Err(CryptoMaterialError::ValidationError);
-                }
+        if threshold == 0 || num_of_public_keys < threshold as usize {
+            return Err(CryptoMaterialError::ValidationError);
+        }
+        if num_of_public_keys > MAX_NUM_OF_KEYS || private_keys.len() != indexes.len() {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        if indexes.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(CryptoMaterialError::ValidationError);
+        }
+        for (private_key, index) in private_keys.iter().zip(indexes.iter()) {
+            let public_key = public_keys
+                .get(*index as usize)
+                .ok_or(CryptoMaterialError::WrongLengthError)?;
+            if public_key != &private_key.public_key() {
+                return Err(CryptoMaterialError::ValidationError);
             }
-            Ok(Self {
-                public_keys,
-                threshold,
-                private_keys,
-                index,
+        }
+        Ok(Self {
+            public_keys,
+            threshold,
+            private_keys,
+            indexes,
+        })
+    }
+
+    /// Create a shard with the `private_keys` of any indexes, the index of every private key is
+    /// found by its public key. The `private_keys` may be empty, then the shard only holds the
+    /// multi public key and can not sign.
+    pub fn from_private_keys(
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Ed25519PrivateKey>,
+    ) -> Result<Self> {
+        ensure!(
+            public_keys.len() <= MAX_NUM_OF_KEYS,
+            "the number of public keys {} exceeds the max {}",
+            public_keys.len(),
+            MAX_NUM_OF_KEYS
+        );
+        ensure!(
+            threshold > 0 && threshold as usize <= public_keys.len(),
+            "threshold {} should be in [1, {}]",
+            threshold,
+            public_keys.len()
+        );
+        let mut keys = private_keys
+            .into_iter()
+            .map(|private_key| {
+                let public_key = private_key.public_key();
+                let index = public_keys
+                    .iter()
+                    .position(|key| key == &public_key)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "the public key {} of private key is not in the public keys",
+                            hex::encode(public_key.to_bytes())
+                        )
+                    })?;
+                Ok((index as u8, private_key))
             })
+            .collect::<Result<Vec<_>>>()?;
+        keys.sort_by_key(|(index, _)| *index);
+        if let Some(pair) = keys.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            bail!(
+                "duplicate private key of public key {}",
+                hex::encode(public_keys[pair[0].0 as usize].to_bytes())
+            );
         }
+        let (indexes, private_keys) = keys.into_iter().unzip();
+        Self::new_with_indexes(public_keys, threshold, private_keys, indexes)
+            .map_err(anyhow::Error::new)
     }
 
     /// Generate `shards` MultiEd25519SignatureShard for test
@@ -116,8 +189,9 @@ impl MultiEd25519KeyShard {
         self.threshold
     }
 
-    pub fn index(&self) -> u8 {
-        self.index
+    /// The index of every private key in MultiEd25519PrivateKey.
+    pub fn indexes(&self) -> &[u8] {
+        self.indexes.as_slice()
     }
 
     pub fn len(&self) -> usize {
@@ -128,12 +202,13 @@ impl MultiEd25519KeyShard {
         self.private_keys.is_empty()
     }
 
+    /// Sign the `message` with all private keys of the shard, the shard should not be empty.
     pub fn sign<T: CryptoHash + Serialize>(&self, message: &T) -> MultiEd25519SignatureShard {
         let signatures: Vec<(Ed25519Signature, u8)> = self
             .private_keys
             .iter()
-            .enumerate()
-            .map(|(i, item)| (item.sign(message), self.index + (i as u8)))
+            .zip(self.indexes.iter())
+            .map(|(item, index)| (item.sign(message), *index))
             .collect();
 
         MultiEd25519SignatureShard::new(
@@ -145,13 +220,22 @@ impl MultiEd25519KeyShard {
 
 impl ValidCryptoMaterial for MultiEd25519KeyShard {
     /// Serialize a MultiEd25519PrivateKeyShard.
+    /// The shard with sequential private keys is serialized with the index of the first key,
+    /// otherwise with the flag index followed by the indexes of all keys.
     #[allow(clippy::vec_init_then_push)]
     fn to_bytes(&self) -> Vec<u8> {
+        let sequential =
+            !self.indexes.is_empty() && self.indexes.windows(2).all(|pair| pair[0] + 1 == pair[1]);
         let mut bytes: Vec<u8> = vec![];
         bytes.push(self.public_keys.len() as u8);
         bytes.push(self.threshold);
         bytes.push(self.private_keys.len() as u8);
-        bytes.push(self.index);
+        if sequential {
+            bytes.push(self.indexes[0]);
+        } else {
+            bytes.push(UNSEQUENTIAL_INDEXES_FLAG);
+            bytes.extend(self.indexes.iter());
+        }
 
         bytes.extend(
             self.public_keys
@@ -195,26 +279,43 @@ impl TryFrom<&[u8]> for MultiEd25519KeyShard {
         let threshold = bytes[1];
         let private_key_len = bytes[2];
         let index = bytes[3];
+        let (indexes, header_len) = if index == UNSEQUENTIAL_INDEXES_FLAG {
+            let header_len = 4 + private_key_len as usize;
+            if bytes_len < header_len {
+                return Err(CryptoMaterialError::WrongLengthError);
+            }
+            (Some(bytes[4..header_len].to_vec()), header_len)
+        } else {
+            (None, 4)
+        };
 
         let public_key_bytes_len = public_key_len as usize * ED25519_PUBLIC_KEY_LENGTH;
         let private_key_bytes_len = private_key_len as usize * ED25519_PRIVATE_KEY_LENGTH;
-        if bytes_len < 4 + public_key_bytes_len + private_key_bytes_len {
+        if bytes_len < header_len + public_key_bytes_len + private_key_bytes_len {
             return Err(CryptoMaterialError::WrongLengthError);
         }
-        let public_key_bytes = &bytes[4..4 + public_key_bytes_len];
+        let public_key_bytes = &bytes[header_len..header_len + public_key_bytes_len];
 
         let public_keys: Result<Vec<Ed25519PublicKey>, _> = public_key_bytes
             .chunks_exact(ED25519_PUBLIC_KEY_LENGTH)
             .map(Ed25519PublicKey::try_from)
             .collect();
 
-        let private_key_bytes = &bytes[4 + public_key_bytes_len..];
+        let private_key_bytes = &bytes[header_len + public_key_bytes_len..];
         let private_keys: Result<Vec<Ed25519PrivateKey>, _> = private_key_bytes
             .chunks_exact(ED25519_PRIVATE_KEY_LENGTH)
             .map(Ed25519PrivateKey::try_from)
             .collect();
 
-        MultiEd25519KeyShard::new_multi(public_keys?, threshold, private_keys?, index)
+        match indexes {
+            Some(indexes) => MultiEd25519KeyShard::new_with_indexes(
+                public_keys?,
+                threshold,
+                private_keys?,
+                indexes,
+            ),
+            None => MultiEd25519KeyShard::new_multi(public_keys?, threshold, private_keys?, index),
+        }
     }
 }
 
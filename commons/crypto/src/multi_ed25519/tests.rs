@@ -1,7 +1,8 @@
 use super::*;
+use crate::ed25519::Ed25519PrivateKey;
 use crate::multi_ed25519::multi_shard::MultiEd25519SignatureShard;
 use crate::test_utils::{TestDiemCrypto, TEST_SEED};
-use crate::{Signature, ValidCryptoMaterial, ValidCryptoMaterialStringExt};
+use crate::{PrivateKey, Signature, Uniform, ValidCryptoMaterial, ValidCryptoMaterialStringExt};
 use once_cell::sync::Lazy;
 use rand::prelude::*;
 use std::convert::TryFrom;
@@ -69,3 +70,60 @@ pub fn test_shard_sign_and_verify() {
     let multi_signature: MultiEd25519Signature = signature2of3.into();
     multi_signature.verify(msg, &public_key).unwrap();
 }
+
+#[test]
+pub fn test_shard_of_unsequential_keys() {
+    let mut rng = StdRng::from_seed(TEST_SEED);
+    let private_keys = (0..4)
+        .map(|_| Ed25519PrivateKey::generate(&mut rng))
+        .collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|key| key.public_key())
+        .collect::<Vec<_>>();
+    let key_of = |i: usize| Ed25519PrivateKey::try_from(private_keys[i].to_bytes().as_slice());
+
+    // the private keys of index 3 and 1, in any order.
+    let shard = MultiEd25519KeyShard::from_private_keys(
+        public_keys.clone(),
+        2,
+        vec![key_of(3).unwrap(), key_of(1).unwrap()],
+    )
+    .unwrap();
+    assert_eq!(shard.indexes(), &[1, 3]);
+    let bytes = shard.to_bytes();
+    assert_eq!(
+        MultiEd25519KeyShard::try_from(bytes.as_slice()).unwrap(),
+        shard
+    );
+    let signature = shard.sign(message());
+    assert!(signature.is_enough());
+    let multi_signature: MultiEd25519Signature = signature.into();
+    multi_signature
+        .verify(message(), &shard.public_key())
+        .unwrap();
+
+    // a shard without private keys only holds the public key.
+    let shard = MultiEd25519KeyShard::from_private_keys(public_keys.clone(), 2, vec![]).unwrap();
+    assert!(shard.is_empty());
+    let bytes = shard.to_bytes();
+    assert_eq!(
+        MultiEd25519KeyShard::try_from(bytes.as_slice()).unwrap(),
+        shard
+    );
+
+    // duplicate or unknown private keys are invalid.
+    assert!(MultiEd25519KeyShard::from_private_keys(
+        public_keys.clone(),
+        2,
+        vec![key_of(0).unwrap(), key_of(0).unwrap()],
+    )
+    .is_err());
+    assert!(MultiEd25519KeyShard::from_private_keys(
+        public_keys[1..].to_vec(),
+        2,
+        vec![key_of(0).unwrap()],
+    )
+    .is_err());
+    assert!(MultiEd25519KeyShard::from_private_keys(public_keys, 5, vec![]).is_err());
+}
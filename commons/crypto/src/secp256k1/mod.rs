@@ -0,0 +1,185 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! ECDSA signatures over the secp256k1 curve.
+//! The message is signed by its `CryptoHash`, the signature is in the compact (r, s) form with a
+//! low s, and the public key is in the compressed form.
+
+use crate::derive::{DeserializeKey, SerializeKey};
+use crate::hash::CryptoHash;
+use crate::{CryptoMaterialError, ValidCryptoMaterial, ValidCryptoMaterialStringExt};
+use anyhow::{bail, Result};
+use rand::{CryptoRng, RngCore};
+use serde::Serialize;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The length of the Secp256k1PrivateKey
+pub const SECP256K1_PRIVATE_KEY_LENGTH: usize = 32;
+/// The length of the compressed Secp256k1PublicKey
+pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = 33;
+/// The length of the compact Secp256k1Signature
+pub const SECP256K1_SIGNATURE_LENGTH: usize = 64;
+
+/// A secp256k1 private key.
+#[derive(DeserializeKey, SerializeKey, Eq, PartialEq)]
+pub struct Secp256k1PrivateKey(secp256k1::SecretKey);
+
+/// A secp256k1 public key.
+#[derive(DeserializeKey, SerializeKey, Clone, Eq, PartialEq)]
+pub struct Secp256k1PublicKey(secp256k1::PublicKey);
+
+/// A secp256k1 ECDSA signature.
+#[derive(DeserializeKey, SerializeKey, Clone, Eq, PartialEq)]
+pub struct Secp256k1Signature(secp256k1::Signature);
+
+impl Secp256k1PrivateKey {
+    /// Generate a random private key.
+    pub fn generate<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + CryptoRng,
+    {
+        loop {
+            let mut bytes = [0u8; SECP256K1_PRIVATE_KEY_LENGTH];
+            rng.fill_bytes(&mut bytes);
+            // retry if the bytes overflow the curve order or is zero.
+            if let Ok(key) = secp256k1::SecretKey::parse(&bytes) {
+                return Self(key);
+            }
+        }
+    }
+
+    pub fn public_key(&self) -> Secp256k1PublicKey {
+        Secp256k1PublicKey(secp256k1::PublicKey::from_secret_key(&self.0))
+    }
+
+    /// Sign the `CryptoHash` of the message.
+    pub fn sign<T: CryptoHash + Serialize>(&self, message: &T) -> Secp256k1Signature {
+        let message = secp256k1::Message::parse(message.hash().as_ref());
+        let (signature, _recovery_id) = secp256k1::sign(&message, &self.0);
+        Secp256k1Signature(signature)
+    }
+}
+
+impl Secp256k1Signature {
+    /// Verify the signature of the `CryptoHash` of the message by the public key.
+    pub fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Secp256k1PublicKey,
+    ) -> Result<()> {
+        // reject the high s signature, to avoid the signature malleability.
+        if self.0.s.is_high() {
+            bail!("Secp256k1 signature with high s is not allowed");
+        }
+        let message = secp256k1::Message::parse(message.hash().as_ref());
+        if !secp256k1::verify(&message, &self.0, &public_key.0) {
+            bail!("Secp256k1 signature verification failed");
+        }
+        Ok(())
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1PrivateKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.serialize().to_vec()
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1PublicKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.serialize_compressed().to_vec()
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.serialize().to_vec()
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1PrivateKey {
+    type Error = CryptoMaterialError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != SECP256K1_PRIVATE_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        secp256k1::SecretKey::parse_slice(bytes)
+            .map(Self)
+            .map_err(|_| CryptoMaterialError::DeserializationError)
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1PublicKey {
+    type Error = CryptoMaterialError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != SECP256K1_PUBLIC_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        let mut compressed = [0u8; SECP256K1_PUBLIC_KEY_LENGTH];
+        compressed.copy_from_slice(bytes);
+        secp256k1::PublicKey::parse_compressed(&compressed)
+            .map(Self)
+            .map_err(|_| CryptoMaterialError::DeserializationError)
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1Signature {
+    type Error = CryptoMaterialError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != SECP256K1_SIGNATURE_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        secp256k1::Signature::parse_slice(bytes)
+            .map(Self)
+            .map_err(|_| CryptoMaterialError::DeserializationError)
+    }
+}
+
+impl std::hash::Hash for Secp256k1PublicKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write(&self.to_bytes())
+    }
+}
+
+impl std::hash::Hash for Secp256k1Signature {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write(&self.to_bytes())
+    }
+}
+
+impl fmt::Debug for Secp256k1PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1PrivateKey(<elided secret>)")
+    }
+}
+
+impl fmt::Debug for Secp256k1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1PublicKey({})", hex::encode(&self.to_bytes()))
+    }
+}
+
+impl fmt::Display for Secp256k1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()))
+    }
+}
+
+impl fmt::Debug for Secp256k1Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1Signature({})", hex::encode(&self.to_bytes()))
+    }
+}
+
+impl fmt::Display for Secp256k1Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use super::*;
+use crate::test_utils::{TestDiemCrypto, TEST_SEED};
+use rand::prelude::*;
+
+fn generate_key() -> Secp256k1PrivateKey {
+    let mut rng = StdRng::from_seed(TEST_SEED);
+    Secp256k1PrivateKey::generate(&mut rng)
+}
+
+#[test]
+pub fn test_sign_and_verify() {
+    let private_key = generate_key();
+    let public_key = private_key.public_key();
+    let message = TestDiemCrypto("Test Message".to_string());
+    let signature = private_key.sign(&message);
+    assert!(signature.verify(&message, &public_key).is_ok());
+
+    let other_message = TestDiemCrypto("Other Message".to_string());
+    assert!(signature.verify(&other_message, &public_key).is_err());
+
+    let mut rng = rand::rngs::OsRng;
+    let other_public_key = Secp256k1PrivateKey::generate(&mut rng).public_key();
+    assert!(signature.verify(&message, &other_public_key).is_err());
+}
+
+#[test]
+pub fn test_key_and_signature_serialize() {
+    let private_key = generate_key();
+    let public_key = private_key.public_key();
+    let signature = private_key.sign(&TestDiemCrypto("Test Message".to_string()));
+
+    let private_key2 = Secp256k1PrivateKey::try_from(private_key.to_bytes().as_slice()).unwrap();
+    assert_eq!(private_key, private_key2);
+    assert_eq!(public_key.to_bytes().len(), SECP256K1_PUBLIC_KEY_LENGTH);
+    let public_key2 =
+        Secp256k1PublicKey::from_encoded_string(public_key.to_encoded_string().unwrap().as_str())
+            .unwrap();
+    assert_eq!(public_key, public_key2);
+    let signature2 = Secp256k1Signature::try_from(signature.to_bytes().as_slice()).unwrap();
+    assert_eq!(signature, signature2);
+
+    let bytes = bcs_ext::to_bytes(&signature).unwrap();
+    let signature3: Secp256k1Signature = bcs_ext::from_bytes(bytes.as_slice()).unwrap();
+    assert_eq!(signature, signature3);
+}
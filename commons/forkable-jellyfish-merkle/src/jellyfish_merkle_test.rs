@@ -692,6 +692,16 @@ proptest! {
         let root_hash = root_hash_option.unwrap();
         let nth_key = *btree.keys().nth(n).unwrap();
         let proof = tree.get_range_proof(root_hash, nth_key.key_hash()).unwrap();
+        let leaves: Vec<_> = btree
+            .iter()
+            .take(n + 1)
+            .map(|(key, blob)| (key.key_hash(), blob.crypto_hash()))
+            .collect();
+        prop_assert!(proof.verify(root_hash, &leaves).is_ok());
+        if n > 0 {
+            // Missing the leftmost leaf.
+            prop_assert!(proof.verify(root_hash, &leaves[1..]).is_err());
+        }
         verify_range_proof(
             root_hash,
             btree.into_iter().take(n + 1).collect(),
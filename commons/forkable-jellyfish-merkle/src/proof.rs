@@ -172,4 +172,134 @@ impl SparseMerkleRangeProof {
     pub fn right_siblings(&self) -> &[HashValue] {
         &self.right_siblings
     }
+
+    /// Verifies that `leaves` are all the leaves of the tree from the leftmost one up to the last
+    /// one in `leaves`. `leaves` are the pairs of key and value hash in the order of key.
+    pub fn verify(
+        &self,
+        expected_root_hash: HashValue,
+        leaves: &[(HashValue, HashValue)],
+    ) -> Result<()> {
+        self.verify_with_left_nodes(expected_root_hash, vec![], leaves)
+    }
+
+    /// Verifies that `leaves` are all the leaves of the tree from the first one in `leaves` up to
+    /// the last one, so a chunk of leaves can be verified without the leaves before it.
+    /// The subtrees on the left of the first leaf are authenticated by `first_leaf_proof`, the
+    /// inclusion proof of the first leaf.
+    pub fn verify_chunk(
+        &self,
+        expected_root_hash: HashValue,
+        first_leaf_proof: &SparseMerkleProof,
+        leaves: &[(HashValue, HashValue)],
+    ) -> Result<()> {
+        let first_leaf = match leaves.first() {
+            Some(leaf) => *leaf,
+            None => bail!("Range proof should prove at least one leaf."),
+        };
+        ensure!(
+            first_leaf_proof.leaf() == Some(first_leaf),
+            "The proof of the first leaf is not an inclusion proof of key {:x}.",
+            first_leaf.0
+        );
+        let siblings = first_leaf_proof.siblings();
+        ensure!(
+            siblings.len() <= HashValue::LENGTH_IN_BITS,
+            "Sparse Merkle Tree proof has more than {} ({}) siblings.",
+            HashValue::LENGTH_IN_BITS,
+            siblings.len(),
+        );
+        // The siblings are ordered from the bottom level, a sibling is on the left when the path
+        // to the first leaf goes right.
+        let mut left_nodes = vec![];
+        for ((depth, bit), sibling) in first_leaf
+            .0
+            .iter_bits()
+            .enumerate()
+            .take(siblings.len())
+            .zip(siblings.iter().rev())
+        {
+            if bit {
+                left_nodes.push((sibling_position(first_leaf.0, depth, false)?, *sibling));
+            }
+        }
+        self.verify_with_left_nodes(expected_root_hash, left_nodes, leaves)
+    }
+
+    fn verify_with_left_nodes(
+        &self,
+        expected_root_hash: HashValue,
+        left_nodes: Vec<(HashValue, HashValue)>,
+        leaves: &[(HashValue, HashValue)],
+    ) -> Result<()> {
+        ensure!(
+            self.right_siblings.len() <= HashValue::LENGTH_IN_BITS,
+            "Sparse Merkle Tree range proof has more than {} ({}) siblings.",
+            HashValue::LENGTH_IN_BITS,
+            self.right_siblings.len(),
+        );
+        let last_key = match leaves.last() {
+            Some((key, _)) => *key,
+            None => bail!("Range proof should prove at least one leaf."),
+        };
+        ensure!(
+            leaves.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "Leaves of range proof must be sorted by key without duplicates."
+        );
+
+        // Every sibling is the only node in the subtree on the side of the path to the leaf, so
+        // it is put at a made-up key which ends up in that subtree: the path to the sibling's
+        // position, followed by zeros.
+        let mut nodes = left_nodes;
+        nodes.extend(leaves.iter().map(|(key, value_hash)| {
+            (
+                *key,
+                SparseMerkleLeafNode::new(*key, *value_hash).crypto_hash(),
+            )
+        }));
+        for (depth, sibling) in last_key
+            .iter_bits()
+            .enumerate()
+            .filter_map(|(depth, bit)| if !bit { Some(depth) } else { None })
+            .zip(self.right_siblings.iter().rev())
+        {
+            nodes.push((sibling_position(last_key, depth, true)?, *sibling));
+        }
+        nodes.sort_by_key(|(key, _)| *key);
+
+        let actual_root_hash = Self::subtree_hash(&nodes, 0);
+        ensure!(
+            actual_root_hash == expected_root_hash,
+            "Root hashes do not match. Actual root hash: {:x}. Expected root hash: {:x}.",
+            actual_root_hash,
+            expected_root_hash,
+        );
+        Ok(())
+    }
+
+    /// Computes the hash of the subtree at `depth` which contains all the sorted `nodes`.
+    fn subtree_hash(nodes: &[(HashValue, HashValue)], depth: usize) -> HashValue {
+        match nodes {
+            [] => *SPARSE_MERKLE_PLACEHOLDER_HASH,
+            [(_, hash)] => *hash,
+            _ => {
+                let split = nodes
+                    .iter()
+                    .position(|(key, _)| key.iter_bits().nth(depth) == Some(true))
+                    .unwrap_or_else(|| nodes.len());
+                let left = Self::subtree_hash(&nodes[..split], depth + 1);
+                let right = Self::subtree_hash(&nodes[split..], depth + 1);
+                SparseMerkleInternalNode::new(left, right).crypto_hash()
+            }
+        }
+    }
+}
+
+/// A made-up key in the subtree of the sibling at `depth` of the path to `key`, `bit` is the side
+/// of the sibling.
+fn sibling_position(key: HashValue, depth: usize, bit: bool) -> Result<HashValue> {
+    let mut bits: Vec<_> = key.iter_bits().take(depth).collect();
+    bits.push(bit);
+    bits.resize(HashValue::LENGTH_IN_BITS, false);
+    HashValue::from_bit_iter(bits.into_iter())
 }
@@ -0,0 +1,76 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, RwLock};
+
+static HEALTH_CHECKER: Lazy<RwLock<Option<Arc<dyn HealthChecker>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HealthCheck {
+    pub name: String,
+    pub healthy: bool,
+    /// Structured detail of the check, such as the observed value and the threshold.
+    pub detail: Value,
+}
+
+impl HealthCheck {
+    pub fn new(name: &str, healthy: bool, detail: Value) -> Self {
+        Self {
+            name: name.to_string(),
+            healthy,
+            detail,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// True if all checks are healthy.
+    pub healthy: bool,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    pub fn new(checks: Vec<HealthCheck>) -> Self {
+        Self {
+            healthy: checks.iter().all(|check| check.healthy),
+            checks,
+        }
+    }
+}
+
+/// Report the node health for the /health and /ready endpoints of metric server.
+#[async_trait::async_trait]
+pub trait HealthChecker: Send + Sync {
+    /// Whether the node is alive, an unhealthy node should been restarted.
+    async fn health(&self) -> HealthReport;
+    /// Whether the node is ready to serve requests.
+    async fn readiness(&self) -> HealthReport;
+}
+
+/// Set the global health checker, the metric server start before the node services,
+/// the checker is set after the services are registered.
+pub fn set_health_checker(checker: Arc<dyn HealthChecker>) {
+    *HEALTH_CHECKER
+        .write()
+        .expect("Health checker lock should not poisoned.") = Some(checker);
+}
+
+/// Clear the global health checker when the node stopped, the endpoints report the node
+/// unavailable until a checker is set again.
+pub fn clear_health_checker() {
+    *HEALTH_CHECKER
+        .write()
+        .expect("Health checker lock should not poisoned.") = None;
+}
+
+pub fn health_checker() -> Option<Arc<dyn HealthChecker>> {
+    HEALTH_CHECKER
+        .read()
+        .expect("Health checker lock should not poisoned.")
+        .clone()
+}
@@ -23,6 +23,7 @@ use std::{
     thread, time,
 };
 
+pub mod health;
 mod json_encoder;
 pub mod metric_server;
 mod op_counters;
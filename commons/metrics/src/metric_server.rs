@@ -1,9 +1,11 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::health::{health_checker, HealthCheck, HealthReport};
 use crate::json_encoder::JsonEncoder;
 use futures::future;
 use hyper::{
+    header::{HeaderValue, CONTENT_TYPE},
     service::{make_service_fn, service_fn},
     Body, Method, Request, Response, Server, StatusCode,
 };
@@ -23,7 +25,39 @@ fn encode_metrics(encoder: impl Encoder) -> Vec<u8> {
     buffer
 }
 
-async fn serve_metrics(req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
+async fn check_health(ready: bool) -> HealthReport {
+    match health_checker() {
+        Some(checker) => {
+            if ready {
+                checker.readiness().await
+            } else {
+                checker.health().await
+            }
+        }
+        None => HealthReport::new(vec![HealthCheck::new(
+            "node",
+            false,
+            "node is not started".into(),
+        )]),
+    }
+}
+
+fn health_response(resp: &mut Response<Body>, report: HealthReport) {
+    if !report.healthy {
+        *resp.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+    }
+    resp.headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    match serde_json::to_vec(&report) {
+        Ok(body) => *resp.body_mut() = Body::from(body),
+        Err(e) => {
+            error!("Encode health report error: {:?}", e);
+            *resp.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        }
+    }
+}
+
+pub(crate) async fn serve_metrics(req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
     let mut resp = Response::new(Body::empty());
     match (req.method(), req.uri().path()) {
         (&Method::GET, "/metrics") => {
@@ -39,6 +73,14 @@ async fn serve_metrics(req: Request<Body>) -> Result<Response<Body>, hyper::Erro
             let buffer = encode_metrics(encoder);
             *resp.body_mut() = Body::from(buffer);
         }
+        // liveness probe, 503 if the node is unhealthy.
+        (&Method::GET, "/health") => {
+            health_response(&mut resp, check_health(false).await);
+        }
+        // readiness probe, 503 if the node is not ready.
+        (&Method::GET, "/ready") => {
+            health_response(&mut resp, check_health(true).await);
+        }
         _ => {
             *resp.status_mut() = StatusCode::NOT_FOUND;
         }
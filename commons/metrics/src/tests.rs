@@ -1,10 +1,17 @@
 use super::*;
+use crate::health::{
+    clear_health_checker, set_health_checker, HealthCheck, HealthChecker, HealthReport,
+};
 use crate::json_encoder::JsonEncoder;
+use crate::metric_server::serve_metrics;
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::{Body, Request, StatusCode};
 use prometheus::{
     core::{Collector, Metric},
     IntCounter, IntCounterVec, Opts,
 };
-use serde_json::Value;
+use serde_json::{json, Value};
+use std::sync::Arc;
 
 #[test]
 fn test_flatten_labels() {
@@ -72,3 +79,65 @@ fn test_encoder() {
 
     assert_eq!(v, expected_v);
 }
+
+struct MockHealthChecker;
+
+#[async_trait::async_trait]
+impl HealthChecker for MockHealthChecker {
+    async fn health(&self) -> HealthReport {
+        HealthReport::new(vec![HealthCheck::new("services", true, Value::Null)])
+    }
+
+    async fn readiness(&self) -> HealthReport {
+        HealthReport::new(vec![
+            HealthCheck::new("services", true, Value::Null),
+            HealthCheck::new("peers", false, json!({ "count": 0, "min": 1 })),
+        ])
+    }
+}
+
+fn get_health(path: &str) -> (StatusCode, Option<HeaderValue>, HealthReport) {
+    futures::executor::block_on(async {
+        let req = Request::get(path).body(Body::empty()).unwrap();
+        let resp = serve_metrics(req).await.unwrap();
+        let status = resp.status();
+        let content_type = resp.headers().get(CONTENT_TYPE).cloned();
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        (status, content_type, serde_json::from_slice(&body).unwrap())
+    })
+}
+
+#[test]
+fn test_health_endpoints() {
+    let json_content_type = Some(HeaderValue::from_static("application/json"));
+    let not_started = vec![HealthCheck::new(
+        "node",
+        false,
+        "node is not started".into(),
+    )];
+
+    let (status, _, report) = get_health("/health");
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(report.checks, not_started);
+
+    set_health_checker(Arc::new(MockHealthChecker));
+    let (status, content_type, report) = get_health("/health");
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, json_content_type);
+    assert!(report.healthy);
+
+    // a not ready report returns 503 with the checks.
+    let (status, content_type, report) = get_health("/ready");
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(content_type, json_content_type);
+    assert!(!report.healthy);
+    assert_eq!(
+        report.checks[1],
+        HealthCheck::new("peers", false, json!({ "count": 0, "min": 1 }))
+    );
+
+    clear_health_checker();
+    let (status, _, report) = get_health("/ready");
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(report.checks, not_started);
+}
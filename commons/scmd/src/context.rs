@@ -4,7 +4,7 @@
 use crate::error::CmdError;
 use crate::{print_action_result, Command, CommandAction, CommandExec, OutputFormat};
 use anyhow::Result;
-use clap::{crate_authors, App, Arg, SubCommand};
+use clap::{crate_authors, App, Arg, ArgMatches, SubCommand};
 use once_cell::sync::Lazy;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -33,6 +33,8 @@ pub static DEFAULT_CONSOLE_CONFIG: Lazy<ConsoleConfig> = Lazy::new(|| {
 
 static OUTPUT_FORMAT_ARG: &str = "output-format";
 
+type StatelessCommandExec<GlobalOpt> = Box<dyn FnOnce(&GlobalOpt, &ArgMatches) -> Result<Value>>;
+
 pub struct CmdContext<State, GlobalOpt>
 where
     State: 'static,
@@ -40,6 +42,7 @@ where
 {
     app: App<'static, 'static>,
     commands: HashMap<String, Box<dyn CommandExec<State, GlobalOpt>>>,
+    stateless_commands: HashMap<String, StatelessCommandExec<GlobalOpt>>,
     default_action: Box<dyn FnOnce(App, GlobalOpt, State)>,
     state_initializer: Box<dyn FnOnce(&GlobalOpt) -> Result<State>>,
     console_support: Option<(
@@ -109,6 +112,7 @@ where
         Self {
             app,
             commands: HashMap::new(),
+            stateless_commands: HashMap::new(),
             default_action: Box::new(default_action),
             state_initializer: Box::new(state_initializer),
             console_support: None,
@@ -154,7 +158,10 @@ where
         if self.commands.contains_key(name) {
             panic!("Command with name {} exist.", name);
         }
-        let order = self.commands.len();
+        if self.stateless_commands.contains_key(name) {
+            panic!("Command with name {} exist.", name);
+        }
+        let order = self.commands.len() + self.stateless_commands.len();
         self.app = self
             .app
             .subcommand(command.app().clone().display_order(order));
@@ -162,6 +169,33 @@ where
         self
     }
 
+    /// Add a command which runs without the state, the state initializer is not called for it,
+    /// so it can be used for the work which must not init the state, such as start a node.
+    /// The command's name is the name of `Opt`, and it is not available in the console.
+    /// An error of the command is returned by `exec`, instead of be printed as the result.
+    pub fn stateless_command<Opt, ReturnItem, Action>(mut self, action: Action) -> Self
+    where
+        Opt: StructOpt + 'static,
+        ReturnItem: serde::Serialize + 'static,
+        Action: FnOnce(&GlobalOpt, Opt) -> Result<ReturnItem> + 'static,
+    {
+        let app = Opt::clap();
+        let name = app.get_name().to_string();
+        if self.commands.contains_key(&name) || self.stateless_commands.contains_key(&name) {
+            panic!("Command with name {} exist.", name);
+        }
+        let order = self.commands.len() + self.stateless_commands.len();
+        self.app = self.app.subcommand(app.display_order(order));
+        self.stateless_commands.insert(
+            name,
+            Box::new(move |global_opt, arg_matches| {
+                let opt = Opt::from_clap(arg_matches);
+                Ok(serde_json::to_value(action(global_opt, opt)?)?)
+            }),
+        );
+        self
+    }
+
     pub fn print_help(&mut self) {
         self.app
             .print_long_help()
@@ -212,9 +246,15 @@ where
             .expect("parse output-format must success.");
 
         let global_opt = GlobalOpt::from_clap(&matches);
+        let (cmd_name, arg_matches) = matches.subcommand();
+        if let (Some(cmd), Some(arg_matches)) =
+            (self.stateless_commands.remove(cmd_name), arg_matches)
+        {
+            let value = cmd(&global_opt, arg_matches)?;
+            return Ok((output_format, Ok(value)));
+        }
         let state = (self.state_initializer)(&global_opt)?;
 
-        let (cmd_name, arg_matches) = matches.subcommand();
         let default_action = self.default_action;
         let result = match cmd_name {
             "console" => {
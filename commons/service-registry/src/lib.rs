@@ -3,6 +3,7 @@
 
 pub mod bus;
 mod handler_proxy;
+mod metrics;
 pub mod mocker;
 mod service;
 mod service_actor;
@@ -0,0 +1,42 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::ServiceStatus;
+use once_cell::sync::Lazy;
+use starcoin_metrics::{
+    register_int_counter_vec, register_int_gauge_vec, IntCounterVec, IntGaugeVec, Opts,
+};
+
+const ALL_STATUS: [ServiceStatus; 4] = [
+    ServiceStatus::Started,
+    ServiceStatus::Stopped,
+    ServiceStatus::Shutdown,
+    ServiceStatus::Unavailable,
+];
+
+pub static SERVICE_STATUS: Lazy<IntGaugeVec> = Lazy::new(|| {
+    let opts = Opts::new(
+        "service_status",
+        "Service status, the current status of a service is 1, others are 0",
+    )
+    .namespace("starcoin");
+    register_int_gauge_vec!(opts, &["service", "status"]).unwrap()
+});
+
+pub static SERVICE_RESTART_COUNTER: Lazy<IntCounterVec> = Lazy::new(|| {
+    let opts = Opts::new(
+        "service_restart",
+        "Counter of how many times a service is restarted by supervision",
+    )
+    .namespace("starcoin");
+    register_int_counter_vec!(opts, &["service"]).unwrap()
+});
+
+pub fn record_service_status(service_name: &str, status: ServiceStatus) {
+    for s in ALL_STATUS.iter() {
+        let value = if *s == status { 1 } else { 0 };
+        SERVICE_STATUS
+            .with_label_values(&[service_name, s.to_string().as_str()])
+            .set(value);
+    }
+}
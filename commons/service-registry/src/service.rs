@@ -4,7 +4,7 @@
 use crate::bus::{Bus, BusService};
 use crate::service_actor::{EventMessage, ServiceActor};
 use crate::service_cache::ServiceCache;
-use crate::{RegistryAsyncService, RegistryService};
+use crate::{RegistryAsyncService, RegistryService, SupervisionPolicy};
 use crate::{ServiceRef, ServiceRequest};
 use actix::fut::{wrap_future, IntoActorFuture};
 use actix::{ActorContext, ActorFuture, AsyncContext, Context};
@@ -23,6 +23,17 @@ pub trait ActorService: Send + Sized {
         type_name::<Self>()
     }
 
+    /// How the registry handles the service actor thread shutdown unexpectedly.
+    fn supervision_policy() -> SupervisionPolicy {
+        SupervisionPolicy::Ignore
+    }
+
+    /// Names of the services this service depends on, they must be registered before this service.
+    /// Dependencies are started before, and stopped after this service.
+    fn dependencies() -> Vec<&'static str> {
+        vec![]
+    }
+
     fn started(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
         Ok(())
     }
@@ -6,7 +6,6 @@ use crate::{ActorService, RegistryAsyncService, RegistryService, ServiceRef};
 use anyhow::{format_err, Result};
 use futures::executor::block_on;
 use std::any::{Any, TypeId};
-use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 pub(crate) struct ServiceCache {
@@ -32,23 +31,38 @@ impl ServiceCache {
         })
     }
 
+    /// Get the service ref from cache, the cached ref is refreshed from registry if the service
+    /// actor is disconnected, because the service may been restarted by registry supervision.
     pub fn service_ref<S>(&mut self) -> Result<Option<&ServiceRef<S>>>
     where
         S: ActorService,
     {
         let type_id = TypeId::of::<S>();
-        let entry = self.service_ref_cache.entry(type_id);
-        let any_box = match entry {
-            Entry::Occupied(e) => e.into_mut(),
-            Entry::Vacant(e) => {
-                let registry = &self.registry;
-                let service_ref = block_on(async move { registry.service_ref_opt::<S>().await })?;
-                e.insert(Box::new(service_ref))
-            }
+        let expired = match self.service_ref_cache.get(&type_id) {
+            Some(any_box) => Self::downcast::<S>(&**any_box)
+                .map(|service_ref| !service_ref.connected())
+                .unwrap_or(false),
+            None => true,
         };
-        Ok(any_box
+        if expired {
+            let registry = &self.registry;
+            let service_ref = block_on(async move { registry.service_ref_opt::<S>().await })?;
+            self.service_ref_cache
+                .insert(type_id, Box::new(service_ref));
+        }
+        Ok(self
+            .service_ref_cache
+            .get(&type_id)
+            .and_then(|any_box| Self::downcast::<S>(&**any_box)))
+    }
+
+    fn downcast<S>(any_box: &(dyn Any + Send)) -> Option<&ServiceRef<S>>
+    where
+        S: ActorService,
+    {
+        any_box
             .downcast_ref::<Option<ServiceRef<S>>>()
             .expect("Downcast service ref should success.")
-            .as_ref())
+            .as_ref()
     }
 }
@@ -1,37 +1,60 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::bus::BusService;
+use crate::bus::{Bus, BusService};
+use crate::metrics::{record_service_status, SERVICE_RESTART_COUNTER};
 use crate::mocker::MockHandler;
 use crate::service::{ActorService, ServiceFactory};
 use crate::service_actor::ServiceActor;
 use crate::{
     EventHandler, ServiceCmd, ServiceContext, ServiceHandler, ServiceInfo, ServicePing, ServiceRef,
-    ServiceRequest, ServiceStatus,
+    ServiceRequest, ServiceStatus, ServiceStatusChanged, SupervisionPolicy,
 };
 use actix::prelude::SendError;
-use actix::{Actor, AsyncContext};
+use actix::{Actor, AsyncContext, System};
 use actix_rt::Arbiter;
 use anyhow::{bail, format_err, Result};
 use futures::executor::block_on;
-use log::info;
+use log::{error, info, warn};
 use std::any::{type_name, Any, TypeId};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+const SUPERVISION_INTERVAL: Duration = Duration::from_millis(100);
+
+type ServiceCreator<S> = fn(ServiceRef<RegistryService>) -> ServiceActor<S>;
 
 trait ServiceRefProxy: Send + Sync {
     fn service_name(&self) -> &'static str;
+    fn dependencies(&self) -> Vec<&'static str>;
+    fn supervision_policy(&self) -> SupervisionPolicy;
     fn service_info(&self) -> ServiceInfo;
     fn status(&self) -> ServiceStatus;
     fn check_status(&self) -> ServiceStatus;
     fn update_status(&mut self, status: ServiceStatus);
     fn exec_service_cmd(&self, service_cmd: ServiceCmd) -> Result<()>;
-    fn shutdown(&self) -> Result<()>;
+    /// Stop the service thread deliberately, the service is no longer supervised.
+    fn shutdown(&mut self) -> Result<()>;
+    /// Start a new actor thread for the service, replace the shutdown one.
+    fn respawn(&mut self, registry_ref: ServiceRef<RegistryService>) -> Result<()>;
+    fn supervision_state(&mut self) -> &mut SupervisionState;
     fn as_any(&self) -> &dyn Any;
 }
 
+#[derive(Default)]
+struct SupervisionState {
+    retries: u32,
+    next_restart_at: Option<Instant>,
+    // the last respawn time, the retries are reset after a stable run.
+    respawned_at: Option<Instant>,
+    gave_up: bool,
+    // the service is shutdown deliberately, should not been respawned.
+    shutdown: bool,
+}
+
 struct ServiceHolder<S>
 where
     S: ActorService + 'static,
@@ -39,17 +62,26 @@ where
     arbiter: Arbiter,
     status: ServiceStatus,
     service_ref: ServiceRef<S>,
+    // mocker service can not been created again, so it has no creator.
+    creator: Option<ServiceCreator<S>>,
+    supervision: SupervisionState,
 }
 
 impl<S> ServiceHolder<S>
 where
     S: ActorService,
 {
-    pub fn new(arbiter: Arbiter, service_ref: ServiceRef<S>) -> Self {
+    pub fn new(
+        arbiter: Arbiter,
+        service_ref: ServiceRef<S>,
+        creator: Option<ServiceCreator<S>>,
+    ) -> Self {
         Self {
             arbiter,
             status: ServiceStatus::Started,
             service_ref,
+            creator,
+            supervision: SupervisionState::default(),
         }
     }
 }
@@ -62,6 +94,18 @@ where
         S::service_name()
     }
 
+    fn dependencies(&self) -> Vec<&'static str> {
+        S::dependencies()
+    }
+
+    fn supervision_policy(&self) -> SupervisionPolicy {
+        if self.creator.is_some() {
+            S::supervision_policy()
+        } else {
+            SupervisionPolicy::Ignore
+        }
+    }
+
     fn service_info(&self) -> ServiceInfo {
         ServiceInfo {
             name: self.service_name().to_string(),
@@ -70,7 +114,7 @@ where
     }
 
     fn status(&self) -> ServiceStatus {
-        if self.service_ref.addr.connected() {
+        if self.service_ref.connected() {
             self.status
         } else {
             ServiceStatus::Shutdown
@@ -79,7 +123,7 @@ where
 
     fn check_status(&self) -> ServiceStatus {
         if self.status.is_started() {
-            if let Err(e) = self.service_ref.addr.try_send(ServicePing) {
+            if let Err(e) = self.service_ref.addr().try_send(ServicePing) {
                 match e {
                     SendError::Full(_) => ServiceStatus::Unavailable,
                     SendError::Closed(_) => ServiceStatus::Shutdown,
@@ -100,12 +144,32 @@ where
         self.service_ref.exec_service_cmd(service_cmd)
     }
 
-    fn shutdown(&self) -> Result<()> {
+    fn shutdown(&mut self) -> Result<()> {
         info!("Start to shutdown {}.", self.service_name());
+        self.supervision.shutdown = true;
+        self.arbiter.stop();
+        Ok(())
+    }
+
+    fn respawn(&mut self, registry_ref: ServiceRef<RegistryService>) -> Result<()> {
+        let creator = self
+            .creator
+            .ok_or_else(|| format_err!("Service {} can not been respawned.", S::service_name()))?;
+        info!("Respawn service: {}", self.service_name());
+        let arbiter = Arbiter::new();
+        let addr = ServiceActor::start_in_arbiter(&arbiter, move |_ctx| creator(registry_ref));
         self.arbiter.stop();
+        self.arbiter = arbiter;
+        // the refs held by other services are refreshed too.
+        self.service_ref.refresh(addr);
+        self.status = ServiceStatus::Started;
         Ok(())
     }
 
+    fn supervision_state(&mut self) -> &mut SupervisionState {
+        &mut self.supervision
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -116,6 +180,10 @@ pub struct Registry {
     shared: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
     //use vec to keep service registry order.
     services: Vec<Box<dyn ServiceRefProxy>>,
+    // last status published to bus and metrics.
+    published_status: HashMap<&'static str, ServiceStatus>,
+    // the system is shutting down, stop supervising the services.
+    shutting_down: bool,
 }
 
 impl Registry {
@@ -124,6 +192,8 @@ impl Registry {
             service_ref,
             shared: HashMap::new(),
             services: vec![],
+            published_status: HashMap::new(),
+            shutting_down: false,
         };
         registry
             .register::<BusService, BusService>()
@@ -184,7 +254,11 @@ impl Registry {
             .map(|handle| handle.check_status())
     }
 
-    fn do_register<S, F>(&mut self, f: F) -> Result<ServiceRef<S>>
+    fn do_register<S, F>(
+        &mut self,
+        f: F,
+        creator: Option<ServiceCreator<S>>,
+    ) -> Result<ServiceRef<S>>
     where
         S: ActorService + 'static,
         F: FnOnce(ServiceRef<RegistryService>) -> ServiceActor<S> + Send + 'static,
@@ -193,13 +267,22 @@ impl Registry {
         if self.has_service(service_name) {
             bail!("Service with name: {} exist.", service_name)
         }
+        for dep in S::dependencies() {
+            if !self.has_service(dep) {
+                bail!(
+                    "Service {} depends on service {}, which is not registered.",
+                    service_name,
+                    dep
+                )
+            }
+        }
         info!("Registry service: {}", service_name);
 
         let arbiter = Arbiter::new();
         let registry_ref = self.service_ref.clone();
         let addr = ServiceActor::start_in_arbiter(&arbiter, move |_ctx| f(registry_ref));
         let service_ref: ServiceRef<S> = addr.into();
-        let holder = ServiceHolder::new(arbiter, service_ref.clone());
+        let holder = ServiceHolder::new(arbiter, service_ref.clone(), creator);
         self.services.push(Box::new(holder));
         Ok(service_ref)
     }
@@ -209,25 +292,85 @@ impl Registry {
         S: ActorService + 'static,
         F: ServiceFactory<S> + 'static,
     {
-        self.do_register(ServiceActor::new::<F>)
+        let creator: ServiceCreator<S> = ServiceActor::new::<F>;
+        self.do_register(creator, Some(creator))
     }
 
     pub fn register_mocker<S>(&mut self, mocker: Box<dyn MockHandler<S>>) -> Result<ServiceRef<S>>
     where
         S: ActorService + 'static,
     {
-        self.do_register(|registry_ref| ServiceActor::new_mocker(registry_ref, mocker))
+        self.do_register(
+            |registry_ref| ServiceActor::new_mocker(registry_ref, mocker),
+            None,
+        )
     }
 
-    /// Stop service thread and remove from registry.
+    /// Stop service thread and remove from registry, the services depend on it are shutdown first.
     /// A service after shutdown, can not start again, must been registry again.
     pub fn shutdown_service(&mut self, service_name: &str) -> Result<()> {
-        self.do_with_proxy(service_name, |proxy| proxy.shutdown())?;
+        if !self.has_service(service_name) {
+            bail!("Can not find service {}", service_name)
+        }
+        for dependent in self.dependents(service_name).into_iter().rev() {
+            info!(
+                "Shutdown service {} before its dependency {}",
+                dependent, service_name
+            );
+            self.do_shutdown_service(dependent)?;
+        }
+        self.do_shutdown_service(service_name)
+    }
+
+    fn do_shutdown_service(&mut self, service_name: &str) -> Result<()> {
+        self.services
+            .iter_mut()
+            .find(|proxy| proxy.service_name() == service_name)
+            .ok_or_else(|| format_err!("Can not find service {}", service_name))?
+            .shutdown()?;
         self.services
             .retain(|proxy| proxy.service_name() != service_name);
+        self.published_status.remove(service_name);
         Ok(())
     }
 
+    /// The services which directly or indirectly depend on the service, in registry order.
+    fn dependents(&self, service_name: &str) -> Vec<&'static str> {
+        let mut dependents: Vec<&'static str> = vec![];
+        for proxy in &self.services {
+            if proxy
+                .dependencies()
+                .iter()
+                .any(|dep| *dep == service_name || dependents.contains(dep))
+            {
+                dependents.push(proxy.service_name());
+            }
+        }
+        dependents
+    }
+
+    /// The services which the service directly or indirectly depends on, in registry order.
+    fn dependencies(&self, service_name: &str) -> Vec<&'static str> {
+        let mut dependencies: Vec<&'static str> = vec![];
+        for proxy in self.services.iter().rev() {
+            let name = proxy.service_name();
+            if name != service_name && !dependencies.contains(&name) {
+                continue;
+            }
+            for dep in proxy.dependencies() {
+                if !dependencies.contains(&dep) {
+                    dependencies.push(dep);
+                }
+            }
+        }
+        // dependencies must been registered before, so keep the registry order.
+        self.services
+            .iter()
+            .map(|proxy| proxy.service_name())
+            .filter(|name| dependencies.contains(name))
+            .collect()
+    }
+
     pub fn list(&self) -> Vec<ServiceInfo> {
         self.services
             .iter()
@@ -267,15 +410,178 @@ impl Registry {
         }
     }
 
+    /// Exec the service cmd in dependency order:
+    /// Start starts the stopped dependencies first,
+    /// Stop stops the dependents first,
+    /// Restart stops the dependents, restarts the service, then starts the dependents.
     fn exec_service_cmd(&self, service_name: &str, service_cmd: ServiceCmd) -> Result<()> {
+        match service_cmd {
+            ServiceCmd::Start => {
+                for dep in self.dependencies(service_name) {
+                    self.start_if_stopped(dep)?;
+                }
+                self.do_exec_service_cmd(service_name, ServiceCmd::Start)
+            }
+            ServiceCmd::Stop => {
+                self.stop_dependents(service_name)?;
+                self.do_exec_service_cmd(service_name, ServiceCmd::Stop)
+            }
+            ServiceCmd::Restart => {
+                // the cmd is processed in actor mailbox order, so the stopped dependents will
+                // start after they are stopped.
+                let stopped = self.stop_dependents(service_name)?;
+                self.do_exec_service_cmd(service_name, ServiceCmd::Restart)?;
+                for dependent in stopped.into_iter().rev() {
+                    self.do_exec_service_cmd(dependent, ServiceCmd::Start)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Stop the started dependents in reverse registry order, return the stopped services.
+    fn stop_dependents(&self, service_name: &str) -> Result<Vec<&'static str>> {
+        let mut stopped = vec![];
+        for dependent in self.dependents(service_name).into_iter().rev() {
+            if self.get_service_status(dependent) == Some(ServiceStatus::Started) {
+                self.do_exec_service_cmd(dependent, ServiceCmd::Stop)?;
+                stopped.push(dependent);
+            }
+        }
+        Ok(stopped)
+    }
+
+    fn do_exec_service_cmd(&self, service_name: &str, service_cmd: ServiceCmd) -> Result<()> {
         self.do_with_proxy(service_name, |proxy| proxy.exec_service_cmd(service_cmd))
     }
 
+    fn start_if_stopped(&self, service_name: &str) -> Result<()> {
+        if self.get_service_status(service_name) == Some(ServiceStatus::Stopped) {
+            self.do_exec_service_cmd(service_name, ServiceCmd::Start)?;
+        }
+        Ok(())
+    }
+
+    /// Apply the supervision policy to the services which are shutdown unexpectedly.
+    /// Return true if the failure is escalated, and the system should been shutdown.
+    fn supervise(&mut self) -> bool {
+        if self.shutting_down {
+            return false;
+        }
+        let registry_ref = self.service_ref.clone();
+        let now = Instant::now();
+        let mut respawned = vec![];
+        for proxy in self.services.iter_mut() {
+            let policy = proxy.supervision_policy();
+            if proxy.status() != ServiceStatus::Shutdown {
+                if let SupervisionPolicy::Restart { reset_after, .. } = policy {
+                    let state = proxy.supervision_state();
+                    let stable = state
+                        .respawned_at
+                        .map(|at| now.duration_since(at) >= reset_after)
+                        .unwrap_or(false);
+                    if stable {
+                        state.retries = 0;
+                        state.respawned_at = None;
+                    }
+                }
+                continue;
+            }
+            if proxy.supervision_state().shutdown {
+                continue;
+            }
+            let service_name = proxy.service_name();
+            match policy {
+                SupervisionPolicy::Ignore => {}
+                SupervisionPolicy::Escalate => {
+                    error!(
+                        "Service {} is shutdown unexpectedly, escalate to shutdown system.",
+                        service_name
+                    );
+                    return true;
+                }
+                SupervisionPolicy::Restart { max_retries, .. } => {
+                    let state = proxy.supervision_state();
+                    if state.gave_up {
+                        continue;
+                    }
+                    if state.retries >= max_retries {
+                        error!(
+                            "Service {} is shutdown unexpectedly, give up after {} restarts.",
+                            service_name, state.retries
+                        );
+                        state.gave_up = true;
+                        continue;
+                    }
+                    match state.next_restart_at {
+                        None => {
+                            let backoff = policy.backoff(state.retries);
+                            warn!(
+                                "Service {} is shutdown unexpectedly, restart it after {:?}.",
+                                service_name, backoff
+                            );
+                            state.next_restart_at = Some(now + backoff);
+                            continue;
+                        }
+                        Some(restart_at) if restart_at > now => continue,
+                        Some(_) => {
+                            state.retries += 1;
+                            state.next_restart_at = None;
+                        }
+                    }
+                    match proxy.respawn(registry_ref.clone()) {
+                        Ok(()) => {
+                            proxy.supervision_state().respawned_at = Some(now);
+                            SERVICE_RESTART_COUNTER
+                                .with_label_values(&[service_name])
+                                .inc();
+                            respawned.push(service_name);
+                        }
+                        Err(e) => error!("Respawn service {} error: {:?}", service_name, e),
+                    }
+                }
+            }
+        }
+        for service_name in respawned {
+            for dependent in self.dependents(service_name) {
+                info!(
+                    "Restart service {} after its dependency {} respawned.",
+                    dependent, service_name
+                );
+                if self.get_service_status(dependent) == Some(ServiceStatus::Started) {
+                    if let Err(e) = self.do_exec_service_cmd(dependent, ServiceCmd::Restart) {
+                        error!("Restart service {} error: {:?}", dependent, e);
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Collect the service status changes since last call, and record them to metrics.
+    fn status_changes(&mut self) -> Vec<ServiceStatusChanged> {
+        let mut changes = vec![];
+        for proxy in &self.services {
+            let service_name = proxy.service_name();
+            let status = proxy.status();
+            if self.published_status.get(service_name) != Some(&status) {
+                self.published_status.insert(service_name, status);
+                record_service_status(service_name, status);
+                changes.push(ServiceStatusChanged {
+                    service_name: service_name.to_string(),
+                    status,
+                });
+            }
+        }
+        changes
+    }
+
     fn exec_system_cmd(&mut self, cmd: SystemCmd) -> Result<()> {
         match cmd {
             SystemCmd::Shutdown => {
                 info!("Start to shutdown system");
-                for service in self.services.iter().rev() {
+                self.shutting_down = true;
+                for service in self.services.iter_mut().rev() {
                     service.shutdown()?;
                 }
             }
@@ -321,12 +627,47 @@ impl RegistryService {
     }
 }
 
+impl RegistryService {
+    fn publish_status_changes(&mut self) {
+        let changes = self.registry.status_changes();
+        if changes.is_empty() {
+            return;
+        }
+        // can not use ctx.broadcast, it query the bus service ref from registry self.
+        if let Some(bus) = self.registry.service_ref::<BusService>() {
+            for change in changes {
+                if let Err(e) = bus.broadcast(change) {
+                    error!("Broadcast ServiceStatusChanged error: {:?}", e);
+                }
+            }
+        }
+    }
+}
+
 impl ActorService for RegistryService {
-    fn started(&mut self, _ctx: &mut ServiceContext<Self>) -> Result<()> {
+    fn started(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
+        ctx.run_interval(SUPERVISION_INTERVAL, |ctx| ctx.notify(SupervisionTick));
         Ok(())
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct SupervisionTick;
+
+impl EventHandler<Self, SupervisionTick> for RegistryService {
+    fn handle_event(&mut self, _msg: SupervisionTick, ctx: &mut ServiceContext<RegistryService>) {
+        let escalate = self.registry.supervise();
+        self.publish_status_changes();
+        if escalate {
+            if let Err(e) = self.registry.exec_system_cmd(SystemCmd::Shutdown) {
+                error!("Shutdown system error: {:?}", e);
+            }
+            ctx.stop_actor();
+            System::current().stop_with_code(1);
+        }
+    }
+}
+
 pub struct RegisterRequest<S, F>
 where
     S: ActorService + 'static,
@@ -733,6 +1074,7 @@ impl EventHandler<Self, ServiceStatusChangeEvent> for RegistryService {
     ) {
         self.registry
             .update_service_status(msg.service_name.as_str(), msg.status);
+        self.publish_status_changes();
     }
 }
 
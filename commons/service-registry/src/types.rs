@@ -6,6 +6,7 @@ use anyhow::{Error, Result};
 use futures::Stream;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use std::time::Duration;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ServiceStatus {
@@ -29,6 +30,77 @@ impl ServiceStatus {
     }
 }
 
+impl std::fmt::Display for ServiceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Self::Started => "started",
+            Self::Stopped => "stopped",
+            Self::Shutdown => "shutdown",
+            Self::Unavailable => "unavailable",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// What the registry does when a service actor thread is shutdown unexpectedly, such as by a panic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SupervisionPolicy {
+    /// Leave the service shutdown.
+    Ignore,
+    /// Restart the service, and the services depend on it.
+    /// The delay before each restart doubles from `initial_backoff` up to `max_backoff`,
+    /// the registry gives up after `max_retries` restarts, the restarts are forgotten after the
+    /// service runs `reset_after` without failure.
+    Restart {
+        max_retries: u32,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+        reset_after: Duration,
+    },
+    /// Shutdown all services and stop the system.
+    Escalate,
+}
+
+impl SupervisionPolicy {
+    /// Restart policy with default backoff.
+    pub fn restart(max_retries: u32) -> Self {
+        Self::Restart {
+            max_retries,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            reset_after: Duration::from_secs(300),
+        }
+    }
+
+    /// The backoff before the `retries`th restart.
+    pub(crate) fn backoff(&self, retries: u32) -> Duration {
+        match self {
+            Self::Restart {
+                initial_backoff,
+                max_backoff,
+                ..
+            } => initial_backoff
+                .checked_mul(2u32.saturating_pow(retries))
+                .map(|backoff| std::cmp::min(backoff, *max_backoff))
+                .unwrap_or(*max_backoff),
+            _ => Duration::from_secs(0),
+        }
+    }
+}
+
+impl Default for SupervisionPolicy {
+    fn default() -> Self {
+        Self::Ignore
+    }
+}
+
+/// Broadcast on the bus when the registry observes a service's status change.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ServiceStatusChanged {
+    pub service_name: String,
+    pub status: ServiceStatus,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ServiceInfo {
     pub name: String,
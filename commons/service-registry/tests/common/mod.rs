@@ -8,3 +8,4 @@ pub mod loop_task_service;
 pub mod panic_service;
 pub mod refcell_service;
 pub mod shared_data_service;
+pub mod supervised_service;
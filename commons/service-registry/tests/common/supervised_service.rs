@@ -0,0 +1,123 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::panic_service::{PanicRequest, PingRequest};
+use anyhow::Result;
+use starcoin_service_registry::{
+    ActorService, EventHandler, ServiceContext, ServiceHandler, ServiceRequest,
+    ServiceStatusChanged, SupervisionPolicy,
+};
+use std::time::Duration;
+
+#[derive(Default)]
+pub struct SupervisedService {
+    counter: u64,
+}
+
+impl ActorService for SupervisedService {
+    fn supervision_policy() -> SupervisionPolicy {
+        SupervisionPolicy::Restart {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            reset_after: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Same as SupervisedService, but forget the restarts after a short stable run.
+#[derive(Default)]
+pub struct StableSupervisedService;
+
+impl ActorService for StableSupervisedService {
+    fn supervision_policy() -> SupervisionPolicy {
+        SupervisionPolicy::Restart {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            reset_after: Duration::from_millis(300),
+        }
+    }
+}
+
+impl ServiceHandler<Self, PanicRequest> for StableSupervisedService {
+    fn handle(&mut self, _msg: PanicRequest, _ctx: &mut ServiceContext<Self>) {
+        panic!("Panic by request.");
+    }
+}
+
+impl ServiceHandler<Self, PingRequest> for SupervisedService {
+    fn handle(&mut self, _msg: PingRequest, _ctx: &mut ServiceContext<Self>) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+}
+
+impl ServiceHandler<Self, PanicRequest> for SupervisedService {
+    fn handle(&mut self, _msg: PanicRequest, _ctx: &mut ServiceContext<Self>) {
+        panic!("Panic by request.");
+    }
+}
+
+#[derive(Default)]
+pub struct DependentService {
+    counter: u64,
+}
+
+impl ActorService for DependentService {
+    fn dependencies() -> Vec<&'static str> {
+        vec![SupervisedService::service_name()]
+    }
+}
+
+impl ServiceHandler<Self, PingRequest> for DependentService {
+    fn handle(&mut self, _msg: PingRequest, ctx: &mut ServiceContext<Self>) -> u64 {
+        // ensure the dependency ref is refreshed after it restarted.
+        assert!(ctx
+            .service_ref::<SupervisedService>()
+            .expect("dependency should exist")
+            .connected());
+        self.counter += 1;
+        self.counter
+    }
+}
+
+#[derive(Default)]
+pub struct StatusListenerService {
+    changes: Vec<ServiceStatusChanged>,
+}
+
+impl ActorService for StatusListenerService {
+    fn started(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
+        ctx.subscribe::<ServiceStatusChanged>();
+        Ok(())
+    }
+
+    fn stopped(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
+        ctx.unsubscribe::<ServiceStatusChanged>();
+        Ok(())
+    }
+}
+
+impl EventHandler<Self, ServiceStatusChanged> for StatusListenerService {
+    fn handle_event(&mut self, msg: ServiceStatusChanged, _ctx: &mut ServiceContext<Self>) {
+        self.changes.push(msg);
+    }
+}
+
+#[derive(Debug)]
+pub struct GetStatusChanges;
+
+impl ServiceRequest for GetStatusChanges {
+    type Response = Vec<ServiceStatusChanged>;
+}
+
+impl ServiceHandler<Self, GetStatusChanges> for StatusListenerService {
+    fn handle(
+        &mut self,
+        _msg: GetStatusChanges,
+        _ctx: &mut ServiceContext<Self>,
+    ) -> Vec<ServiceStatusChanged> {
+        self.changes.clone()
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use actix_rt::System;
+use common::panic_service::{PanicRequest, PingRequest};
+use common::supervised_service::{
+    DependentService, GetStatusChanges, StableSupervisedService, StatusListenerService,
+    SupervisedService,
+};
+use futures_timer::Delay;
+use starcoin_service_registry::{
+    ActorService, RegistryAsyncService, RegistryService, ServiceStatus,
+};
+use std::time::Duration;
+
+pub mod common;
+
+#[stest::test]
+fn test_service_supervision() {
+    let mut sys = System::builder().name("supervision_test").build();
+    sys.block_on(async {
+        let registry = RegistryService::launch();
+        let listener = registry.register::<StatusListenerService>().await.unwrap();
+        // dependency must been registered first.
+        assert!(registry.register::<DependentService>().await.is_err());
+        let service_ref = registry.register::<SupervisedService>().await.unwrap();
+        let dependent_ref = registry.register::<DependentService>().await.unwrap();
+
+        assert_eq!(1, service_ref.send(PingRequest).await.unwrap());
+        assert_eq!(1, dependent_ref.send(PingRequest).await.unwrap());
+        assert_eq!(2, dependent_ref.send(PingRequest).await.unwrap());
+
+        assert!(service_ref.send(PanicRequest).await.is_err());
+        // wait backoff and respawn.
+        Delay::new(Duration::from_millis(500)).await;
+
+        let status = registry
+            .check_service_status(SupervisedService::service_name())
+            .await
+            .unwrap();
+        assert_eq!(status, ServiceStatus::Started);
+
+        // the ref got before respawn is refreshed to the new service.
+        assert_eq!(1, service_ref.send(PingRequest).await.unwrap());
+        // dependent service is restarted after its dependency respawned.
+        assert_eq!(1, dependent_ref.send(PingRequest).await.unwrap());
+
+        let changes = listener.send(GetStatusChanges).await.unwrap();
+        let supervised_changes: Vec<ServiceStatus> = changes
+            .into_iter()
+            .filter(|change| change.service_name == SupervisedService::service_name())
+            .map(|change| change.status)
+            .collect();
+        assert_eq!(
+            supervised_changes,
+            vec![
+                ServiceStatus::Started,
+                ServiceStatus::Shutdown,
+                ServiceStatus::Started
+            ]
+        );
+
+        // give up after max retries.
+        assert!(service_ref.send(PanicRequest).await.is_err());
+        Delay::new(Duration::from_millis(500)).await;
+        let status = registry
+            .check_service_status(SupervisedService::service_name())
+            .await
+            .unwrap();
+        assert_eq!(status, ServiceStatus::Shutdown);
+
+        registry.shutdown_system().await.unwrap();
+        System::current().stop();
+    });
+}
+
+#[stest::test]
+fn test_supervision_reset_after_stable_run() {
+    let mut sys = System::builder().name("supervision_reset_test").build();
+    sys.block_on(async {
+        let registry = RegistryService::launch();
+        let service_ref = registry
+            .register::<StableSupervisedService>()
+            .await
+            .unwrap();
+        // the service is respawned every time, because it runs stable between the panics.
+        for _ in 0..3 {
+            assert!(service_ref.send(PanicRequest).await.is_err());
+            // wait backoff and respawn, then run longer than reset_after.
+            Delay::new(Duration::from_millis(800)).await;
+            let status = registry
+                .check_service_status(StableSupervisedService::service_name())
+                .await
+                .unwrap();
+            assert_eq!(status, ServiceStatus::Started);
+        }
+        registry.shutdown_system().await.unwrap();
+        System::current().stop();
+    });
+}
+
+#[stest::test]
+fn test_no_supervision_after_shutdown() {
+    let mut sys = System::builder().name("supervision_shutdown_test").build();
+    sys.block_on(async {
+        let registry = RegistryService::launch();
+        registry.register::<SupervisedService>().await.unwrap();
+        registry.shutdown_system().await.unwrap();
+        // wait longer than the backoff, the service should not been respawned.
+        Delay::new(Duration::from_millis(500)).await;
+        let status = registry
+            .check_service_status(SupervisedService::service_name())
+            .await
+            .unwrap();
+        assert_eq!(status, ServiceStatus::Shutdown);
+        System::current().stop();
+    });
+}
@@ -8,6 +8,7 @@ pub enum Api {
     Account,
     Chain,
     Debug,
+    Dev,
     Miner,
     NetworkManager,
     NodeManager,
@@ -42,6 +43,7 @@ impl std::fmt::Display for Api {
             Self::Account => "account",
             Self::Chain => "chain",
             Self::Debug => "debug",
+            Self::Dev => "dev",
             Self::Miner => "miner",
             Self::NetworkManager => "network_manager",
             Self::NodeManager => "node_manager",
@@ -66,6 +68,7 @@ impl FromStr for Api {
             "account" => Ok(Account),
             "chain" => Ok(Chain),
             "debug" => Ok(Debug),
+            "dev" => Ok(Dev),
             "miner" => Ok(Miner),
             "network_manager" => Ok(NetworkManager),
             "node_manager" => Ok(NodeManager),
@@ -200,6 +203,7 @@ impl ApiSet {
             ApiSet::IpcContext | ApiSet::All => {
                 public_list.insert(Api::PubSub);
                 public_list.insert(Api::Debug);
+                public_list.insert(Api::Dev);
                 public_list.insert(Api::Account);
                 public_list.insert(Api::NetworkManager);
                 public_list.insert(Api::SyncManager);
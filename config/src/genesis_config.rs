@@ -172,6 +172,18 @@ impl BuiltinNetworkID {
         }
     }
 
+    /// The built-in checkpoints of the network, sync and block connector refuse blocks conflict with them.
+    pub fn checkpoints(self) -> &'static [Checkpoint] {
+        match self {
+            BuiltinNetworkID::Test => EMPTY_CHECKPOINTS.as_slice(),
+            BuiltinNetworkID::Dev => EMPTY_CHECKPOINTS.as_slice(),
+            BuiltinNetworkID::Halley => EMPTY_CHECKPOINTS.as_slice(),
+            BuiltinNetworkID::Proxima => EMPTY_CHECKPOINTS.as_slice(),
+            BuiltinNetworkID::Barnard => BARNARD_CHECKPOINTS.as_slice(),
+            BuiltinNetworkID::Main => MAIN_CHECKPOINTS.as_slice(),
+        }
+    }
+
     pub fn boot_nodes_domain(self) -> String {
         match self {
             BuiltinNetworkID::Test | BuiltinNetworkID::Dev => "localhost".to_string(),
@@ -239,6 +251,42 @@ impl FromStr for CustomNetworkID {
     }
 }
 
+/// A block the chain must contain, any block at `number` with another id is refused.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct Checkpoint {
+    pub number: u64,
+    pub block_id: HashValue,
+}
+
+impl Checkpoint {
+    pub fn new(number: u64, block_id: HashValue) -> Self {
+        Self { number, block_id }
+    }
+}
+
+impl Display for Checkpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.number, self.block_id)
+    }
+}
+
+impl FromStr for Checkpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 2 {
+            bail!(
+                "Invalid checkpoint {}, checkpoint format is: block_number:block_id",
+                s
+            );
+        }
+        let number = parts[0].parse::<u64>()?;
+        let block_id = HashValue::from_hex_literal(parts[1])?;
+        Ok(Self::new(number, block_id))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum ChainNetworkID {
@@ -368,6 +416,13 @@ impl ChainNetworkID {
         }
     }
 
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        match self {
+            Self::Builtin(b) => b.checkpoints(),
+            _ => &[],
+        }
+    }
+
     pub fn as_builtin(&self) -> Option<&BuiltinNetworkID> {
         match self {
             Self::Builtin(net) => Some(net),
@@ -488,6 +543,10 @@ impl ChainNetwork {
         self.id.boot_nodes()
     }
 
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        self.id.checkpoints()
+    }
+
     /// Please ensure network is_ready() before genesis_block_parameter
     pub fn genesis_block_parameter(&self) -> &GenesisBlockParameter {
         &self
@@ -743,6 +802,7 @@ pub static TEST_GAS_SCHEDULE: Lazy<CostTable> =
     Lazy::new(|| init_cost_table(TEST_GAS_CONSTANTS.clone()));
 
 static EMPTY_BOOT_NODES: Lazy<Vec<MultiaddrWithPeerId>> = Lazy::new(Vec::new);
+static EMPTY_CHECKPOINTS: Lazy<Vec<Checkpoint>> = Lazy::new(Vec::new);
 const ONE_DAY: u64 = 86400;
 
 pub static TEST_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
@@ -966,6 +1026,12 @@ pub static BARNARD_BOOT_NODES: Lazy<Vec<MultiaddrWithPeerId>> = Lazy::new(|| {
     ]
 });
 
+/// Checkpoints of barnard network, appended on every release.
+/// NOTICE: no checkpoint is built in yet, the block ids will be added from a synced node
+/// with the next release. Until then only the checkpoints configured by
+/// `--checkpoint` or `sync.checkpoints` take effect.
+pub static BARNARD_CHECKPOINTS: Lazy<Vec<Checkpoint>> = Lazy::new(Vec::new);
+
 pub static BARNARD_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
     // This is a test config,
     GenesisConfig {
@@ -1013,6 +1079,12 @@ pub static BARNARD_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
 
 pub static MAIN_BOOT_NODES: Lazy<Vec<MultiaddrWithPeerId>> = Lazy::new(Vec::new);
 
+/// Checkpoints of main network, appended on every release.
+/// NOTICE: no checkpoint is built in yet, the block ids will be added from a synced node
+/// with the next release. Until then only the checkpoints configured by
+/// `--checkpoint` or `sync.checkpoints` take effect.
+pub static MAIN_CHECKPOINTS: Lazy<Vec<Checkpoint>> = Lazy::new(Vec::new);
+
 pub static MAIN_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
     //TODO set public key
     let (_association_private_key, association_public_key) = genesis_multi_key_pair();
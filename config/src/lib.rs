@@ -48,7 +48,7 @@ pub use available_port::{
 };
 pub use diem_temppath::TempPath;
 pub use genesis_config::{
-    BuiltinNetworkID, ChainNetwork, ChainNetworkID, FutureBlockParameter,
+    BuiltinNetworkID, ChainNetwork, ChainNetworkID, Checkpoint, FutureBlockParameter,
     FutureBlockParameterResolver, GenesisBlockParameter, GenesisBlockParameterConfig,
     GenesisConfig, DEFAULT_GAS_CONSTANTS, DEV_CONFIG, HALLEY_CONFIG, INITIAL_GAS_SCHEDULE,
     MAIN_CONFIG, PROXIMA_CONFIG, TEST_CONFIG, TEST_GAS_SCHEDULE,
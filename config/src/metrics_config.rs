@@ -13,6 +13,9 @@ use structopt::StructOpt;
 pub static DEFAULT_METRIC_SERVER_ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
 pub static DEFAULT_METRIC_SERVER_PORT: u16 = 9101;
 pub static DEFAULT_METRIC_PUSH_AUTH_PASSWORD: &str = "";
+pub static DEFAULT_READY_MAX_HEAD_BLOCK_AGE: u64 = 3600;
+pub static DEFAULT_READY_MIN_PEERS: usize = 1;
+pub static DEFAULT_READY_MAX_TXPOOL_USAGE: u8 = 95;
 
 #[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields)]
@@ -52,6 +55,47 @@ impl PushParameterConfig {
     }
 }
 
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields)]
+pub struct ReadinessConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "ready-max-head-block-age", long)]
+    /// The node is not ready if its head block is older than this seconds, default is 3600
+    pub max_head_block_age: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "ready-min-peers", long)]
+    /// The node is not ready if it has less peers than this, default is 1, and 0 for dev and test network
+    pub min_peers: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "ready-max-txpool-usage", long)]
+    /// The node is not ready if the txpool usage percent is greater than this, default is 95
+    pub max_txpool_usage: Option<u8>,
+}
+
+impl ReadinessConfig {
+    pub fn max_head_block_age(&self) -> u64 {
+        self.max_head_block_age
+            .unwrap_or(DEFAULT_READY_MAX_HEAD_BLOCK_AGE)
+    }
+
+    pub fn max_txpool_usage(&self) -> u8 {
+        self.max_txpool_usage
+            .unwrap_or(DEFAULT_READY_MAX_TXPOOL_USAGE)
+    }
+
+    fn merge(&mut self, opt: &ReadinessConfig) {
+        if opt.max_head_block_age.is_some() {
+            self.max_head_block_age = opt.max_head_block_age;
+        }
+        if opt.min_peers.is_some() {
+            self.min_peers = opt.min_peers;
+        }
+        if opt.max_txpool_usage.is_some() {
+            self.max_txpool_usage = opt.max_txpool_usage;
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields)]
 pub struct MetricsConfig {
@@ -65,6 +109,11 @@ pub struct MetricsConfig {
     /// Metrics push server parameter
     pub push_config: PushParameterConfig,
 
+    #[serde(default)]
+    #[structopt(flatten)]
+    /// Readiness thresholds of the /ready endpoint
+    pub readiness: ReadinessConfig,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[structopt(name = "metrics-address", long)]
     /// Metrics server listen address, default is 0.0.0.0
@@ -96,6 +145,17 @@ impl MetricsConfig {
         self.metrics_address
     }
 
+    pub fn ready_min_peers(&self) -> usize {
+        self.readiness.min_peers.unwrap_or_else(|| {
+            let net = &self.base().net;
+            if net.is_test() || net.is_dev() {
+                0
+            } else {
+                DEFAULT_READY_MIN_PEERS
+            }
+        })
+    }
+
     fn generate_address(&mut self) {
         if !self.disable_metrics() {
             self.metrics_address = Some(SocketAddr::new(
@@ -131,6 +191,7 @@ impl ConfigModule for MetricsConfig {
         if opt.metrics.push_config.is_config() {
             self.push_config = opt.metrics.push_config.clone();
         }
+        self.readiness.merge(&opt.metrics.readiness);
         self.generate_address();
         Ok(())
     }
@@ -1,11 +1,12 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{BaseConfig, ConfigModule, StarcoinOpt};
+use crate::{BaseConfig, ConfigModule, Connect, StarcoinOpt};
 use anyhow::Result;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 use structopt::StructOpt;
 
@@ -77,6 +78,25 @@ pub struct StorageConfig {
     #[structopt(name = "cache-sizes", long, help = "cache sizes")]
     pub cache_size: Option<usize>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "address-txn-index", long)]
+    /// Index the transactions sent by or affecting every account address of the main chain
+    /// blocks, to support query transactions by address, default is false.
+    /// This flag support both cli and config file.
+    pub address_txn_index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "fork-url", long)]
+    /// Fork the state of a remote node, by its websocket address or ipc file path.
+    /// The missing state is read from the remote node lazily, and transactions are executed locally.
+    /// Only dev and test network support fork.
+    pub fork_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "fork-block-number", long)]
+    /// The block number of the remote node to fork, default is the remote head block.
+    pub fork_block_number: Option<u64>,
+
     #[serde(skip)]
     #[structopt(skip)]
     base: Option<Arc<BaseConfig>>,
@@ -103,6 +123,14 @@ impl StorageConfig {
     pub fn cache_size(&self) -> usize {
         self.cache_size.unwrap_or(DEFAULT_CACHE_SIZE)
     }
+
+    pub fn address_txn_index(&self) -> bool {
+        self.address_txn_index.unwrap_or(false)
+    }
+
+    pub fn fork_connect(&self) -> Result<Option<Connect>> {
+        self.fork_url.as_deref().map(Connect::from_str).transpose()
+    }
 }
 
 impl ConfigModule for StorageConfig {
@@ -117,6 +145,15 @@ impl ConfigModule for StorageConfig {
         if opt.storage.cache_size.is_some() {
             self.cache_size = opt.storage.cache_size;
         }
+        if opt.storage.address_txn_index.is_some() {
+            self.address_txn_index = opt.storage.address_txn_index;
+        }
+        if opt.storage.fork_url.is_some() {
+            self.fork_url = opt.storage.fork_url.clone();
+        }
+        if opt.storage.fork_block_number.is_some() {
+            self.fork_block_number = opt.storage.fork_block_number;
+        }
         Ok(())
     }
 }
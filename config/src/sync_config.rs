@@ -1,10 +1,12 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::genesis_config::Checkpoint;
 use crate::{BaseConfig, ConfigModule, StarcoinOpt};
 use anyhow::Result;
 use network_api::PeerStrategy;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use structopt::StructOpt;
 
@@ -26,6 +28,28 @@ pub struct SyncConfig {
         help = "max retry times once sync block failed, default 15."
     )]
     max_retry_times: Option<u64>,
+
+    /// Checkpoints the chain must contain, in addition to the built-in checkpoints of the network.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(
+        name = "checkpoint",
+        long,
+        help = "checkpoint the chain must contain, format is block_number:block_id, can be repeated."
+    )]
+    checkpoints: Option<Vec<Checkpoint>>,
+
+    /// Max depth of a reorg, fork which retracts more blocks of main chain is refused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(
+        name = "max-reorg-depth",
+        long,
+        help = "max number of main chain blocks a fork can retract, default no limit."
+    )]
+    max_reorg_depth: Option<u64>,
+
+    #[serde(skip)]
+    #[structopt(skip)]
+    base: Option<Arc<BaseConfig>>,
 }
 
 impl SyncConfig {
@@ -36,10 +60,35 @@ impl SyncConfig {
     pub fn max_retry_times(&self) -> u64 {
         self.max_retry_times.unwrap_or(15)
     }
+
+    /// The built-in checkpoints of the network merged with the configured checkpoints,
+    /// a configured checkpoint overrides the built-in one at same block number.
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        let mut checkpoints: BTreeMap<u64, Checkpoint> = self
+            .base
+            .as_ref()
+            .map(|base| base.net().checkpoints().to_vec())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|checkpoint| (checkpoint.number, checkpoint))
+            .collect();
+        for checkpoint in self.checkpoints.iter().flatten() {
+            checkpoints.insert(checkpoint.number, *checkpoint);
+        }
+        checkpoints
+            .into_iter()
+            .map(|(_, checkpoint)| checkpoint)
+            .collect()
+    }
+
+    pub fn max_reorg_depth(&self) -> Option<u64> {
+        self.max_reorg_depth
+    }
 }
 
 impl ConfigModule for SyncConfig {
-    fn merge_with_opt(&mut self, opt: &StarcoinOpt, _base: Arc<BaseConfig>) -> Result<()> {
+    fn merge_with_opt(&mut self, opt: &StarcoinOpt, base: Arc<BaseConfig>) -> Result<()> {
+        self.base = Some(base);
         if opt.sync.peer_select_strategy.is_some() {
             self.peer_select_strategy = opt.sync.peer_select_strategy;
         }
@@ -48,6 +97,14 @@ impl ConfigModule for SyncConfig {
             self.max_retry_times = opt.sync.max_retry_times;
         }
 
+        if opt.sync.checkpoints.is_some() {
+            self.checkpoints = opt.sync.checkpoints.clone();
+        }
+
+        if opt.sync.max_reorg_depth.is_some() {
+            self.max_reorg_depth = opt.sync.max_reorg_depth;
+        }
+
         Ok(())
     }
 }
@@ -3,6 +3,7 @@
 
 use super::*;
 use crate::helper::to_toml;
+use starcoin_crypto::HashValue;
 use starcoin_vm_types::gas_schedule::GasAlgebra;
 
 #[test]
@@ -129,6 +130,9 @@ fn test_example_config_compact() -> Result<()> {
             //Vault
             "--vault-dir",
             "/data/my_starcoin_vault",
+            //Sync
+            "--max-reorg-depth",
+            "100",
         ];
         let opt = StarcoinOpt::from_iter_safe(args)?;
 
@@ -173,3 +177,23 @@ fn test_genesis_config_security() {
         );
     }
 }
+
+#[test]
+fn test_checkpoint_parse() -> Result<()> {
+    let checkpoint = Checkpoint::new(100, HashValue::random());
+    let checkpoint2 = Checkpoint::from_str(checkpoint.to_string().as_str())?;
+    assert_eq!(checkpoint, checkpoint2);
+    assert!(Checkpoint::from_str("100").is_err());
+    assert!(Checkpoint::from_str("a:0x01").is_err());
+
+    let temp_path = temp_path();
+    let opt = StarcoinOpt {
+        net: Some(BuiltinNetworkID::Test.into()),
+        base_data_dir: Some(temp_path.path().to_path_buf()),
+        ..StarcoinOpt::default()
+    };
+    let config = NodeConfig::load_with_opt(&opt)?;
+    assert!(config.sync.checkpoints().is_empty());
+    assert_eq!(config.sync.max_reorg_depth(), None);
+    Ok(())
+}
@@ -76,6 +76,15 @@ impl Account {
         }
     }
 
+    /// Creates a new account with a random secp256k1 keypair.
+    pub fn new_secp256k1() -> Self {
+        let (privkey, pubkey) = KeyGen::from_os_rng().generate_secp256k1_keypair();
+        Account {
+            addr: AuthenticationKey::secp256k1(&pubkey).derived_address(),
+            private_key: Arc::new(AccountPrivateKey::Secp256k1(privkey)),
+        }
+    }
+
     /// Creates a new account in memory representing an account created in the genesis transaction.
     ///
     /// The address will be [`address`], which should be an address for a genesis account and
@@ -6,24 +6,35 @@ use crate::{encode_create_account_script_function, Account};
 use anyhow::anyhow;
 use anyhow::Result;
 use logger::prelude::*;
+use serde_json::json;
+use starcoin_dev::playground::call_contract;
+use starcoin_dev::state_overlay::{StateOverlay, StateOverride};
+use starcoin_resource_viewer::json_arg::JsonArgEncoder;
+use starcoin_resource_viewer::AnnotatedMoveValue;
 use starcoin_resource_viewer::MoveValueAnnotator;
+use starcoin_state_api::ChainStateWriter;
 use starcoin_transaction_builder::{DEFAULT_EXPIRATION_TIME, DEFAULT_MAX_GAS_AMOUNT};
 use starcoin_types::identifier::Identifier;
 use starcoin_types::language_storage::ModuleId;
-use starcoin_types::transaction::{RawUserTransaction, ScriptFunction};
+use starcoin_types::transaction::{
+    DryRunTransaction, FeePayerRawTransaction, RawUserTransaction, ScriptFunction,
+};
 use starcoin_types::{
     account_config, block_metadata::BlockMetadata, transaction::Transaction,
     transaction::TransactionPayload, transaction::TransactionStatus,
 };
 use starcoin_vm_types::access_path::AccessPath;
-use starcoin_vm_types::account_config::genesis_address;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::{genesis_address, BalanceResource};
 use starcoin_vm_types::genesis_config::ChainId;
-use starcoin_vm_types::on_chain_config::{ConsensusConfig, OnChainConfig};
+use starcoin_vm_types::on_chain_config::{ConsensusConfig, OnChainConfig, Version};
+use starcoin_vm_types::parser::parse_type_tag;
 use starcoin_vm_types::state_view::StateView;
 use starcoin_vm_types::token::stc::stc_type_tag;
+use starcoin_vm_types::transaction_argument::TransactionArgument;
 use starcoin_vm_types::value::{serialize_values, MoveValue};
 use starcoin_vm_types::values::VMValueCast;
-use starcoin_vm_types::vm_status::KeptVMStatus;
+use starcoin_vm_types::vm_status::{KeptVMStatus, VMStatus};
 use starcoin_vm_types::{transaction::Package, vm_status::StatusCode};
 use test_helper::executor::{
     account_execute, association_execute, blockmeta_execute, build_raw_txn, current_block_number,
@@ -476,6 +487,195 @@ fn test_validate_txn() -> Result<()> {
     Ok(())
 }
 
+#[stest::test]
+fn test_execute_secp256k1_txn() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+
+    let account1 = Account::new_secp256k1();
+    let txn1 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &account1, 0, 50_000_000, 1, &net,
+    ));
+    let output1 = execute_and_apply(&chain_state, txn1);
+    assert_eq!(KeptVMStatus::Executed, output1.status().status().unwrap());
+
+    let account2 = Account::new();
+    let raw_txn = crate::build_transfer_txn(
+        *account1.address(),
+        *account2.address(),
+        Some(account2.auth_key()),
+        0,
+        1000,
+        1,
+        DEFAULT_MAX_GAS_AMOUNT,
+        net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+        net.chain_id(),
+    );
+    let txn2 = account1.sign_txn(raw_txn);
+    assert!(txn2.clone().check_signature().is_ok());
+
+    let output = crate::validate_transaction(&chain_state, txn2.clone());
+    assert_eq!(output, None);
+
+    let output2 = execute_and_apply(&chain_state, Transaction::UserTransaction(txn2));
+    assert_eq!(KeptVMStatus::Executed, output2.status().status().unwrap());
+    assert_eq!(get_sequence_number(*account1.address(), &chain_state), 1);
+    assert_eq!(get_balance(*account2.address(), &chain_state), 1000);
+    Ok(())
+}
+
+#[stest::test]
+fn test_validate_secp256k1_txn_before_activated() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+    // the network on the stdlib version 1.
+    chain_state.set(
+        &Version::config_id().access_path(),
+        bcs_ext::to_bytes(&Version { major: 1 })?,
+    )?;
+
+    let account1 = Account::new_secp256k1();
+    let txn1 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &account1, 0, 50_000_000, 1, &net,
+    ));
+    let output1 = execute_and_apply(&chain_state, txn1);
+    assert_eq!(KeptVMStatus::Executed, output1.status().status().unwrap());
+
+    let account2 = Account::new();
+    let raw_txn = crate::build_transfer_txn(
+        *account1.address(),
+        *account2.address(),
+        Some(account2.auth_key()),
+        0,
+        1000,
+        1,
+        DEFAULT_MAX_GAS_AMOUNT,
+        net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+        net.chain_id(),
+    );
+    let txn2 = account1.sign_txn(raw_txn);
+    assert!(txn2.clone().check_signature().is_ok());
+
+    let output = crate::validate_transaction(&chain_state, txn2.clone());
+    assert_eq!(output, Some(VMStatus::Error(StatusCode::INVALID_SIGNATURE)));
+
+    let output2 = execute_and_apply(&chain_state, Transaction::UserTransaction(txn2));
+    assert_eq!(
+        TransactionStatus::Discard(StatusCode::INVALID_SIGNATURE),
+        *output2.status()
+    );
+    Ok(())
+}
+
+#[stest::test]
+fn test_execute_fee_payer_txn() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+
+    let fee_payer = Account::new();
+    let txn1 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &fee_payer, 0, 50_000_000, 1, &net,
+    ));
+    let output1 = execute_and_apply(&chain_state, txn1);
+    assert_eq!(KeptVMStatus::Executed, output1.status().status().unwrap());
+    let sender = Account::new();
+    let txn2 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &sender, 1, 1000, 1, &net,
+    ));
+    let output2 = execute_and_apply(&chain_state, txn2);
+    assert_eq!(KeptVMStatus::Executed, output2.status().status().unwrap());
+
+    let receiver = Account::new();
+    let raw_txn = crate::build_transfer_txn(
+        *sender.address(),
+        *receiver.address(),
+        Some(receiver.auth_key()),
+        0,
+        100,
+        1,
+        DEFAULT_MAX_GAS_AMOUNT,
+        net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+        net.chain_id(),
+    );
+    let fee_payer_txn = FeePayerRawTransaction::new(raw_txn, *fee_payer.address());
+    let txn3 = sender
+        .private_key()
+        .sign(&fee_payer_txn)
+        .build_fee_payer_transaction(
+            fee_payer_txn.clone(),
+            fee_payer.private_key().sign(&fee_payer_txn),
+        )?;
+    assert!(txn3.clone().check_signature().is_ok());
+
+    let output = crate::validate_transaction(&chain_state, txn3.clone());
+    assert_eq!(output, None);
+
+    let output3 = execute_and_apply(&chain_state, Transaction::UserTransaction(txn3));
+    assert_eq!(KeptVMStatus::Executed, output3.status().status().unwrap());
+    assert!(output3.gas_used() > 0);
+    // the gas is paid by the fee payer, the sender only pays the transferred amount.
+    assert_eq!(
+        get_balance(*fee_payer.address(), &chain_state),
+        50_000_000 - output3.gas_used() as u128
+    );
+    assert_eq!(get_balance(*sender.address(), &chain_state), 900);
+    assert_eq!(get_balance(*receiver.address(), &chain_state), 100);
+    assert_eq!(get_sequence_number(*sender.address(), &chain_state), 1);
+    assert_eq!(get_sequence_number(*fee_payer.address(), &chain_state), 0);
+    Ok(())
+}
+
+#[stest::test]
+fn test_validate_fee_payer_txn_before_activated() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+    // the network on the stdlib version 1.
+    chain_state.set(
+        &Version::config_id().access_path(),
+        bcs_ext::to_bytes(&Version { major: 1 })?,
+    )?;
+
+    let fee_payer = Account::new();
+    let txn1 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &fee_payer, 0, 50_000_000, 1, &net,
+    ));
+    let output1 = execute_and_apply(&chain_state, txn1);
+    assert_eq!(KeptVMStatus::Executed, output1.status().status().unwrap());
+    let sender = Account::new();
+    let txn2 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &sender, 1, 0, 1, &net,
+    ));
+    let output2 = execute_and_apply(&chain_state, txn2);
+    assert_eq!(KeptVMStatus::Executed, output2.status().status().unwrap());
+
+    let raw_txn = crate::build_transfer_txn(
+        *sender.address(),
+        *fee_payer.address(),
+        None,
+        0,
+        0,
+        1,
+        DEFAULT_MAX_GAS_AMOUNT,
+        net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+        net.chain_id(),
+    );
+    let fee_payer_txn = FeePayerRawTransaction::new(raw_txn, *fee_payer.address());
+    let txn3 = sender
+        .private_key()
+        .sign(&fee_payer_txn)
+        .build_fee_payer_transaction(
+            fee_payer_txn.clone(),
+            fee_payer.private_key().sign(&fee_payer_txn),
+        )?;
+    assert!(txn3.clone().check_signature().is_ok());
+
+    let output = crate::validate_transaction(&chain_state, txn3.clone());
+    assert_eq!(output, Some(VMStatus::Error(StatusCode::INVALID_SIGNATURE)));
+
+    let output3 = execute_and_apply(&chain_state, Transaction::UserTransaction(txn3));
+    assert_eq!(
+        TransactionStatus::Discard(StatusCode::INVALID_SIGNATURE),
+        *output3.status()
+    );
+    Ok(())
+}
+
 #[stest::test]
 fn test_validate_txn_chain_id() -> Result<()> {
     let (chain_state, net) = prepare_genesis();
@@ -644,6 +844,105 @@ fn test_execute_transfer_txn_with_starcoin_vm() -> Result<()> {
     Ok(())
 }
 
+#[stest::test]
+fn test_dry_run_transfer_txn_gas_breakdown() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+
+    let account1 = Account::new();
+    let txn1 = Transaction::UserTransaction(create_account_txn_sent_as_association(
+        &account1, 0, 50_000_000, 1, &net,
+    ));
+    let output1 = execute_and_apply(&chain_state, txn1);
+    assert_eq!(KeptVMStatus::Executed, output1.status().status().unwrap());
+
+    let account2 = Account::new();
+    let raw_txn = crate::build_transfer_txn(
+        *account1.address(),
+        *account2.address(),
+        Some(account2.auth_key()),
+        0,
+        1000,
+        1,
+        DEFAULT_MAX_GAS_AMOUNT,
+        net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+        net.chain_id(),
+    );
+
+    let mut vm = StarcoinVM::new();
+    let (_, output, gas_breakdown) = vm.dry_run_transaction_with_gas_breakdown(
+        &chain_state,
+        DryRunTransaction {
+            public_key: account1.public_key(),
+            raw_txn,
+        },
+    )?;
+    assert_eq!(KeptVMStatus::Executed, output.status().status().unwrap());
+    assert!(gas_breakdown.intrinsic > 0);
+    assert!(gas_breakdown.execution > 0);
+    assert_eq!(gas_breakdown.total(), output.gas_used());
+    assert_eq!(gas_breakdown.frames.len(), 1);
+    assert!(gas_breakdown.frames[0]
+        .function
+        .contains("::TransferScripts::"));
+    assert_eq!(gas_breakdown.frames[0].gas, gas_breakdown.execution);
+    assert!(gas_breakdown.mutated_accounts > 0);
+
+    Ok(())
+}
+
+#[stest::test]
+fn test_encode_json_args() -> Result<()> {
+    let (chain_state, _net) = prepare_genesis();
+    let encoder = JsonArgEncoder::new(&chain_state);
+
+    let args = encoder.encode_function_args(
+        &ModuleId::new(core_code_address(), Identifier::new("TransferScripts")?),
+        Identifier::new("peer_to_peer")?.as_ident_str(),
+        &[stc_type_tag()],
+        &[
+            json!("0x1"),
+            json!("0x0102"),
+            json!("340282366920938463463374607431768211455"),
+        ],
+    )?;
+    assert_eq!(
+        args,
+        serialize_values(&vec![
+            MoveValue::Address(core_code_address()),
+            MoveValue::vector_u8(vec![1, 2]),
+            MoveValue::U128(u128::MAX),
+        ])
+    );
+    // the signer is skipped, so only 3 args are accepted.
+    assert!(encoder
+        .encode_function_args(
+            &ModuleId::new(core_code_address(), Identifier::new("TransferScripts")?),
+            Identifier::new("peer_to_peer")?.as_ident_str(),
+            &[stc_type_tag()],
+            &[json!("0x1")],
+        )
+        .is_err());
+
+    let option_u64 = parse_type_tag("0x1::Option::Option<u64>")?;
+    assert_eq!(encoder.encode(&option_u64, &json!(null))?, vec![0]);
+    assert_eq!(
+        encoder.encode(&option_u64, &json!(1))?,
+        vec![1, 1, 0, 0, 0, 0, 0, 0, 0]
+    );
+    let token = parse_type_tag("0x1::Token::Token<0x1::STC::STC>")?;
+    assert_eq!(
+        encoder.encode(&token, &json!({"value": 5}))?,
+        encoder.encode(&token, &json!(["5"]))?
+    );
+    assert!(encoder.encode(&token, &json!({"amount": 5})).is_err());
+    let nested = parse_type_tag("vector<vector<u8>>")?;
+    assert_eq!(
+        encoder.encode(&nested, &json!(["0x0102", "a", [3]]))?,
+        vec![3, 2, 1, 2, 1, b'a', 1, 3]
+    );
+    Ok(())
+}
+
 #[stest::test]
 fn test_execute_multi_txn_with_same_account() -> Result<()> {
     let (chain_state, net) = prepare_genesis();
@@ -832,3 +1131,64 @@ fn test_block_metadata() -> Result<()> {
 
     Ok(())
 }
+
+#[stest::test]
+fn test_state_overlay() -> Result<()> {
+    let (chain_state, _net) = prepare_genesis();
+    let address = AccountAddress::random();
+    let balance_of = |state_view: &dyn StateView| {
+        call_contract(
+            state_view,
+            ModuleId::new(core_code_address(), Identifier::new("Account")?),
+            "balance",
+            vec![stc_type_tag()],
+            vec![TransactionArgument::Address(address)],
+        )
+    };
+
+    let overlay = StateOverlay::new(
+        &chain_state,
+        vec![StateOverride::Balance {
+            address,
+            token: stc_type_tag(),
+            amount: 100,
+        }],
+    )?;
+    assert!(matches!(
+        balance_of(&overlay)?.as_slice(),
+        [AnnotatedMoveValue::U128(100)]
+    ));
+    // the base state is not changed.
+    assert!(balance_of(&chain_state).is_err());
+
+    // the later override replace the former one.
+    let balance_path = AccessPath::new(address, BalanceResource::access_path_for(stc_type_tag()));
+    let overlay = StateOverlay::new(
+        &chain_state,
+        vec![
+            StateOverride::Balance {
+                address,
+                token: stc_type_tag(),
+                amount: 100,
+            },
+            StateOverride::JsonValue {
+                access_path: balance_path.clone(),
+                value: json!({"token": {"value": 7}}),
+            },
+        ],
+    )?;
+    assert!(matches!(
+        balance_of(&overlay)?.as_slice(),
+        [AnnotatedMoveValue::U128(7)]
+    ));
+
+    let overlay = StateOverlay::new(
+        &chain_state,
+        vec![StateOverride::Value {
+            access_path: balance_path,
+            value: None,
+        }],
+    )?;
+    assert!(balance_of(&overlay).is_err());
+    Ok(())
+}
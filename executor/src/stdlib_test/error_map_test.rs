@@ -0,0 +1,89 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::account::{create_account_txn_sent_as_association, Account};
+use anyhow::Result;
+use starcoin_state_api::AccountStateReader;
+use starcoin_types::identifier::Identifier;
+use starcoin_types::language_storage::ModuleId;
+use starcoin_types::transaction::{Package, ScriptFunction, Transaction, TransactionPayload};
+use starcoin_vm_types::account_config::{core_code_address, ErrorMapResource};
+use starcoin_vm_types::vm_status::KeptVMStatus;
+use statedb::ChainStateDB;
+use test_helper::executor::{compile_modules_with_address, execute_and_apply, prepare_genesis};
+
+fn publish_error_map(error_map: &[u8]) -> Result<ScriptFunction> {
+    Ok(ScriptFunction::new(
+        ModuleId::new(core_code_address(), Identifier::new("ErrorMap").unwrap()),
+        Identifier::new("publish").unwrap(),
+        vec![],
+        vec![bcs_ext::to_bytes(&error_map.to_vec())?],
+    ))
+}
+
+fn get_error_map(chain_state: &ChainStateDB, account: &Account) -> Result<Option<Vec<u8>>> {
+    Ok(AccountStateReader::new(chain_state)
+        .get_resource::<ErrorMapResource>(*account.address())?
+        .map(|resource| resource.bytes().to_vec()))
+}
+
+#[stest::test]
+fn test_publish_error_map_with_package() -> Result<()> {
+    let (chain_state, net) = prepare_genesis();
+    let alice = Account::new();
+    let output = execute_and_apply(
+        &chain_state,
+        Transaction::UserTransaction(create_account_txn_sent_as_association(
+            &alice, 0, 50_000_000, 1, &net,
+        )),
+    );
+    assert_eq!(KeptVMStatus::Executed, output.status().status().unwrap());
+    assert_eq!(get_error_map(&chain_state, &alice)?, None);
+
+    // the init script of the package publishes the error map of the modules.
+    let module = compile_modules_with_address(
+        *alice.address(),
+        r#"
+        module Demo {
+            public fun demo() {
+            }
+        }
+        "#,
+    )
+    .pop()
+    .unwrap();
+    let package = Package::new(vec![module], Some(publish_error_map(b"error map")?))?;
+    let txn = alice.create_signed_txn_impl(
+        *alice.address(),
+        TransactionPayload::Package(package),
+        0,
+        100_000,
+        1,
+        1,
+        net.chain_id(),
+    );
+    let output = execute_and_apply(&chain_state, Transaction::UserTransaction(txn));
+    assert_eq!(KeptVMStatus::Executed, output.status().status().unwrap());
+    assert_eq!(
+        get_error_map(&chain_state, &alice)?,
+        Some(b"error map".to_vec())
+    );
+
+    // publish again replaces the error map.
+    let txn = alice.create_signed_txn_impl(
+        *alice.address(),
+        TransactionPayload::ScriptFunction(publish_error_map(b"new error map")?),
+        1,
+        100_000,
+        1,
+        1,
+        net.chain_id(),
+    );
+    let output = execute_and_apply(&chain_state, Transaction::UserTransaction(txn));
+    assert_eq!(KeptVMStatus::Executed, output.status().status().unwrap());
+    assert_eq!(
+        get_error_map(&chain_state, &alice)?,
+        Some(b"new error map".to_vec())
+    );
+    Ok(())
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod block_reward_test;
+mod error_map_test;
 mod module_upgrade_test;
 mod on_chain_config_test;
 mod sip_flag_test;
@@ -0,0 +1,248 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Genesis;
+use anyhow::{ensure, format_err, Result};
+use serde::{Deserialize, Serialize};
+use starcoin_chain::{BlockChain, ChainReader};
+use starcoin_config::ChainNetwork;
+use starcoin_crypto::hash::ACCUMULATOR_PLACEHOLDER_HASH;
+use starcoin_crypto::HashValue;
+use starcoin_logger::prelude::*;
+use starcoin_state_api::{ChainStateReader, ChainStateWriter, StateReaderExt, StateView};
+use starcoin_statedb::ChainStateDB;
+use starcoin_storage::{BlockStore, Storage};
+use starcoin_types::block::{Block, BlockBody, BlockHeader};
+use starcoin_types::startup_info::{ChainInfo, StartupInfo};
+use starcoin_vm_types::access_path::AccessPath;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::{
+    association_address, genesis_address, stc_type_tag, AccountResource, BalanceResource,
+};
+use starcoin_vm_types::event::EventHandle;
+use starcoin_vm_types::genesis_config::{ChainId, ConsensusStrategy};
+use starcoin_vm_types::move_resource::MoveResource;
+use starcoin_vm_types::on_chain_config::{ConsensusConfig, OnChainConfig};
+use starcoin_vm_types::on_chain_resource::{BlockMetadata, Epoch, EpochData};
+use starcoin_vm_types::token::stc::STC_TOKEN_CODE;
+use starcoin_vm_types::token::token_info::TokenInfoResource;
+use starcoin_vm_types::transaction::authenticator::AuthenticationKey;
+use std::sync::Arc;
+
+/// The STC balance of the association account after fork, for the dev rpc to set the balance of accounts.
+const FORK_ASSOCIATION_BALANCE: u128 = u64::MAX as u128;
+
+/// On chain resource BlockReward::RewardQueue mapping
+#[derive(Debug, Serialize, Deserialize)]
+struct RewardQueue {
+    reward_number: u64,
+    infos: Vec<RewardInfo>,
+    reward_events: EventHandle,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RewardInfo {
+    number: u64,
+    reward: u128,
+    miner: AccountAddress,
+    gas_fees: u128,
+}
+
+impl MoveResource for RewardQueue {
+    const MODULE_NAME: &'static str = "BlockReward";
+    const STRUCT_NAME: &'static str = "RewardQueue";
+}
+
+impl Genesis {
+    /// Init the storage with the genesis of a fork, or check the storage is forked from the block `fork_block_id`.
+    /// The genesis block of a fork has no transaction, its parent is the block forked from, and its
+    /// state is the forked state, with the block number, consensus and chain id reset for `net`.
+    /// The `storage` should read the missing state nodes of `fork_state_root` from a state node source.
+    pub fn init_and_check_fork_storage(
+        net: &ChainNetwork,
+        storage: Arc<Storage>,
+        fork_block_id: HashValue,
+        fork_block_timestamp: u64,
+        fork_state_root: HashValue,
+    ) -> Result<(ChainInfo, Genesis)> {
+        if let Some(chain_info) = storage.get_chain_info()? {
+            let block = storage
+                .get_block(chain_info.genesis_hash())?
+                .ok_or_else(|| format_err!("Can not find genesis block in storage."))?;
+            ensure!(
+                block.transactions().is_empty() && block.header().parent_hash() == fork_block_id,
+                "The storage is not forked from block {}, its genesis is: {:?}",
+                fork_block_id,
+                block.header()
+            );
+            return Ok((chain_info, Genesis { block }));
+        }
+        let block = Self::build_fork_genesis_block(
+            net,
+            storage.clone(),
+            fork_block_id,
+            fork_block_timestamp,
+            fork_state_root,
+        )?;
+        info!("Build fork genesis block: {:?}", block.header());
+        storage.save_genesis(block.id())?;
+        let genesis_chain = BlockChain::new_with_fork_genesis(
+            net.time_service(),
+            storage.clone(),
+            net.genesis_epoch(),
+            block.clone(),
+        )?;
+        storage.save_startup_info(StartupInfo::new(genesis_chain.current_header().id()))?;
+        let chain_info = storage
+            .get_chain_info()?
+            .ok_or_else(|| format_err!("ChainInfo should exist after fork genesis saved."))?;
+        Ok((chain_info, Genesis { block }))
+    }
+
+    fn build_fork_genesis_block(
+        net: &ChainNetwork,
+        storage: Arc<Storage>,
+        fork_block_id: HashValue,
+        fork_block_timestamp: u64,
+        fork_state_root: HashValue,
+    ) -> Result<Block> {
+        let chain_state_db = ChainStateDB::new(storage, Some(fork_state_root));
+        Self::reset_fork_state(net, &chain_state_db, fork_block_id)?;
+        chain_state_db.commit()?;
+        chain_state_db.flush()?;
+
+        let difficulty = net
+            .genesis_config()
+            .genesis_block_parameter()
+            .map(|parameter| parameter.difficulty)
+            .unwrap_or_default();
+        let body = BlockBody::new_empty();
+        let header = BlockHeader::genesis_block_header(
+            fork_block_id,
+            fork_block_timestamp,
+            *ACCUMULATOR_PLACEHOLDER_HASH,
+            chain_state_db.state_root(),
+            difficulty,
+            body.hash(),
+            net.chain_id(),
+        );
+        Ok(Block::new(header, body))
+    }
+
+    /// Reset the on chain resources of the forked state, to start a new chain from number 0 with
+    /// the dummy consensus and the chain id of `net`.
+    /// The pending block rewards are dropped, and the association account is signed by the
+    /// association key of `net`, with its STC balance raised to `FORK_ASSOCIATION_BALANCE` and the
+    /// total supply of STC raised by the same amount.
+    fn reset_fork_state(
+        net: &ChainNetwork,
+        chain_state: &ChainStateDB,
+        fork_block_id: HashValue,
+    ) -> Result<()> {
+        let set_resource = |address: AccountAddress, resource: Vec<u8>, path| {
+            chain_state.set(&AccessPath::new(address, path), resource)
+        };
+
+        set_resource(
+            genesis_address(),
+            bcs_ext::to_bytes(&ChainId::new(net.chain_id().id()))?,
+            ChainId::resource_path(),
+        )?;
+
+        let mut block_metadata = chain_state
+            .get_resource::<BlockMetadata>(genesis_address())?
+            .ok_or_else(|| format_err!("BlockMetadata resource should exist."))?;
+        block_metadata.number = 0;
+        block_metadata.parent_hash = fork_block_id;
+        block_metadata.uncles = 0;
+        set_resource(
+            genesis_address(),
+            bcs_ext::to_bytes(&block_metadata)?,
+            BlockMetadata::resource_path(),
+        )?;
+
+        let reward_queue = chain_state
+            .get_resource::<RewardQueue>(genesis_address())?
+            .ok_or_else(|| format_err!("RewardQueue resource should exist."))?;
+        set_resource(
+            genesis_address(),
+            bcs_ext::to_bytes(&RewardQueue {
+                reward_number: 0,
+                infos: vec![],
+                reward_events: reward_queue.reward_events,
+            })?,
+            RewardQueue::resource_path(),
+        )?;
+
+        let epoch = chain_state.get_epoch()?;
+        let epoch = Epoch::new(
+            epoch.number(),
+            epoch.start_time(),
+            0,
+            epoch.end_block_number() - epoch.start_block_number(),
+            epoch.block_time_target(),
+            epoch.reward_per_block(),
+            epoch.reward_per_uncle_percent(),
+            epoch.block_difficulty_window(),
+            epoch.max_uncles_per_block(),
+            epoch.block_gas_limit(),
+            ConsensusStrategy::Dummy.value(),
+            epoch.new_epoch_events().clone(),
+        );
+        set_resource(
+            genesis_address(),
+            bcs_ext::to_bytes(&epoch)?,
+            Epoch::resource_path(),
+        )?;
+        set_resource(
+            genesis_address(),
+            bcs_ext::to_bytes(&EpochData::new(0, 0, 0))?,
+            EpochData::resource_path(),
+        )?;
+
+        let mut consensus_config = chain_state
+            .get_on_chain_config::<ConsensusConfig>()?
+            .ok_or_else(|| format_err!("ConsensusConfig should exist."))?;
+        consensus_config.strategy = ConsensusStrategy::Dummy.value();
+        chain_state.set(
+            &ConsensusConfig::config_id().access_path(),
+            bcs_ext::to_bytes(&consensus_config)?,
+        )?;
+
+        let association_public_key = &net.genesis_config().association_key_pair.1;
+        let mut association_account = chain_state
+            .get_account_resource(association_address())?
+            .ok_or_else(|| format_err!("Association account should exist."))?;
+        association_account.rotate_authentication_key(
+            AuthenticationKey::multi_ed25519(association_public_key).to_vec(),
+        );
+        set_resource(
+            association_address(),
+            bcs_ext::to_bytes(&association_account)?,
+            AccountResource::resource_path(),
+        )?;
+        let association_balance = chain_state
+            .get_balance(association_address())?
+            .unwrap_or_default();
+        if association_balance < FORK_ASSOCIATION_BALANCE {
+            chain_state.set(
+                &AccessPath::new(
+                    association_address(),
+                    BalanceResource::access_path_for(stc_type_tag()),
+                ),
+                bcs_ext::to_bytes(&BalanceResource::new(FORK_ASSOCIATION_BALANCE))?,
+            )?;
+            let token_info_path = TokenInfoResource::resource_path_for(STC_TOKEN_CODE.clone());
+            let mut token_info = chain_state
+                .get(&token_info_path)?
+                .map(|bytes| TokenInfoResource::try_from_bytes(bytes.as_slice()))
+                .transpose()?
+                .ok_or_else(|| format_err!("STC TokenInfo resource should exist."))?;
+            token_info.set_total_value(
+                token_info.total_value() + (FORK_ASSOCIATION_BALANCE - association_balance),
+            );
+            chain_state.set(&token_info_path, bcs_ext::to_bytes(&token_info)?)?;
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use starcoin_accumulator::accumulator_info::AccumulatorInfo;
 use starcoin_accumulator::node::AccumulatorStoreType;
 use starcoin_accumulator::{Accumulator, MerkleAccumulator};
+use starcoin_chain::address_txn_index::move_address_txn_index;
 use starcoin_chain::{BlockChain, ChainReader};
 use starcoin_config::{
     genesis_key_pair, BuiltinNetworkID, ChainNetwork, ChainNetworkID, GenesisBlockParameter,
@@ -33,6 +34,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 mod errors;
+mod fork;
 pub use errors::GenesisError;
 
 pub static GENESIS_GENERATED_DIR: &str = "generated";
@@ -240,6 +242,7 @@ impl Genesis {
             self.block.clone(),
         )?;
         let startup_info = StartupInfo::new(genesis_chain.current_header().id());
+        move_address_txn_index(storage.as_ref(), None, startup_info.main)?;
         storage.save_startup_info(startup_info)?;
         storage
             .get_chain_info()?
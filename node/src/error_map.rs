@@ -0,0 +1,37 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Result};
+use starcoin_move_explain::{decode_error_mapping, ErrorMapReader, ErrorMapping};
+use starcoin_state_api::AccountStateReader;
+use starcoin_statedb::ChainStateDB;
+use starcoin_storage::{BlockStore, Storage};
+use starcoin_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::ErrorMapResource;
+use std::sync::Arc;
+
+/// Read the error maps published on chain from the state of the main chain head.
+pub struct ChainErrorMapReader {
+    storage: Arc<Storage>,
+}
+
+impl ChainErrorMapReader {
+    pub fn new(storage: Arc<Storage>) -> Self {
+        Self { storage }
+    }
+}
+
+impl ErrorMapReader for ChainErrorMapReader {
+    fn get_error_map(&self, address: &AccountAddress) -> Result<Option<ErrorMapping>> {
+        let chain_info = self
+            .storage
+            .get_chain_info()?
+            .ok_or_else(|| format_err!("Can not get chain info."))?;
+        let state_root = chain_info.status().head().state_root();
+        let statedb = ChainStateDB::new(self.storage.clone(), Some(state_root));
+        AccountStateReader::new(&statedb)
+            .get_resource::<ErrorMapResource>(*address)?
+            .map(|resource| decode_error_mapping(resource.bytes()))
+            .transpose()
+    }
+}
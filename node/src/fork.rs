@@ -0,0 +1,170 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, format_err, Result};
+use starcoin_config::{Connect, NodeConfig};
+use starcoin_crypto::HashValue;
+use starcoin_genesis::Genesis;
+use starcoin_logger::prelude::*;
+use starcoin_rpc_client::RpcClient;
+use starcoin_state_api::{StateNode, StateNodeSource};
+use starcoin_storage::{BlockStore, Storage};
+use starcoin_types::startup_info::ChainInfo;
+use starcoin_vm_types::on_chain_config::GlobalTimeOnChain;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+
+type RemoteTask = Box<dyn FnOnce(&RpcClient) + Send>;
+
+/// The remote node forked from.
+/// The rpc client can not block on the runtime of the node, so the client is owned by a dedicated
+/// thread, which runs the calls to the remote node one by one.
+#[derive(Clone)]
+struct RemoteNode {
+    sender: Arc<Mutex<Sender<RemoteTask>>>,
+}
+
+impl RemoteNode {
+    fn connect(connect: Connect) -> Result<Self> {
+        let (sender, receiver) = channel::<RemoteTask>();
+        let (connected_sender, connected_receiver) = channel();
+        std::thread::Builder::new()
+            .name("fork-remote".to_string())
+            .spawn(move || {
+                let client = match connect_remote(connect) {
+                    Ok(client) => client,
+                    Err(e) => {
+                        let _ = connected_sender.send(Err(e));
+                        return;
+                    }
+                };
+                let _ = connected_sender.send(Ok(()));
+                // the thread exits after all the senders dropped.
+                for task in receiver {
+                    task(&client);
+                }
+            })?;
+        connected_receiver
+            .recv()
+            .map_err(|_| format_err!("The thread of the fork remote node exit unexpectedly."))??;
+        Ok(Self {
+            sender: Arc::new(Mutex::new(sender)),
+        })
+    }
+
+    fn call<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&RpcClient) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (result_sender, result_receiver) = channel();
+        let task: RemoteTask = Box::new(move |client| {
+            let _ = result_sender.send(f(client));
+        });
+        self.sender
+            .lock()
+            .map_err(|_| format_err!("The sender of the fork remote node is poisoned."))?
+            .send(task)
+            .map_err(|_| format_err!("The thread of the fork remote node exit unexpectedly."))?;
+        result_receiver
+            .recv()
+            .map_err(|_| format_err!("Call the fork remote node failed."))?
+    }
+}
+
+/// Read the missing state nodes from the remote node, the nodes are verified and saved by the storage.
+struct RemoteStateNodeSource {
+    remote: RemoteNode,
+}
+
+impl StateNodeSource for RemoteStateNodeSource {
+    fn get_node(&self, hash: &HashValue) -> Result<Option<StateNode>> {
+        let hash = *hash;
+        let node = self
+            .remote
+            .call(move |client| client.state_get_state_node_by_node_hash(hash))?;
+        if node.is_some() {
+            trace!("Fetch state node {} from the fork remote node", hash);
+        }
+        Ok(node.map(StateNode))
+    }
+}
+
+fn connect_remote(connect: Connect) -> Result<RpcClient> {
+    match connect {
+        Connect::IPC(Some(ipc_file)) => RpcClient::connect_ipc(ipc_file),
+        Connect::IPC(None) => Err(format_err!("The ipc file of the fork url is missing.")),
+        Connect::WebSocket(address) => RpcClient::connect_websocket(address.as_str()),
+    }
+}
+
+/// Fork the state of the remote node by the `config.storage.fork_url`.
+/// At the first start, fork the remote block by `config.storage.fork_block_number`, default is the
+/// remote head block, then the storage is always forked from the block.
+pub fn init_fork_storage(
+    config: &NodeConfig,
+    connect: Connect,
+    storage: Storage,
+) -> Result<(Arc<Storage>, ChainInfo, Genesis)> {
+    let net = config.net();
+    ensure!(
+        net.is_dev() || net.is_test(),
+        "Only dev and test network support fork, current network: {}",
+        net
+    );
+    let fork_block_id = match storage.get_chain_info()? {
+        Some(chain_info) => Some(
+            storage
+                .get_block_header_by_hash(chain_info.genesis_hash())?
+                .ok_or_else(|| format_err!("Can not find genesis block in storage."))?
+                .parent_hash(),
+        ),
+        None => None,
+    };
+    let fork_block_number = config.storage.fork_block_number;
+    let remote = RemoteNode::connect(connect)?;
+    let fork_block = remote.call(move |client| {
+        let fork_block = match (fork_block_id, fork_block_number) {
+            (Some(block_id), _) => client
+                .chain_get_block_by_hash(block_id)?
+                .ok_or_else(|| format_err!("Can not find fork block {} on remote", block_id))?,
+            (None, Some(number)) => client
+                .chain_get_block_by_number(number)?
+                .ok_or_else(|| format_err!("Can not find block by number {} on remote", number))?,
+            (None, None) => {
+                let number = client.chain_info()?.head.number.0;
+                client
+                    .chain_get_block_by_number(number)?
+                    .ok_or_else(|| format_err!("Can not find head block {} on remote", number))?
+            }
+        };
+        Ok(fork_block)
+    })?;
+    let fork_header = fork_block.header;
+    if let Some(number) = fork_block_number {
+        ensure!(
+            number == fork_header.number.0,
+            "The storage is forked from remote block {}, not {}",
+            fork_header.number.0,
+            number
+        );
+    }
+    info!(
+        "Fork the state of remote block: {}, number: {}",
+        fork_header.block_hash, fork_header.number.0
+    );
+
+    let storage =
+        Arc::new(storage.with_state_node_source(Arc::new(RemoteStateNodeSource { remote })));
+    let (chain_info, genesis) = Genesis::init_and_check_fork_storage(
+        net,
+        storage.clone(),
+        fork_header.block_hash,
+        fork_header.timestamp.0,
+        fork_header.state_root,
+    )?;
+    // the local time should be after the remote block for executing transactions on the forked state.
+    net.time_service()
+        .adjust(GlobalTimeOnChain::new(chain_info.head().timestamp()));
+    Ok((storage, chain_info, genesis))
+}
@@ -0,0 +1,140 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use network_api::PeerProvider;
+use serde_json::json;
+use starcoin_config::NodeConfig;
+use starcoin_metrics::health::{HealthCheck, HealthChecker, HealthReport};
+use starcoin_network::NetworkServiceRef;
+use starcoin_service_registry::{RegistryAsyncService, RegistryService, ServiceRef, ServiceStatus};
+use starcoin_sync::sync::SyncService;
+use starcoin_sync_api::SyncStatusRequest;
+use starcoin_txpool::TxPoolService;
+use starcoin_txpool_api::TxPoolSyncService;
+use std::sync::Arc;
+
+const STORAGE_PROBE_FILE: &str = ".health_probe";
+
+/// Check node health by the services in registry.
+pub struct NodeHealthChecker {
+    config: Arc<NodeConfig>,
+    registry: ServiceRef<RegistryService>,
+}
+
+impl NodeHealthChecker {
+    pub fn new(config: Arc<NodeConfig>, registry: ServiceRef<RegistryService>) -> Self {
+        Self { config, registry }
+    }
+
+    fn check_result(name: &str, result: Result<HealthCheck>) -> HealthCheck {
+        result.unwrap_or_else(|e| HealthCheck::new(name, false, json!({ "error": e.to_string() })))
+    }
+
+    async fn check_services(&self) -> Result<HealthCheck> {
+        let services = self.registry.list_service().await?;
+        let unhealthy: Vec<_> = services
+            .into_iter()
+            .filter(|service| {
+                matches!(
+                    service.status,
+                    ServiceStatus::Shutdown | ServiceStatus::Unavailable
+                )
+            })
+            .collect();
+        Ok(HealthCheck::new(
+            "services",
+            unhealthy.is_empty(),
+            json!({ "unhealthy": unhealthy }),
+        ))
+    }
+
+    fn check_storage(&self) -> Result<HealthCheck> {
+        let probe = self.config.data_dir().join(STORAGE_PROBE_FILE);
+        std::fs::write(probe.as_path(), b"")?;
+        std::fs::remove_file(probe.as_path())?;
+        Ok(HealthCheck::new(
+            "storage",
+            true,
+            json!({ "data_dir": self.config.data_dir() }),
+        ))
+    }
+
+    async fn check_sync(&self) -> Result<Vec<HealthCheck>> {
+        let sync_service = self.registry.service_ref::<SyncService>().await?;
+        let sync_status = sync_service.send(SyncStatusRequest).await?;
+        let head = sync_status.chain_status().head();
+        let now = self.config.net().time_service().now_secs();
+        let head_block_age = now.saturating_sub(head.timestamp() / 1000);
+        let max_head_block_age = self.config.metrics.readiness.max_head_block_age();
+        Ok(vec![
+            HealthCheck::new(
+                "sync",
+                sync_status.is_nearly_synced(),
+                json!({ "state": sync_status.sync_status(), "head_number": head.number() }),
+            ),
+            HealthCheck::new(
+                "head_block_age",
+                head_block_age <= max_head_block_age,
+                json!({ "age": head_block_age, "max": max_head_block_age }),
+            ),
+        ])
+    }
+
+    async fn check_peers(&self) -> Result<HealthCheck> {
+        let network = self.registry.get_shared::<NetworkServiceRef>().await?;
+        let peers = network.peer_set().await?.len();
+        let min_peers = self.config.metrics.ready_min_peers();
+        Ok(HealthCheck::new(
+            "peers",
+            peers >= min_peers,
+            json!({ "count": peers, "min": min_peers }),
+        ))
+    }
+
+    async fn check_txpool(&self) -> Result<HealthCheck> {
+        let txpool = self.registry.get_shared::<TxPoolService>().await?;
+        let status = txpool.status();
+        let usage = if status.txn_max_count == 0 {
+            100
+        } else {
+            status.txn_count * 100 / status.txn_max_count
+        };
+        let max_usage = self.config.metrics.readiness.max_txpool_usage();
+        Ok(HealthCheck::new(
+            "txpool",
+            !status.is_full && usage <= max_usage as usize,
+            json!({
+                "txn_count": status.txn_count,
+                "txn_max_count": status.txn_max_count,
+                "usage": usage,
+                "max_usage": max_usage,
+            }),
+        ))
+    }
+
+    async fn health_checks(&self) -> Vec<HealthCheck> {
+        vec![
+            Self::check_result("services", self.check_services().await),
+            Self::check_result("storage", self.check_storage()),
+        ]
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthChecker for NodeHealthChecker {
+    async fn health(&self) -> HealthReport {
+        HealthReport::new(self.health_checks().await)
+    }
+
+    async fn readiness(&self) -> HealthReport {
+        let mut checks = self.health_checks().await;
+        match self.check_sync().await {
+            Ok(sync_checks) => checks.extend(sync_checks),
+            Err(e) => checks.push(Self::check_result("sync", Err(e))),
+        }
+        checks.push(Self::check_result("peers", self.check_peers().await));
+        checks.push(Self::check_result("txpool", self.check_txpool().await));
+        HealthReport::new(checks)
+    }
+}
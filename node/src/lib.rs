@@ -25,7 +25,10 @@ use std::time::Duration;
 use tokio::runtime::Runtime;
 
 pub mod crash_handler;
+mod error_map;
+mod fork;
 mod genesis_parameter_resolve;
+pub mod health;
 mod metrics;
 pub mod network_service_factory;
 pub mod node;
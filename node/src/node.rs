@@ -1,6 +1,9 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::error_map::ChainErrorMapReader;
+use crate::fork;
+use crate::health::NodeHealthChecker;
 use crate::metrics::MetricsActorService;
 use crate::network_service_factory::NetworkServiceFactory;
 use crate::peer_message_handler::NodePeerMessageHandler;
@@ -47,6 +50,10 @@ use starcoin_types::system_events::SystemStarted;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// The dir under data dir to put the error map files of third-party modules.
+/// The files are only registered at node startup, the node must restart to load new files.
+const ERROR_MAP_DIR: &str = "error_maps";
+
 pub struct NodeService {
     registry: ServiceRef<RegistryService>,
 }
@@ -95,6 +102,7 @@ impl ServiceHandler<Self, NodeRequest> for NodeService {
             }
             NodeRequest::ShutdownSystem => {
                 info!("Receive StopSystem request, try to stop system.");
+                starcoin_metrics::health::clear_health_checker();
                 if let Err(e) = self.registry.shutdown_system_sync() {
                     error!("Shutdown registry error: {}", e);
                 };
@@ -136,9 +144,13 @@ impl NodeService {
         }
 
         // start metric server
-        if let Some(metrics_address) = config.metrics.metrics_address() {
+        let metrics_enabled = if let Some(metrics_address) = config.metrics.metrics_address() {
             starcoin_metrics::metric_server::start_server(metrics_address);
-        }
+            true
+        } else {
+            false
+        };
+        let health_config = config.clone();
 
         let (start_sender, start_receiver) = oneshot::channel();
         let join_handle = timeout_join_handler::spawn(move || {
@@ -168,6 +180,12 @@ impl NodeService {
         });
         let (registry, node_service) =
             block_on(async { start_receiver.await }).expect("Wait node start error.")?;
+        if metrics_enabled {
+            starcoin_metrics::health::set_health_checker(Arc::new(NodeHealthChecker::new(
+                health_config,
+                registry.clone(),
+            )));
+        }
         Ok(NodeHandle::new(join_handle, node_service, registry))
     }
 
@@ -181,16 +199,41 @@ impl NodeService {
         registry.put_shared(logger_handle).await?;
 
         let bus = registry.service_ref::<BusService>().await?;
-        let storage = Arc::new(Storage::new(StorageInstance::new_cache_and_db_instance(
+        let storage = Storage::new(StorageInstance::new_cache_and_db_instance(
             CacheStorage::new_with_capacity(config.storage.cache_size()),
             DBStorage::new(config.storage.dir(), config.storage.rocksdb_config())?,
-        ))?);
+        ))?
+        .with_address_txn_index(config.storage.address_txn_index());
+        let (storage, chain_info, genesis) = match config.storage.fork_connect()? {
+            Some(connect) => fork::init_fork_storage(config.as_ref(), connect, storage)?,
+            None => {
+                let storage = Arc::new(storage);
+                let (chain_info, genesis) = Genesis::init_and_check_storage(
+                    config.net(),
+                    storage.clone(),
+                    config.data_dir(),
+                )?;
+                (storage, chain_info, genesis)
+            }
+        };
         registry.put_shared(storage.clone()).await?;
-        let (chain_info, genesis) =
-            Genesis::init_and_check_storage(config.net(), storage.clone(), config.data_dir())?;
 
         info!("Start node with chain info: {}", chain_info);
 
+        let error_map_dir = config.data_dir().join(ERROR_MAP_DIR);
+        if error_map_dir.is_dir() {
+            match starcoin_move_explain::register_error_mapping_dir(error_map_dir.as_path()) {
+                Ok(count) => info!("Register {} error maps from {:?}", count, error_map_dir),
+                Err(e) => warn!(
+                    "Register error maps from {:?} error: {:?}",
+                    error_map_dir, e
+                ),
+            }
+        }
+        starcoin_move_explain::set_error_map_reader(Arc::new(ChainErrorMapReader::new(
+            storage.clone(),
+        )));
+
         registry.put_shared(genesis).await?;
 
         let node_service = registry.register::<NodeService>().await?;
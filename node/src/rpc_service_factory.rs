@@ -12,7 +12,7 @@ use starcoin_logger::LoggerHandle;
 use starcoin_miner::MinerService;
 use starcoin_network::NetworkServiceRef;
 use starcoin_rpc_server::module::{
-    AccountRpcImpl, ChainRpcImpl, ContractRpcImpl, DebugRpcImpl, MinerRpcImpl,
+    AccountRpcImpl, ChainRpcImpl, ContractRpcImpl, DebugRpcImpl, DevRpcImpl, MinerRpcImpl,
     NetworkManagerRpcImpl, NodeManagerRpcImpl, NodeRpcImpl, PubSubImpl, PubSubService,
     StateRpcImpl, SyncManagerRpcImpl, TxPoolRpcImpl,
 };
@@ -20,6 +20,7 @@ use starcoin_rpc_server::service::RpcService;
 use starcoin_service_registry::{ServiceContext, ServiceFactory};
 use starcoin_state_service::ChainStateService;
 use starcoin_storage::Storage;
+use starcoin_sync::block_connector::BlockConnectorService;
 use starcoin_sync::sync::SyncService;
 use starcoin_txpool::TxPoolService;
 use std::sync::Arc;
@@ -45,7 +46,12 @@ impl ServiceFactory<RpcService> for RpcServiceFactory {
         let chain_api = ctx
             .service_ref_opt::<ChainReaderService>()?
             .map(|service_ref| {
-                ChainRpcImpl::new(config.clone(), genesis.block().id(), service_ref.clone())
+                ChainRpcImpl::new(
+                    config.clone(),
+                    genesis.block().id(),
+                    service_ref.clone(),
+                    storage.clone(),
+                )
             });
         let txpool_service = ctx.get_shared::<TxPoolService>()?;
         let txpool_api = Some(TxPoolRpcImpl::new(txpool_service.clone()));
@@ -63,6 +69,7 @@ impl ServiceFactory<RpcService> for RpcServiceFactory {
                 txpool_service.clone(),
                 chain_state_service.clone(),
                 chain_service.clone(),
+                PlaygroudService::new(storage.clone()),
             )
         });
         let pubsub_service = ctx.service_ref::<PubSubService>()?.clone();
@@ -72,6 +79,21 @@ impl ServiceFactory<RpcService> for RpcServiceFactory {
             .service_ref_opt::<MinerService>()?
             .map(|service_ref| MinerRpcImpl::new(service_ref.clone()));
 
+        //Dev api only available on test and dev network.
+        let dev_api = if config.net().is_test() || config.net().is_dev() {
+            Some(DevRpcImpl::new(
+                config.clone(),
+                ctx.bus_ref().clone(),
+                chain_service.clone(),
+                txpool_service.clone(),
+                chain_state_service.clone(),
+                ctx.service_ref::<BlockConnectorService>()?.clone(),
+                PlaygroudService::new(storage.clone()),
+            ))
+        } else {
+            None
+        };
+
         let contract_api = {
             let dev_playground = PlaygroudService::new(storage);
 
@@ -99,6 +121,7 @@ impl ServiceFactory<RpcService> for RpcServiceFactory {
             debug_api,
             miner_api,
             Some(contract_api),
+            dev_api,
         ))
     }
 }
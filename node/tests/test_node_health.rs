@@ -0,0 +1,42 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::executor::block_on;
+use starcoin_config::NodeConfig;
+use starcoin_metrics::health::{health_checker, HealthChecker};
+use starcoin_node::health::NodeHealthChecker;
+use starcoin_node::run_node;
+use std::sync::Arc;
+
+#[stest::test]
+fn test_node_health_checker() {
+    let mut node_config = NodeConfig::random_for_test();
+    node_config.network.disable_seed = true;
+    // a single node is not ready if it requires a peer.
+    node_config.metrics.readiness.min_peers = Some(1);
+    let config = Arc::new(node_config);
+    let handle = run_node(config.clone()).unwrap();
+    assert!(health_checker().is_some());
+
+    let checker = NodeHealthChecker::new(config, handle.registry().clone());
+    let health = block_on(checker.health());
+    assert!(health.healthy, "{:?}", health);
+    let names: Vec<_> = health
+        .checks
+        .iter()
+        .map(|check| check.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["services", "storage"]);
+
+    let readiness = block_on(checker.readiness());
+    assert!(!readiness.healthy, "{:?}", readiness);
+    let peers = readiness
+        .checks
+        .iter()
+        .find(|check| check.name == "peers")
+        .unwrap();
+    assert!(!peers.healthy);
+
+    handle.stop().unwrap();
+    assert!(health_checker().is_none());
+}
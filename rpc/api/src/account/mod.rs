@@ -6,10 +6,15 @@ use jsonrpc_derive::rpc;
 pub use self::gen_client::Client as AccountClient;
 use crate::types::{StrView, TransactionRequest};
 use crate::FutureResult;
-use starcoin_account_api::AccountInfo;
+use starcoin_account_api::{AccountInfo, PartiallySignedTransaction};
+use starcoin_crypto::ed25519::Ed25519PublicKey;
+use starcoin_crypto::HashValue;
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::sign_message::SigningMessage;
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::authenticator::AccountSignature;
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 use starcoin_vm_types::token::token_code::TokenCode;
 
 #[rpc]
@@ -43,6 +48,51 @@ pub trait AccountApi {
         signer: AccountAddress,
     ) -> FutureResult<SignedUserTransaction>;
 
+    /// sign a sponsored txn as the sender or the fee payer, return the signature.
+    /// The txn is built by combining the signatures of the sender and the fee payer.
+    #[rpc(name = "account.sign_fee_payer_txn")]
+    fn sign_fee_payer_txn(
+        &self,
+        txn: FeePayerRawTransaction,
+        signer: AccountAddress,
+    ) -> FutureResult<AccountSignature>;
+
+    /// Create a multisig account with the public keys of all signers and the threshold,
+    /// `private_keys` are the local shards of the account held by this wallet.
+    #[rpc(name = "account.create_multisig")]
+    fn create_multisig(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    ) -> FutureResult<AccountInfo>;
+
+    /// Create a multisig txn of the txn sender, and sign it with the local shards.
+    #[rpc(name = "account.create_multisig_txn")]
+    fn create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> FutureResult<PartiallySignedTransaction>;
+
+    /// Co-sign the multisig txn with the local shards of the txn sender.
+    #[rpc(name = "account.sign_multisig_txn")]
+    fn sign_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> FutureResult<PartiallySignedTransaction>;
+
+    /// Return the public keys of the signers who have not signed the multisig txn yet.
+    #[rpc(name = "account.multisig_txn_missing_signers")]
+    fn multisig_txn_missing_signers(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> FutureResult<Vec<Ed25519PublicKey>>;
+
+    /// Submit the multisig txn to txpool when enough signatures are collected, return the txn hash.
+    #[rpc(name = "account.submit_multisig_txn")]
+    fn submit_multisig_txn(&self, txn: PartiallySignedTransaction) -> FutureResult<HashValue>;
+
     /// unlock account for duration in seconds, default to u32::max.
     #[rpc(name = "account.unlock")]
     fn unlock(
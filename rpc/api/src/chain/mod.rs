@@ -4,13 +4,15 @@
 pub use self::gen_client::Client as ChainClient;
 use crate::types::pubsub::EventFilter;
 use crate::types::{
-    BlockHeaderView, BlockSummaryView, BlockView, ChainId, ChainInfoView, EpochUncleSummaryView,
+    AddressTransactionInfoView, BlockHeaderView, BlockSummaryView, BlockView, ChainId,
+    ChainInfoView, EpochUncleSummaryView, GetEventOption, GetTransactionsByAddressOption,
     TransactionEventView, TransactionInfoView, TransactionView,
 };
 use crate::FutureResult;
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use starcoin_crypto::HashValue;
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::block::{BlockInfo, BlockNumber};
 use starcoin_vm_types::on_chain_resource::{EpochInfo, GlobalTimeOnChain};
 
@@ -60,12 +62,24 @@ pub trait ChainApi {
         idx: u64,
     ) -> FutureResult<Option<TransactionInfoView>>;
 
+    /// Get the main chain transaction infos sent by or affecting the `address`, page by `option`.
+    /// Only available when the node enables the address txn index.
+    #[rpc(name = "chain.get_transactions_by_address")]
+    fn get_transactions_by_address(
+        &self,
+        address: AccountAddress,
+        option: Option<GetTransactionsByAddressOption>,
+    ) -> FutureResult<Vec<AddressTransactionInfoView>>;
+
+    /// Get events of txn `txn_hash`, decode the event data if `option.decode` is true.
     #[rpc(name = "chain.get_events_by_txn_hash")]
     fn get_events_by_txn_hash(
         &self,
         txn_hash: HashValue,
+        option: Option<GetEventOption>,
     ) -> FutureResult<Vec<TransactionEventView>>;
 
+    /// Get events by filter, decode the event data if `filter.decode` is true.
     #[rpc(name = "chain.get_events")]
     fn get_events(&self, filter: EventFilter) -> FutureResult<Vec<TransactionEventView>>;
 
@@ -3,11 +3,12 @@ use jsonrpc_derive::rpc;
 pub use self::gen_client::Client as ContractClient;
 use crate::types::{
     AnnotatedMoveStructView, AnnotatedMoveValueView, ContractCall, DryRunTransactionRequest,
-    StrView, TransactionOutputView,
+    FunctionIdView, StrView, TransactionOutputView,
 };
 use crate::FutureResult;
 use starcoin_vm_types::account_address::AccountAddress;
 use starcoin_vm_types::language_storage::{ModuleId, StructTag};
+use starcoin_vm_types::module_abi::{FunctionABI, ModuleABI};
 
 #[rpc]
 pub trait ContractApi {
@@ -15,6 +16,14 @@ pub trait ContractApi {
     #[rpc(name = "contract.get_code")]
     fn get_code(&self, module_id: StrView<ModuleId>) -> FutureResult<Option<StrView<Vec<u8>>>>;
 
+    /// get the ABI of module, include functions, structs and script functions.
+    #[rpc(name = "contract.get_module_abi")]
+    fn get_module_abi(&self, module_id: StrView<ModuleId>) -> FutureResult<Option<ModuleABI>>;
+
+    /// get the ABI of function, return None if the module or function not exists.
+    #[rpc(name = "contract.resolve_function")]
+    fn resolve_function(&self, function_id: FunctionIdView) -> FutureResult<Option<FunctionABI>>;
+
     /// get resource data of `addr`
     #[rpc(name = "contract.get_resource")]
     fn get_resource(
@@ -0,0 +1,57 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2
+
+pub use self::gen_client::Client as DevClient;
+use crate::types::{BlockHeaderView, StateOverrideView, StrView};
+use crate::FutureResult;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::transaction::authenticator::AuthenticationKey;
+
+/// Development api, only available on test and dev network, for controlling the local chain in tests.
+#[rpc]
+pub trait DevApi {
+    /// Move the node clock forward by `millis` milliseconds, return the new time in milliseconds.
+    #[rpc(name = "dev.increase_time")]
+    fn increase_time(&self, millis: u64) -> Result<u64>;
+
+    /// Set the node clock to `millis`, the clock can not go backwards. Return the new time in milliseconds.
+    #[rpc(name = "dev.set_time")]
+    fn set_time(&self, millis: u64) -> Result<u64>;
+
+    /// Mine `count` blocks immediately, with the pending transactions of txpool, return the new block headers.
+    #[rpc(name = "dev.mine_blocks")]
+    fn mine_blocks(&self, count: u64) -> FutureResult<Vec<BlockHeaderView>>;
+
+    /// Take a snapshot of the main chain head, the node clock and the pending transactions of txpool,
+    /// return the snapshot id, which can be passed to `dev.revert`.
+    #[rpc(name = "dev.snapshot")]
+    fn snapshot(&self) -> FutureResult<u64>;
+
+    /// Revert the main chain head, the node clock and the pending transactions to the snapshot,
+    /// return the new head block header. The snapshot and the snapshots taken after it are removed.
+    /// The blocks after the snapshot are kept in storage, but no longer on the main chain.
+    #[rpc(name = "dev.revert")]
+    fn revert(&self, snapshot_id: u64) -> FutureResult<BlockHeaderView>;
+
+    /// Write the state overrides into the chain state directly, return the new head block header.
+    /// The main chain head is replaced by a block with the same transactions and the changed state,
+    /// so the block can not be verified by executing its transactions.
+    /// If the head is the genesis block, a block is mined first.
+    #[rpc(name = "dev.set_state")]
+    fn set_state(&self, state_overrides: Vec<StateOverrideView>) -> FutureResult<BlockHeaderView>;
+
+    /// Set the STC balance of `address` to `amount`, return the new balance.
+    /// The balance is topped up by a transfer from the association account in a new block, and the
+    /// account is created with `auth_key` if it does not exist.
+    /// The balance is decreased by moving the difference to the association account with `dev.set_state`,
+    /// so the total supply is not changed.
+    #[rpc(name = "dev.set_balance")]
+    fn set_balance(
+        &self,
+        address: AccountAddress,
+        amount: StrView<u128>,
+        auth_key: Option<AuthenticationKey>,
+    ) -> FutureResult<StrView<u128>>;
+}
@@ -9,7 +9,7 @@ use starcoin_types::{
 };
 
 pub use self::gen_client::Client as StateClient;
-use crate::types::{AccountStateSetView, StateWithProofView};
+use crate::types::{AccountStateSetView, StateWithProofView, StrView};
 
 #[rpc]
 pub trait StateApi {
@@ -37,4 +37,11 @@ pub trait StateApi {
         access_path: AccessPath,
         state_root: HashValue,
     ) -> FutureResult<StateWithProofView>;
+
+    /// Get the encoded state tree node by the node hash, used to read the state lazily by node.
+    #[rpc(name = "state.get_state_node_by_node_hash")]
+    fn get_state_node_by_node_hash(
+        &self,
+        key_hash: HashValue,
+    ) -> FutureResult<Option<StrView<Vec<u8>>>>;
 }
@@ -1,4 +1,10 @@
-use crate::types::{ContractCall, TransactionArgumentView, TypeTagView};
+use crate::types::pubsub::EventFilter;
+use crate::types::{
+    ContractCall, StateOverrideView, TransactionArgumentView, TransactionEventView, TypeTagView,
+};
+use starcoin_types::account_config::genesis_address;
+use starcoin_types::contract_event::ContractEvent;
+use starcoin_types::event::EventKey;
 use starcoin_vm_types::token::stc::stc_type_tag;
 use starcoin_vm_types::transaction_argument::TransactionArgument;
 
@@ -31,4 +37,54 @@ fn test_deserialize() {
         "#;
     let v = serde_json::from_str::<ContractCall>(s).unwrap();
     println!("{:?}", v);
+    assert!(v.state_overrides.is_empty());
+}
+
+#[test]
+fn test_deserialize_state_overrides() {
+    let s = r#"
+[
+  {"value": {"access_path": "0x1/1/0x1::Account::Balance<0x1::STC::STC>", "value": null}},
+  {"json_value": {"access_path": "0x1/1/0x1::Account::Balance<0x1::STC::STC>", "value": {"token": {"value": 1}}}},
+  {"balance": {"address": "0x1", "amount": "100"}},
+  {"module": {"code": "0x0102"}}
+]
+        "#;
+    let v = serde_json::from_str::<Vec<StateOverrideView>>(s).unwrap();
+    assert!(matches!(
+        &v[0],
+        StateOverrideView::Value { value: None, .. }
+    ));
+    assert!(matches!(
+        &v[2],
+        StateOverrideView::Balance { token: None, .. }
+    ));
+    let s2 = serde_json::to_string(&v).unwrap();
+    assert_eq!(
+        v,
+        serde_json::from_str::<Vec<StateOverrideView>>(&s2).unwrap()
+    );
+}
+
+#[test]
+fn test_event_filter_decode_option() {
+    let filter = serde_json::from_str::<EventFilter>(r#"{"from_block": 1}"#).unwrap();
+    assert!(!filter.decode);
+    let filter = serde_json::from_str::<EventFilter>(r#"{"decode": true}"#).unwrap();
+    assert!(filter.decode);
+}
+
+#[test]
+fn test_event_view_without_decode_data() {
+    let event = ContractEvent::new(
+        EventKey::new_from_address(&genesis_address(), 0),
+        0,
+        stc_type_tag(),
+        vec![],
+    );
+    let view = TransactionEventView::from(event);
+    let s = serde_json::to_string(&view).unwrap();
+    assert!(!s.contains("decode_event_data"));
+    let view2: TransactionEventView = serde_json::from_str(s.as_str()).unwrap();
+    assert_eq!(view, view2);
 }
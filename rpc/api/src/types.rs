@@ -13,7 +13,7 @@ use serde::de::Error;
 use serde::{Deserialize, Serializer};
 use serde::{Deserializer, Serialize};
 use starcoin_crypto::{CryptoMaterialError, HashValue, ValidCryptoMaterialStringExt};
-use starcoin_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+use starcoin_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator};
 use starcoin_service_registry::ServiceRequest;
 use starcoin_state_api::{StateProof, StateWithProof};
 use starcoin_types::account_address::AccountAddress;
@@ -51,7 +51,7 @@ use std::str::FromStr;
 
 pub type ByteCode = Vec<u8>;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct AnnotatedMoveStructView {
     pub abilities: u8,
     pub type_: StructTagView,
@@ -71,7 +71,7 @@ impl From<AnnotatedMoveStruct> for AnnotatedMoveStructView {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub enum AnnotatedMoveValueView {
     U8(u8),
     U64(StrView<u64>),
@@ -171,6 +171,34 @@ pub struct DryRunTransactionRequest {
     pub transaction: TransactionRequest,
     /// Sender's public key
     pub sender_public_key: Option<StrView<AccountPublicKey>>,
+    /// The state overrides applied before the dry run, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub state_overrides: Vec<StateOverrideView>,
+}
+
+/// A change of the state which only lives in a `contract.call` or `contract.dry_run`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateOverrideView {
+    /// Replace the value at the access path with the BCS bytes, or remove it if the value is null.
+    Value {
+        access_path: AccessPath,
+        value: Option<StrView<Vec<u8>>>,
+    },
+    /// Replace the resource at the access path with the JSON value, in the same form as JSON args.
+    JsonValue {
+        access_path: AccessPath,
+        value: serde_json::Value,
+    },
+    /// Set the balance of the token for the address, the default token is STC.
+    Balance {
+        address: AccountAddress,
+        #[serde(default)]
+        token: Option<TypeTagView>,
+        amount: StrView<u128>,
+    },
+    /// Add or replace the module with the bytecode.
+    Module { code: StrView<Vec<u8>> },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -180,7 +208,20 @@ pub enum ArgumentsView {
     BCS(Vec<StrView<Vec<u8>>>),
 }
 
+impl Default for ArgumentsView {
+    fn default() -> Self {
+        Self::HumanReadable(vec![])
+    }
+}
+
 impl ArgumentsView {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::HumanReadable(vs) => vs.is_empty(),
+            Self::BCS(vs) => vs.is_empty(),
+        }
+    }
+
     pub fn to_bcs_bytes(&self) -> Vec<Vec<u8>> {
         match self {
             Self::HumanReadable(vs) => {
@@ -226,10 +267,41 @@ pub struct ScriptData {
     pub code: StrView<ByteCodeOrScriptFunction>,
     #[serde(default)]
     pub type_args: Vec<TypeTagView>,
+    #[serde(default)]
     pub args: ArgumentsView,
+    /// The JSON args of script function, encoded by the function signature before build the
+    /// transaction, can not be used together with `args`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_args: Option<Vec<serde_json::Value>>,
 }
 
 impl ScriptData {
+    /// Encode the `json_args` to BCS `args` by `encode`, the `json_args` should be resolved
+    /// before converting to script function or payload, otherwise they are ignored.
+    pub fn resolve_json_args<F>(mut self, encode: F) -> anyhow::Result<Self>
+    where
+        F: FnOnce(&FunctionId, &[TypeTag], &[serde_json::Value]) -> anyhow::Result<Vec<Vec<u8>>>,
+    {
+        let json_args = match self.json_args.take() {
+            Some(json_args) => json_args,
+            None => return Ok(self),
+        };
+        anyhow::ensure!(
+            self.args.is_empty(),
+            "args and json_args can not be used together"
+        );
+        let function_id = match &self.code.0 {
+            ByteCodeOrScriptFunction::ScriptFunction(function_id) => function_id,
+            ByteCodeOrScriptFunction::ByteCode(_) => {
+                anyhow::bail!("json_args is only supported by script function")
+            }
+        };
+        let type_args: Vec<_> = self.type_args.iter().map(|t| t.0.clone()).collect();
+        let args = encode(function_id, type_args.as_slice(), json_args.as_slice())?;
+        self.args = ArgumentsView::BCS(args.into_iter().map(StrView).collect());
+        Ok(self)
+    }
+
     pub fn into_script_function(self) -> anyhow::Result<ScriptFunction> {
         match self.into_data() {
             Err(script_function) => Ok(script_function),
@@ -275,6 +347,7 @@ impl From<Script> for ScriptData {
             code: StrView(ByteCodeOrScriptFunction::ByteCode(code)),
             type_args: ty_args.into_iter().map(TypeTagView::from).collect(),
             args: ArgumentsView::BCS(args.into_iter().map(StrView).collect()),
+            json_args: None,
         }
     }
 }
@@ -288,6 +361,7 @@ impl From<ScriptFunction> for ScriptData {
             })),
             type_args: ty_args.into_iter().map(TypeTagView::from).collect(),
             args: ArgumentsView::BCS(args.into_iter().map(StrView).collect()),
+            json_args: None,
         }
     }
 }
@@ -426,6 +500,23 @@ impl TryFrom<RawUserTransaction> for RawUserTransactionView {
     }
 }
 
+impl TryFrom<RawUserTransactionView> for RawUserTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(view: RawUserTransactionView) -> Result<Self, Self::Error> {
+        Ok(RawUserTransaction::new(
+            view.sender,
+            view.sequence_number.0,
+            TransactionPayload::decode(view.payload.0.as_slice())?,
+            view.max_gas_amount.0,
+            view.gas_unit_price.0,
+            view.expiration_timestamp_secs.0,
+            genesis_config::ChainId::new(view.chain_id),
+            view.gas_token_code,
+        ))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct SignedUserTransactionView {
     pub transaction_hash: HashValue,
@@ -434,6 +525,10 @@ pub struct SignedUserTransactionView {
 
     /// Public key and signature to authenticate
     pub authenticator: TransactionAuthenticator,
+
+    /// The account which pays the gas, if it is a sponsored transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_payer: Option<AccountAddress>,
 }
 
 impl TryFrom<SignedUserTransaction> for SignedUserTransactionView {
@@ -442,10 +537,12 @@ impl TryFrom<SignedUserTransaction> for SignedUserTransactionView {
     fn try_from(txn: SignedUserTransaction) -> Result<Self, Self::Error> {
         let auth = txn.authenticator();
         let txn_hash = txn.id();
+        let fee_payer = txn.fee_payer_address();
         Ok(SignedUserTransactionView {
             transaction_hash: txn_hash,
             raw_txn: txn.into_raw_transaction().try_into()?,
             authenticator: auth,
+            fee_payer,
         })
     }
 }
@@ -705,6 +802,9 @@ pub enum TransactionVMStatus {
     MoveAbort {
         location: AbortLocation,
         abort_code: StrView<u64>,
+        /// The human-readable explanation of the abort code, if the error map of the module is known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        explanation: Option<AbortExplanationView>,
     },
     ExecutionFailure {
         location: AbortLocation,
@@ -716,6 +816,41 @@ pub enum TransactionVMStatus {
         status_code: StrView<u64>,
     },
 }
+impl TransactionVMStatus {
+    /// Fill the explanation of MoveAbort status by the `explain` fn, which takes the abort module and code.
+    pub fn explain<F>(self, explain: F) -> Self
+    where
+        F: FnOnce(&ModuleId, u64) -> Option<AbortExplanationView>,
+    {
+        match self {
+            TransactionVMStatus::MoveAbort {
+                location: AbortLocation::Module(module_id),
+                abort_code,
+                explanation: None,
+            } => {
+                let explanation = explain(&module_id, abort_code.0);
+                TransactionVMStatus::MoveAbort {
+                    location: AbortLocation::Module(module_id),
+                    abort_code,
+                    explanation,
+                }
+            }
+            status => status,
+        }
+    }
+}
+
+/// Explanation of a Move abort code, which is defined as a global category + module-specific reason.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbortExplanationView {
+    pub category_code: u64,
+    pub category_name: String,
+    pub category_description: String,
+    pub reason_code: u64,
+    pub reason_name: String,
+    pub reason_description: String,
+}
+
 impl From<TransactionStatus> for TransactionVMStatus {
     fn from(s: TransactionStatus) -> Self {
         match s {
@@ -733,6 +868,7 @@ impl From<KeptVMStatus> for TransactionVMStatus {
             KeptVMStatus::MoveAbort(l, c) => TransactionVMStatus::MoveAbort {
                 location: l,
                 abort_code: c.into(),
+                explanation: None,
             },
             KeptVMStatus::ExecutionFailure {
                 location,
@@ -767,6 +903,9 @@ pub struct TransactionEventView {
     pub type_tag: TypeTag,
     pub event_key: EventKey,
     pub event_seq_number: StrView<u64>,
+    /// The annotated move value of `data`, only present when decoding is requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decode_event_data: Option<AnnotatedMoveValueView>,
 }
 
 impl From<ContractEventInfo> for TransactionEventView {
@@ -780,6 +919,7 @@ impl From<ContractEventInfo> for TransactionEventView {
             type_tag: info.event.type_tag().clone(),
             event_key: *info.event.key(),
             event_seq_number: info.event.sequence_number().into(),
+            decode_event_data: None,
         }
     }
 }
@@ -794,6 +934,7 @@ impl From<ContractEvent> for TransactionEventView {
             type_tag: event.type_tag().clone(),
             event_key: *event.key(),
             event_seq_number: event.sequence_number().into(),
+            decode_event_data: None,
         }
     }
 }
@@ -815,8 +956,57 @@ impl TransactionEventView {
             type_tag: contract_event.type_tag().clone(),
             event_key: *contract_event.key(),
             event_seq_number: contract_event.sequence_number().into(),
+            decode_event_data: None,
         }
     }
+
+    /// Decode the event data into an annotated move value with the given `annotator`.
+    pub fn decode(&mut self, annotator: &MoveValueAnnotator) -> anyhow::Result<()> {
+        let value = annotator.view_value(&self.type_tag, self.data.0.as_slice())?;
+        self.decode_event_data = Some(value.into());
+        Ok(())
+    }
+}
+
+/// Options of event queries.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetEventOption {
+    /// Decode the event data into an annotated move value.
+    #[serde(default)]
+    pub decode: bool,
+}
+
+/// Options of address transaction queries.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetTransactionsByAddressOption {
+    /// The index of the address transaction to start from,
+    /// default to the first one, or the last one if `reverse`.
+    #[serde(default)]
+    pub start_index: Option<u64>,
+    /// Query from the latest transaction to the older ones.
+    #[serde(default)]
+    pub reverse: bool,
+    /// The max number of transactions to return, default is 20, and at most 100.
+    #[serde(default)]
+    pub max_size: Option<u64>,
+}
+
+impl GetTransactionsByAddressOption {
+    pub const DEFAULT_MAX_SIZE: u64 = 20;
+    pub const MAX_SIZE_LIMIT: u64 = 100;
+
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+            .unwrap_or(Self::DEFAULT_MAX_SIZE)
+            .min(Self::MAX_SIZE_LIMIT)
+    }
+}
+
+/// A transaction info in the address txn index, with its index of the address transactions.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressTransactionInfoView {
+    pub index: StrView<u64>,
+    pub txn_info: TransactionInfoView,
 }
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TransactionOutputView {
@@ -1257,7 +1447,14 @@ impl Serialize for BytesView {
 pub struct ContractCall {
     pub function_id: FunctionIdView,
     pub type_args: Vec<TypeTagView>,
+    #[serde(default)]
     pub args: Vec<TransactionArgumentView>,
+    /// The JSON args, encoded by the function signature, can not be used together with `args`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_args: Option<Vec<serde_json::Value>>,
+    /// The state overrides applied before the call, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub state_overrides: Vec<StateOverrideView>,
 }
 
 #[derive(Debug, Clone)]
@@ -1269,8 +1466,13 @@ impl ServiceRequest for ConnectLocal {
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{ByteCodeOrScriptFunction, FunctionId};
+    use crate::types::{
+        AbortExplanationView, ByteCodeOrScriptFunction, FunctionId, TransactionVMStatus,
+    };
     use starcoin_types::account_address::AccountAddress;
+    use starcoin_types::vm_error::AbortLocation;
+    use starcoin_vm_types::identifier::Identifier;
+    use starcoin_vm_types::language_storage::ModuleId;
 
     #[test]
     fn test_script_data() {
@@ -1290,4 +1492,69 @@ mod tests {
         let bytecode: ByteCodeOrScriptFunction = "0x123432ab34".parse().unwrap();
         assert!(matches!(bytecode, ByteCodeOrScriptFunction::ByteCode(_)));
     }
+
+    fn explanation(abort_code: u64) -> AbortExplanationView {
+        AbortExplanationView {
+            category_code: abort_code & 0xFF,
+            category_name: "CATEGORY".to_string(),
+            category_description: "category".to_string(),
+            reason_code: abort_code >> 8,
+            reason_name: "REASON".to_string(),
+            reason_description: "reason".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_explain_vm_status() {
+        let module_id = ModuleId::new(AccountAddress::random(), Identifier::new("Demo").unwrap());
+        let status = TransactionVMStatus::MoveAbort {
+            location: AbortLocation::Module(module_id.clone()),
+            abort_code: 263.into(),
+            explanation: None,
+        };
+        // no explanation field if the abort code is unknown.
+        let unknown = status.clone().explain(|_, _| None);
+        assert_eq!(unknown, status);
+        assert!(!serde_json::to_string(&unknown)
+            .unwrap()
+            .contains("explanation"));
+
+        let explained = status.explain(|id, code| {
+            assert_eq!(id, &module_id);
+            Some(explanation(code))
+        });
+        let expected = TransactionVMStatus::MoveAbort {
+            location: AbortLocation::Module(module_id.clone()),
+            abort_code: 263.into(),
+            explanation: Some(explanation(263)),
+        };
+        assert_eq!(explained, expected);
+        let json = serde_json::to_string(&explained).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransactionVMStatus>(json.as_str()).unwrap(),
+            expected
+        );
+        // the existing explanation is kept.
+        assert_eq!(
+            explained.explain(|_, _| panic!("should not explain again")),
+            expected
+        );
+
+        // only the abort in module is explained.
+        let script_abort = TransactionVMStatus::MoveAbort {
+            location: AbortLocation::Script,
+            abort_code: 263.into(),
+            explanation: None,
+        };
+        assert_eq!(
+            script_abort
+                .clone()
+                .explain(|_, _| panic!("script abort should not be explained")),
+            script_abort
+        );
+        assert_eq!(
+            TransactionVMStatus::Executed.explain(|_, _| panic!("should not explain")),
+            TransactionVMStatus::Executed
+        );
+    }
 }
@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors;
-use crate::types::{BlockView, TransactionEventView};
+use crate::types::{BlockView, TransactionEventView, TypeTagView};
 use jsonrpc_core::error::Error as JsonRpcError;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{from_value, Value};
 use starcoin_crypto::HashValue;
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::event::EventKey;
 use starcoin_types::filter::Filter;
 use starcoin_types::U256;
@@ -100,9 +101,18 @@ pub struct EventFilter {
     /// Event keys
     #[serde(default)]
     pub event_keys: Vec<EventKey>,
+    /// Addresses which create the event keys
+    #[serde(default)]
+    pub addrs: Vec<AccountAddress>,
+    /// Event type tags, a struct type without type params matches all its instantiations
+    #[serde(default)]
+    pub type_tags: Vec<TypeTagView>,
     /// Limit: from latest to oldest
     #[serde(default)]
     pub limit: Option<usize>,
+    /// Decode the event data into an annotated move value
+    #[serde(default)]
+    pub decode: bool,
 }
 
 impl TryInto<Filter> for EventFilter {
@@ -122,6 +132,8 @@ impl TryInto<Filter> for EventFilter {
             from_block: self.from_block.unwrap_or(0),
             to_block: self.to_block.unwrap_or(std::u64::MAX),
             event_keys: self.event_keys,
+            addrs: self.addrs,
+            type_tags: self.type_tags.into_iter().map(|t| t.0).collect(),
             limit: self.limit,
             reverse: true,
         })
@@ -13,7 +13,8 @@ use network_api::PeerStrategy;
 use network_p2p_types::network_state::NetworkState;
 use parking_lot::Mutex;
 use serde_json::Value;
-use starcoin_account_api::AccountInfo;
+use starcoin_account_api::{AccountInfo, PartiallySignedTransaction};
+use starcoin_crypto::ed25519::Ed25519PublicKey;
 use starcoin_crypto::HashValue;
 use starcoin_logger::{prelude::*, LogPattern};
 use starcoin_rpc_api::node::NodeInfo;
@@ -21,15 +22,16 @@ use starcoin_rpc_api::service::RpcAsyncService;
 use starcoin_rpc_api::types::pubsub::EventFilter;
 use starcoin_rpc_api::types::pubsub::MintBlock;
 use starcoin_rpc_api::types::{
-    AccountStateSetView, AnnotatedMoveStructView, AnnotatedMoveValueView, BlockHeaderView,
-    BlockSummaryView, BlockView, ChainId, ChainInfoView, ContractCall, DryRunTransactionRequest,
-    EpochUncleSummaryView, FactoryAction, PeerInfoView, SignedUserTransactionView,
+    AccountStateSetView, AddressTransactionInfoView, AnnotatedMoveStructView,
+    AnnotatedMoveValueView, BlockHeaderView, BlockSummaryView, BlockView, ChainId, ChainInfoView,
+    ContractCall, DryRunTransactionRequest, EpochUncleSummaryView, FactoryAction, GetEventOption,
+    GetTransactionsByAddressOption, PeerInfoView, SignedUserTransactionView, StateOverrideView,
     StateWithProofView, StrView, TransactionInfoView, TransactionOutputView, TransactionRequest,
     TransactionView,
 };
 use starcoin_rpc_api::{
     account::AccountClient, chain::ChainClient, contract_api::ContractClient, debug::DebugClient,
-    miner::MinerClient, network_manager::NetworkManagerClient, node::NodeClient,
+    dev::DevClient, miner::MinerClient, network_manager::NetworkManagerClient, node::NodeClient,
     node_manager::NodeManagerClient, state::StateClient, sync_manager::SyncManagerClient,
     txpool::TxPoolClient, types::TransactionEventView,
 };
@@ -42,7 +44,10 @@ use starcoin_types::account_state::AccountState;
 use starcoin_types::block::{BlockInfo, BlockNumber};
 use starcoin_types::peer_info::{Multiaddr, PeerId};
 use starcoin_types::sync_status::SyncStatus;
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::authenticator::{AccountSignature, AuthenticationKey};
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 use starcoin_vm_types::on_chain_resource::{EpochInfo, GlobalTimeOnChain};
 use starcoin_vm_types::token::token_code::TokenCode;
 use std::collections::HashMap;
@@ -57,7 +62,8 @@ mod remote_state_reader;
 pub use crate::remote_state_reader::RemoteStateReader;
 pub use jsonrpc_core::Params;
 use starcoin_types::sign_message::SigningMessage;
-use starcoin_vm_types::language_storage::{ModuleId, StructTag};
+use starcoin_vm_types::language_storage::{FunctionId, ModuleId, StructTag};
+use starcoin_vm_types::module_abi::{FunctionABI, ModuleABI};
 use tokio::runtime::Runtime;
 
 #[derive(Clone)]
@@ -331,6 +337,64 @@ impl RpcClient {
             .map_err(map_err)
     }
 
+    /// sign a sponsored txn as the sender or the fee payer
+    pub fn account_sign_fee_payer_txn(
+        &self,
+        txn: FeePayerRawTransaction,
+        signer: AccountAddress,
+    ) -> anyhow::Result<AccountSignature> {
+        self.call_rpc_blocking(|inner| inner.account_client.sign_fee_payer_txn(txn, signer))
+            .map_err(map_err)
+    }
+
+    pub fn account_create_multisig(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    ) -> anyhow::Result<AccountInfo> {
+        self.call_rpc_blocking(|inner| {
+            inner
+                .account_client
+                .create_multisig(public_keys, threshold, private_keys, password)
+        })
+        .map_err(map_err)
+    }
+
+    pub fn account_create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> anyhow::Result<PartiallySignedTransaction> {
+        self.call_rpc_blocking(|inner| inner.account_client.create_multisig_txn(raw_txn))
+            .map_err(map_err)
+    }
+
+    /// co-sign a partially signed multisig txn with the wallet's local shards
+    pub fn account_sign_partially_signed_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> anyhow::Result<PartiallySignedTransaction> {
+        self.call_rpc_blocking(|inner| inner.account_client.sign_multisig_txn(txn))
+            .map_err(map_err)
+    }
+
+    pub fn account_multisig_txn_missing_signers(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> anyhow::Result<Vec<Ed25519PublicKey>> {
+        self.call_rpc_blocking(|inner| inner.account_client.multisig_txn_missing_signers(txn))
+            .map_err(map_err)
+    }
+
+    pub fn account_submit_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> anyhow::Result<HashValue> {
+        self.call_rpc_blocking(|inner| inner.account_client.submit_multisig_txn(txn))
+            .map_err(map_err)
+    }
+
     pub fn account_sign_message(
         &self,
         signer: AccountAddress,
@@ -403,6 +467,16 @@ impl RpcClient {
         Ok(result.map(|s| s.to_string()))
     }
 
+    pub fn get_module_abi(&self, module_id: ModuleId) -> anyhow::Result<Option<ModuleABI>> {
+        self.call_rpc_blocking(|inner| inner.contract_client.get_module_abi(StrView(module_id)))
+            .map_err(map_err)
+    }
+
+    pub fn resolve_function(&self, function_id: FunctionId) -> anyhow::Result<Option<FunctionABI>> {
+        self.call_rpc_blocking(|inner| inner.contract_client.resolve_function(StrView(function_id)))
+            .map_err(map_err)
+    }
+
     pub fn get_resource(
         &self,
         addr: AccountAddress,
@@ -442,6 +516,15 @@ impl RpcClient {
         .map_err(map_err)
     }
 
+    pub fn state_get_state_node_by_node_hash(
+        &self,
+        key_hash: HashValue,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        self.call_rpc_blocking(|inner| inner.state_client.get_state_node_by_node_hash(key_hash))
+            .map(|node| node.map(|node| node.0))
+            .map_err(map_err)
+    }
+
     pub fn state_get_state_root(&self) -> anyhow::Result<HashValue> {
         self.call_rpc_blocking(|inner| inner.state_client.get_state_root())
             .map_err(map_err)
@@ -501,6 +584,54 @@ impl RpcClient {
             .map_err(map_err)
     }
 
+    pub fn dev_increase_time(&self, millis: u64) -> anyhow::Result<u64> {
+        self.call_rpc_blocking(|inner| inner.dev_client.increase_time(millis))
+            .map_err(map_err)
+    }
+
+    pub fn dev_set_time(&self, millis: u64) -> anyhow::Result<u64> {
+        self.call_rpc_blocking(|inner| inner.dev_client.set_time(millis))
+            .map_err(map_err)
+    }
+
+    pub fn dev_mine_blocks(&self, count: u64) -> anyhow::Result<Vec<BlockHeaderView>> {
+        self.call_rpc_blocking(|inner| inner.dev_client.mine_blocks(count))
+            .map_err(map_err)
+    }
+
+    pub fn dev_snapshot(&self) -> anyhow::Result<u64> {
+        self.call_rpc_blocking(|inner| inner.dev_client.snapshot())
+            .map_err(map_err)
+    }
+
+    pub fn dev_revert(&self, snapshot_id: u64) -> anyhow::Result<BlockHeaderView> {
+        self.call_rpc_blocking(|inner| inner.dev_client.revert(snapshot_id))
+            .map_err(map_err)
+    }
+
+    pub fn dev_set_state(
+        &self,
+        state_overrides: Vec<StateOverrideView>,
+    ) -> anyhow::Result<BlockHeaderView> {
+        self.call_rpc_blocking(|inner| inner.dev_client.set_state(state_overrides))
+            .map_err(map_err)
+    }
+
+    pub fn dev_set_balance(
+        &self,
+        address: AccountAddress,
+        amount: u128,
+        auth_key: Option<AuthenticationKey>,
+    ) -> anyhow::Result<u128> {
+        self.call_rpc_blocking(|inner| {
+            inner
+                .dev_client
+                .set_balance(address, StrView(amount), auth_key)
+        })
+        .map(|balance| balance.0)
+        .map_err(map_err)
+    }
+
     pub fn chain_id(&self) -> anyhow::Result<ChainId> {
         self.call_rpc_blocking(|inner| inner.chain_client.id())
             .map_err(map_err)
@@ -602,11 +733,25 @@ impl RpcClient {
     pub fn chain_get_events_by_txn_hash(
         &self,
         txn_hash: HashValue,
+        option: Option<GetEventOption>,
     ) -> anyhow::Result<Vec<TransactionEventView>> {
-        self.call_rpc_blocking(|inner| inner.chain_client.get_events_by_txn_hash(txn_hash))
+        self.call_rpc_blocking(|inner| inner.chain_client.get_events_by_txn_hash(txn_hash, option))
             .map_err(map_err)
     }
 
+    pub fn chain_get_transactions_by_address(
+        &self,
+        address: AccountAddress,
+        option: Option<GetTransactionsByAddressOption>,
+    ) -> anyhow::Result<Vec<AddressTransactionInfoView>> {
+        self.call_rpc_blocking(|inner| {
+            inner
+                .chain_client
+                .get_transactions_by_address(address, option)
+        })
+        .map_err(map_err)
+    }
+
     pub fn chain_get_block_txn_infos(
         &self,
         block_id: HashValue,
@@ -847,6 +992,7 @@ pub(crate) struct RpcClientInner {
     account_client: AccountClient,
     state_client: StateClient,
     debug_client: DebugClient,
+    dev_client: DevClient,
     chain_client: ChainClient,
     pubsub_client: PubSubClient,
     contract_client: ContractClient,
@@ -865,6 +1011,7 @@ impl RpcClientInner {
             account_client: channel.clone().into(),
             state_client: channel.clone().into(),
             debug_client: channel.clone().into(),
+            dev_client: channel.clone().into(),
             chain_client: channel.clone().into(),
             contract_client: channel.clone().into(),
             pubsub_client: channel.clone().into(),
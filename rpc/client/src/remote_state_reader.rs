@@ -3,17 +3,21 @@
 
 use crate::RpcClient;
 use anyhow::Result;
+use parking_lot::Mutex;
 use starcoin_crypto::HashValue;
 use starcoin_state_api::{ChainStateReader, StateView, StateWithProof};
 use starcoin_types::access_path::AccessPath;
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::account_state::AccountState;
 use starcoin_types::state_set::{AccountStateSet, ChainStateSet};
+use std::collections::HashMap;
 
+/// Read the state of a remote node at `state_root`.
+/// The states read by `StateView::get` are verified by their proofs, and cached.
 pub struct RemoteStateReader<'a> {
-    //TODO add cache.
     client: &'a RpcClient,
     state_root: HashValue,
+    cache: Mutex<HashMap<AccessPath, Option<Vec<u8>>>>,
 }
 
 impl<'a> RemoteStateReader<'a> {
@@ -23,7 +27,11 @@ impl<'a> RemoteStateReader<'a> {
     }
 
     pub fn new_with_root(client: &'a RpcClient, state_root: HashValue) -> Self {
-        Self { client, state_root }
+        Self {
+            client,
+            state_root,
+            cache: Mutex::new(HashMap::new()),
+        }
     }
 }
 
@@ -53,15 +61,29 @@ impl<'a> ChainStateReader for RemoteStateReader<'a> {
 
 impl<'a> StateView for RemoteStateReader<'a> {
     fn get(&self, access_path: &AccessPath) -> Result<Option<Vec<u8>>> {
-        Ok(self
+        if let Some(state) = self.cache.lock().get(access_path) {
+            return Ok(state.clone());
+        }
+        let state_with_proof: StateWithProof = self
             .client
-            .state_get_with_proof_by_root(access_path.clone(), self.state_root())?
-            .state
-            .map(|v| v.0))
+            .state_get_with_proof_by_root(access_path.clone(), self.state_root)?
+            .into();
+        state_with_proof.proof.verify(
+            self.state_root,
+            access_path.clone(),
+            state_with_proof.state.as_deref(),
+        )?;
+        self.cache
+            .lock()
+            .insert(access_path.clone(), state_with_proof.state.clone());
+        Ok(state_with_proof.state)
     }
 
-    fn multi_get(&self, _access_paths: &[AccessPath]) -> Result<Vec<Option<Vec<u8>>>> {
-        unimplemented!()
+    fn multi_get(&self, access_paths: &[AccessPath]) -> Result<Vec<Option<Vec<u8>>>> {
+        access_paths
+            .iter()
+            .map(|access_path| self.get(access_path))
+            .collect()
     }
 
     fn is_genesis(&self) -> bool {
@@ -3,10 +3,16 @@
 
 use anyhow::Result;
 use futures::{StreamExt, TryStreamExt};
-use starcoin_config::NodeConfig;
+use starcoin_config::{BuiltinNetworkID, NodeConfig, StarcoinOpt, StorageConfig};
 use starcoin_logger::prelude::*;
 use starcoin_rpc_api::types::pubsub::MintBlock;
+use starcoin_rpc_api::types::{StateOverrideView, StrView};
 use starcoin_rpc_client::RpcClient;
+use starcoin_state_api::StateWithProof;
+use starcoin_types::access_path::AccessPath;
+use starcoin_types::account_config::{AccountResource, BalanceResource};
+use starcoin_types::transaction::authenticator::AuthenticationKey;
+use starcoin_vm_types::move_resource::MoveResource;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -139,3 +145,128 @@ fn test_client_reconnect_subscribe() -> Result<()> {
     assert_ne!(events2.len(), 0);
     Ok(())
 }
+
+#[stest::test(timeout = 120)]
+fn test_dev_rpc() -> Result<()> {
+    let config = Arc::new(NodeConfig::random_for_test());
+    let node_handle = test_helper::run_node_by_config(config)?;
+    let client = RpcClient::connect_local(node_handle.rpc_service()?)?;
+
+    let now = client.dev_increase_time(1000)?;
+    assert!(client.dev_set_time(now - 1).is_err());
+    assert_eq!(client.dev_set_time(now + 1000)?, now + 1000);
+
+    let snapshot = client.dev_snapshot()?;
+    let snapshot_number = client.chain_info()?.head.number.0;
+    let headers = client.dev_mine_blocks(2)?;
+    assert_eq!(headers.len(), 2);
+    assert_eq!(headers[1].number.0, snapshot_number + 2);
+
+    let auth_key = AuthenticationKey::random();
+    let address = auth_key.derived_address();
+    assert_eq!(client.dev_set_balance(address, 1000, Some(auth_key))?, 1000);
+    assert_eq!(client.dev_set_balance(address, 2000, None)?, 2000);
+    // decrease the balance replaces the head block, no block is mined.
+    let head = client.chain_info()?.head;
+    assert_eq!(client.dev_set_balance(address, 1000, None)?, 1000);
+    let new_head = client.chain_info()?.head;
+    assert_eq!(new_head.number.0, head.number.0);
+    assert_eq!(new_head.parent_hash, head.parent_hash);
+    assert_ne!(new_head.block_hash, head.block_hash);
+
+    let balance_path = AccessPath::new(address, BalanceResource::resource_path());
+    let header = client.dev_set_state(vec![StateOverrideView::Balance {
+        address,
+        token: None,
+        amount: StrView(500),
+    }])?;
+    assert_eq!(header.number.0, head.number.0);
+    let balance = |client: &RpcClient| -> Result<u128> {
+        let state = client
+            .state_get(balance_path.clone())?
+            .expect("balance should exist");
+        Ok(bcs_ext::from_bytes::<BalanceResource>(state.as_slice())?.token())
+    };
+    assert_eq!(balance(&client)?, 500);
+    // the following blocks are executed on the written state.
+    client.dev_mine_blocks(1)?;
+    assert_eq!(balance(&client)?, 500);
+
+    let header = client.dev_revert(snapshot)?;
+    assert_eq!(header.number.0, snapshot_number);
+    assert_eq!(client.chain_info()?.head.number.0, snapshot_number);
+    // the snapshot is removed after revert.
+    assert!(client.dev_revert(snapshot).is_err());
+
+    client.close();
+    let _e = node_handle.stop();
+    Ok(())
+}
+
+#[stest::test(timeout = 240)]
+fn test_fork_remote_state() -> Result<()> {
+    let remote_config = Arc::new(NodeConfig::random_for_test());
+    let remote_ipc_file = remote_config.rpc.get_ipc_file();
+    let remote_node = test_helper::run_node_by_config(remote_config)?;
+    let remote_client = RpcClient::connect_local(remote_node.rpc_service()?)?;
+    let auth_key = AuthenticationKey::random();
+    let address = auth_key.derived_address();
+    assert_eq!(
+        remote_client.dev_set_balance(address, 1000, Some(auth_key))?,
+        1000
+    );
+    let remote_head = remote_client.chain_info()?.head;
+
+    let opt = StarcoinOpt {
+        net: Some(BuiltinNetworkID::Test.into()),
+        storage: StorageConfig {
+            fork_url: Some(remote_ipc_file.to_string_lossy().to_string()),
+            ..StorageConfig::default()
+        },
+        ..StarcoinOpt::default()
+    };
+    let config = Arc::new(NodeConfig::load_with_opt(&opt)?);
+    let node_handle = test_helper::run_node_by_config(config)?;
+    let client = RpcClient::connect_local(node_handle.rpc_service()?)?;
+
+    let chain_info = client.chain_info()?;
+    let snapshot = client.dev_snapshot()?;
+    assert_eq!(chain_info.head.number.0, 0);
+    assert_eq!(chain_info.head.parent_hash, remote_head.block_hash);
+    let access_path = AccessPath::new(address, AccountResource::resource_path());
+    assert!(client.state_get(access_path.clone())?.is_some());
+    assert_eq!(
+        client.state_get(access_path.clone())?,
+        remote_client.state_get(access_path.clone())?
+    );
+    // the forked state is proved by the local state root.
+    let state_with_proof: StateWithProof = client.state_get_with_proof(access_path.clone())?.into();
+    state_with_proof.proof.verify(
+        client.state_get_state_root()?,
+        access_path,
+        state_with_proof.state.as_deref(),
+    )?;
+
+    // the balance forked from remote is read locally.
+    assert_eq!(client.dev_set_balance(address, 500, None)?, 500);
+    assert_eq!(client.dev_set_balance(address, 2000, None)?, 2000);
+    let headers = client.dev_mine_blocks(2)?;
+    assert_eq!(headers[1].number.0, client.chain_info()?.head.number.0);
+
+    // the remote node is not changed.
+    assert_eq!(
+        remote_client.chain_info()?.head.block_hash,
+        remote_head.block_hash
+    );
+    assert!(remote_client.dev_set_balance(address, 1500, None).is_ok());
+
+    let header = client.dev_revert(snapshot)?;
+    assert_eq!(header.number.0, 0);
+    assert_eq!(client.dev_set_balance(address, 1500, None)?, 1500);
+
+    client.close();
+    remote_client.close();
+    let _e = node_handle.stop();
+    let _e = remote_node.stop();
+    Ok(())
+}
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::module::helpers::TransactionRequestFiller;
-use crate::module::map_err;
+use crate::module::{convert_to_rpc_error, map_err};
 use futures::future::TryFutureExt;
 use futures::FutureExt;
-use starcoin_account_api::{AccountAsyncService, AccountInfo};
+use starcoin_account_api::{AccountAsyncService, AccountInfo, PartiallySignedTransaction};
 use starcoin_chain_service::ChainAsyncService;
 use starcoin_config::NodeConfig;
+use starcoin_crypto::ed25519::Ed25519PublicKey;
+use starcoin_crypto::HashValue;
+use starcoin_dev::playground::PlaygroudService;
 use starcoin_rpc_api::types::{StrView, TransactionRequest};
 use starcoin_rpc_api::{account::AccountApi, FutureResult};
 use starcoin_state_api::ChainStateAsyncService;
@@ -15,7 +18,10 @@ use starcoin_txpool_api::TxPoolSyncService;
 use starcoin_types::account_address::AccountAddress;
 use starcoin_types::account_config::token_code::TokenCode;
 use starcoin_types::sign_message::SigningMessage;
-use starcoin_types::transaction::{RawUserTransaction, SignedUserTransaction};
+use starcoin_types::transaction::authenticator::AccountSignature;
+use starcoin_types::transaction::{
+    FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction,
+};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -32,6 +38,7 @@ where
     chain_state: State,
     chain: Chain,
     node_config: Arc<NodeConfig>,
+    playground: PlaygroudService,
 }
 
 impl<Account, Pool, State, Chain> AccountRpcImpl<Account, Pool, State, Chain>
@@ -47,6 +54,7 @@ where
         pool: Pool,
         chain_state: State,
         chain: Chain,
+        playground: PlaygroudService,
     ) -> Self {
         Self {
             account,
@@ -54,6 +62,7 @@ where
             chain_state,
             chain,
             node_config,
+            playground,
         }
     }
     fn txn_request_filler(&self) -> TransactionRequestFiller<Account, Pool, State, Chain> {
@@ -63,6 +72,7 @@ where
             chain_state: self.chain_state.clone(),
             chain: self.chain.clone(),
             node_config: self.node_config.clone(),
+            playground: self.playground.clone(),
         }
     }
 }
@@ -168,6 +178,84 @@ where
         Box::pin(fut.boxed())
     }
 
+    fn sign_fee_payer_txn(
+        &self,
+        txn: FeePayerRawTransaction,
+        signer: AccountAddress,
+    ) -> FutureResult<AccountSignature> {
+        let service = self.account.clone();
+        let fut = async move {
+            let result = service.sign_fee_payer_txn(txn, signer).await?;
+            Ok(result)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn create_multisig(
+        &self,
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+        private_keys: Vec<Vec<u8>>,
+        password: String,
+    ) -> FutureResult<AccountInfo> {
+        let service = self.account.clone();
+        let fut = async move {
+            let result = service
+                .create_multisig_account(public_keys, threshold, private_keys, password)
+                .await?;
+            Ok(result)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn create_multisig_txn(
+        &self,
+        raw_txn: RawUserTransaction,
+    ) -> FutureResult<PartiallySignedTransaction> {
+        let service = self.account.clone();
+        let fut = async move {
+            let result = service.create_multisig_txn(raw_txn).await?;
+            Ok(result)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn sign_multisig_txn(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> FutureResult<PartiallySignedTransaction> {
+        let service = self.account.clone();
+        let fut = async move {
+            let result = service.sign_multisig_txn(txn).await?;
+            Ok(result)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn multisig_txn_missing_signers(
+        &self,
+        txn: PartiallySignedTransaction,
+    ) -> FutureResult<Vec<Ed25519PublicKey>> {
+        Box::pin(futures::future::ok(txn.missing_signers()))
+    }
+
+    fn submit_multisig_txn(&self, txn: PartiallySignedTransaction) -> FutureResult<HashValue> {
+        let result = txn.into_signed_txn().map_err(map_err).and_then(|txn| {
+            let txn_hash = txn.id();
+            self.pool
+                .add_txns(vec![txn])
+                .pop()
+                .expect("txpool should return result")
+                .map(|_| txn_hash)
+                .map_err(convert_to_rpc_error)
+        });
+        Box::pin(futures::future::ready(result))
+    }
+
     fn unlock(
         &self,
         address: AccountAddress,
@@ -1,6 +1,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::module::helpers::{decode_event, txn_info_view};
 use crate::module::map_err;
 use futures::future::{FutureExt, TryFutureExt};
 use starcoin_chain_service::ChainAsyncService;
@@ -9,15 +10,21 @@ use starcoin_crypto::HashValue;
 use starcoin_rpc_api::chain::ChainApi;
 use starcoin_rpc_api::types::pubsub::EventFilter;
 use starcoin_rpc_api::types::{
-    BlockHeaderView, BlockSummaryView, BlockView, ChainId, ChainInfoView, EpochUncleSummaryView,
+    AddressTransactionInfoView, BlockHeaderView, BlockSummaryView, BlockView, ChainId,
+    ChainInfoView, EpochUncleSummaryView, GetEventOption, GetTransactionsByAddressOption,
     TransactionEventView, TransactionInfoView, TransactionView,
 };
 use starcoin_rpc_api::FutureResult;
+use starcoin_state_api::StateNodeStore;
+use starcoin_statedb::ChainStateDB;
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::block::{BlockInfo, BlockNumber};
 use starcoin_types::filter::Filter;
 use starcoin_types::startup_info::ChainInfo;
 use starcoin_types::transaction::TransactionInfo;
 use starcoin_vm_types::on_chain_resource::{EpochInfo, GlobalTimeOnChain};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -28,21 +35,64 @@ where
     config: Arc<NodeConfig>,
     genesis_hash: HashValue,
     service: S,
+    state_store: Arc<dyn StateNodeStore>,
 }
 
 impl<S> ChainRpcImpl<S>
 where
     S: ChainAsyncService,
 {
-    pub fn new(config: Arc<NodeConfig>, genesis_hash: HashValue, service: S) -> Self {
+    pub fn new(
+        config: Arc<NodeConfig>,
+        genesis_hash: HashValue,
+        service: S,
+        state_store: Arc<dyn StateNodeStore>,
+    ) -> Self {
         Self {
             config,
             genesis_hash,
             service,
+            state_store,
         }
     }
 }
 
+/// Decode events data with the module layouts at the state of the event's block, an event which
+/// can not be decoded is returned without the decoded data.
+async fn decode_events<S>(
+    service: S,
+    state_store: Arc<dyn StateNodeStore>,
+    events: &mut [TransactionEventView],
+) -> anyhow::Result<()>
+where
+    S: ChainAsyncService,
+{
+    let mut block_states: HashMap<HashValue, ChainStateDB> = HashMap::new();
+    for event in events.iter_mut() {
+        let block_hash = match event.block_hash {
+            Some(block_hash) => block_hash,
+            None => continue,
+        };
+        let statedb = match block_states.entry(block_hash) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let header = service
+                    .get_header_by_hash(&block_hash)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow::format_err!("cannot find block header {}", block_hash)
+                    })?;
+                entry.insert(ChainStateDB::new(
+                    state_store.clone(),
+                    Some(header.state_root()),
+                ))
+            }
+        };
+        decode_event(statedb, event);
+    }
+    Ok(())
+}
+
 impl<S> ChainApi for ChainRpcImpl<S>
 where
     S: ChainAsyncService,
@@ -185,8 +235,7 @@ where
                     )
                 })?;
 
-            TransactionInfoView::new(Into::<(_, TransactionInfo)>::into(txn_info).1, &block)
-                .map(Some)
+            txn_info_view(Into::<(_, TransactionInfo)>::into(txn_info).1, &block).map(Some)
         }
         .map_err(map_err);
 
@@ -202,9 +251,7 @@ where
                 None => Ok(vec![]),
                 Some(block) => txn_infos
                     .into_iter()
-                    .map(|info| {
-                        TransactionInfoView::new(Into::<(_, TransactionInfo)>::into(info).1, &block)
-                    })
+                    .map(|info| txn_info_view(Into::<(_, TransactionInfo)>::into(info).1, &block))
                     .collect::<Result<Vec<_>, _>>(),
             }
         }
@@ -213,6 +260,47 @@ where
         Box::pin(fut.boxed())
     }
 
+    fn get_transactions_by_address(
+        &self,
+        address: AccountAddress,
+        option: Option<GetTransactionsByAddressOption>,
+    ) -> FutureResult<Vec<AddressTransactionInfoView>> {
+        let service = self.service.clone();
+        let option = option.unwrap_or_default();
+        let fut = async move {
+            let txn_infos = service
+                .get_transaction_infos_by_address(
+                    address,
+                    option.start_index,
+                    option.reverse,
+                    option.max_size(),
+                )
+                .await?;
+            let mut blocks = HashMap::new();
+            let mut views = Vec::with_capacity(txn_infos.len());
+            for (index, txn_info) in txn_infos {
+                let block_id = txn_info.block_id();
+                if !blocks.contains_key(&block_id) {
+                    let block = service.get_block_by_hash(block_id).await?.ok_or_else(|| {
+                        anyhow::format_err!("cannot find block {} of txn info", block_id)
+                    })?;
+                    blocks.insert(block_id, block);
+                }
+                views.push(AddressTransactionInfoView {
+                    index: index.into(),
+                    txn_info: txn_info_view(
+                        Into::<(_, TransactionInfo)>::into(txn_info).1,
+                        &blocks[&block_id],
+                    )?,
+                });
+            }
+            Ok(views)
+        }
+        .map_err(map_err);
+
+        Box::pin(fut.boxed())
+    }
+
     fn get_txn_info_by_block_and_index(
         &self,
         block_hash: HashValue,
@@ -229,10 +317,7 @@ where
                         .await?;
                     txn_info
                         .map(|info| {
-                            TransactionInfoView::new(
-                                Into::<(_, TransactionInfo)>::into(info).1,
-                                &block,
-                            )
+                            txn_info_view(Into::<(_, TransactionInfo)>::into(info).1, &block)
                         })
                         .transpose()
                 }
@@ -245,11 +330,19 @@ where
     fn get_events_by_txn_hash(
         &self,
         txn_hash: HashValue,
+        option: Option<GetEventOption>,
     ) -> FutureResult<Vec<TransactionEventView>> {
         let service = self.service.clone();
+        let state_store = self.state_store.clone();
+        let decode = option.unwrap_or_default().decode;
         let fut = async move {
             let events = service.get_events_by_txn_hash(txn_hash).await?;
-            Ok(events.into_iter().map(Into::into).collect())
+            let mut events: Vec<TransactionEventView> =
+                events.into_iter().map(Into::into).collect();
+            if decode {
+                decode_events(service, state_store, &mut events).await?;
+            }
+            Ok(events)
         }
         .map_err(map_err);
 
@@ -259,7 +352,9 @@ where
     fn get_events(&self, mut filter: EventFilter) -> FutureResult<Vec<TransactionEventView>> {
         let service = self.service.clone();
         let config = self.config.clone();
+        let state_store = self.state_store.clone();
         let fut = async move {
+            let decode = filter.decode;
             if filter.to_block.is_none() {
                 // if user hasn't specify the `to_block`, we use latest block as the to_block.
                 let header_block_number = service.main_head_header().await?.number();
@@ -283,9 +378,14 @@ where
                 .into());
             }
 
-            service.main_events(filter).await
+            let events = service.clone().main_events(filter).await?;
+            let mut events: Vec<TransactionEventView> =
+                events.into_iter().map(Into::into).collect();
+            if decode {
+                decode_events(service, state_store, &mut events).await?;
+            }
+            Ok(events)
         }
-        .map_ok(|d| d.into_iter().map(|e| e.into()).collect())
         .map_err(map_err);
 
         Box::pin(fut.boxed())
@@ -1,7 +1,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::module::helpers::TransactionRequestFiller;
+use crate::module::helpers::{state_overrides, txn_output_view, TransactionRequestFiller};
 use crate::module::map_err;
 use futures::future::TryFutureExt;
 use futures::FutureExt;
@@ -12,7 +12,7 @@ use starcoin_dev::playground::PlaygroudService;
 use starcoin_rpc_api::contract_api::ContractApi;
 use starcoin_rpc_api::types::{
     AnnotatedMoveStructView, AnnotatedMoveValueView, ContractCall, DryRunTransactionRequest,
-    StrView, TransactionOutputView,
+    FunctionIdView, StrView, TransactionOutputView,
 };
 use starcoin_rpc_api::FutureResult;
 use starcoin_state_api::ChainStateAsyncService;
@@ -21,6 +21,8 @@ use starcoin_types::account_address::AccountAddress;
 use starcoin_types::language_storage::{ModuleId, StructTag};
 use starcoin_types::transaction::DryRunTransaction;
 use starcoin_vm_types::access_path::AccessPath;
+use starcoin_vm_types::file_format::CompiledModule;
+use starcoin_vm_types::module_abi::{FunctionABI, ModuleABI};
 use std::sync::Arc;
 
 pub struct ContractRpcImpl<Account, Pool, State, Chain> {
@@ -63,6 +65,25 @@ where
             chain_state: self.chain_state.clone(),
             chain: self.chain.clone(),
             node_config: self.node_config.clone(),
+            playground: self.playground.clone(),
+        }
+    }
+}
+
+async fn get_module_abi<State>(
+    service: State,
+    module_id: &ModuleId,
+) -> anyhow::Result<Option<ModuleABI>>
+where
+    State: ChainStateAsyncService + 'static,
+{
+    match service.get(AccessPath::from(module_id)).await? {
+        None => Ok(None),
+        Some(code) => {
+            let module = CompiledModule::deserialize(code.as_slice()).map_err(|e| {
+                anyhow::format_err!("Module {} deserialize with error: {:?}", module_id, e)
+            })?;
+            Ok(Some(ModuleABI::new(&module)))
         }
     }
 }
@@ -83,6 +104,23 @@ where
         Box::pin(f.map_err(map_err).boxed())
     }
 
+    fn get_module_abi(&self, module_id: StrView<ModuleId>) -> FutureResult<Option<ModuleABI>> {
+        let service = self.chain_state.clone();
+        let f = async move { get_module_abi(service, &module_id.0).await };
+        Box::pin(f.map_err(map_err).boxed())
+    }
+
+    fn resolve_function(&self, function_id: FunctionIdView) -> FutureResult<Option<FunctionABI>> {
+        let service = self.chain_state.clone();
+        let f = async move {
+            let function_id = function_id.0;
+            let module_abi = get_module_abi(service, &function_id.module).await?;
+            Ok(module_abi
+                .and_then(|abi| abi.function(function_id.function.as_ident_str()).cloned()))
+        };
+        Box::pin(f.map_err(map_err).boxed())
+    }
+
     fn get_resource(
         &self,
         addr: AccountAddress,
@@ -116,16 +154,36 @@ where
             function_id,
             type_args,
             args,
+            json_args,
+            state_overrides: overrides,
         } = call;
         let f = async move {
             let state_root = service.state_root().await?;
-            let output = playground.call_contract(
-                state_root,
-                function_id.0.module,
-                function_id.0.function,
-                type_args.into_iter().map(|v| v.0).collect(),
-                args.into_iter().map(|v| v.0).collect(),
-            )?;
+            let type_args = type_args.into_iter().map(|v| v.0).collect();
+            let output = match json_args {
+                Some(json_args) => {
+                    anyhow::ensure!(
+                        args.is_empty(),
+                        "args and json_args can not be used together"
+                    );
+                    playground.call_contract_with_json_args(
+                        state_root,
+                        function_id.0.module,
+                        function_id.0.function,
+                        type_args,
+                        json_args,
+                        state_overrides(overrides),
+                    )?
+                }
+                None => playground.call_contract(
+                    state_root,
+                    function_id.0.module,
+                    function_id.0.function,
+                    type_args,
+                    args.into_iter().map(|v| v.0).collect(),
+                    state_overrides(overrides),
+                )?,
+            };
             Ok(output.into_iter().map(Into::into).collect())
         }
         .map_err(map_err);
@@ -141,6 +199,7 @@ where
             let DryRunTransactionRequest {
                 transaction,
                 sender_public_key,
+                state_overrides: overrides,
             } = txn;
 
             let txn = txn_builder.fill_transaction(transaction).await?;
@@ -164,8 +223,9 @@ where
                     raw_txn: txn,
                     public_key: sender_public_key,
                 },
+                state_overrides(overrides),
             )?;
-            Ok(output.1.into())
+            Ok(txn_output_view(output.1))
         }
         .map_err(map_err);
         Box::pin(f.boxed())
@@ -0,0 +1,435 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::module::helpers::state_overrides;
+use crate::module::{map_err, to_invalid_param_err};
+use anyhow::{bail, ensure, format_err, Result};
+use futures::future::TryFutureExt;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use starcoin_chain_service::ChainAsyncService;
+use starcoin_config::NodeConfig;
+use starcoin_crypto::HashValue;
+use starcoin_dev::playground::PlaygroudService;
+use starcoin_dev::state_overlay::StateOverride;
+use starcoin_executor::{build_transfer_txn, DEFAULT_EXPIRATION_TIME, DEFAULT_MAX_GAS_AMOUNT};
+use starcoin_logger::prelude::*;
+use starcoin_rpc_api::dev::DevApi;
+use starcoin_rpc_api::types::{BlockHeaderView, StateOverrideView, StrView};
+use starcoin_rpc_api::FutureResult;
+use starcoin_service_registry::bus::{Bus, BusService};
+use starcoin_service_registry::{ActorService, ServiceHandler, ServiceRef};
+use starcoin_state_api::ChainStateAsyncService;
+use starcoin_sync_api::{ResetHeadRequest, WriteStateRequest};
+use starcoin_txpool_api::TxPoolSyncService;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::account_config::{association_address, AccountResource, BalanceResource};
+use starcoin_types::block::BlockHeader;
+use starcoin_types::system_events::{GenerateBlockEvent, NewHeadBlock};
+use starcoin_types::transaction::authenticator::AuthenticationKey;
+use starcoin_types::transaction::SignedUserTransaction;
+use starcoin_vm_types::access_path::AccessPath;
+use starcoin_vm_types::move_resource::MoveResource;
+use starcoin_vm_types::time::MockTimeService;
+use starcoin_vm_types::token::stc::stc_type_tag;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{delay_for, timeout};
+
+const MINE_BLOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A revert point of the dev node.
+struct Snapshot {
+    head: HashValue,
+    /// The node clock in milliseconds, `None` if the time of network can not be changed.
+    time: Option<u64>,
+    pending_txns: Vec<SignedUserTransaction>,
+}
+
+#[derive(Default)]
+struct Snapshots {
+    next_id: u64,
+    snapshots: BTreeMap<u64, Snapshot>,
+}
+
+pub struct DevRpcImpl<Chain, Pool, State, Connector>
+where
+    Connector: ActorService
+        + ServiceHandler<Connector, ResetHeadRequest>
+        + ServiceHandler<Connector, WriteStateRequest>,
+{
+    config: Arc<NodeConfig>,
+    bus: ServiceRef<BusService>,
+    chain: Chain,
+    pool: Pool,
+    chain_state: State,
+    block_connector: ServiceRef<Connector>,
+    playground: PlaygroudService,
+    snapshots: Arc<Mutex<Snapshots>>,
+}
+
+impl<Chain, Pool, State, Connector> DevRpcImpl<Chain, Pool, State, Connector>
+where
+    Chain: ChainAsyncService + 'static,
+    Pool: TxPoolSyncService + 'static,
+    State: ChainStateAsyncService + 'static,
+    Connector: ActorService
+        + ServiceHandler<Connector, ResetHeadRequest>
+        + ServiceHandler<Connector, WriteStateRequest>,
+{
+    pub fn new(
+        config: Arc<NodeConfig>,
+        bus: ServiceRef<BusService>,
+        chain: Chain,
+        pool: Pool,
+        chain_state: State,
+        block_connector: ServiceRef<Connector>,
+        playground: PlaygroudService,
+    ) -> Self {
+        Self {
+            config,
+            bus,
+            chain,
+            pool,
+            chain_state,
+            block_connector,
+            playground,
+            snapshots: Arc::new(Mutex::new(Snapshots::default())),
+        }
+    }
+}
+
+/// Run `f` with the mock time service of the network, return error if the time of network can not be changed.
+fn with_mock_time<R, F>(config: &NodeConfig, f: F) -> Result<R>
+where
+    F: FnOnce(&MockTimeService) -> R,
+{
+    let time_service = config.net().time_service();
+    let mock_time_service = time_service
+        .as_any()
+        .downcast_ref::<MockTimeService>()
+        .ok_or_else(|| format_err!("The time of network {} can not be changed.", config.net()))?;
+    Ok(f(mock_time_service))
+}
+
+/// Generate a block with the pending transactions and wait it to become the main head.
+async fn mine_block<Chain>(bus: &ServiceRef<BusService>, chain: &Chain) -> Result<BlockHeader>
+where
+    Chain: ChainAsyncService,
+{
+    let head = chain.main_head_header().await?;
+    let receiver = bus.oneshot::<NewHeadBlock>().await?;
+    bus.broadcast(GenerateBlockEvent::new(true))?;
+    if let Ok(Ok(event)) = timeout(MINE_BLOCK_TIMEOUT, receiver).await {
+        //wait for new head block event to been processed.
+        delay_for(Duration::from_millis(100)).await;
+        return Ok(event.0.header().clone());
+    }
+    let latest_head = chain.main_head_header().await?;
+    if latest_head.number() > head.number() {
+        Ok(latest_head)
+    } else {
+        bail!("Wait timeout for mine block")
+    }
+}
+
+/// Return the main chain head, mine a block first if the head is the genesis block,
+/// because the state of genesis block can not be changed.
+async fn non_genesis_head<Chain>(bus: &ServiceRef<BusService>, chain: &Chain) -> Result<BlockHeader>
+where
+    Chain: ChainAsyncService,
+{
+    let head = chain.main_head_header().await?;
+    if head.is_genesis() {
+        mine_block(bus, chain).await
+    } else {
+        Ok(head)
+    }
+}
+
+/// Write the overrides resolved on the state of `head` into the chain state, return the new head.
+async fn write_state<Connector>(
+    block_connector: &ServiceRef<Connector>,
+    playground: &PlaygroudService,
+    head: &BlockHeader,
+    overrides: Vec<StateOverride>,
+) -> Result<BlockHeader>
+where
+    Connector: ActorService + ServiceHandler<Connector, WriteStateRequest>,
+{
+    let write_set = playground.state_write_set(head.state_root(), overrides)?;
+    let header = block_connector
+        .send(WriteStateRequest { write_set })
+        .await??;
+    //wait for new head block event to been processed.
+    delay_for(Duration::from_millis(100)).await;
+    Ok(header)
+}
+
+async fn get_balance_by_root<State>(
+    chain_state: State,
+    address: AccountAddress,
+    state_root: HashValue,
+) -> Result<u128>
+where
+    State: ChainStateAsyncService,
+{
+    let state = chain_state
+        .get_with_proof_by_root(
+            AccessPath::new(address, BalanceResource::resource_path()),
+            state_root,
+        )
+        .await?
+        .state;
+    Ok(match state {
+        Some(state) => bcs_ext::from_bytes::<BalanceResource>(state.as_slice())?.token(),
+        None => 0,
+    })
+}
+
+impl<Chain, Pool, State, Connector> DevApi for DevRpcImpl<Chain, Pool, State, Connector>
+where
+    Chain: ChainAsyncService + 'static,
+    Pool: TxPoolSyncService + 'static,
+    State: ChainStateAsyncService + 'static,
+    Connector: ActorService
+        + ServiceHandler<Connector, ResetHeadRequest>
+        + ServiceHandler<Connector, WriteStateRequest>,
+{
+    fn increase_time(&self, millis: u64) -> jsonrpc_core::Result<u64> {
+        with_mock_time(&self.config, |time_service| {
+            time_service.increment_by(millis);
+            time_service.now_millis()
+        })
+        .map_err(to_invalid_param_err)
+    }
+
+    fn set_time(&self, millis: u64) -> jsonrpc_core::Result<u64> {
+        with_mock_time(&self.config, |time_service| {
+            let now = time_service.now_millis();
+            ensure!(
+                millis >= now,
+                "The time can not go backwards, current time: {}, target time: {}",
+                now,
+                millis
+            );
+            time_service.set(millis);
+            Ok(time_service.now_millis())
+        })
+        .and_then(|r| r)
+        .map_err(to_invalid_param_err)
+    }
+
+    fn mine_blocks(&self, count: u64) -> FutureResult<Vec<BlockHeaderView>> {
+        let bus = self.bus.clone();
+        let chain = self.chain.clone();
+        let fut = async move {
+            let mut headers = vec![];
+            for _ in 0..count {
+                headers.push(mine_block(&bus, &chain).await?.into());
+            }
+            Ok(headers)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn snapshot(&self) -> FutureResult<u64> {
+        let config = self.config.clone();
+        let chain = self.chain.clone();
+        let pool = self.pool.clone();
+        let snapshots = self.snapshots.clone();
+        let fut = async move {
+            let head = chain.main_head_header().await?;
+            let time = with_mock_time(&config, |time_service| time_service.now_millis()).ok();
+            let now = config.net().time_service().now_secs();
+            let snapshot = Snapshot {
+                head: head.id(),
+                time,
+                pending_txns: pool.get_pending_txns(None, Some(now)),
+            };
+            let mut snapshots = snapshots.lock();
+            let snapshot_id = snapshots.next_id;
+            snapshots.next_id += 1;
+            snapshots.snapshots.insert(snapshot_id, snapshot);
+            Ok(snapshot_id)
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn revert(&self, snapshot_id: u64) -> FutureResult<BlockHeaderView> {
+        let config = self.config.clone();
+        let pool = self.pool.clone();
+        let block_connector = self.block_connector.clone();
+        let snapshots = self.snapshots.clone();
+        let fut = async move {
+            let snapshot = {
+                let mut snapshots = snapshots.lock();
+                ensure!(
+                    snapshots.snapshots.contains_key(&snapshot_id),
+                    "Can not find snapshot {}",
+                    snapshot_id
+                );
+                // the snapshots taken after the snapshot are removed too.
+                snapshots
+                    .snapshots
+                    .split_off(&snapshot_id)
+                    .remove(&snapshot_id)
+                    .expect("snapshot should exist")
+            };
+            let header = block_connector
+                .send(ResetHeadRequest {
+                    block_id: snapshot.head,
+                })
+                .await??;
+            if let Some(time) = snapshot.time {
+                with_mock_time(&config, |time_service| time_service.set(time))?;
+            }
+            // remove the txns added after the snapshot, and add back the pending txns of the
+            // snapshot which are included in the reverted blocks.
+            let now = config.net().time_service().now_secs();
+            let snapshot_txns: HashSet<HashValue> =
+                snapshot.pending_txns.iter().map(|txn| txn.id()).collect();
+            let mut pending_txns = HashSet::new();
+            for txn in pool.get_pending_txns(None, Some(now)) {
+                if snapshot_txns.contains(&txn.id()) {
+                    pending_txns.insert(txn.id());
+                } else {
+                    pool.remove_txn(txn.id(), false);
+                }
+            }
+            let txns: Vec<_> = snapshot
+                .pending_txns
+                .into_iter()
+                .filter(|txn| !pending_txns.contains(&txn.id()))
+                .collect();
+            for result in pool.add_txns(txns) {
+                if let Err(e) = result {
+                    warn!("Add back the pending txn of snapshot failed: {:?}", e);
+                }
+            }
+            info!(
+                "Dev revert to snapshot {}, head: {}",
+                snapshot_id,
+                header.id()
+            );
+            Ok(header.into())
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn set_state(&self, overrides: Vec<StateOverrideView>) -> FutureResult<BlockHeaderView> {
+        let bus = self.bus.clone();
+        let chain = self.chain.clone();
+        let block_connector = self.block_connector.clone();
+        let playground = self.playground.clone();
+        let fut = async move {
+            let head = non_genesis_head(&bus, &chain).await?;
+            let header = write_state(
+                &block_connector,
+                &playground,
+                &head,
+                state_overrides(overrides),
+            )
+            .await?;
+            Ok(header.into())
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+
+    fn set_balance(
+        &self,
+        address: AccountAddress,
+        amount: StrView<u128>,
+        auth_key: Option<AuthenticationKey>,
+    ) -> FutureResult<StrView<u128>> {
+        let config = self.config.clone();
+        let bus = self.bus.clone();
+        let chain = self.chain.clone();
+        let pool = self.pool.clone();
+        let chain_state = self.chain_state.clone();
+        let block_connector = self.block_connector.clone();
+        let playground = self.playground.clone();
+        let fut = async move {
+            let amount = amount.0;
+            let head = chain.main_head_header().await?;
+            let balance =
+                get_balance_by_root(chain_state.clone(), address, head.state_root()).await?;
+            if amount == balance {
+                return Ok(StrView(balance));
+            }
+            let sender = association_address();
+            if amount < balance {
+                ensure!(
+                    address != sender,
+                    "Can not decrease the balance of association account"
+                );
+                // move the difference to the association account, so the total supply is not changed.
+                let head = non_genesis_head(&bus, &chain).await?;
+                let balance =
+                    get_balance_by_root(chain_state.clone(), address, head.state_root()).await?;
+                let association_balance =
+                    get_balance_by_root(chain_state.clone(), sender, head.state_root()).await?;
+                let overrides = vec![
+                    StateOverride::Balance {
+                        address,
+                        token: stc_type_tag(),
+                        amount,
+                    },
+                    StateOverride::Balance {
+                        address: sender,
+                        token: stc_type_tag(),
+                        amount: association_balance.saturating_add(balance.saturating_sub(amount)),
+                    },
+                ];
+                let header = write_state(&block_connector, &playground, &head, overrides).await?;
+                let new_balance =
+                    get_balance_by_root(chain_state, address, header.state_root()).await?;
+                return Ok(StrView(new_balance));
+            }
+            let net = config.net();
+            let sequence_number = match pool.next_sequence_number(sender) {
+                Some(n) => n,
+                None => chain_state
+                    .clone()
+                    .get_resource::<AccountResource>(sender)
+                    .await?
+                    .ok_or_else(|| format_err!("Can not find association account onchain"))?
+                    .sequence_number(),
+            };
+            let raw_txn = build_transfer_txn(
+                sender,
+                address,
+                auth_key,
+                sequence_number,
+                amount - balance,
+                1,
+                DEFAULT_MAX_GAS_AMOUNT,
+                net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+                net.chain_id(),
+            );
+            let txn = net.genesis_config().sign_with_association(raw_txn)?;
+            let txn_hash = txn.id();
+            pool.add_txns(vec![txn])
+                .pop()
+                .expect("txpool should return result")?;
+            let header = mine_block(&bus, &chain).await?;
+            let new_balance =
+                get_balance_by_root(chain_state, address, header.state_root()).await?;
+            ensure!(
+                new_balance == amount,
+                "The top up txn {} is not executed in block {}, current balance: {}",
+                txn_hash,
+                header.id(),
+                new_balance
+            );
+            Ok(StrView(new_balance))
+        }
+        .map_err(map_err);
+        Box::pin(fut.boxed())
+    }
+}
@@ -1,11 +1,24 @@
 use starcoin_account_api::AccountAsyncService;
 use starcoin_chain_service::ChainAsyncService;
 use starcoin_config::NodeConfig;
-use starcoin_rpc_api::types::TransactionRequest;
+use starcoin_dev::playground::PlaygroudService;
+use starcoin_dev::state_overlay::StateOverride;
+use starcoin_logger::prelude::*;
+use starcoin_resource_viewer::MoveValueAnnotator;
+use starcoin_rpc_api::types::{
+    AbortExplanationView, StateOverrideView, TransactionEventView, TransactionInfoView,
+    TransactionOutputView, TransactionRequest, TransactionVMStatus,
+};
 use starcoin_state_api::ChainStateAsyncService;
 use starcoin_txpool_api::TxPoolSyncService;
 use starcoin_types::account_config::AccountResource;
-use starcoin_types::transaction::{Module, Package, RawUserTransaction, TransactionPayload};
+use starcoin_types::block::Block;
+use starcoin_types::language_storage::ModuleId;
+use starcoin_types::transaction::{
+    Module, Package, RawUserTransaction, TransactionInfo, TransactionOutput, TransactionPayload,
+};
+use starcoin_vm_types::state_view::StateView;
+use starcoin_vm_types::token::stc::stc_type_tag;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -15,6 +28,7 @@ pub(crate) struct TransactionRequestFiller<Account, Pool, State, Chain> {
     pub(crate) chain_state: State,
     pub(crate) chain: Chain,
     pub(crate) node_config: Arc<NodeConfig>,
+    pub(crate) playground: PlaygroudService,
 }
 
 impl<Account, Pool, State, Chain> TransactionRequestFiller<Account, Pool, State, Chain>
@@ -28,19 +42,28 @@ where
         &self,
         txn_request: TransactionRequest,
     ) -> anyhow::Result<RawUserTransaction> {
+        let script = match txn_request.script {
+            Some(script) if script.json_args.is_some() => {
+                let state_root = self.chain_state.clone().state_root().await?;
+                let playground = self.playground.clone();
+                Some(script.resolve_json_args(|function_id, type_args, args| {
+                    playground.encode_json_args(state_root, function_id, type_args, args)
+                })?)
+            }
+            script => script,
+        };
         let payload = if !txn_request.modules.is_empty() {
             let modules = txn_request
                 .modules
                 .into_iter()
                 .map(|c| Module::new(c.0))
                 .collect();
-            let script_function = txn_request
-                .script
+            let script_function = script
                 .map(|script_data| script_data.into_script_function())
                 .transpose()?;
             TransactionPayload::Package(Package::new(modules, script_function)?)
         } else {
-            let script = txn_request.script.ok_or_else(|| {
+            let script = script.ok_or_else(|| {
                 anyhow::anyhow!(
                     "invalid transaction request: script should not be empty if no modules"
                 )
@@ -105,3 +128,110 @@ where
         Ok(raw_txn)
     }
 }
+
+fn explain_abort(module_id: &ModuleId, abort_code: u64) -> Option<AbortExplanationView> {
+    starcoin_move_explain::get_explanation(module_id, abort_code).map(|ctx| AbortExplanationView {
+        category_code: abort_code & 0xFFu64,
+        category_name: ctx.category.code_name,
+        category_description: ctx.category.code_description,
+        reason_code: abort_code >> 8,
+        reason_name: ctx.reason.code_name,
+        reason_description: ctx.reason.code_description,
+    })
+}
+
+/// Fill the human-readable explanation of MoveAbort status.
+pub(crate) fn explain_vm_status(status: TransactionVMStatus) -> TransactionVMStatus {
+    status.explain(explain_abort)
+}
+
+pub(crate) fn txn_info_view(
+    txn_info: TransactionInfo,
+    block: &Block,
+) -> anyhow::Result<TransactionInfoView> {
+    let mut view = TransactionInfoView::new(txn_info, block)?;
+    view.status = explain_vm_status(view.status);
+    Ok(view)
+}
+
+pub(crate) fn txn_output_view(output: TransactionOutput) -> TransactionOutputView {
+    let mut view = TransactionOutputView::from(output);
+    view.status = explain_vm_status(view.status);
+    view
+}
+
+pub(crate) fn state_overrides(views: Vec<StateOverrideView>) -> Vec<StateOverride> {
+    views
+        .into_iter()
+        .map(|view| match view {
+            StateOverrideView::Value { access_path, value } => StateOverride::Value {
+                access_path,
+                value: value.map(|v| v.0),
+            },
+            StateOverrideView::JsonValue { access_path, value } => {
+                StateOverride::JsonValue { access_path, value }
+            }
+            StateOverrideView::Balance {
+                address,
+                token,
+                amount,
+            } => StateOverride::Balance {
+                address,
+                token: token.map(|t| t.0).unwrap_or_else(stc_type_tag),
+                amount: amount.0,
+            },
+            StateOverrideView::Module { code } => StateOverride::Module { code: code.0 },
+        })
+        .collect()
+}
+
+/// Decode the event data with the state, log a warning and leave the decoded data empty if failed.
+pub(crate) fn decode_event(state_view: &dyn StateView, event: &mut TransactionEventView) {
+    let annotator = MoveValueAnnotator::new(state_view);
+    if let Err(e) = event.decode(&annotator) {
+        warn!(
+            "Decode event data of type {} failed: {:?}",
+            event.type_tag, e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starcoin_types::identifier::Identifier;
+    use starcoin_types::vm_error::AbortLocation;
+    use starcoin_vm_types::account_config::core_code_address;
+
+    #[test]
+    fn test_explain_vm_status() {
+        let account = ModuleId::new(core_code_address(), Identifier::new("Account").unwrap());
+        // Errors::limit_exceeded(EINSUFFICIENT_BALANCE)
+        let abort_code = (10 << 8) | 8;
+        let status = explain_vm_status(TransactionVMStatus::MoveAbort {
+            location: AbortLocation::Module(account.clone()),
+            abort_code: abort_code.into(),
+            explanation: None,
+        });
+        match status {
+            TransactionVMStatus::MoveAbort {
+                explanation: Some(explanation),
+                ..
+            } => {
+                assert_eq!(explanation.category_code, 8);
+                assert_eq!(explanation.category_name, "LIMIT_EXCEEDED");
+                assert_eq!(explanation.reason_code, 10);
+                assert_eq!(explanation.reason_name, "EINSUFFICIENT_BALANCE");
+            }
+            status => panic!("Unexpected status: {:?}", status),
+        }
+
+        // the unknown abort code is not explained.
+        let unknown = TransactionVMStatus::MoveAbort {
+            location: AbortLocation::Module(account),
+            abort_code: ((10_000 << 8) | 8).into(),
+            explanation: None,
+        };
+        assert_eq!(explain_vm_status(unknown.clone()), unknown);
+    }
+}
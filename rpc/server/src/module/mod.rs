@@ -5,6 +5,7 @@ mod account_rpc;
 mod chain_rpc;
 mod contract_rpc;
 mod debug_rpc;
+mod dev_rpc;
 mod helpers;
 mod miner_rpc;
 mod network_manager_rpc;
@@ -20,6 +21,7 @@ pub use self::account_rpc::AccountRpcImpl;
 pub use self::chain_rpc::ChainRpcImpl;
 pub use self::contract_rpc::ContractRpcImpl;
 pub use self::debug_rpc::DebugRpcImpl;
+pub use self::dev_rpc::DevRpcImpl;
 pub use self::miner_rpc::MinerRpcImpl;
 pub use self::network_manager_rpc::NetworkManagerRpcImpl;
 pub use self::node_manager_rpc::NodeManagerRpcImpl;
@@ -30,6 +32,8 @@ pub use self::sync_manager_rpc::SyncManagerRpcImpl;
 pub use self::txfactory_rpc::TxFactoryStatusHandle;
 pub use self::txpool_rpc::TxPoolRpcImpl;
 
+use self::helpers::explain_vm_status;
+
 use actix::MailboxError;
 use anyhow::Error;
 use hex::FromHexError;
@@ -138,8 +142,8 @@ impl From<TransactionError> for RpcError {
                     ErrorCode::ServerError(TXN_ERROR_BASE + 2),
                     Some(
                         // translate to jsonrpc types
-                        serde_json::to_value(TransactionVMStatus::from(TransactionStatus::from(
-                            vm_status,
+                        serde_json::to_value(explain_vm_status(TransactionVMStatus::from(
+                            TransactionStatus::from(vm_status),
                         )))
                         .expect("vm status to json should be ok"),
                     ),
@@ -190,8 +194,8 @@ impl From<VMStatus> for RpcError {
             message: vm_status.to_string(),
             data: Some(
                 // use jsonrpc types do serialization.
-                serde_json::to_value(TransactionVMStatus::from(TransactionStatus::from(
-                    vm_status,
+                serde_json::to_value(explain_vm_status(TransactionVMStatus::from(
+                    TransactionStatus::from(vm_status),
                 )))
                 .expect("vm status to json should be ok"),
             ),
@@ -1,7 +1,9 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use crate::module::helpers::decode_event;
+use crate::module::map_err;
+use anyhow::{format_err, Result};
 use futures::channel::mpsc;
 use futures::future::AbortHandle;
 use futures::StreamExt;
@@ -20,10 +22,13 @@ use starcoin_service_registry::{
     ActorService, EventHandler as ActorEventHandler, ServiceContext, ServiceFactory,
     ServiceHandler, ServiceRef, ServiceRequest,
 };
+use starcoin_statedb::ChainStateDB;
+use starcoin_storage::{BlockStore, Storage};
 use starcoin_txpool::TxPoolService;
 use starcoin_txpool_api::TxPoolSyncService;
 use starcoin_types::filter::Filter;
 use starcoin_types::system_events::MintBlockEvent;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
@@ -102,12 +107,14 @@ impl PubSubImpl {
                 errors::invalid_params("newPendingTransactions", "Expected no parameters."),
             )),
             (pubsub::Kind::Events, Some(pubsub::Params::Events(filter))) => {
+                let decode = filter.decode;
                 match filter.try_into() {
                     Ok(f) => self
                         .service
                         .try_send(SubscribeEvents {
                             subscriber,
                             filter: f,
+                            decode,
                         })
                         .map_err(|e| {
                             let msg = map_send_err(&e);
@@ -186,6 +193,7 @@ impl ServiceFactory<PubSubService> for PubSubServiceFactory {
         Ok(PubSubService::new(
             ctx.get_shared::<TxPoolService>()?,
             miner_service,
+            ctx.get_shared::<Arc<Storage>>()?,
         ))
     }
 }
@@ -194,6 +202,7 @@ pub struct PubSubService {
     subscriber_id: Arc<atomic::AtomicU64>,
     txpool: TxPoolService,
     miner_service: ServiceRef<MinerService>,
+    storage: Arc<Storage>,
 
     new_header_subscribers: HashMap<SubscriptionId, mpsc::UnboundedSender<NewHeadNotification>>,
     new_event_subscribers: HashMap<SubscriptionId, mpsc::UnboundedSender<NewEventNotification>>,
@@ -202,12 +211,17 @@ pub struct PubSubService {
 }
 
 impl PubSubService {
-    fn new(txpool: TxPoolService, miner_service: ServiceRef<MinerService>) -> Self {
+    fn new(
+        txpool: TxPoolService,
+        miner_service: ServiceRef<MinerService>,
+        storage: Arc<Storage>,
+    ) -> Self {
         let subscriber_id = Arc::new(atomic::AtomicU64::new(0));
         Self {
             subscriber_id,
             txpool,
             miner_service,
+            storage,
             new_event_subscribers: Default::default(),
             new_header_subscribers: Default::default(),
             mint_block_subscribers: Default::default(),
@@ -323,6 +337,7 @@ impl ServiceHandler<Self, SubscribeMintBlock> for PubSubService {
 struct SubscribeEvents {
     subscriber: Subscriber<pubsub::Result>,
     filter: Filter,
+    decode: bool,
 }
 
 impl ServiceRequest for SubscribeEvents {
@@ -331,16 +346,25 @@ impl ServiceRequest for SubscribeEvents {
 
 impl ServiceHandler<Self, SubscribeEvents> for PubSubService {
     fn handle(&mut self, msg: SubscribeEvents, ctx: &mut ServiceContext<Self>) {
-        let SubscribeEvents { subscriber, filter } = msg;
+        let SubscribeEvents {
+            subscriber,
+            filter,
+            decode,
+        } = msg;
         let (sender, receiver) = mpsc::unbounded();
         let subscriber_id = self.next_id();
         self.new_event_subscribers
             .insert(subscriber_id.clone(), sender);
+        let storage = if decode {
+            Some(self.storage.clone())
+        } else {
+            None
+        };
         ctx.spawn(run_subscription(
             receiver,
             subscriber_id,
             subscriber,
-            ContractEventHandler { filter },
+            ContractEventHandler { filter, storage },
         ));
     }
 }
@@ -495,9 +519,20 @@ impl EventHandler<MintBlockEvent> for NewMintBlockHandler {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ContractEventHandler {
     filter: Filter,
+    /// Decode event data with the state of the event's block if present.
+    storage: Option<Arc<Storage>>,
+}
+
+impl ContractEventHandler {
+    fn block_state(storage: Arc<Storage>, block_hash: HashValue) -> Result<ChainStateDB> {
+        let header = storage
+            .get_block_header_by_hash(block_hash)?
+            .ok_or_else(|| format_err!("cannot find block header {}", block_hash))?;
+        Ok(ChainStateDB::new(storage, Some(header.state_root())))
+    }
 }
 
 impl EventHandler<Notification<Arc<[Event]>>> for ContractEventHandler {
@@ -516,18 +551,28 @@ impl EventHandler<Notification<Arc<[Event]>>> for ContractEventHandler {
             }
         };
 
+        let mut block_states: HashMap<HashValue, ChainStateDB> = HashMap::new();
         filtered_events
             .into_iter()
             .map(|e| {
-                TransactionEventView::new(
+                let mut view = TransactionEventView::new(
                     Some(e.block_hash),
                     Some(e.block_number),
                     Some(e.transaction_hash),
                     e.transaction_index,
                     &e.contract_event,
-                )
+                );
+                if let Some(storage) = self.storage.as_ref() {
+                    let statedb = match block_states.entry(e.block_hash) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => entry.insert(
+                            Self::block_state(storage.clone(), e.block_hash).map_err(map_err)?,
+                        ),
+                    };
+                    decode_event(statedb, &mut view);
+                }
+                Ok(pubsub::Result::Event(Box::new(view)))
             })
-            .map(|e| Ok(pubsub::Result::Event(Box::new(e))))
             .collect()
     }
 }
@@ -11,6 +11,7 @@ use starcoin_account_api::AccountInfo;
 use starcoin_chain::BlockChain;
 use starcoin_chain::{ChainReader, ChainWriter};
 use starcoin_chain_notify::ChainNotifyHandlerService;
+use starcoin_config::ChainNetwork;
 use starcoin_consensus::Consensus;
 use starcoin_crypto::{ed25519::Ed25519PrivateKey, Genesis, PrivateKey};
 use starcoin_executor::DEFAULT_EXPIRATION_TIME;
@@ -18,28 +19,32 @@ use starcoin_logger::prelude::*;
 use starcoin_rpc_api::metadata::Metadata;
 use starcoin_rpc_api::pubsub::StarcoinPubSub;
 use starcoin_rpc_api::types::pubsub::MintBlock;
+use starcoin_rpc_api::types::{AnnotatedMoveValueView, StrView, TransactionEventView};
 use starcoin_service_registry::bus::{Bus, BusService};
 use starcoin_service_registry::RegistryAsyncService;
 use starcoin_state_api::StateReaderExt;
-use starcoin_storage::BlockStore;
+use starcoin_storage::{BlockStore, Storage};
 use starcoin_txpool_api::TxPoolSyncService;
+use starcoin_types::account_config::DepositEvent;
+use starcoin_types::block::ExecutedBlock;
+use starcoin_types::language_storage::TypeTag;
 use starcoin_types::system_events::MintBlockEvent;
 use starcoin_types::system_events::NewHeadBlock;
 use starcoin_types::transaction::authenticator::AuthenticationKey;
 use starcoin_types::{account_address, U256};
 use starcoin_vm_types::genesis_config::ConsensusStrategy;
+use starcoin_vm_types::move_resource::MoveResource;
 use std::sync::Arc;
 use tokio::time::timeout;
 use tokio::time::Duration;
 
-#[actix_rt::test]
-pub async fn test_subscribe_to_events() -> Result<()> {
-    starcoin_logger::init_for_test();
-    // prepare
-
-    let (_txpool_service, storage, config, _, registry) = test_helper::start_txpool().await;
+/// Apply a block with a transfer from the association account to a new account.
+fn apply_transfer_block(
+    storage: Arc<Storage>,
+    net: &ChainNetwork,
+    amount: u128,
+) -> Result<ExecutedBlock> {
     let startup_info = storage.get_startup_info()?.unwrap();
-    let net = config.net();
     let mut block_chain = BlockChain::new(net.time_service(), startup_info.main, storage)?;
     let miner_account = AccountInfo::random();
 
@@ -51,9 +56,9 @@ pub async fn test_subscribe_to_events() -> Result<()> {
             account_address,
             Some(AuthenticationKey::ed25519(&public_key)),
             0,
-            10000,
-            config.net().time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
-            config.net(),
+            amount,
+            net.time_service().now_secs() + DEFAULT_EXPIRATION_TIME,
+            net,
         );
         txn.as_signed_user_txn()?.clone()
     };
@@ -61,7 +66,7 @@ pub async fn test_subscribe_to_events() -> Result<()> {
         *miner_account.address(),
         Some(miner_account.public_key.authentication_key()),
         None,
-        vec![txn.clone()],
+        vec![txn],
         vec![],
         None,
     )?;
@@ -69,11 +74,21 @@ pub async fn test_subscribe_to_events() -> Result<()> {
     let new_block = block_chain
         .consensus()
         .create_block(block_template, net.time_service().as_ref())?;
-    let executed_block = block_chain.apply(new_block.clone())?;
+    let executed_block = block_chain.apply(new_block)?;
 
     let reader = block_chain.chain_state_reader();
     let balance = reader.get_balance(account_address)?;
-    assert_eq!(balance, Some(10000));
+    assert_eq!(balance, Some(amount));
+    Ok(executed_block)
+}
+
+#[actix_rt::test]
+pub async fn test_subscribe_to_events() -> Result<()> {
+    starcoin_logger::init_for_test();
+    // prepare
+
+    let (_txpool_service, storage, config, _, registry) = test_helper::start_txpool().await;
+    let executed_block = apply_transfer_block(storage, config.net(), 10000)?;
 
     // now block is applied, we can emit events.
 
@@ -133,6 +148,56 @@ pub async fn test_subscribe_to_events() -> Result<()> {
     Ok(())
 }
 
+#[stest::test]
+pub async fn test_subscribe_to_decoded_events() -> Result<()> {
+    let (_txpool_service, storage, config, _, registry) = test_helper::start_txpool().await;
+    let executed_block = apply_transfer_block(storage, config.net(), 10000)?;
+
+    let bus = registry.service_ref::<BusService>().await?;
+    let _notify_service = registry.register::<ChainNotifyHandlerService>().await?;
+    let service = registry
+        .register_by_factory::<PubSubService, PubSubServiceFactory>()
+        .await?;
+    let pubsub = PubSubImpl::new(service);
+    let pubsub = pubsub.to_delegate();
+
+    let mut io = MetaIoHandler::default();
+    io.extend_with(pubsub);
+
+    let mut metadata = Metadata::default();
+    let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+    metadata.session = Some(Arc::new(Session::new(sender)));
+
+    let request = r#"{"jsonrpc": "2.0", "method": "starcoin_subscribe", "params": [{"type_name":"events"}, {"decode": true}], "id": 1}"#;
+    let response = r#"{"jsonrpc":"2.0","result":0,"id":1}"#;
+    let resp = io.handle_request(request, metadata.clone()).await;
+    assert_eq!(resp, Some(response.to_owned()));
+
+    bus.broadcast(NewHeadBlock(Arc::new(executed_block)))?;
+
+    // find the deposit event of the transfer, and check the decoded amount.
+    loop {
+        let res = timeout(Duration::from_secs(5), receiver.next())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Empty value"))?;
+        let r: Value = serde_json::from_str(&res)?;
+        let event: TransactionEventView = serde_json::from_value(r["params"]["result"].clone())?;
+        if event.type_tag != TypeTag::Struct(DepositEvent::struct_tag()) {
+            continue;
+        }
+        let fields = match event.decode_event_data {
+            Some(AnnotatedMoveValueView::Struct(s)) => s.value,
+            v => panic!("unexpected decoded event data: {:?}", v),
+        };
+        assert_eq!(
+            fields.get(0).map(|(name, value)| (name.as_str(), value)),
+            Some(("amount", &AnnotatedMoveValueView::U128(StrView(10000))))
+        );
+        break;
+    }
+    Ok(())
+}
+
 #[stest::test]
 pub async fn test_subscribe_to_pending_transactions() -> Result<()> {
     // given
@@ -141,4 +141,16 @@ where
             .map_err(map_err);
         Box::pin(fut)
     }
+
+    fn get_state_node_by_node_hash(
+        &self,
+        key_hash: HashValue,
+    ) -> FutureResult<Option<StrView<Vec<u8>>>> {
+        let state_store = self.state_store.clone();
+        let fut = async move {
+            let node = state_store.get(&key_hash)?;
+            Ok(node.map(|node| StrView(node.0)))
+        };
+        Box::pin(fut.map_err(map_err).boxed())
+    }
 }
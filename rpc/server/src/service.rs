@@ -24,8 +24,8 @@ use starcoin_rpc_api::node_manager::NodeManagerApi;
 use starcoin_rpc_api::sync_manager::SyncManagerApi;
 use starcoin_rpc_api::types::ConnectLocal;
 use starcoin_rpc_api::{
-    account::AccountApi, chain::ChainApi, debug::DebugApi, miner::MinerApi, node::NodeApi,
-    pubsub::StarcoinPubSub, state::StateApi, txpool::TxPoolApi,
+    account::AccountApi, chain::ChainApi, debug::DebugApi, dev::DevApi, miner::MinerApi,
+    node::NodeApi, pubsub::StarcoinPubSub, state::StateApi, txpool::TxPoolApi,
 };
 use starcoin_service_registry::{ActorService, ServiceContext, ServiceHandler};
 use std::collections::HashSet;
@@ -69,7 +69,7 @@ impl RpcService {
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn new_with_api<C, N, NM, SM, NWM, T, A, S, D, P, M, Contract>(
+    pub fn new_with_api<C, N, NM, SM, NWM, T, A, S, D, P, M, Contract, Dev>(
         config: Arc<NodeConfig>,
         node_api: N,
         node_manager_api: Option<NM>,
@@ -83,6 +83,7 @@ impl RpcService {
         debug_api: Option<D>,
         miner_api: Option<M>,
         contract_api: Option<Contract>,
+        dev_api: Option<Dev>,
     ) -> Self
     where
         N: NodeApi,
@@ -97,6 +98,7 @@ impl RpcService {
         D: DebugApi,
         M: MinerApi,
         Contract: ContractApi,
+        Dev: DevApi,
     {
         let mut api_registry = ApiRegistry::new(config.rpc.api_quotas.clone());
 
@@ -143,6 +145,9 @@ impl RpcService {
         if let Some(contract_api) = contract_api {
             api_registry.register(Api::Contract, ContractApi::to_delegate(contract_api));
         }
+        if let Some(dev_api) = dev_api {
+            api_registry.register(Api::Dev, DevApi::to_delegate(dev_api));
+        }
         Self::new(config, api_registry)
     }
 
@@ -1,14 +1,50 @@
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use forkable_jellyfish_merkle::node_type::Node;
 use forkable_jellyfish_merkle::RawKey;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use starcoin_crypto::hash::HashValue;
+use starcoin_crypto::hash::{HashValue, PlainCryptoHash};
 use std::collections::BTreeMap;
 use std::convert::{TryFrom, TryInto};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StateNode(pub Vec<u8>);
 
+impl StateNode {
+    /// Verify the node is the node of `hash` by its content, for the node from an untrusted source.
+    pub fn verify(&self, hash: &HashValue) -> Result<()> {
+        let node = Node::<AnyKey>::decode(self.0.as_slice())?;
+        if let Node::Leaf(leaf) = &node {
+            // the hash of leaf node is computed by the blob hash stored in it.
+            ensure!(
+                leaf.blob().crypto_hash() == leaf.blob_hash(),
+                "The blob hash of state node {} is mismatch",
+                hash
+            );
+        }
+        ensure!(
+            node.hash() == *hash,
+            "The state node hash is mismatch, expect: {}, got: {}",
+            hash,
+            node.hash()
+        );
+        Ok(())
+    }
+}
+
+/// The raw key of any state tree, the key hash is computed by its encoded bytes.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct AnyKey(Vec<u8>);
+
+impl RawKey for AnyKey {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        Ok(self.0.clone())
+    }
+
+    fn decode_key(bytes: &[u8]) -> Result<Self> {
+        Ok(AnyKey(bytes.to_vec()))
+    }
+}
+
 impl<K> TryFrom<Node<K>> for StateNode
 where
     K: RawKey,
@@ -55,3 +91,8 @@ pub trait StateNodeStore: std::marker::Send + std::marker::Sync {
     fn put(&self, key: HashValue, node: StateNode) -> Result<()>;
     fn write_nodes(&self, nodes: BTreeMap<HashValue, StateNode>) -> Result<()>;
 }
+
+/// The source of the state nodes missing in a store, such as the remote node a dev chain is forked from.
+pub trait StateNodeSource: std::marker::Send + std::marker::Sync {
+    fn get_node(&self, hash: &HashValue) -> Result<Option<StateNode>>;
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use forkable_jellyfish_merkle::blob::Blob;
 use forkable_jellyfish_merkle::iterator::JellyfishMerkleIterator;
 use forkable_jellyfish_merkle::node_type::{Node, NodeKey};
-use forkable_jellyfish_merkle::proof::SparseMerkleProof;
+use forkable_jellyfish_merkle::proof::{SparseMerkleProof, SparseMerkleRangeProof};
 use forkable_jellyfish_merkle::{
     JellyfishMerkleTree, RawKey, StaleNodeIndex, TreeReader, TreeUpdateBatch,
 };
@@ -221,6 +221,53 @@ where
         Ok(StateSet::new(states))
     }
 
+    /// Dump at most `limit` key-value pairs of the tree in the order of key hash,
+    /// starting after the key whose hash is `start_after`, or from the smallest key if it is None.
+    pub fn dump_range(
+        &self,
+        start_after: Option<HashValue>,
+        limit: usize,
+    ) -> Result<Vec<(K, Vec<u8>)>> {
+        let cur_root_hash = self.root_hash();
+        let mut cache_guard = self.cache.lock();
+        let cache = cache_guard.deref_mut();
+        let reader = CachedTreeReader {
+            store: self.storage.as_ref(),
+            cache,
+        };
+        let iterator = JellyfishMerkleIterator::new(
+            &reader,
+            cur_root_hash,
+            start_after.unwrap_or_else(HashValue::zero),
+        )?;
+        let mut states = vec![];
+        for item in iterator {
+            if states.len() >= limit {
+                break;
+            }
+            let (key, blob) = item?;
+            if Some(key.key_hash()) == start_after {
+                continue;
+            }
+            states.push((key, blob.into()));
+        }
+        Ok(states)
+    }
+
+    /// Get the proof that shows all the keys up to `rightmost_key` exist in the tree.
+    /// NOTICE: this will only read from state tree.
+    pub fn get_range_proof(&self, rightmost_key: &K) -> Result<SparseMerkleRangeProof> {
+        let mut cache_guard = self.cache.lock();
+        let cache = cache_guard.deref_mut();
+        let cur_root_hash = cache.root_hash;
+        let reader = CachedTreeReader {
+            store: self.storage.as_ref(),
+            cache,
+        };
+        let tree = JellyfishMerkleTree::new(&reader);
+        tree.get_range_proof(cur_root_hash, rightmost_key.key_hash())
+    }
+
     /// passing None value with a key means delete the key
     fn updates(&self, updates: Vec<(K, Option<Blob>)>) -> Result<HashValue> {
         let cur_root_hash = self.root_hash();
@@ -166,6 +166,97 @@ pub fn test_state_dump() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_state_dump_range() -> Result<()> {
+    let s = MockStateNodeStore::new();
+    let state = StateTree::new(Arc::new(s), None);
+    for _ in 0..10 {
+        state.put(HashValueKey(HashValue::random()), vec![1u8, 2u8]);
+    }
+    state.commit()?;
+    let root_hash = state.root_hash();
+    let mut start_after = None;
+    let mut leaves = vec![];
+    loop {
+        let chunk = state.dump_range(start_after, 3)?;
+        if chunk.is_empty() {
+            break;
+        }
+        assert!(chunk.len() <= 3);
+        let last_key = chunk.last().unwrap().0;
+        let proof = state.get_range_proof(&last_key)?;
+        start_after = Some(last_key.key_hash());
+        leaves.extend(
+            chunk
+                .into_iter()
+                .map(|(k, v)| (k.key_hash(), Blob::from(v).crypto_hash())),
+        );
+        proof.verify(root_hash, &leaves)?;
+        if leaves.len() > 1 {
+            assert!(proof.verify(root_hash, &leaves[1..]).is_err());
+        }
+    }
+    assert_eq!(10, leaves.len());
+    let keys: Vec<_> = leaves.iter().map(|(k, _)| *k).collect();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+    assert_eq!(sorted_keys, keys);
+    Ok(())
+}
+
+#[test]
+pub fn test_state_dump_range_verify_chunk() -> Result<()> {
+    let s = MockStateNodeStore::new();
+    let state = StateTree::new(Arc::new(s), None);
+    for _ in 0..10 {
+        state.put(HashValueKey(HashValue::random()), vec![1u8, 2u8]);
+    }
+    state.commit()?;
+    let root_hash = state.root_hash();
+    let mut start_after = None;
+    let mut previous_key = None;
+    let mut count = 0;
+    loop {
+        let chunk = state.dump_range(start_after, 3)?;
+        if chunk.is_empty() {
+            break;
+        }
+        count += chunk.len();
+        let last_key = chunk.last().unwrap().0;
+        let proof = state.get_range_proof(&last_key)?;
+        let mut leaves: Vec<_> = chunk
+            .into_iter()
+            .map(|(k, v)| (k.key_hash(), Blob::from(v).crypto_hash()))
+            .collect();
+        // only the chunk and the last leaf of the previous chunk are verified.
+        match previous_key {
+            Some(previous_key) => {
+                let (value, first_proof) = state.get_with_proof(&previous_key)?;
+                leaves.insert(
+                    0,
+                    (
+                        previous_key.key_hash(),
+                        Blob::from(value.unwrap()).crypto_hash(),
+                    ),
+                );
+                proof.verify_chunk(root_hash, &first_proof, &leaves)?;
+                if leaves.len() > 2 {
+                    let mut missing = leaves.clone();
+                    missing.remove(1);
+                    assert!(proof
+                        .verify_chunk(root_hash, &first_proof, &missing)
+                        .is_err());
+                }
+            }
+            None => proof.verify(root_hash, &leaves)?,
+        }
+        start_after = Some(last_key.key_hash());
+        previous_key = Some(last_key);
+    }
+    assert_eq!(10, count);
+    Ok(())
+}
+
 #[test]
 pub fn test_repeat_commit() -> Result<()> {
     let s = MockStateNodeStore::new();
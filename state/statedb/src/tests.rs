@@ -1,8 +1,10 @@
 use super::*;
 use starcoin_state_tree::mock::MockStateNodeStore;
+use starcoin_state_tree::StateNode;
 use starcoin_types::write_set::{WriteOp, WriteSet, WriteSetMut};
 use starcoin_vm_types::account_config::AccountResource;
 use starcoin_vm_types::move_resource::MoveResource;
+use std::collections::BTreeMap;
 
 fn random_bytes() -> Vec<u8> {
     HashValue::random().to_vec()
@@ -101,3 +103,83 @@ fn test_state_version() -> Result<()> {
 
     Ok(())
 }
+
+/// A store forked from another store, the missing nodes are read from it and saved.
+struct ForkedStateNodeStore {
+    store: MockStateNodeStore,
+    fork_store: Arc<MockStateNodeStore>,
+}
+
+impl StateNodeStore for ForkedStateNodeStore {
+    fn get(&self, hash: &HashValue) -> Result<Option<StateNode>> {
+        if let Some(node) = self.store.get(hash)? {
+            return Ok(Some(node));
+        }
+        let node = self.fork_store.get(hash)?;
+        if let Some(node) = &node {
+            self.store.put(*hash, node.clone())?;
+        }
+        Ok(node)
+    }
+
+    fn put(&self, key: HashValue, node: StateNode) -> Result<()> {
+        self.store.put(key, node)
+    }
+
+    fn write_nodes(&self, nodes: BTreeMap<HashValue, StateNode>) -> Result<()> {
+        self.store.write_nodes(nodes)
+    }
+}
+
+#[test]
+fn test_state_db_fork() -> Result<()> {
+    let fork_store = Arc::new(MockStateNodeStore::new());
+    let fork_state = ChainStateDB::new(fork_store.clone(), None);
+    let access_path1 = AccessPath::random_resource();
+    let access_path2 = AccessPath::random_resource();
+    let state1 = random_bytes();
+    let state2 = random_bytes();
+    fork_state.apply_write_set(to_write_set(access_path1.clone(), state1.clone()))?;
+    fork_state.apply_write_set(to_write_set(access_path2.clone(), state2.clone()))?;
+    let fork_state_root = fork_state.commit()?;
+    fork_state.flush()?;
+
+    let storage = Arc::new(ForkedStateNodeStore {
+        store: MockStateNodeStore::new(),
+        fork_store,
+    });
+    let chain_state_db = ChainStateDB::new(storage.clone(), Some(fork_state_root));
+    assert_eq!(chain_state_db.get(&access_path1)?, Some(state1.clone()));
+    assert_eq!(chain_state_db.get(&access_path2)?, Some(state2.clone()));
+
+    let new_state1 = random_bytes();
+    chain_state_db.apply_write_set(to_write_set(access_path1.clone(), new_state1.clone()))?;
+    chain_state_db.remove(&access_path2)?;
+    let state_root = chain_state_db.commit()?;
+    chain_state_db.flush()?;
+    assert_eq!(chain_state_db.get(&access_path1)?, Some(new_state1.clone()));
+    assert_eq!(chain_state_db.get(&access_path2)?, None);
+
+    // the state forked from is not changed.
+    assert_eq!(fork_state.get(&access_path1)?, Some(state1));
+    assert_eq!(fork_state.get(&access_path2)?, Some(state2));
+
+    let chain_state_db2 = ChainStateDB::new(storage, Some(state_root));
+    assert_eq!(
+        chain_state_db2.get(&access_path1)?,
+        Some(new_state1.clone())
+    );
+    assert_eq!(chain_state_db2.get(&access_path2)?, None);
+    assert_eq!(chain_state_db2.get(&AccessPath::random_resource())?, None);
+    // the proofs are consistent with the forked state.
+    let state_with_proof = chain_state_db2.get_with_proof(&access_path1)?;
+    state_with_proof
+        .proof
+        .verify(state_root, access_path1, state_with_proof.state.as_deref())?;
+    assert_eq!(state_with_proof.state, Some(new_state1));
+    let state_with_proof = chain_state_db2.get_with_proof(&access_path2)?;
+    state_with_proof
+        .proof
+        .verify(state_root, access_path2, None)?;
+    Ok(())
+}
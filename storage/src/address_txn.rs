@@ -0,0 +1,191 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::define_storage;
+use crate::storage::{
+    CodecKVStore, CodecWriteBatch, InnerStore, KeyCodec, StorageInstance, ValueCodec,
+};
+use crate::{ADDRESS_TXN_COUNT_PREFIX_NAME, ADDRESS_TXN_PREFIX_NAME};
+use anyhow::{ensure, format_err, Result};
+use byteorder::{BigEndian, ReadBytesExt};
+use crypto::HashValue;
+use starcoin_types::account_address::AccountAddress;
+use std::convert::{TryFrom, TryInto};
+
+/// The `index`th transaction of the `address` in the address txn index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct AddressTxnKey {
+    pub address: AccountAddress,
+    pub index: u64,
+}
+
+impl AddressTxnKey {
+    pub fn new(address: AccountAddress, index: u64) -> Self {
+        Self { address, index }
+    }
+}
+
+impl KeyCodec for AddressTxnKey {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        let mut key = self.address.to_vec();
+        key.extend_from_slice(&self.index.to_be_bytes());
+        Ok(key)
+    }
+
+    fn decode_key(data: &[u8]) -> Result<Self> {
+        ensure!(
+            data.len() == AccountAddress::LENGTH + 8,
+            "Invalid address txn key length: {}",
+            data.len()
+        );
+        let (address, mut index) = data.split_at(AccountAddress::LENGTH);
+        Ok(Self {
+            address: AccountAddress::try_from(address)?,
+            index: index.read_u64::<BigEndian>()?,
+        })
+    }
+}
+
+impl KeyCodec for AccountAddress {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        Ok(self.to_vec())
+    }
+
+    fn decode_key(data: &[u8]) -> Result<Self> {
+        Ok(AccountAddress::try_from(data)?)
+    }
+}
+
+impl ValueCodec for u64 {
+    fn encode_value(&self) -> Result<Vec<u8>> {
+        Ok(self.to_be_bytes().to_vec())
+    }
+
+    #[allow(clippy::redundant_slicing)]
+    fn decode_value(data: &[u8]) -> Result<Self> {
+        Ok((&data[..]).read_u64::<BigEndian>()?)
+    }
+}
+
+define_storage!(
+    AddressTxnCountStorage,
+    AccountAddress,
+    u64,
+    ADDRESS_TXN_COUNT_PREFIX_NAME
+);
+
+define_storage!(
+    AddressTxnStorage,
+    AddressTxnKey,
+    HashValue,
+    ADDRESS_TXN_PREFIX_NAME
+);
+
+/// Map account address to the txn info ids of the transactions sent by or affecting it,
+/// in the order they are saved.
+#[derive(Clone)]
+pub struct AddressTxnIndexStorage {
+    instance: StorageInstance,
+    count_storage: AddressTxnCountStorage,
+    txn_storage: AddressTxnStorage,
+}
+
+impl AddressTxnIndexStorage {
+    pub fn new(instance: StorageInstance) -> Self {
+        Self {
+            count_storage: AddressTxnCountStorage::new(instance.clone()),
+            txn_storage: AddressTxnStorage::new(instance.clone()),
+            instance,
+        }
+    }
+
+    pub(crate) fn get_count(&self, address: AccountAddress) -> Result<u64> {
+        Ok(self.count_storage.get(address)?.unwrap_or(0))
+    }
+
+    /// Put the txn info ids of every address from its start index, and update the counts,
+    /// in one write batch, so the ids in the count range always exist.
+    pub(crate) fn put_txn_info_ids(
+        &self,
+        entries: Vec<(AccountAddress, u64, Vec<HashValue>)>,
+    ) -> Result<()> {
+        let mut txn_batch = CodecWriteBatch::new();
+        let mut count_batch = CodecWriteBatch::new();
+        for (address, start_index, txn_info_ids) in entries {
+            if txn_info_ids.is_empty() {
+                continue;
+            }
+            count_batch.put(address, start_index + txn_info_ids.len() as u64)?;
+            for (index, txn_info_id) in (start_index..).zip(txn_info_ids) {
+                txn_batch.put(AddressTxnKey::new(address, index), txn_info_id)?;
+            }
+        }
+        self.instance.write_batch_multi_cf(vec![
+            (ADDRESS_TXN_PREFIX_NAME, txn_batch.try_into()?),
+            (ADDRESS_TXN_COUNT_PREFIX_NAME, count_batch.try_into()?),
+        ])
+    }
+
+    /// Remove the txn info ids of every address in the index range [new count, old count),
+    /// and update the counts, in one write batch.
+    pub(crate) fn remove_txn_info_ids(
+        &self,
+        entries: Vec<(AccountAddress, u64, u64)>,
+    ) -> Result<()> {
+        let mut txn_batch = CodecWriteBatch::<AddressTxnKey, HashValue>::new();
+        let mut count_batch = CodecWriteBatch::new();
+        for (address, new_count, old_count) in entries {
+            count_batch.put(address, new_count)?;
+            for index in new_count..old_count {
+                txn_batch.delete(AddressTxnKey::new(address, index))?;
+            }
+        }
+        self.instance.write_batch_multi_cf(vec![
+            (ADDRESS_TXN_PREFIX_NAME, txn_batch.try_into()?),
+            (ADDRESS_TXN_COUNT_PREFIX_NAME, count_batch.try_into()?),
+        ])
+    }
+
+    pub(crate) fn get_txn_info_ids(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        limit: u64,
+        reverse: bool,
+    ) -> Result<Vec<(u64, HashValue)>> {
+        let count = self.get_count(address)?;
+        if count == 0 || limit == 0 {
+            return Ok(vec![]);
+        }
+        let indexes: Vec<u64> = if reverse {
+            let start_index = start_index.unwrap_or(count - 1).min(count - 1);
+            let end_index = start_index.saturating_sub(limit - 1);
+            (end_index..=start_index).rev().collect()
+        } else {
+            let start_index = start_index.unwrap_or(0);
+            if start_index >= count {
+                return Ok(vec![]);
+            }
+            let end_index = start_index.saturating_add(limit).min(count);
+            (start_index..end_index).collect()
+        };
+        let keys = indexes
+            .iter()
+            .map(|index| AddressTxnKey::new(address, *index))
+            .collect();
+        let txn_info_ids = self.txn_storage.multiple_get(keys)?;
+        indexes
+            .into_iter()
+            .zip(txn_info_ids)
+            .map(|(index, txn_info_id)| {
+                txn_info_id.map(|id| (index, id)).ok_or_else(|| {
+                    format_err!(
+                        "invalid state: txn info id {} of address {} should exist",
+                        index,
+                        address
+                    )
+                })
+            })
+            .collect()
+    }
+}
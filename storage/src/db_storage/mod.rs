@@ -316,6 +316,24 @@ impl InnerStore for DBStorage {
         })
     }
 
+    fn write_batch_multi_cf(&self, batches: Vec<(ColumnFamilyName, WriteBatch)>) -> Result<()> {
+        record_metrics("db", "batch", "multi_cf").end_with(|| {
+            let mut db_batch = DBWriteBatch::default();
+            for (prefix_name, batch) in &batches {
+                let cf_handle = self.get_cf_handle(prefix_name)?;
+                for (key, write_op) in &batch.rows {
+                    match write_op {
+                        WriteOp::Value(value) => db_batch.put_cf(cf_handle, key, value),
+                        WriteOp::Deletion => db_batch.delete_cf(cf_handle, key),
+                    };
+                }
+            }
+            self.db
+                .write_opt(db_batch, &Self::default_write_options())?;
+            Ok(())
+        })
+    }
+
     fn get_len(&self) -> Result<u64> {
         unimplemented!()
     }
@@ -4,6 +4,7 @@
 use crate::accumulator::{
     AccumulatorStorage, BlockAccumulatorStorage, TransactionAccumulatorStorage,
 };
+use crate::address_txn::AddressTxnIndexStorage;
 use crate::block::BlockStorage;
 use crate::block_info::{BlockInfoStorage, BlockInfoStore};
 use crate::chain_info::ChainInfoStorage;
@@ -12,12 +13,14 @@ use crate::state_node::StateStorage;
 use crate::storage::{CodecKVStore, CodecWriteBatch, ColumnFamilyName, StorageInstance};
 use crate::transaction::TransactionStorage;
 use crate::transaction_info::{TransactionInfoHashStorage, TransactionInfoStorage};
-use anyhow::{bail, format_err, Error, Result};
+use anyhow::{bail, ensure, format_err, Error, Result};
 use crypto::HashValue;
 use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use starcoin_accumulator::node::AccumulatorStoreType;
 use starcoin_accumulator::AccumulatorTreeStore;
-use starcoin_state_store_api::{StateNode, StateNodeStore};
+use starcoin_state_store_api::{StateNode, StateNodeSource, StateNodeStore};
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::contract_event::ContractEvent;
 use starcoin_types::peer_info::PeerId;
 use starcoin_types::startup_info::{ChainInfo, ChainStatus};
@@ -31,6 +34,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
 
 pub mod accumulator;
+pub mod address_txn;
 pub mod batch;
 pub mod block;
 pub mod block_info;
@@ -65,6 +69,8 @@ pub const TRANSACTION_INFO_PREFIX_NAME: ColumnFamilyName = "transaction_info";
 pub const TRANSACTION_INFO_HASH_PREFIX_NAME: ColumnFamilyName = "transaction_info_hash";
 pub const CONTRACT_EVENT_PREFIX_NAME: ColumnFamilyName = "contract_event";
 pub const FAILED_BLOCK_PREFIX_NAME: ColumnFamilyName = "failed_block";
+pub const ADDRESS_TXN_COUNT_PREFIX_NAME: ColumnFamilyName = "address_txn_count";
+pub const ADDRESS_TXN_PREFIX_NAME: ColumnFamilyName = "address_txn";
 
 ///db storage use prefix_name vec to init
 /// Please note that adding a prefix needs to be added in vec simultaneously, remember！！
@@ -85,6 +91,8 @@ pub static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TRANSACTION_INFO_HASH_PREFIX_NAME,
         CONTRACT_EVENT_PREFIX_NAME,
         FAILED_BLOCK_PREFIX_NAME,
+        ADDRESS_TXN_COUNT_PREFIX_NAME,
+        ADDRESS_TXN_PREFIX_NAME,
     ]
 });
 
@@ -172,6 +180,43 @@ pub trait TransactionStore {
     fn save_transaction_batch(&self, txn_vec: Vec<Transaction>) -> Result<()>;
 }
 
+/// Index of the transactions sent by or affecting an account address.
+/// The index is optional, only maintained when it is enabled.
+pub trait AddressTxnIndexStore {
+    fn address_txn_index_enabled(&self) -> bool;
+
+    /// Append the txn info ids to the index of their addresses, in order.
+    /// Do nothing if the index is not enabled.
+    fn append_address_txn_index(&self, entries: Vec<(AccountAddress, HashValue)>) -> Result<()>;
+
+    /// Remove the txn info ids from the end of the index of their addresses, the reverse of
+    /// `append_address_txn_index` with the same entries, used when the blocks are retracted.
+    /// Do nothing if the index is not enabled.
+    fn remove_address_txn_index(&self, entries: Vec<(AccountAddress, HashValue)>) -> Result<()>;
+
+    /// Save the txn info ids of `address` from `start_index`, and reset the count of
+    /// the address to the end of them, used to rebuild the index.
+    fn put_address_txn_index(
+        &self,
+        address: AccountAddress,
+        start_index: u64,
+        txn_info_ids: Vec<HashValue>,
+    ) -> Result<()>;
+
+    /// The count of the indexed txn info ids of `address`.
+    fn get_address_txn_count(&self, address: AccountAddress) -> Result<u64>;
+
+    /// Get at most `limit` (index, txn info id) of `address` from `start_index`,
+    /// the `start_index` default to the first one, or the last one if `reverse`.
+    fn get_address_txn_info_ids(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        limit: u64,
+        reverse: bool,
+    ) -> Result<Vec<(u64, HashValue)>>;
+}
+
 // TODO: remove Arc<dyn Store>, we can clone Storage directly.
 #[derive(Clone)]
 pub struct Storage {
@@ -185,6 +230,11 @@ pub struct Storage {
     block_info_storage: BlockInfoStorage,
     event_storage: ContractEventStorage,
     chain_info_storage: ChainInfoStorage,
+    address_txn_index_storage: AddressTxnIndexStorage,
+    address_txn_index: bool,
+    /// Serialize the read-modify-write of the address txn counts.
+    address_txn_index_lock: Arc<Mutex<()>>,
+    state_node_source: Option<Arc<dyn StateNodeSource>>,
 }
 
 impl Storage {
@@ -202,10 +252,27 @@ impl Storage {
                 AccumulatorStorage::new_transaction_accumulator_storage(instance.clone()),
             block_info_storage: BlockInfoStorage::new(instance.clone()),
             event_storage: ContractEventStorage::new(instance.clone()),
-            chain_info_storage: ChainInfoStorage::new(instance),
+            chain_info_storage: ChainInfoStorage::new(instance.clone()),
+            address_txn_index_storage: AddressTxnIndexStorage::new(instance),
+            address_txn_index: false,
+            address_txn_index_lock: Arc::new(Mutex::new(())),
+            state_node_source: None,
         })
     }
 
+    /// Enable or disable maintaining the address txn index when saving blocks.
+    pub fn with_address_txn_index(mut self, enable: bool) -> Self {
+        self.address_txn_index = enable;
+        self
+    }
+
+    /// Read the state nodes missing in this storage from the `source`, the nodes are verified by
+    /// their hashes and saved in this storage.
+    pub fn with_state_node_source(mut self, source: Arc<dyn StateNodeSource>) -> Self {
+        self.state_node_source = Some(source);
+        self
+    }
+
     pub fn get_block_accumulator_storage(&self) -> AccumulatorStorage<BlockAccumulatorStorage> {
         self.block_accumulator_storage.clone()
     }
@@ -219,7 +286,18 @@ impl Storage {
 
 impl StateNodeStore for Storage {
     fn get(&self, hash: &HashValue) -> Result<Option<StateNode>> {
-        self.state_node_storage.get(*hash)
+        let node = self.state_node_storage.get(*hash)?;
+        match (node, &self.state_node_source) {
+            (None, Some(source)) => match source.get_node(hash)? {
+                Some(node) => {
+                    node.verify(hash)?;
+                    self.state_node_storage.put(*hash, node.clone())?;
+                    Ok(Some(node))
+                }
+                None => Ok(None),
+            },
+            (node, _) => Ok(node),
+        }
     }
 
     fn put(&self, key: HashValue, node: StateNode) -> Result<()> {
@@ -428,6 +506,98 @@ impl TransactionStore for Storage {
     }
 }
 
+impl AddressTxnIndexStore for Storage {
+    fn address_txn_index_enabled(&self) -> bool {
+        self.address_txn_index
+    }
+
+    fn append_address_txn_index(&self, entries: Vec<(AccountAddress, HashValue)>) -> Result<()> {
+        if !self.address_txn_index {
+            return Ok(());
+        }
+        let mut address_txn_info_ids: BTreeMap<AccountAddress, Vec<HashValue>> = BTreeMap::new();
+        for (address, txn_info_id) in entries {
+            address_txn_info_ids
+                .entry(address)
+                .or_default()
+                .push(txn_info_id);
+        }
+        let _guard = self.address_txn_index_lock.lock();
+        let entries = address_txn_info_ids
+            .into_iter()
+            .map(|(address, txn_info_ids)| {
+                let count = self.address_txn_index_storage.get_count(address)?;
+                Ok((address, count, txn_info_ids))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.address_txn_index_storage.put_txn_info_ids(entries)
+    }
+
+    fn remove_address_txn_index(&self, entries: Vec<(AccountAddress, HashValue)>) -> Result<()> {
+        if !self.address_txn_index {
+            return Ok(());
+        }
+        let mut address_txn_info_ids: BTreeMap<AccountAddress, Vec<HashValue>> = BTreeMap::new();
+        for (address, txn_info_id) in entries {
+            address_txn_info_ids
+                .entry(address)
+                .or_default()
+                .push(txn_info_id);
+        }
+        let _guard = self.address_txn_index_lock.lock();
+        let entries = address_txn_info_ids
+            .into_iter()
+            .map(|(address, txn_info_ids)| {
+                let count = self.address_txn_index_storage.get_count(address)?;
+                let last_ids = self.address_txn_index_storage.get_txn_info_ids(
+                    address,
+                    None,
+                    txn_info_ids.len() as u64,
+                    true,
+                )?;
+                ensure!(
+                    last_ids.len() == txn_info_ids.len()
+                        && last_ids
+                            .iter()
+                            .rev()
+                            .map(|(_, id)| id)
+                            .eq(txn_info_ids.iter()),
+                    "The txn info ids to remove are not the last ones of address {} in the address txn index",
+                    address
+                );
+                Ok((address, count - txn_info_ids.len() as u64, count))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.address_txn_index_storage.remove_txn_info_ids(entries)
+    }
+
+    fn put_address_txn_index(
+        &self,
+        address: AccountAddress,
+        start_index: u64,
+        txn_info_ids: Vec<HashValue>,
+    ) -> Result<()> {
+        let _guard = self.address_txn_index_lock.lock();
+        self.address_txn_index_storage
+            .put_txn_info_ids(vec![(address, start_index, txn_info_ids)])
+    }
+
+    fn get_address_txn_count(&self, address: AccountAddress) -> Result<u64> {
+        self.address_txn_index_storage.get_count(address)
+    }
+
+    fn get_address_txn_info_ids(
+        &self,
+        address: AccountAddress,
+        start_index: Option<u64>,
+        limit: u64,
+        reverse: bool,
+    ) -> Result<Vec<(u64, HashValue)>> {
+        self.address_txn_index_storage
+            .get_txn_info_ids(address, start_index, limit, reverse)
+    }
+}
+
 /// Chain storage define
 pub trait Store:
     StateNodeStore
@@ -436,6 +606,7 @@ pub trait Store:
     + TransactionStore
     + BlockTransactionInfoStore
     + ContractEventStore
+    + AddressTxnIndexStore
     + IntoSuper<dyn StateNodeStore>
 {
     fn get_transaction_info_by_block_and_index(
@@ -38,6 +38,8 @@ pub trait InnerStore: Send + Sync {
     fn contains_key(&self, prefix_name: &str, key: Vec<u8>) -> Result<bool>;
     fn remove(&self, prefix_name: &str, key: Vec<u8>) -> Result<()>;
     fn write_batch(&self, prefix_name: &str, batch: WriteBatch) -> Result<()>;
+    /// Write the batches of multiple column families atomically.
+    fn write_batch_multi_cf(&self, batches: Vec<(ColumnFamilyName, WriteBatch)>) -> Result<()>;
     fn get_len(&self) -> Result<u64>;
     fn keys(&self) -> Result<Vec<Vec<u8>>>;
 }
@@ -191,6 +193,24 @@ impl InnerStore for StorageInstance {
             }
         }
     }
+
+    fn write_batch_multi_cf(&self, batches: Vec<(ColumnFamilyName, WriteBatch)>) -> Result<()> {
+        match self {
+            StorageInstance::CACHE { cache } => cache.write_batch_multi_cf(batches),
+            StorageInstance::DB { db } => db.write_batch_multi_cf(batches),
+            StorageInstance::CacheAndDb { cache, db } => {
+                match db.write_batch_multi_cf(batches.clone()) {
+                    Ok(_) => {
+                        for (prefix_name, batch) in batches {
+                            cache.write_batch_obj(prefix_name, batch)?;
+                        }
+                        Ok(())
+                    }
+                    Err(err) => bail!("write batch db error: {}", err),
+                }
+            }
+        }
+    }
     fn get_len(&self) -> Result<u64> {
         match self {
             StorageInstance::CACHE { cache } => cache.get_len(),
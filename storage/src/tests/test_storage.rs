@@ -7,14 +7,21 @@ use crate::cache_storage::CacheStorage;
 use crate::db_storage::DBStorage;
 use crate::storage::{CodecKVStore, InnerStore, StorageInstance, ValueCodec, CACHE_NONE_OBJECT};
 use crate::{
-    BlockTransactionInfoStore, Storage, DEFAULT_PREFIX_NAME, TRANSACTION_INFO_PREFIX_NAME,
-    VEC_PREFIX_NAME,
+    AddressTxnIndexStore, BlockTransactionInfoStore, Storage, DEFAULT_PREFIX_NAME,
+    TRANSACTION_INFO_PREFIX_NAME, VEC_PREFIX_NAME,
 };
 use anyhow::Result;
 use crypto::HashValue;
+use forkable_jellyfish_merkle::blob::Blob;
+use forkable_jellyfish_merkle::node_type::Node;
 use starcoin_config::RocksdbConfig;
+use starcoin_state_store_api::{StateNode, StateNodeSource, StateNodeStore};
+use starcoin_types::account_address::AccountAddress;
 use starcoin_types::transaction::{BlockTransactionInfo, TransactionInfo};
 use starcoin_types::vm_error::KeptVMStatus;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
 
 #[test]
 fn test_reopen() {
@@ -218,3 +225,140 @@ fn test_missing_key_handle() -> Result<()> {
     assert_eq!(contains, false);
     Ok(())
 }
+
+#[test]
+fn test_address_txn_index() -> Result<()> {
+    let tmpdir = starcoin_config::temp_path();
+    let instance = StorageInstance::new_cache_and_db_instance(
+        CacheStorage::new(),
+        DBStorage::new(tmpdir.path(), RocksdbConfig::default()).unwrap(),
+    );
+    let address1 = AccountAddress::random();
+    let address2 = AccountAddress::random();
+    let txn_info_ids: Vec<HashValue> = (0..5).map(|_| HashValue::random()).collect();
+
+    // the index is disabled by default.
+    let storage = Storage::new(instance.clone())?;
+    storage.append_address_txn_index(vec![(address1, txn_info_ids[0])])?;
+    assert_eq!(storage.get_address_txn_count(address1)?, 0);
+
+    let storage = Storage::new(instance)?.with_address_txn_index(true);
+    storage.append_address_txn_index(vec![
+        (address1, txn_info_ids[0]),
+        (address2, txn_info_ids[0]),
+        (address1, txn_info_ids[1]),
+    ])?;
+    storage
+        .append_address_txn_index(txn_info_ids[2..].iter().map(|id| (address1, *id)).collect())?;
+    assert_eq!(storage.get_address_txn_count(address1)?, 5);
+    assert_eq!(storage.get_address_txn_count(address2)?, 1);
+
+    let ids = storage.get_address_txn_info_ids(address1, None, 2, false)?;
+    assert_eq!(ids, vec![(0, txn_info_ids[0]), (1, txn_info_ids[1])]);
+    let ids = storage.get_address_txn_info_ids(address1, Some(3), 10, false)?;
+    assert_eq!(ids, vec![(3, txn_info_ids[3]), (4, txn_info_ids[4])]);
+    let ids = storage.get_address_txn_info_ids(address1, None, 2, true)?;
+    assert_eq!(ids, vec![(4, txn_info_ids[4]), (3, txn_info_ids[3])]);
+    let ids = storage.get_address_txn_info_ids(address1, Some(1), 10, true)?;
+    assert_eq!(ids, vec![(1, txn_info_ids[1]), (0, txn_info_ids[0])]);
+    assert!(storage
+        .get_address_txn_info_ids(address1, Some(5), 10, false)?
+        .is_empty());
+
+    // remove the last ids, only the last ids of an address can be removed.
+    assert!(storage
+        .remove_address_txn_index(vec![(address1, txn_info_ids[3])])
+        .is_err());
+    storage.remove_address_txn_index(vec![
+        (address1, txn_info_ids[3]),
+        (address2, txn_info_ids[0]),
+        (address1, txn_info_ids[4]),
+    ])?;
+    assert_eq!(storage.get_address_txn_count(address1)?, 3);
+    assert_eq!(storage.get_address_txn_count(address2)?, 0);
+    let ids = storage.get_address_txn_info_ids(address1, None, 10, true)?;
+    assert_eq!(
+        ids,
+        vec![
+            (2, txn_info_ids[2]),
+            (1, txn_info_ids[1]),
+            (0, txn_info_ids[0])
+        ]
+    );
+    storage
+        .append_address_txn_index(txn_info_ids[3..].iter().map(|id| (address1, *id)).collect())?;
+
+    // rebuild overwrites the index from the start index.
+    storage.put_address_txn_index(address1, 0, vec![txn_info_ids[4]])?;
+    assert_eq!(storage.get_address_txn_count(address1)?, 1);
+    let ids = storage.get_address_txn_info_ids(address1, None, 10, true)?;
+    assert_eq!(ids, vec![(0, txn_info_ids[4])]);
+    Ok(())
+}
+
+#[test]
+fn test_address_txn_index_concurrent_append() -> Result<()> {
+    let tmpdir = starcoin_config::temp_path();
+    let instance = StorageInstance::new_cache_and_db_instance(
+        CacheStorage::new(),
+        DBStorage::new(tmpdir.path(), RocksdbConfig::default()).unwrap(),
+    );
+    let storage = Storage::new(instance)?.with_address_txn_index(true);
+    let address = AccountAddress::random();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let storage = storage.clone();
+            std::thread::spawn(move || -> Result<()> {
+                for _ in 0..25 {
+                    storage.append_address_txn_index(vec![(address, HashValue::random())])?;
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("append thread should not panic")?;
+    }
+    assert_eq!(storage.get_address_txn_count(address)?, 100);
+    let ids = storage.get_address_txn_info_ids(address, None, 100, false)?;
+    assert_eq!(ids.len(), 100);
+    Ok(())
+}
+
+struct MockStateNodeSource {
+    nodes: HashMap<HashValue, StateNode>,
+}
+
+impl StateNodeSource for MockStateNodeSource {
+    fn get_node(&self, hash: &HashValue) -> Result<Option<StateNode>> {
+        Ok(self.nodes.get(hash).cloned())
+    }
+}
+
+#[test]
+fn test_state_node_source() -> Result<()> {
+    let leaf = Node::new_leaf(
+        AccountAddress::random(),
+        Blob::from(HashValue::random().to_vec()),
+    );
+    let leaf_hash = leaf.hash();
+    let node = StateNode::try_from(leaf)?;
+    let fake_hash = HashValue::random();
+    let mut nodes = HashMap::new();
+    nodes.insert(leaf_hash, node.clone());
+    nodes.insert(fake_hash, node.clone());
+    let storage = Storage::new(StorageInstance::new_cache_instance())?
+        .with_state_node_source(Arc::new(MockStateNodeSource { nodes }));
+
+    assert_eq!(
+        StateNodeStore::get(&storage, &leaf_hash)?,
+        Some(node.clone())
+    );
+    // the fetched node is saved in the storage.
+    assert_eq!(storage.state_node_storage.get(leaf_hash)?, Some(node));
+    // the node which mismatch the hash is refused.
+    assert!(StateNodeStore::get(&storage, &fake_hash).is_err());
+    assert_eq!(storage.state_node_storage.get(fake_hash)?, None);
+    assert_eq!(StateNodeStore::get(&storage, &HashValue::random())?, None);
+    Ok(())
+}
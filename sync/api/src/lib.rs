@@ -7,10 +7,11 @@ use serde::{Deserialize, Serialize};
 pub use service::{SyncAsyncService, SyncServiceHandler};
 use starcoin_crypto::HashValue;
 use starcoin_service_registry::ServiceRequest;
-use starcoin_types::block::{Block, BlockIdAndNumber, BlockInfo, BlockNumber};
+use starcoin_types::block::{Block, BlockHeader, BlockIdAndNumber, BlockInfo, BlockNumber};
 use starcoin_types::peer_info::PeerId;
 use starcoin_types::sync_status::SyncStatus;
 use starcoin_types::U256;
+use starcoin_vm_types::write_set::WriteSet;
 pub use stream_task::TaskProgressReport;
 
 mod service;
@@ -112,3 +113,25 @@ impl From<Option<Vec<(PeerId, u64)>>> for PeerScoreResponse {
         Self { peers }
     }
 }
+
+/// Reset the main chain head to the main chain block `block_id`, the blocks after it are
+/// retracted from the main chain but kept in the storage. Only for dev and test network.
+#[derive(Debug, Clone)]
+pub struct ResetHeadRequest {
+    pub block_id: HashValue,
+}
+
+impl ServiceRequest for ResetHeadRequest {
+    type Response = Result<BlockHeader>;
+}
+
+/// Replace the main chain head by a block whose state is changed by the `write_set`, return the
+/// new head block header. Only for dev and test network.
+#[derive(Debug, Clone)]
+pub struct WriteStateRequest {
+    pub write_set: WriteSet,
+}
+
+impl ServiceRequest for WriteStateRequest {
+    type Response = Result<BlockHeader>;
+}
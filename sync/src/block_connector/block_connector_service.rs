@@ -4,15 +4,18 @@
 use crate::block_connector::WriteBlockChainService;
 use crate::sync::{CheckSyncEvent, SyncService};
 use crate::tasks::BlockConnectedEvent;
-use anyhow::{format_err, Result};
+use anyhow::{bail, format_err, Result};
 use config::NodeConfig;
 use logger::prelude::*;
 use network::NetworkServiceRef;
 use network_api::PeerProvider;
 use starcoin_chain_api::{ConnectBlockError, WriteableChainService};
-use starcoin_service_registry::{ActorService, EventHandler, ServiceContext, ServiceFactory};
+use starcoin_service_registry::{
+    ActorService, EventHandler, ServiceContext, ServiceFactory, ServiceHandler,
+};
 use starcoin_storage::{BlockStore, Storage};
-use starcoin_sync_api::PeerNewBlock;
+use starcoin_sync_api::{PeerNewBlock, ResetHeadRequest, WriteStateRequest};
+use starcoin_types::block::BlockHeader;
 use starcoin_types::sync_status::SyncStatus;
 use starcoin_types::system_events::{MinedBlock, SyncStatusChangeEvent};
 use std::sync::Arc;
@@ -101,6 +104,42 @@ impl EventHandler<Self, MinedBlock> for BlockConnectorService {
     }
 }
 
+impl ServiceHandler<Self, ResetHeadRequest> for BlockConnectorService {
+    fn handle(
+        &mut self,
+        msg: ResetHeadRequest,
+        ctx: &mut ServiceContext<BlockConnectorService>,
+    ) -> Result<BlockHeader> {
+        let config = ctx.get_shared::<Arc<NodeConfig>>()?;
+        let net = config.net().id();
+        if !net.is_test_or_dev() {
+            bail!(
+                "Reset head is only available in test or dev network, current network is: {}",
+                net
+            );
+        }
+        self.chain_service.reset(msg.block_id)
+    }
+}
+
+impl ServiceHandler<Self, WriteStateRequest> for BlockConnectorService {
+    fn handle(
+        &mut self,
+        msg: WriteStateRequest,
+        ctx: &mut ServiceContext<BlockConnectorService>,
+    ) -> Result<BlockHeader> {
+        let config = ctx.get_shared::<Arc<NodeConfig>>()?;
+        let net = config.net().id();
+        if !net.is_test_or_dev() {
+            bail!(
+                "Write state is only available in test or dev network, current network is: {}",
+                net
+            );
+        }
+        self.chain_service.write_state(msg.write_set)
+    }
+}
+
 impl EventHandler<Self, SyncStatusChangeEvent> for BlockConnectorService {
     fn handle_event(&mut self, msg: SyncStatusChangeEvent, _ctx: &mut ServiceContext<Self>) {
         self.sync_status = Some(msg.0);
@@ -19,6 +19,7 @@ pub struct ChainMetrics {
     pub exe_block_time: HistogramVec,
     pub rollback_block_size: IntGauge,
     pub current_head_number: IntGauge,
+    pub reorg_refused_depth: IntGauge,
 }
 
 impl ChainMetrics {
@@ -44,6 +45,12 @@ impl ChainMetrics {
         )
         .namespace(SC_NS))?;
 
+        let reorg_refused_depth = register_int_gauge!(Opts::new(
+            format!("{}{}", PREFIX, "reorg_refused_depth"),
+            "depth of the latest refused reorg".to_string()
+        )
+        .namespace(SC_NS))?;
+
         let block_connect_count = UIntCounterVec::new(
             Opts::new(
                 format!("{}{}", PREFIX, "block_connect_count"),
@@ -59,6 +66,7 @@ impl ChainMetrics {
             exe_block_time,
             rollback_block_size,
             current_head_number,
+            reorg_refused_depth,
             block_connect_count,
         })
     }
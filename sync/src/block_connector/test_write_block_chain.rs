@@ -2,27 +2,46 @@
 // SPDX-License-Identifier: Apache-2.0
 #![allow(clippy::integer_arithmetic)]
 use crate::block_connector::WriteBlockChainService;
-use config::NodeConfig;
+use config::{NodeConfig, StarcoinOpt};
 use consensus::Consensus;
 use starcoin_account_api::AccountInfo;
+use starcoin_chain::address_txn_index::rebuild_address_txn_index;
 use starcoin_chain::{BlockChain, ChainReader};
 use starcoin_chain_service::WriteableChainService;
+use starcoin_crypto::HashValue;
 use starcoin_genesis::Genesis as StarcoinGenesis;
 use starcoin_service_registry::bus::BusService;
 use starcoin_service_registry::{RegistryAsyncService, RegistryService};
-use starcoin_storage::Store;
+use starcoin_storage::storage::StorageInstance;
+use starcoin_storage::{AddressTxnIndexStore, BlockStore, Storage, Store};
 use starcoin_txpool_mock_service::MockTxPoolService;
+use starcoin_types::access_path::AccessPath;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_types::account_config::BalanceResource;
 use starcoin_types::block::Block;
 use starcoin_types::startup_info::StartupInfo;
+use starcoin_vm_types::move_resource::MoveResource;
+use starcoin_vm_types::state_view::StateView;
 use starcoin_vm_types::time::TimeService;
+use starcoin_vm_types::write_set::{WriteOp, WriteSet, WriteSetMut};
 use std::sync::Arc;
+use structopt::StructOpt;
 
 pub async fn create_writeable_block_chain() -> (
     WriteBlockChainService<MockTxPoolService>,
     Arc<NodeConfig>,
     Arc<dyn Store>,
 ) {
-    let node_config = NodeConfig::random_for_test();
+    create_writeable_block_chain_with_config(NodeConfig::random_for_test()).await
+}
+
+pub async fn create_writeable_block_chain_with_config(
+    node_config: NodeConfig,
+) -> (
+    WriteBlockChainService<MockTxPoolService>,
+    Arc<NodeConfig>,
+    Arc<dyn Store>,
+) {
     let node_config = Arc::new(node_config);
 
     let (storage, chain_info, _) = StarcoinGenesis::init_storage_for_test(node_config.net())
@@ -217,3 +236,343 @@ async fn test_block_chain_switch_main() {
         2 * times
     );
 }
+
+#[stest::test]
+async fn test_block_chain_refuse_deep_reorg() {
+    let times = 10;
+    let opt = StarcoinOpt::from_iter_safe(vec!["starcoin", "-n", "test", "--max-reorg-depth", "3"])
+        .unwrap();
+    let node_config = NodeConfig::load_with_opt(&opt).unwrap();
+    let (mut writeable_block_chain_service, node_config, _) =
+        create_writeable_block_chain_with_config(node_config).await;
+    let net = node_config.net();
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    let main_head = writeable_block_chain_service.get_main().current_header();
+
+    let miner_account = AccountInfo::random();
+    let mut parent_id = writeable_block_chain_service
+        .get_main()
+        .get_header_by_number(0)
+        .unwrap()
+        .unwrap()
+        .id();
+    let mut refused = false;
+    for _i in 0..2 * times {
+        let block_chain = BlockChain::new(
+            net.time_service(),
+            parent_id,
+            writeable_block_chain_service.get_main().get_storage(),
+        )
+        .unwrap();
+        let (block_template, _) = block_chain
+            .create_block_template(
+                *miner_account.address(),
+                Some(miner_account.public_key.authentication_key()),
+                None,
+                Vec::new(),
+                vec![],
+                None,
+            )
+            .unwrap();
+        let block = block_chain
+            .consensus()
+            .create_block(block_template, net.time_service().as_ref())
+            .unwrap();
+        parent_id = block.id();
+        if writeable_block_chain_service.try_connect(block).is_err() {
+            refused = true;
+        }
+    }
+    assert!(refused);
+    assert_eq!(
+        writeable_block_chain_service.get_main().current_header(),
+        main_head
+    );
+}
+
+fn new_fork_block(
+    parent_id: HashValue,
+    miner_account: &AccountInfo,
+    node_config: &NodeConfig,
+    storage: Arc<dyn Store>,
+) -> Block {
+    let net = node_config.net();
+    let block_chain = BlockChain::new(net.time_service(), parent_id, storage).unwrap();
+    let (block_template, _) = block_chain
+        .create_block_template(
+            *miner_account.address(),
+            Some(miner_account.public_key.authentication_key()),
+            None,
+            Vec::new(),
+            vec![],
+            None,
+        )
+        .unwrap();
+    block_chain
+        .consensus()
+        .create_block(block_template, net.time_service().as_ref())
+        .unwrap()
+}
+
+#[stest::test]
+async fn test_block_chain_refuse_fork_above_checkpoint() {
+    let times = 10;
+    let (mut writeable_block_chain_service, node_config, storage) =
+        create_writeable_block_chain().await;
+    let net = node_config.net();
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    let main = writeable_block_chain_service.get_main();
+    let main_head = main.current_header();
+    let checkpoint = main.get_header_by_number(3).unwrap().unwrap();
+
+    // a fork from block 1 passes the checkpoint number before the checkpoint is configured.
+    let miner_account = AccountInfo::random();
+    let mut parent_id = main.get_header_by_number(1).unwrap().unwrap().id();
+    for _i in 0..5 {
+        let block = new_fork_block(parent_id, &miner_account, &node_config, storage.clone());
+        parent_id = block.id();
+        writeable_block_chain_service.try_connect(block).unwrap();
+    }
+    assert_eq!(
+        writeable_block_chain_service.get_main().current_header(),
+        main_head
+    );
+
+    let checkpoint_arg = format!("{}:{}", checkpoint.number(), checkpoint.id());
+    let opt = StarcoinOpt::from_iter_safe(vec![
+        "starcoin",
+        "-n",
+        "test",
+        "--checkpoint",
+        checkpoint_arg.as_str(),
+    ])
+    .unwrap();
+    let node_config = Arc::new(NodeConfig::load_with_opt(&opt).unwrap());
+    let registry = RegistryService::launch();
+    let bus = registry.service_ref::<BusService>().await.unwrap();
+    let mut writeable_block_chain_service = WriteBlockChainService::new(
+        node_config.clone(),
+        StartupInfo::new(main_head.id()),
+        storage.clone(),
+        MockTxPoolService::new(),
+        bus,
+    )
+    .unwrap();
+    // the fork conflicts with the checkpoint, so it is refused above the checkpoint number.
+    let block = new_fork_block(parent_id, &miner_account, &node_config, storage.clone());
+    assert!(block.header().number() > checkpoint.number());
+    assert!(writeable_block_chain_service.try_connect(block).is_err());
+    assert_eq!(
+        writeable_block_chain_service.get_main().current_header(),
+        main_head
+    );
+    // the main chain contains the checkpoint.
+    gen_blocks(
+        1,
+        &mut writeable_block_chain_service,
+        node_config.net().time_service().as_ref(),
+    );
+    assert_eq!(
+        writeable_block_chain_service
+            .get_main()
+            .current_header()
+            .number(),
+        times + 1
+    );
+}
+
+fn address_txn_index(
+    storage: &dyn Store,
+    addresses: &[AccountAddress],
+) -> Vec<Vec<(u64, HashValue)>> {
+    addresses
+        .iter()
+        .map(|address| {
+            storage
+                .get_address_txn_info_ids(*address, None, u64::max_value(), false)
+                .unwrap()
+        })
+        .collect()
+}
+
+#[stest::test]
+async fn test_block_chain_address_txn_index() {
+    let times = 5;
+    let node_config = Arc::new(NodeConfig::random_for_test());
+    let net = node_config.net();
+    let storage = Arc::new(
+        Storage::new(StorageInstance::new_cache_instance())
+            .unwrap()
+            .with_address_txn_index(true),
+    );
+    let chain_info = StarcoinGenesis::load(net)
+        .unwrap()
+        .execute_genesis_block(net, storage.clone())
+        .unwrap();
+    let registry = RegistryService::launch();
+    let bus = registry.service_ref::<BusService>().await.unwrap();
+    let mut writeable_block_chain_service = WriteBlockChainService::new(
+        node_config.clone(),
+        StartupInfo::new(chain_info.head().id()),
+        storage.clone(),
+        MockTxPoolService::new(),
+        bus,
+    )
+    .unwrap();
+
+    let main_miner = AccountInfo::random();
+    for _i in 0..times {
+        let block = new_block(
+            Some(&main_miner),
+            &mut writeable_block_chain_service,
+            net.time_service().as_ref(),
+        );
+        writeable_block_chain_service.try_connect(block).unwrap();
+    }
+    let fork_miner = AccountInfo::random();
+    let addresses = vec![*main_miner.address(), *fork_miner.address()];
+    let main_index = address_txn_index(storage.as_ref(), &addresses);
+    assert!(!main_index[0].is_empty());
+
+    // the fork blocks are not indexed until the fork becomes the main chain.
+    let mut parent_id = chain_info.head().id();
+    for _i in 0..times - 2 {
+        let block = new_fork_block(parent_id, &fork_miner, &node_config, storage.clone());
+        parent_id = block.id();
+        writeable_block_chain_service.try_connect(block).unwrap();
+    }
+    assert_eq!(address_txn_index(storage.as_ref(), &addresses), main_index);
+
+    // switch main to the fork, the retracted blocks are removed from the index.
+    let mut switched = false;
+    for _i in 0..2 * times {
+        let block = new_fork_block(parent_id, &fork_miner, &node_config, storage.clone());
+        parent_id = block.id();
+        writeable_block_chain_service.try_connect(block).unwrap();
+        if writeable_block_chain_service
+            .get_main()
+            .current_header()
+            .id()
+            == parent_id
+        {
+            switched = true;
+            break;
+        }
+    }
+    assert!(switched);
+    let fork_index = address_txn_index(storage.as_ref(), &addresses);
+    assert!(fork_index[0].is_empty());
+    assert!(!fork_index[1].is_empty());
+
+    rebuild_address_txn_index(storage.as_ref()).unwrap();
+    assert_eq!(address_txn_index(storage.as_ref(), &addresses), fork_index);
+}
+
+#[stest::test]
+async fn test_block_chain_reset_head() {
+    let times = 10;
+    let (mut writeable_block_chain_service, node_config, storage) =
+        create_writeable_block_chain().await;
+    let net = node_config.net();
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    let snapshot = writeable_block_chain_service
+        .get_main()
+        .get_header_by_number(times / 2)
+        .unwrap()
+        .unwrap();
+    let old_head = writeable_block_chain_service.get_main().current_header();
+    let header = writeable_block_chain_service.reset(snapshot.id()).unwrap();
+    assert_eq!(header, snapshot);
+    // the retracted blocks are not on the main chain any more, can not reset to them.
+    assert!(writeable_block_chain_service.reset(old_head.id()).is_err());
+    assert_eq!(
+        writeable_block_chain_service.get_main().current_header(),
+        snapshot
+    );
+    assert_eq!(
+        storage.get_startup_info().unwrap().unwrap().main,
+        snapshot.id()
+    );
+
+    // the chain can continue to grow from the reset head.
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    assert_eq!(
+        writeable_block_chain_service
+            .get_main()
+            .current_header()
+            .number(),
+        times / 2 + times
+    );
+}
+
+#[stest::test]
+async fn test_block_chain_write_state() {
+    let times = 2;
+    let (mut writeable_block_chain_service, node_config, storage) =
+        create_writeable_block_chain().await;
+    let net = node_config.net();
+    // the state of genesis block can not be changed.
+    assert!(writeable_block_chain_service
+        .write_state(WriteSet::default())
+        .is_err());
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    let old_head = writeable_block_chain_service.get_main().current_header();
+    let access_path = AccessPath::new(AccountAddress::random(), BalanceResource::resource_path());
+    let value = bcs_ext::to_bytes(&BalanceResource::new(100)).unwrap();
+    let write_set = WriteSetMut::new(vec![(access_path.clone(), WriteOp::Value(value.clone()))])
+        .freeze()
+        .unwrap();
+    let header = writeable_block_chain_service
+        .write_state(write_set)
+        .unwrap();
+    assert_eq!(header.number(), old_head.number());
+    assert_eq!(header.parent_hash(), old_head.parent_hash());
+    assert_ne!(header.id(), old_head.id());
+    assert_eq!(
+        storage.get_startup_info().unwrap().unwrap().main,
+        header.id()
+    );
+    let main = writeable_block_chain_service.get_main();
+    assert_eq!(main.current_header(), header);
+    assert_eq!(
+        storage.get_block_txn_info_ids(header.id()).unwrap(),
+        storage.get_block_txn_info_ids(old_head.id()).unwrap()
+    );
+    assert_eq!(
+        main.chain_state_reader().get(&access_path).unwrap(),
+        Some(value.clone())
+    );
+
+    // the following blocks are executed on the written state.
+    gen_blocks(
+        times,
+        &mut writeable_block_chain_service,
+        net.time_service().as_ref(),
+    );
+    let main = writeable_block_chain_service.get_main();
+    assert_eq!(main.current_header().number(), old_head.number() + times);
+    assert_eq!(
+        main.chain_state_reader().get(&access_path).unwrap(),
+        Some(value)
+    );
+}
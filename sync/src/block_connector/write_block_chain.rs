@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::metrics::WRITE_BLOCK_CHAIN_METRICS;
-use anyhow::{format_err, Result};
+use anyhow::{ensure, format_err, Result};
 use config::NodeConfig;
 use logger::prelude::*;
+use starcoin_chain::address_txn_index::move_address_txn_index;
+use starcoin_chain::finality::ChainFinality;
 use starcoin_chain::BlockChain;
 use starcoin_chain_api::{ChainReader, ChainWriter, ConnectBlockError, WriteableChainService};
 use starcoin_crypto::HashValue;
@@ -15,9 +17,10 @@ use starcoin_txpool_api::TxPoolSyncService;
 use starcoin_types::{
     block::{Block, BlockHeader, ExecutedBlock},
     startup_info::StartupInfo,
-    system_events::{NewBranch, NewHeadBlock},
+    system_events::{NewBranch, NewHeadBlock, ReorgRefusedEvent},
 };
 use starcoin_vm_types::on_chain_config::GlobalTimeOnChain;
+use starcoin_vm_types::write_set::WriteSet;
 use std::sync::Arc;
 
 const MAX_ROLL_BACK_BLOCK: usize = 10;
@@ -32,6 +35,7 @@ where
     storage: Arc<dyn Store>,
     txpool: P,
     bus: ServiceRef<BusService>,
+    finality: ChainFinality,
 }
 
 impl<P> WriteableChainService for WriteBlockChainService<P>
@@ -56,6 +60,7 @@ where
     ) -> Result<Self> {
         let net = config.net();
         let main = BlockChain::new(net.time_service(), startup_info.main, storage.clone())?;
+        let finality = ChainFinality::new_with_config(config.as_ref());
         Ok(Self {
             config,
             startup_info,
@@ -63,6 +68,7 @@ where
             storage,
             txpool,
             bus,
+            finality,
         })
     }
 
@@ -124,6 +130,9 @@ where
                 } else {
                     (1, vec![block], 0, vec![])
                 };
+            if retracted_count > 0 {
+                self.verify_reorg(&new_branch, retracted_count)?;
+            }
             self.main = new_branch;
 
             self.do_new_head(
@@ -150,6 +159,11 @@ where
     ) -> Result<()> {
         debug_assert!(!enacted_blocks.is_empty());
         debug_assert_eq!(enacted_blocks.last().unwrap(), executed_block.block());
+        move_address_txn_index(
+            self.storage.as_ref(),
+            Some(self.startup_info.main),
+            executed_block.header().id(),
+        )?;
         self.update_startup_info(executed_block.header())?;
         if retracted_count > 0 {
             WRITE_BLOCK_CHAIN_METRICS
@@ -170,6 +184,114 @@ where
         Ok(())
     }
 
+    /// Reset the main chain head to the main chain block `block_id`, the blocks after it are
+    /// retracted but kept in the storage, and their transactions are not returned to the txpool.
+    pub fn reset(&mut self, block_id: HashValue) -> Result<BlockHeader> {
+        let target = self
+            .storage
+            .get_block_header_by_hash(block_id)?
+            .ok_or_else(|| format_err!("Can not find block header by id {}", block_id))?;
+        ensure!(
+            self.main.get_hash_by_number(target.number())? == Some(block_id),
+            "Block {} is not on the main chain, can not reset head to it",
+            block_id
+        );
+        let new_main = self.main.fork(block_id)?;
+        let block = new_main.head_block();
+        let block_info = new_main
+            .get_block_info(Some(block_id))?
+            .ok_or_else(|| format_err!("Can not find block info by id {}", block_id))?;
+        let header = block.header().clone();
+        let retracted_count = self
+            .main
+            .current_header()
+            .number()
+            .saturating_sub(header.number());
+        self.main = new_main;
+        move_address_txn_index(
+            self.storage.as_ref(),
+            Some(self.startup_info.main),
+            header.id(),
+        )?;
+        self.update_startup_info(&header)?;
+        info!(
+            "[chain] Reset head to block id: {}, number: {}, retracted_block_count: {}",
+            header.id(),
+            header.number(),
+            retracted_count
+        );
+        self.broadcast_new_head(ExecutedBlock::new(block, block_info));
+        Ok(header)
+    }
+
+    /// Replace the main chain head by a block whose state is changed by the `write_set`,
+    /// see `BlockChain::apply_write_set_to_head`. Only for dev and test network.
+    pub fn write_state(&mut self, write_set: WriteSet) -> Result<BlockHeader> {
+        let executed_block = self.main.apply_write_set_to_head(write_set)?;
+        let header = executed_block.header().clone();
+        if header.id() == self.startup_info.main {
+            return Ok(header);
+        }
+        move_address_txn_index(
+            self.storage.as_ref(),
+            Some(self.startup_info.main),
+            header.id(),
+        )?;
+        let replaced = self.startup_info.main;
+        self.update_startup_info(&header)?;
+        info!(
+            "[chain] Write state to head, new head id: {}, number: {}, replaced head id: {}",
+            header.id(),
+            header.number(),
+            replaced
+        );
+        self.broadcast_new_head(executed_block);
+        Ok(header)
+    }
+
+    /// Refuse the block if its chain conflicts with a checkpoint.
+    fn verify_checkpoints(&self, chain: &BlockChain, header: &BlockHeader) -> Result<()> {
+        self.finality
+            .verify_checkpoints(chain, header)
+            .map_err(|e| {
+                WRITE_BLOCK_CHAIN_METRICS
+                    .block_connect_count
+                    .with_label_values(&["checkpoint_refused"])
+                    .inc();
+                e
+            })
+    }
+
+    /// Refuse to switch to `new_branch` if it retracts too many main chain blocks,
+    /// and fire a `ReorgRefusedEvent` for alert.
+    fn verify_reorg(&self, new_branch: &BlockChain, retracted_count: u64) -> Result<()> {
+        let main_head = self.main.current_header();
+        let ancestor_number = main_head.number().saturating_sub(retracted_count);
+        if let Err(e) = self
+            .finality
+            .verify_reorg(main_head.number(), ancestor_number)
+        {
+            WRITE_BLOCK_CHAIN_METRICS
+                .block_connect_count
+                .with_label_values(&["reorg_refused"])
+                .inc();
+            WRITE_BLOCK_CHAIN_METRICS
+                .reorg_refused_depth
+                .set(e.depth as i64);
+            warn!("[chain] {}", e);
+            if let Err(err) = self.bus.broadcast(ReorgRefusedEvent {
+                main_head,
+                branch_head: new_branch.current_header(),
+                ancestor_number,
+                depth: e.depth,
+            }) {
+                error!("Broadcast ReorgRefusedEvent error: {:?}", err);
+            }
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
     fn is_main_head(&self, parent_id: &HashValue) -> bool {
         parent_id == &self.startup_info.main
     }
@@ -278,6 +400,7 @@ where
         if self.main.current_header().id() == block.header().parent_hash()
             && !self.block_exist(block_id)
         {
+            self.verify_checkpoints(&self.main, block.header())?;
             let executed_block = self.main.apply(block).map_err(|e| {
                 WRITE_BLOCK_CHAIN_METRICS
                     .block_connect_count
@@ -293,6 +416,7 @@ where
         match (block_exist, fork) {
             //block has bean processed, so just trigger a head select.
             (true, Some(branch)) => {
+                self.verify_checkpoints(&branch, block.header())?;
                 debug!(
                     "Block {} has bean processed, trigger head select, total_difficulty: {}",
                     block_id,
@@ -306,11 +430,13 @@ where
                 Ok(())
             }
             (true, None) => {
+                self.verify_checkpoints(&self.main, block.header())?;
                 let executed_block = self.main.update_chain_head(block.clone())?;
                 self.do_new_head(executed_block, 1, vec![block], 0, vec![])?;
                 Ok(())
             }
             (false, Some(mut branch)) => {
+                self.verify_checkpoints(&branch, block.header())?;
                 let timer = WRITE_BLOCK_CHAIN_METRICS
                     .exe_block_time
                     .with_label_values(&["time"])
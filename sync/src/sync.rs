@@ -13,10 +13,11 @@ use logger::prelude::*;
 use network::NetworkServiceRef;
 use network::PeerEvent;
 use network_api::{PeerProvider, PeerSelector, PeerStrategy, ReputationChange};
+use starcoin_chain::finality::ChainFinality;
 use starcoin_chain::BlockChain;
 use starcoin_chain_api::ChainReader;
 use starcoin_service_registry::{
-    ActorService, EventHandler, ServiceContext, ServiceFactory, ServiceHandler,
+    ActorService, EventHandler, ServiceContext, ServiceFactory, ServiceHandler, SupervisionPolicy,
 };
 use starcoin_storage::block_info::BlockInfoStore;
 use starcoin_storage::{BlockStore, Storage};
@@ -192,6 +193,7 @@ impl SyncService {
                     self_ref.clone(),
                     network.clone(),
                     config.sync.max_retry_times(),
+                    ChainFinality::new_with_config(config.as_ref()),
                 )?;
 
                 self_ref.notify(SyncBeginEvent {
@@ -303,6 +305,14 @@ impl ServiceFactory<Self> for SyncService {
 }
 
 impl ActorService for SyncService {
+    fn supervision_policy() -> SupervisionPolicy {
+        SupervisionPolicy::restart(10)
+    }
+
+    fn dependencies() -> Vec<&'static str> {
+        vec![BlockConnectorService::service_name()]
+    }
+
     fn started(&mut self, ctx: &mut ServiceContext<Self>) -> Result<()> {
         ctx.subscribe::<SystemStarted>();
         ctx.subscribe::<PeerEvent>();
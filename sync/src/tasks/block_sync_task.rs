@@ -10,7 +10,7 @@ use futures::FutureExt;
 use logger::prelude::*;
 use network_api::PeerProvider;
 use starcoin_accumulator::{Accumulator, MerkleAccumulator};
-use starcoin_chain::{verifier::BasicVerifier, BlockChain};
+use starcoin_chain::{finality::ChainFinality, verifier::BasicVerifier, BlockChain};
 use starcoin_chain_api::{ChainReader, ChainWriter, ConnectBlockError, ExecutedBlock};
 use starcoin_sync_api::SyncTarget;
 use starcoin_types::block::{Block, BlockIdAndNumber, BlockInfo, BlockNumber};
@@ -187,6 +187,7 @@ pub struct BlockCollector<N, H> {
     event_handle: H,
     peer_provider: N,
     skip_pow_verify: bool,
+    finality: ChainFinality,
 }
 
 impl<N, H> BlockCollector<N, H>
@@ -201,6 +202,7 @@ where
         event_handle: H,
         peer_provider: N,
         skip_pow_verify: bool,
+        finality: ChainFinality,
     ) -> Self {
         Self {
             current_block_info,
@@ -209,6 +211,7 @@ where
             event_handle,
             peer_provider,
             skip_pow_verify,
+            finality,
         }
     }
 
@@ -222,12 +225,18 @@ where
             .sync_apply_block_time
             .with_label_values(&["time"])
             .start_timer();
-        if let Err(err) = if self.skip_pow_verify {
-            self.chain
-                .apply_with_verifier::<BasicVerifier>(block.clone())
-        } else {
-            self.chain.apply(block.clone())
-        } {
+        if let Err(err) = self
+            .finality
+            .verify_checkpoints(&self.chain, block.header())
+            .and_then(|_| {
+                if self.skip_pow_verify {
+                    self.chain
+                        .apply_with_verifier::<BasicVerifier>(block.clone())
+                } else {
+                    self.chain.apply(block.clone())
+                }
+            })
+        {
             error!(
                 "[sync] collect block error: {:?}, peer_id:{:?} ",
                 err, peer_id
@@ -274,6 +283,8 @@ where
             Some(block_info) => {
                 //If block_info exists, it means that this block was already executed and try connect in the previous sync, but the sync task was interrupted.
                 //So, we just need to update chain and continue
+                self.finality
+                    .verify_checkpoints(&self.chain, block.header())?;
                 self.chain.connect(ExecutedBlock {
                     block,
                     block_info: block_info.clone(),
@@ -5,6 +5,7 @@ use crate::tasks::{
 use anyhow::format_err;
 use network_api::PeerProvider;
 use starcoin_accumulator::node::AccumulatorStoreType;
+use starcoin_chain::finality::ChainFinality;
 use starcoin_chain::BlockChain;
 use starcoin_storage::Store;
 use starcoin_sync_api::SyncTarget;
@@ -30,6 +31,7 @@ where
     time_service: Arc<dyn TimeService>,
     peer_provider: N,
     custom_error_handle: Arc<dyn CustomErrorHandle>,
+    finality: ChainFinality,
 }
 
 impl<H, F, N> InnerSyncTask<H, F, N>
@@ -48,6 +50,7 @@ where
         time_service: Arc<dyn TimeService>,
         peer_provider: N,
         custom_error_handle: Arc<dyn CustomErrorHandle>,
+        finality: ChainFinality,
     ) -> Self {
         Self {
             ancestor,
@@ -59,6 +62,7 @@ where
             time_service,
             peer_provider,
             custom_error_handle,
+            finality,
         }
     }
 
@@ -127,6 +131,7 @@ where
                 self.block_event_handle.clone(),
                 self.peer_provider.clone(),
                 skip_pow_verify_when_sync,
+                self.finality.clone(),
             );
             Ok(TaskGenerator::new(
                 block_sync_task,
@@ -13,6 +13,7 @@ use network_api::{PeerProvider, PeerSelector};
 use network_rpc_core::{NetRpcError, RpcErrorCode};
 use starcoin_accumulator::node::AccumulatorStoreType;
 use starcoin_accumulator::MerkleAccumulator;
+use starcoin_chain::finality::ChainFinality;
 use starcoin_chain::{BlockChain, ChainReader};
 use starcoin_crypto::HashValue;
 use starcoin_service_registry::{ActorService, EventHandler, ServiceRef};
@@ -484,6 +485,7 @@ pub fn full_sync_task<H, A, F, N>(
     ancestor_event_handle: A,
     peer_provider: N,
     max_retry_times: u64,
+    finality: ChainFinality,
 ) -> Result<(
     BoxFuture<'static, Result<BlockChain, TaskError>>,
     TaskHandle,
@@ -540,6 +542,10 @@ where
 
     let all_fut = async move {
         let ancestor = fut.await?;
+        // refuse to sync a fork which retracts too many blocks of the node's main chain.
+        finality
+            .verify_reorg(current_block_number, ancestor.number)
+            .map_err(|e| TaskError::BreakError(e.into()))?;
         let mut ancestor_block_info = storage
             .get_block_info(ancestor.id)
             .map_err(TaskError::BreakError)?
@@ -588,6 +594,7 @@ where
                 time_service.clone(),
                 peer_provider.clone(),
                 ext_error_handle.clone(),
+                finality.clone(),
             );
             let start_now = Instant::now();
             let (block_chain, _) = inner
@@ -11,7 +11,7 @@ use crate::tasks::{
 use crate::verified_rpc_client::RpcVerifyError;
 use anyhow::Context;
 use anyhow::{format_err, Result};
-use config::{BuiltinNetworkID, ChainNetwork};
+use config::{BuiltinNetworkID, ChainNetwork, Checkpoint};
 use futures::channel::mpsc::unbounded;
 use futures::future::BoxFuture;
 use futures::FutureExt;
@@ -22,6 +22,7 @@ use pin_utils::core_reexport::time::Duration;
 use starcoin_accumulator::accumulator_info::AccumulatorInfo;
 use starcoin_accumulator::tree_store::mock::MockAccumulatorStore;
 use starcoin_accumulator::{Accumulator, MerkleAccumulator};
+use starcoin_chain::finality::ChainFinality;
 use starcoin_chain::BlockChain;
 use starcoin_chain_api::ChainReader;
 use starcoin_chain_mock::MockChain;
@@ -71,6 +72,7 @@ pub async fn test_full_sync_new_node() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver_1).await;
     let branch = sync_task.await?;
@@ -100,6 +102,7 @@ pub async fn test_full_sync_new_node() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver_1).await;
     let branch = sync_task.await?;
@@ -148,6 +151,7 @@ pub async fn test_sync_invalid_target() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let _join_handle = node2.process_block_connect_event(receiver_1).await;
     let sync_result = sync_task.await;
@@ -166,6 +170,47 @@ pub async fn test_sync_invalid_target() -> Result<()> {
     Ok(())
 }
 
+#[stest::test]
+pub async fn test_sync_conflict_checkpoint() -> Result<()> {
+    let net1 = ChainNetwork::new_builtin(BuiltinNetworkID::Test);
+    let mut node1 = SyncNodeMocker::new(net1, 1, 0)?;
+    node1.produce_block(10)?;
+
+    let arc_node1 = Arc::new(node1);
+
+    let net2 = ChainNetwork::new_builtin(BuiltinNetworkID::Test);
+
+    let node2 = SyncNodeMocker::new(net2.clone(), 1, 0)?;
+
+    let target = arc_node1.sync_target();
+
+    let current_block_header = node2.chain().current_header();
+
+    let storage = node2.chain().get_storage();
+    let (sender_1, receiver_1) = unbounded();
+    let (sender_2, _receiver_2) = unbounded();
+    let finality = ChainFinality::new(vec![Checkpoint::new(5, HashValue::random())], None);
+    let (sync_task, _task_handle, _task_event_counter) = full_sync_task(
+        current_block_header.id(),
+        target.clone(),
+        false,
+        net2.time_service(),
+        storage.clone(),
+        sender_1,
+        arc_node1.clone(),
+        sender_2,
+        DummyNetworkService::default(),
+        15,
+        finality,
+    )?;
+    let join_handle = node2.process_block_connect_event(receiver_1).await;
+    let sync_result = sync_task.await;
+    assert!(sync_result.is_err());
+    let node2 = join_handle.await;
+    assert!(node2.chain().current_header().number() < 5);
+    Ok(())
+}
+
 #[stest::test]
 pub async fn test_failed_block() -> Result<()> {
     let net = ChainNetwork::new_builtin(BuiltinNetworkID::Halley);
@@ -186,6 +231,7 @@ pub async fn test_failed_block() -> Result<()> {
         sender,
         DummyNetworkService::default(),
         true,
+        ChainFinality::default(),
     );
     let header = BlockHeaderBuilder::random().with_number(1).build();
     let body = BlockBody::new(Vec::new(), None);
@@ -229,6 +275,7 @@ pub async fn test_full_sync_fork() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver).await;
     let branch = sync_task.await?;
@@ -260,6 +307,7 @@ pub async fn test_full_sync_fork() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver).await;
     let branch = sync_task.await?;
@@ -307,6 +355,7 @@ pub async fn test_full_sync_fork_from_genesis() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver).await;
     let branch = sync_task.await?;
@@ -359,6 +408,7 @@ pub async fn test_full_sync_continue() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver).await;
     let branch = sync_task.await?;
@@ -392,6 +442,7 @@ pub async fn test_full_sync_continue() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
 
     let join_handle = node2.process_block_connect_event(receiver).await;
@@ -442,6 +493,7 @@ pub async fn test_full_sync_cancel() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let join_handle = node2.process_block_connect_event(receiver).await;
     let sync_join_handle = tokio::task::spawn(sync_task);
@@ -883,6 +935,7 @@ async fn test_net_rpc_err() -> Result<()> {
         sender_2,
         DummyNetworkService::default(),
         15,
+        ChainFinality::default(),
     )?;
     let _join_handle = node2.process_block_connect_event(receiver).await;
     let sync_join_handle = tokio::task::spawn(sync_task);
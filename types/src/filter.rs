@@ -1,8 +1,10 @@
 //! Blockchain filter
 
+use crate::account_address::AccountAddress;
 use crate::block::BlockNumber;
 use crate::contract_event::ContractEvent;
 use crate::event::EventKey;
+use crate::language_storage::TypeTag;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Filter {
@@ -16,6 +18,18 @@ pub struct Filter {
     /// If empty, match all.
     /// If specified, event must produced from one of the event keys.
     pub event_keys: Vec<EventKey>,
+    /// Search events.
+    ///
+    /// If empty, match all.
+    /// If specified, event must produced from one of the event keys created by the addresses.
+    pub addrs: Vec<AccountAddress>,
+    /// Search events.
+    ///
+    /// If empty, match all.
+    /// If specified, event type must match one of the type tags.
+    /// A struct type tag without type params matches all instantiations of the struct,
+    /// such as `0x1::Token::MintEvent` matches `0x1::Token::MintEvent<0x1::STC::STC>`.
+    pub type_tags: Vec<TypeTag>,
     /// Events limit
     ///
     /// If None, return all events
@@ -31,6 +45,8 @@ impl Default for Filter {
             from_block: 0,
             to_block: 0,
             event_keys: vec![],
+            addrs: vec![],
+            type_tags: vec![],
             limit: None,
             reverse: true,
         }
@@ -42,9 +58,70 @@ impl Filter {
         if self.from_block <= block_number
             && block_number <= self.to_block
             && (self.event_keys.is_empty() || self.event_keys.contains(e.key()))
+            && (self.addrs.is_empty() || self.addrs.contains(&e.key().get_creator_address()))
+            && (self.type_tags.is_empty()
+                || self
+                    .type_tags
+                    .iter()
+                    .any(|type_tag| type_tag_matching(type_tag, e.type_tag())))
         {
             return true;
         }
         false
     }
 }
+
+/// Whether the event type tag matches the `filter` type tag,
+/// a struct `filter` without type params matches all instantiations of the struct.
+fn type_tag_matching(filter: &TypeTag, type_tag: &TypeTag) -> bool {
+    match (filter, type_tag) {
+        (TypeTag::Struct(filter), TypeTag::Struct(struct_tag)) if filter.type_params.is_empty() => {
+            filter.address == struct_tag.address
+                && filter.module == struct_tag.module
+                && filter.name == struct_tag.name
+        }
+        _ => filter == type_tag,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starcoin_vm_types::parser::parse_type_tag;
+
+    #[test]
+    fn test_filter_type_tags_and_addrs() {
+        let address = AccountAddress::random();
+        let event = ContractEvent::new(
+            EventKey::new_from_address(&address, 0),
+            0,
+            parse_type_tag("0x1::Token::MintEvent<0x1::STC::STC>").unwrap(),
+            vec![],
+        );
+        let filter = |addrs: Vec<AccountAddress>, type_tags: Vec<&str>| Filter {
+            from_block: 0,
+            to_block: 10,
+            addrs,
+            type_tags: type_tags
+                .into_iter()
+                .map(|t| parse_type_tag(t).unwrap())
+                .collect(),
+            ..Default::default()
+        };
+        assert!(filter(vec![], vec![]).matching(1, &event));
+        assert!(filter(vec![address], vec![]).matching(1, &event));
+        assert!(!filter(vec![AccountAddress::random()], vec![]).matching(1, &event));
+        assert!(filter(vec![], vec!["0x1::Token::MintEvent"]).matching(1, &event));
+        assert!(filter(vec![], vec!["0x1::Token::MintEvent<0x1::STC::STC>"]).matching(1, &event));
+        assert!(
+            !filter(vec![], vec!["0x1::Token::MintEvent<0x1::Dummy::Dummy>"]).matching(1, &event)
+        );
+        assert!(!filter(vec![], vec!["0x1::Token::BurnEvent"]).matching(1, &event));
+        assert!(filter(
+            vec![address],
+            vec!["0x1::Token::BurnEvent", "0x1::Token::MintEvent"]
+        )
+        .matching(1, &event));
+        assert!(!filter(vec![address], vec!["0x1::Token::MintEvent"]).matching(11, &event));
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::block::{Block, BlockHeaderExtra, ExecutedBlock};
+use crate::block::{Block, BlockHeader, BlockHeaderExtra, ExecutedBlock};
 use crate::sync_status::SyncStatus;
 use crate::U256;
 use starcoin_vm_types::genesis_config::ConsensusStrategy;
@@ -17,6 +17,16 @@ pub struct NewBranch(pub Arc<ExecutedBlock>);
 #[derive(Clone, Debug)]
 pub struct MinedBlock(pub Arc<Block>);
 
+/// Fire this event when a fork with more total difficulty is refused,
+/// because it retracts more main chain blocks than the max reorg depth.
+#[derive(Clone, Debug)]
+pub struct ReorgRefusedEvent {
+    pub main_head: BlockHeader,
+    pub branch_head: BlockHeader,
+    pub ancestor_number: u64,
+    pub depth: u64,
+}
+
 ///Fire this event on System start and all service is init.
 #[derive(Clone, Debug)]
 pub struct SystemStarted;
@@ -0,0 +1,356 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Detailed module upgrade compatibility check, explain why a new module version is not
+//! compatible with the previous one.
+
+use serde::{Deserialize, Serialize};
+use starcoin_vm_types::compatibility::Compatibility;
+use starcoin_vm_types::file_format::{Ability, AbilitySet, CompiledModule};
+use starcoin_vm_types::language_storage::ModuleId;
+use starcoin_vm_types::normalized::{Field, Module, Type};
+use std::fmt;
+
+/// How an element of a struct or function, such as a field or a parameter, is changed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ElementChange {
+    Added { new: String },
+    Removed { old: String },
+    Changed { old: String, new: String },
+}
+
+impl fmt::Display for ElementChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { new } => write!(f, "added {}", new),
+            Self::Removed { old } => write!(f, "removed {}", old),
+            Self::Changed { old, new } => write!(f, "changed from {} to {}", old, new),
+        }
+    }
+}
+
+/// An incompatible change between two versions of a module.
+/// The fields, type parameters and parameters are compared by their position.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IncompatibleChange {
+    StructRemoved {
+        name: String,
+    },
+    StructAbility {
+        name: String,
+        change: ElementChange,
+    },
+    StructTypeParameter {
+        name: String,
+        index: usize,
+        change: ElementChange,
+    },
+    StructField {
+        name: String,
+        index: usize,
+        change: ElementChange,
+    },
+    FunctionRemoved {
+        name: String,
+    },
+    FunctionTypeParameter {
+        name: String,
+        index: usize,
+        change: ElementChange,
+    },
+    FunctionParameter {
+        name: String,
+        index: usize,
+        change: ElementChange,
+    },
+    FunctionReturn {
+        name: String,
+        index: usize,
+        change: ElementChange,
+    },
+    /// The function signature is changed in other ways, such as the visibility.
+    FunctionSignatureChanged {
+        name: String,
+    },
+}
+
+impl fmt::Display for IncompatibleChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StructRemoved { name } => write!(f, "struct {} removed", name),
+            Self::StructAbility { name, change } => {
+                write!(f, "struct {} ability: {}", name, change)
+            }
+            Self::StructTypeParameter {
+                name,
+                index,
+                change,
+            } => write!(f, "struct {} type parameter #{}: {}", name, index, change),
+            Self::StructField {
+                name,
+                index,
+                change,
+            } => write!(f, "struct {} field #{}: {}", name, index, change),
+            Self::FunctionRemoved { name } => write!(f, "public function {} removed", name),
+            Self::FunctionTypeParameter {
+                name,
+                index,
+                change,
+            } => write!(
+                f,
+                "public function {} type parameter #{}: {}",
+                name, index, change
+            ),
+            Self::FunctionParameter {
+                name,
+                index,
+                change,
+            } => write!(
+                f,
+                "public function {} parameter #{}: {}",
+                name, index, change
+            ),
+            Self::FunctionReturn {
+                name,
+                index,
+                change,
+            } => write!(
+                f,
+                "public function {} return value #{}: {}",
+                name, index, change
+            ),
+            Self::FunctionSignatureChanged { name } => {
+                write!(f, "public function {} signature changed", name)
+            }
+        }
+    }
+}
+
+/// The compatibility report of a module upgrade.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    pub module_id: ModuleId,
+    pub compatible: bool,
+    pub changes: Vec<IncompatibleChange>,
+}
+
+impl CompatibilityReport {
+    pub fn new(old: &Module, new: &Module) -> Self {
+        let compatible = Compatibility::check(old, new).is_fully_compatible();
+        let mut changes = vec![];
+        for (name, old_struct) in &old.structs {
+            let new_struct = match new.structs.get(name) {
+                Some(new_struct) => new_struct,
+                None => {
+                    changes.push(IncompatibleChange::StructRemoved {
+                        name: name.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let name = name.to_string();
+            let old_abilities = abilities(old_struct.abilities);
+            let new_abilities = abilities(new_struct.abilities);
+            for ability in &old_abilities {
+                if !new_abilities.contains(ability) {
+                    changes.push(IncompatibleChange::StructAbility {
+                        name: name.clone(),
+                        change: ElementChange::Removed {
+                            old: ability.clone(),
+                        },
+                    });
+                }
+            }
+            for ability in &new_abilities {
+                if !old_abilities.contains(ability) {
+                    changes.push(IncompatibleChange::StructAbility {
+                        name: name.clone(),
+                        change: ElementChange::Added {
+                            new: ability.clone(),
+                        },
+                    });
+                }
+            }
+            for (index, change) in diff_elements(
+                &type_parameters(&old_struct.type_parameters),
+                &type_parameters(&new_struct.type_parameters),
+            ) {
+                changes.push(IncompatibleChange::StructTypeParameter {
+                    name: name.clone(),
+                    index,
+                    change,
+                });
+            }
+            for (index, change) in
+                diff_elements(&fields(&old_struct.fields), &fields(&new_struct.fields))
+            {
+                changes.push(IncompatibleChange::StructField {
+                    name: name.clone(),
+                    index,
+                    change,
+                });
+            }
+        }
+        for (name, old_func) in &old.exposed_functions {
+            let new_func = match new.exposed_functions.get(name) {
+                Some(new_func) => new_func,
+                None => {
+                    changes.push(IncompatibleChange::FunctionRemoved {
+                        name: name.to_string(),
+                    });
+                    continue;
+                }
+            };
+            if new_func == old_func {
+                continue;
+            }
+            let name = name.to_string();
+            let changes_before = changes.len();
+            for (index, change) in diff_elements(
+                &type_parameters(&old_func.type_parameters),
+                &type_parameters(&new_func.type_parameters),
+            ) {
+                changes.push(IncompatibleChange::FunctionTypeParameter {
+                    name: name.clone(),
+                    index,
+                    change,
+                });
+            }
+            for (index, change) in
+                diff_elements(&types(&old_func.parameters), &types(&new_func.parameters))
+            {
+                changes.push(IncompatibleChange::FunctionParameter {
+                    name: name.clone(),
+                    index,
+                    change,
+                });
+            }
+            for (index, change) in
+                diff_elements(&types(&old_func.return_), &types(&new_func.return_))
+            {
+                changes.push(IncompatibleChange::FunctionReturn {
+                    name: name.clone(),
+                    index,
+                    change,
+                });
+            }
+            if changes.len() == changes_before {
+                changes.push(IncompatibleChange::FunctionSignatureChanged { name });
+            }
+        }
+        Self {
+            module_id: new.module_id(),
+            compatible,
+            changes,
+        }
+    }
+
+    pub fn from_compiled_module(old: &CompiledModule, new: &CompiledModule) -> Self {
+        Self::new(&Module::new(old), &Module::new(new))
+    }
+}
+
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.compatible {
+            return write!(f, "module {} is compatible", self.module_id);
+        }
+        write!(f, "module {} is incompatible", self.module_id)?;
+        for change in &self.changes {
+            write!(f, "\n  - {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare the elements by position.
+fn diff_elements(old: &[String], new: &[String]) -> Vec<(usize, ElementChange)> {
+    (0..std::cmp::max(old.len(), new.len()))
+        .filter_map(|index| {
+            match (old.get(index), new.get(index)) {
+                (Some(old), Some(new)) if old != new => Some(ElementChange::Changed {
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+                (Some(old), None) => Some(ElementChange::Removed { old: old.clone() }),
+                (None, Some(new)) => Some(ElementChange::Added { new: new.clone() }),
+                _ => None,
+            }
+            .map(|change| (index, change))
+        })
+        .collect()
+}
+
+fn abilities(abilities: AbilitySet) -> Vec<String> {
+    abilities
+        .into_iter()
+        .map(|ability| match ability {
+            Ability::Copy => "copy",
+            Ability::Drop => "drop",
+            Ability::Store => "store",
+            Ability::Key => "key",
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+fn type_parameters(type_parameters: &[AbilitySet]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .enumerate()
+        .map(|(index, constraints)| {
+            let constraints = abilities(*constraints);
+            if constraints.is_empty() {
+                format!("T{}", index)
+            } else {
+                format!("T{}: {}", index, constraints.join(" + "))
+            }
+        })
+        .collect()
+}
+
+fn fields(fields: &[Field]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, type_to_string(&field.type_)))
+        .collect()
+}
+
+fn types(types: &[Type]) -> Vec<String> {
+    types.iter().map(type_to_string).collect()
+}
+
+fn type_to_string(type_: &Type) -> String {
+    match type_ {
+        Type::Bool => "bool".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::U128 => "u128".to_string(),
+        Type::Address => "address".to_string(),
+        Type::Signer => "signer".to_string(),
+        Type::Struct {
+            address,
+            module,
+            name,
+            type_arguments,
+        } => {
+            let module_id = ModuleId::new(*address, module.clone());
+            if type_arguments.is_empty() {
+                format!("{}::{}", module_id, name)
+            } else {
+                format!(
+                    "{}::{}<{}>",
+                    module_id,
+                    name,
+                    types(type_arguments).join(", ")
+                )
+            }
+        }
+        Type::Vector(element) => format!("vector<{}>", type_to_string(element)),
+        Type::TypeParameter(index) => format!("T{}", index),
+        Type::Reference(inner) => format!("&{}", type_to_string(inner)),
+        Type::MutableReference(inner) => format!("&mut {}", type_to_string(inner)),
+    }
+}
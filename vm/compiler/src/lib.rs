@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 /// A wrap to move-lang compiler
+use crate::compatibility::CompatibilityReport;
 use crate::shared::Address;
 use anyhow::{bail, ensure, Result};
 use once_cell::sync::Lazy;
@@ -22,6 +23,9 @@ pub use move_lang::{
     move_compile, move_compile_and_report, MOVE_COMPILED_EXTENSION, MOVE_EXTENSION,
 };
 
+pub mod compatibility;
+pub mod package;
+
 pub mod errors {
     pub use move_lang::errors::*;
 }
@@ -149,6 +153,21 @@ pub fn check_module_compat(pre_code: &[u8], new_code: &[u8]) -> VMResult<bool> {
     Ok(Compatibility::check(&old, &new).is_fully_compatible())
 }
 
+/// check module compatibility, and return a report with the incompatible changes.
+pub fn check_module_compat_report(
+    pre_code: &[u8],
+    new_code: &[u8],
+) -> VMResult<CompatibilityReport> {
+    let pre_module =
+        CompiledModule::deserialize(pre_code).map_err(|e| e.finish(Location::Undefined))?;
+    let new_module =
+        CompiledModule::deserialize(new_code).map_err(|e| e.finish(Location::Undefined))?;
+    Ok(CompatibilityReport::from_compiled_module(
+        &pre_module,
+        &new_module,
+    ))
+}
+
 /// check module compatibility
 pub fn check_compiled_module_compat(pre: &CompiledModule, new: &CompiledModule) -> bool {
     let old = Module::new(pre);
@@ -186,7 +205,12 @@ pub fn load_bytecode_file<P: AsRef<Path>>(file_path: P) -> Result<(Vec<u8>, bool
 mod tests {
     use super::*;
     use crate::command_line::parse_address;
+    use crate::compatibility::{ElementChange, IncompatibleChange};
+    use crate::package::{build_package, CompiledPackage};
+    use starcoin_vm_types::identifier::IdentStr;
     use starcoin_vm_types::language_storage::CORE_CODE_ADDRESS;
+    use starcoin_vm_types::module_abi::{ModuleABI, TypeABI};
+    use std::str::FromStr;
 
     #[test]
     fn test_unknown_place_holder() {
@@ -287,6 +311,236 @@ mod tests {
         }
     }
 
+    #[stest::test]
+    fn test_compat_report() {
+        let pre_version = r#"
+            module M {
+                struct M{
+                    value: u64,
+                }
+
+                public fun hello(){
+                }
+
+                public fun world(_v: u64){
+                }
+            }
+        "#;
+        let new_version = r#"
+            module M {
+                struct M has copy{
+                    value: u64,
+                    new_field: address,
+                }
+
+                public fun world(_v: u128){
+                }
+            }
+        "#;
+        let pre_code = compile_test_module(pre_version);
+        let new_code = compile_test_module(new_version);
+        let report = check_module_compat_report(pre_code.as_slice(), new_code.as_slice()).unwrap();
+        assert!(!report.compatible);
+        assert_eq!(
+            report.changes,
+            vec![
+                IncompatibleChange::StructAbility {
+                    name: "M".to_string(),
+                    change: ElementChange::Added {
+                        new: "copy".to_string()
+                    },
+                },
+                IncompatibleChange::StructField {
+                    name: "M".to_string(),
+                    index: 1,
+                    change: ElementChange::Added {
+                        new: "new_field: address".to_string()
+                    },
+                },
+                IncompatibleChange::FunctionRemoved {
+                    name: "hello".to_string()
+                },
+                IncompatibleChange::FunctionParameter {
+                    name: "world".to_string(),
+                    index: 0,
+                    change: ElementChange::Changed {
+                        old: "u64".to_string(),
+                        new: "u128".to_string()
+                    },
+                },
+            ]
+        );
+        assert!(report
+            .to_string()
+            .contains("public function world parameter #0: changed from u64 to u128"));
+
+        let report = check_module_compat_report(pre_code.as_slice(), pre_code.as_slice()).unwrap();
+        assert!(report.compatible);
+        assert!(report.changes.is_empty());
+    }
+
+    #[stest::test]
+    fn test_build_package() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        let on_chain_code = compile_test_module(
+            r#"
+            module Remote {
+                public fun value(): u64 {
+                    1
+                }
+            }
+        "#,
+        );
+
+        std::fs::create_dir_all(root.join("utils/sources")).unwrap();
+        std::fs::write(
+            root.join("utils/Package.toml"),
+            r#"
+            [package]
+            name = "Utils"
+            address = "0x2"
+        "#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("utils/sources/Utils.move"),
+            r#"
+            address {{sender}} {
+            module Utils {
+                public fun double(v: u64): u64 {
+                    v * 2
+                }
+            }
+            }
+        "#,
+        )
+        .unwrap();
+
+        std::fs::create_dir_all(root.join("app/sources")).unwrap();
+        std::fs::write(
+            root.join("app/Package.toml"),
+            r#"
+            [package]
+            name = "App"
+            address = "0x3"
+
+            [addresses]
+            Utils = "0x2"
+
+            [dependencies]
+            Utils = { local = "../utils" }
+            Remote = { address = "0x1", modules = ["Remote"] }
+        "#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("app/sources/App.move"),
+            r#"
+            module App {
+                use {{Utils}}::Utils;
+                use 0x1::Remote;
+
+                public(script) fun run(_account: signer, v: u64) {
+                    assert(Utils::double(v) > Remote::value(), 1000);
+                }
+            }
+        "#,
+        )
+        .unwrap();
+
+        let work_dir = root.join("build");
+        std::fs::create_dir_all(work_dir.as_path()).unwrap();
+        let (_, units) = build_package(root.join("app").as_path(), &[], work_dir.as_path(), |id| {
+            Ok(if id.name().as_str() == "Remote" {
+                Some(on_chain_code.clone())
+            } else {
+                None
+            })
+        })
+        .unwrap();
+        let compiled_package = CompiledPackage::new(units.unwrap()).unwrap();
+        assert_eq!(
+            compiled_package.package.package_address(),
+            AccountAddress::from_str("0x3").unwrap()
+        );
+        assert_eq!(compiled_package.package.modules().len(), 1);
+        assert_eq!(compiled_package.abis.len(), 1);
+        assert_eq!(compiled_package.abis[0].name(), "run");
+        assert_eq!(compiled_package.abis[0].args().len(), 1);
+    }
+
+    #[stest::test]
+    fn test_module_abi() {
+        let code = compile_test_module(
+            r#"
+            module Abi {
+                struct Wrapper<T: copy + drop> has copy, drop {
+                    value: T,
+                }
+
+                struct Counter has key {
+                    count: u64,
+                }
+
+                public fun unwrap<T: copy + drop>(w: &Wrapper<T>): T {
+                    *&w.value
+                }
+
+                public(script) fun init(account: signer, count: u64) {
+                    move_to(&account, Counter { count })
+                }
+
+                fun one(): u64 {
+                    1
+                }
+            }
+        "#,
+        );
+        let module = CompiledModule::deserialize(code.as_slice()).unwrap();
+        let abi = ModuleABI::new(&module);
+        assert_eq!(abi.module_id.name().as_str(), "Abi");
+
+        let unwrap = abi.function(IdentStr::new("unwrap").unwrap()).unwrap();
+        assert_eq!(unwrap.visibility, "public");
+        assert_eq!(unwrap.type_parameters.len(), 1);
+        assert_eq!(unwrap.type_parameters[0].abilities, vec!["copy", "drop"]);
+        assert!(matches!(&unwrap.parameters[0], TypeABI::Reference(_)));
+        assert_eq!(unwrap.returns, vec![TypeABI::TypeParameter(0)]);
+        assert_eq!(
+            abi.function(IdentStr::new("init").unwrap())
+                .unwrap()
+                .visibility,
+            "script"
+        );
+        assert_eq!(
+            abi.function(IdentStr::new("one").unwrap())
+                .unwrap()
+                .visibility,
+            "private"
+        );
+
+        let counter = abi.struct_abi(IdentStr::new("Counter").unwrap()).unwrap();
+        assert_eq!(counter.abilities, vec!["key"]);
+        assert_eq!(counter.fields.len(), 1);
+        assert_eq!(counter.fields[0].type_, TypeABI::U64);
+
+        // the signer argument is skipped in script function ABI.
+        assert_eq!(abi.script_functions.len(), 1);
+        assert_eq!(abi.script_functions[0].name(), "init");
+        assert_eq!(abi.script_functions[0].args().len(), 1);
+    }
+
+    fn compile_test_module(source_code: &str) -> Vec<u8> {
+        compile_source_string_no_report(source_code, &[], CORE_CODE_ADDRESS)
+            .unwrap()
+            .1
+            .unwrap()
+            .pop()
+            .unwrap()
+            .serialize()
+    }
+
     fn do_test_compat(pre_source_code: &str, new_source_code: &str, expect: bool) {
         let pre_code = compile_source_string_no_report(pre_source_code, &[], CORE_CODE_ADDRESS)
             .unwrap()
@@ -0,0 +1,285 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Build a Move package, which is a directory with a `Package.toml` manifest like:
+//!
+//! ```toml
+//! [package]
+//! name = "MyPackage"
+//! address = "0x1dcd9f05cc902e4f342a404ade878efa"
+//!
+//! [addresses]
+//! Alice = "0x2"
+//!
+//! [dependencies]
+//! Utils = { local = "../utils" }
+//! Token = { address = "0x1", modules = ["Token", "Account"] }
+//! ```
+//!
+//! The package address and the named addresses can be used in sources as `{{sender}}` and
+//! `{{Alice}}`. A dependency is resolved from a local path, or from the published bytecode of
+//! a node, the interface stubs of published modules are derived for compilation.
+
+use crate::shared::Address;
+use crate::{move_compile, process_source_tpl, CompiledUnit, Errors, FilesSourceText};
+use anyhow::{ensure, format_err, Result};
+use serde::{Deserialize, Serialize};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::file_format::CompiledModule;
+use starcoin_vm_types::identifier::Identifier;
+use starcoin_vm_types::language_storage::ModuleId;
+use starcoin_vm_types::module_abi::script_function_abis;
+use starcoin_vm_types::transaction::{Module, Package, ScriptFunctionABI};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const PACKAGE_MANIFEST_FILE: &str = "Package.toml";
+pub const DEFAULT_SOURCES_DIR: &str = "sources";
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageInfo {
+    pub name: String,
+    /// The address all modules of the package published at.
+    pub address: String,
+    /// The sources dir relative to the package dir, default is `sources`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Dependency {
+    /// A local package dir with manifest, or a dir/file of move sources.
+    Local { local: PathBuf },
+    /// Modules published on chain.
+    OnChain {
+        address: String,
+        modules: Vec<String>,
+    },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageManifest {
+    pub package: PackageInfo,
+    #[serde(default)]
+    pub addresses: BTreeMap<String, String>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
+}
+
+impl PackageManifest {
+    pub fn load<P: AsRef<Path>>(package_dir: P) -> Result<Self> {
+        let manifest_file = package_dir.as_ref().join(PACKAGE_MANIFEST_FILE);
+        ensure!(
+            manifest_file.is_file(),
+            "can not find package manifest {:?}",
+            manifest_file
+        );
+        let content = std::fs::read_to_string(manifest_file.as_path())?;
+        toml::from_str(content.as_str())
+            .map_err(|e| format_err!("invalid package manifest {:?}: {}", manifest_file, e))
+    }
+
+    pub fn address(&self) -> Result<AccountAddress> {
+        parse_account_address(self.package.address.as_str())
+    }
+
+    pub fn sources_dir(&self, package_dir: &Path) -> PathBuf {
+        package_dir.join(
+            self.package
+                .sources
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_SOURCES_DIR)),
+        )
+    }
+
+    /// The placeholder variables of the package sources, `sender` is the package address.
+    pub fn named_addresses(&self) -> Result<HashMap<&str, String>> {
+        let mut vars = HashMap::new();
+        for (name, address) in &self.addresses {
+            let address = Address::new(parse_account_address(address.as_str())?.into());
+            vars.insert(name.as_str(), format!("{}", address));
+        }
+        Ok(vars)
+    }
+}
+
+fn parse_account_address(address: &str) -> Result<AccountAddress> {
+    AccountAddress::from_str(address).map_err(|e| format_err!("invalid address {}: {}", address, e))
+}
+
+/// The result of package build.
+pub struct CompiledPackage {
+    pub package: Package,
+    pub abis: Vec<ScriptFunctionABI>,
+}
+
+impl CompiledPackage {
+    pub fn new(units: Vec<CompiledUnit>) -> Result<Self> {
+        let mut modules = vec![];
+        let mut abis = vec![];
+        for unit in units {
+            let code = unit.serialize();
+            let module = CompiledModule::deserialize(code.as_slice())
+                .map_err(|_| format_err!("package can only contain modules"))?;
+            abis.extend(script_function_abis(&module));
+            modules.push(Module::new(code));
+        }
+        Ok(Self {
+            package: Package::new_with_modules(modules)?,
+            abis,
+        })
+    }
+}
+
+/// Build the package in `package_dir`, the preprocessed sources and the published dependencies
+/// are written to `work_dir`. `deps` is the extra dependencies (such as stdlib), and `fetcher`
+/// is used to get the published bytecode of on chain dependencies.
+pub fn build_package<F>(
+    package_dir: &Path,
+    deps: &[String],
+    work_dir: &Path,
+    fetcher: F,
+) -> Result<(FilesSourceText, Result<Vec<CompiledUnit>, Errors>)>
+where
+    F: Fn(&ModuleId) -> Result<Option<Vec<u8>>>,
+{
+    let manifest = PackageManifest::load(package_dir)?;
+    let sender = Address::new(manifest.address()?.into());
+    let mut deps = deps.to_vec();
+    let mut resolved = HashSet::new();
+    resolved.insert(package_dir.canonicalize()?);
+    resolve_dependencies(
+        &manifest,
+        package_dir,
+        work_dir,
+        &fetcher,
+        &mut resolved,
+        &mut deps,
+    )?;
+    let targets = preprocess_package_sources(&manifest, package_dir, work_dir)?;
+    ensure!(
+        !targets.is_empty(),
+        "package {} does not contain any source file",
+        manifest.package.name
+    );
+    let interface_dir = work_dir.join("interfaces");
+    std::fs::create_dir_all(interface_dir.as_path())?;
+    move_compile(
+        &targets,
+        &deps,
+        Some(sender),
+        Some(path_to_string(interface_dir.as_path())?),
+        true,
+    )
+}
+
+fn resolve_dependencies<F>(
+    manifest: &PackageManifest,
+    package_dir: &Path,
+    work_dir: &Path,
+    fetcher: &F,
+    resolved: &mut HashSet<PathBuf>,
+    deps: &mut Vec<String>,
+) -> Result<()>
+where
+    F: Fn(&ModuleId) -> Result<Option<Vec<u8>>>,
+{
+    for (name, dependency) in &manifest.dependencies {
+        match dependency {
+            Dependency::Local { local } => {
+                let path = package_dir.join(local);
+                ensure!(path.exists(), "dependency {} {:?} not exist", name, path);
+                if !resolved.insert(path.canonicalize()?) {
+                    continue;
+                }
+                if path.join(PACKAGE_MANIFEST_FILE).is_file() {
+                    let dep_manifest = PackageManifest::load(path.as_path())?;
+                    deps.extend(preprocess_package_sources(
+                        &dep_manifest,
+                        path.as_path(),
+                        work_dir,
+                    )?);
+                    resolve_dependencies(
+                        &dep_manifest,
+                        path.as_path(),
+                        work_dir,
+                        fetcher,
+                        resolved,
+                        deps,
+                    )?;
+                } else {
+                    deps.push(path_to_string(path.as_path())?);
+                }
+            }
+            Dependency::OnChain { address, modules } => {
+                let address = parse_account_address(address.as_str())?;
+                let dep_dir = work_dir.join("dependencies");
+                std::fs::create_dir_all(dep_dir.as_path())?;
+                for module_name in modules {
+                    let module_id = ModuleId::new(address, Identifier::new(module_name.as_str())?);
+                    let code = fetcher(&module_id)?.ok_or_else(|| {
+                        format_err!("dependency {} module {} not found", name, module_id)
+                    })?;
+                    let module_file = dep_dir
+                        .join(format!("{}_{}", address, module_name))
+                        .with_extension(crate::MOVE_COMPILED_EXTENSION);
+                    std::fs::write(module_file.as_path(), code)?;
+                    deps.push(path_to_string(module_file.as_path())?);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replace the placeholders of package sources, and write them to `work_dir/<package name>`.
+fn preprocess_package_sources(
+    manifest: &PackageManifest,
+    package_dir: &Path,
+    work_dir: &Path,
+) -> Result<Vec<String>> {
+    let sender = Address::new(manifest.address()?.into());
+    let vars = manifest.named_addresses()?;
+    let sources_dir = manifest.sources_dir(package_dir);
+    ensure!(
+        sources_dir.is_dir(),
+        "sources dir {:?} not exist",
+        sources_dir
+    );
+    let out_dir = work_dir.join(manifest.package.name.as_str());
+    let mut files = vec![];
+    for source_file in find_move_files(sources_dir.as_path())? {
+        let relative_path = source_file.strip_prefix(sources_dir.as_path())?;
+        let out_file = out_dir.join(relative_path);
+        if let Some(parent) = out_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let source = std::fs::read_to_string(source_file.as_path())?;
+        let processed_source = process_source_tpl(source.as_str(), sender, vars.clone());
+        std::fs::write(out_file.as_path(), processed_source)?;
+        files.push(path_to_string(out_file.as_path())?);
+    }
+    Ok(files)
+}
+
+fn find_move_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(find_move_files(path.as_path())?);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some(crate::MOVE_EXTENSION) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn path_to_string(path: &Path) -> Result<String> {
+    path.to_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| format_err!("path {:?} is not a valid utf8 str", path))
+}
@@ -0,0 +1,172 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Gas profiler breaks the gas used of a dry-run transaction down by charging phase, by the
+//! calls into the Move VM and by the accounts written, with the gas charged by the VM gas meter.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use starcoin_vm_runtime::gas_breakdown::GasBreakdown;
+use starcoin_vm_runtime::starcoin_vm::StarcoinVM;
+use starcoin_vm_types::state_view::StateView;
+use starcoin_vm_types::transaction::{DryRunTransaction, TransactionOutput};
+use starcoin_vm_types::vm_status::VMStatus;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FrameGas {
+    pub function: String,
+    pub calls: u64,
+    pub gas: u64,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasProfile {
+    pub gas_used: u64,
+    /// Gas charged for the transaction size.
+    pub intrinsic: u64,
+    /// Gas charged for executing the Move code.
+    pub execution: u64,
+    /// Gas charged for the mutated accounts after execution.
+    pub storage_write: u64,
+    pub mutated_accounts: u64,
+    /// Execution gas charged out of the recorded calls, such as before an aborted transaction.
+    pub unattributed: u64,
+    /// Gas by called function, sorted by gas.
+    pub frames: Vec<FrameGas>,
+}
+
+impl GasProfile {
+    pub fn new(gas_used: u64, gas_breakdown: GasBreakdown) -> Self {
+        let mut frames: BTreeMap<String, FrameGas> = BTreeMap::new();
+        for frame in &gas_breakdown.frames {
+            let frame_gas = frames
+                .entry(frame.function.clone())
+                .or_insert_with(|| FrameGas {
+                    function: frame.function.clone(),
+                    ..Default::default()
+                });
+            frame_gas.calls += 1;
+            frame_gas.gas += frame.gas;
+        }
+        let frame_gas: u64 = frames.values().map(|frame| frame.gas).sum();
+        let mut frames: Vec<_> = frames.into_iter().map(|(_, frame)| frame).collect();
+        frames.sort_by(|a, b| b.gas.cmp(&a.gas));
+        Self {
+            gas_used,
+            intrinsic: gas_breakdown.intrinsic,
+            execution: gas_breakdown.execution,
+            storage_write: gas_breakdown.storage_write,
+            mutated_accounts: gas_breakdown.mutated_accounts,
+            unattributed: gas_breakdown.execution.saturating_sub(frame_gas),
+            frames,
+        }
+    }
+
+    /// Render the profile as a flamegraph compatible folded stacks file.
+    pub fn to_folded(&self) -> String {
+        let mut lines = vec![];
+        for (name, gas) in &[
+            ("intrinsic", self.intrinsic),
+            ("storage_write", self.storage_write),
+            ("unattributed", self.unattributed),
+        ] {
+            if *gas > 0 {
+                lines.push(format!("{} {}", name, gas));
+            }
+        }
+        for frame in &self.frames {
+            if frame.gas > 0 {
+                lines.push(format!("{} {}", frame.function, frame.gas));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Dry run the transaction with the gas profiler.
+pub fn profile_dry_run(
+    state_view: &dyn StateView,
+    txn: DryRunTransaction,
+) -> Result<(VMStatus, TransactionOutput, GasProfile)> {
+    let mut vm = StarcoinVM::new();
+    let (status, output, gas_breakdown) =
+        vm.dry_run_transaction_with_gas_breakdown(state_view, txn)?;
+    let profile = GasProfile::new(output.gas_used(), gas_breakdown);
+    Ok((status, output, profile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starcoin_vm_runtime::gas_breakdown::FrameGas as CallGas;
+
+    #[test]
+    fn test_profile_from_gas_breakdown() {
+        let gas_breakdown = GasBreakdown {
+            intrinsic: 10,
+            execution: 20,
+            storage_write: 6,
+            mutated_accounts: 2,
+            frames: vec![
+                CallGas {
+                    function: "publish(0x1::M)".to_string(),
+                    gas: 4,
+                },
+                CallGas {
+                    function: "0x1::M::init".to_string(),
+                    gas: 5,
+                },
+                CallGas {
+                    function: "publish(0x1::M)".to_string(),
+                    gas: 3,
+                },
+            ],
+        };
+        let profile = GasProfile::new(gas_breakdown.total(), gas_breakdown);
+        assert_eq!(profile.gas_used, 36);
+        assert_eq!(profile.unattributed, 8);
+        assert_eq!(
+            profile.frames,
+            vec![
+                FrameGas {
+                    function: "publish(0x1::M)".to_string(),
+                    calls: 2,
+                    gas: 7,
+                },
+                FrameGas {
+                    function: "0x1::M::init".to_string(),
+                    calls: 1,
+                    gas: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_folded() {
+        let profile = GasProfile {
+            intrinsic: 10,
+            storage_write: 0,
+            unattributed: 5,
+            frames: vec![
+                FrameGas {
+                    function: "0x1::M::g".to_string(),
+                    calls: 1,
+                    gas: 7,
+                },
+                FrameGas {
+                    function: "0x1::M::f".to_string(),
+                    calls: 1,
+                    gas: 0,
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            profile.to_folded(),
+            "intrinsic 10\nunattributed 5\n0x1::M::g 7"
+        );
+        assert_eq!(GasProfile::default().to_folded(), "");
+    }
+}
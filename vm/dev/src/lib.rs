@@ -1,4 +1,6 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod gas_profiler;
 pub mod playground;
+pub mod state_overlay;
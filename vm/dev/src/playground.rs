@@ -1,19 +1,23 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_overlay::{StateOverlay, StateOverride};
 use anyhow::Result;
+use serde_json::Value;
 use starcoin_crypto::HashValue;
+use starcoin_resource_viewer::json_arg::JsonArgEncoder;
 use starcoin_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator};
 use starcoin_state_api::StateNodeStore;
 use starcoin_statedb::ChainStateDB;
 use starcoin_vm_runtime::starcoin_vm::StarcoinVM;
 use starcoin_vm_types::identifier::{IdentStr, Identifier};
-use starcoin_vm_types::language_storage::{ModuleId, StructTag, TypeTag};
+use starcoin_vm_types::language_storage::{FunctionId, ModuleId, StructTag, TypeTag};
 use starcoin_vm_types::state_view::StateView;
 use starcoin_vm_types::transaction::{DryRunTransaction, TransactionOutput};
 use starcoin_vm_types::transaction_argument::convert_txn_args;
 use starcoin_vm_types::transaction_argument::TransactionArgument;
 use starcoin_vm_types::vm_status::VMStatus;
+use starcoin_vm_types::write_set::WriteSet;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -32,11 +36,23 @@ impl PlaygroudService {
         &self,
         state_root: HashValue,
         txn: DryRunTransaction,
+        overrides: Vec<StateOverride>,
     ) -> Result<(VMStatus, TransactionOutput)> {
         let state_view = ChainStateDB::new(self.state.clone(), Some(state_root));
+        let state_view = StateOverlay::new(&state_view, overrides)?;
         dry_run(&state_view, txn)
     }
 
+    /// Resolve the overrides on the state of `state_root` to a write set, which can be written into the chain state.
+    pub fn state_write_set(
+        &self,
+        state_root: HashValue,
+        overrides: Vec<StateOverride>,
+    ) -> Result<WriteSet> {
+        let state_view = ChainStateDB::new(self.state.clone(), Some(state_root));
+        StateOverlay::new(&state_view, overrides)?.into_write_set()
+    }
+
     pub fn call_contract(
         &self,
         state_root: HashValue,
@@ -44,11 +60,51 @@ impl PlaygroudService {
         func: Identifier,
         type_args: Vec<TypeTag>,
         args: Vec<TransactionArgument>,
+        overrides: Vec<StateOverride>,
     ) -> Result<Vec<AnnotatedMoveValue>> {
         let state_view = ChainStateDB::new(self.state.clone(), Some(state_root));
+        let state_view = StateOverlay::new(&state_view, overrides)?;
         let rets = call_contract(&state_view, module_id, func.as_str(), type_args, args)?;
         Ok(rets)
     }
+
+    /// Call contract with the JSON args, which are encoded by the function signature.
+    pub fn call_contract_with_json_args(
+        &self,
+        state_root: HashValue,
+        module_id: ModuleId,
+        func: Identifier,
+        type_args: Vec<TypeTag>,
+        args: Vec<Value>,
+        overrides: Vec<StateOverride>,
+    ) -> Result<Vec<AnnotatedMoveValue>> {
+        let state_view = ChainStateDB::new(self.state.clone(), Some(state_root));
+        let state_view = StateOverlay::new(&state_view, overrides)?;
+        let args = JsonArgEncoder::new(&state_view).encode_function_args(
+            &module_id,
+            func.as_ident_str(),
+            type_args.as_slice(),
+            args.as_slice(),
+        )?;
+        call_contract_with_bcs_args(&state_view, module_id, func.as_str(), type_args, args)
+    }
+
+    /// Encode the JSON args of the function to BCS args.
+    pub fn encode_json_args(
+        &self,
+        state_root: HashValue,
+        function_id: &FunctionId,
+        type_args: &[TypeTag],
+        args: &[Value],
+    ) -> Result<Vec<Vec<u8>>> {
+        let state_view = ChainStateDB::new(self.state.clone(), Some(state_root));
+        JsonArgEncoder::new(&state_view).encode_function_args(
+            &function_id.module,
+            function_id.function.as_ident_str(),
+            type_args,
+            args,
+        )
+    }
     pub fn view_resource(
         &self,
         state_root: HashValue,
@@ -84,6 +140,22 @@ pub fn call_contract(
     func: &str,
     type_args: Vec<TypeTag>,
     args: Vec<TransactionArgument>,
+) -> Result<Vec<AnnotatedMoveValue>> {
+    call_contract_with_bcs_args(
+        state_view,
+        module_id,
+        func,
+        type_args,
+        convert_txn_args(&args),
+    )
+}
+
+pub fn call_contract_with_bcs_args(
+    state_view: &dyn StateView,
+    module_id: ModuleId,
+    func: &str,
+    type_args: Vec<TypeTag>,
+    args: Vec<Vec<u8>>,
 ) -> Result<Vec<AnnotatedMoveValue>> {
     let mut vm = StarcoinVM::new();
     let rets = vm.execute_readonly_function(
@@ -91,7 +163,7 @@ pub fn call_contract(
         &module_id,
         &IdentStr::new(func)?,
         type_args,
-        convert_txn_args(&args),
+        args,
     )?;
     let annotator = MoveValueAnnotator::new(state_view);
     let mut annotated_values = Vec::with_capacity(rets.len());
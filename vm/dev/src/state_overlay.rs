@@ -0,0 +1,130 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{format_err, Result};
+use serde_json::Value;
+use starcoin_resource_viewer::json_arg::JsonArgEncoder;
+use starcoin_vm_types::access::ModuleAccess;
+use starcoin_vm_types::access_path::AccessPath;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::BalanceResource;
+use starcoin_vm_types::file_format::CompiledModule;
+use starcoin_vm_types::language_storage::TypeTag;
+use starcoin_vm_types::state_view::StateView;
+use starcoin_vm_types::write_set::{WriteOp, WriteSet, WriteSetMut};
+use std::collections::BTreeMap;
+
+/// A change of the state which only lives in the `StateOverlay`.
+#[derive(Clone, Debug)]
+pub enum StateOverride {
+    /// Replace the value at the access path with the BCS bytes, or remove it if `None`.
+    Value {
+        access_path: AccessPath,
+        value: Option<Vec<u8>>,
+    },
+    /// Replace the resource at the access path with the JSON value, encoded by the resource type
+    /// in the same way as JSON args.
+    JsonValue {
+        access_path: AccessPath,
+        value: Value,
+    },
+    /// Set the balance of the token for the address.
+    Balance {
+        address: AccountAddress,
+        token: TypeTag,
+        amount: u128,
+    },
+    /// Add or replace the module with the bytecode, without compatibility check.
+    Module { code: Vec<u8> },
+}
+
+/// A temporary state view which applies the overrides on top of the base state view,
+/// nothing is written to the base state.
+pub struct StateOverlay<'a> {
+    base: &'a dyn StateView,
+    overrides: BTreeMap<AccessPath, Option<Vec<u8>>>,
+}
+
+impl<'a> StateOverlay<'a> {
+    /// Resolve the overrides in order, so a JSON value can use the module overridden before it.
+    pub fn new(base: &'a dyn StateView, overrides: Vec<StateOverride>) -> Result<Self> {
+        let mut overlay = Self {
+            base,
+            overrides: BTreeMap::new(),
+        };
+        for state_override in overrides {
+            let (access_path, value) = overlay.resolve(state_override)?;
+            overlay.overrides.insert(access_path, value);
+        }
+        Ok(overlay)
+    }
+
+    /// Convert the resolved overrides to a write set, for writing them into the base state.
+    pub fn into_write_set(self) -> Result<WriteSet> {
+        WriteSetMut::new(
+            self.overrides
+                .into_iter()
+                .map(|(access_path, value)| {
+                    let op = match value {
+                        Some(value) => WriteOp::Value(value),
+                        None => WriteOp::Deletion,
+                    };
+                    (access_path, op)
+                })
+                .collect(),
+        )
+        .freeze()
+    }
+
+    fn resolve(&self, state_override: StateOverride) -> Result<(AccessPath, Option<Vec<u8>>)> {
+        Ok(match state_override {
+            StateOverride::Value { access_path, value } => (access_path, value),
+            StateOverride::JsonValue { access_path, value } => {
+                let struct_tag = access_path
+                    .path
+                    .as_struct_tag()
+                    .ok_or_else(|| {
+                        format_err!("JSON value only support resource, but got {}", access_path)
+                    })?
+                    .clone();
+                let value = JsonArgEncoder::new(self)
+                    .encode(&TypeTag::Struct(struct_tag), &value)
+                    .map_err(|e| format_err!("Invalid value of {}: {}", access_path, e))?;
+                (access_path, Some(value))
+            }
+            StateOverride::Balance {
+                address,
+                token,
+                amount,
+            } => (
+                AccessPath::new(address, BalanceResource::access_path_for(token)),
+                Some(bcs_ext::to_bytes(&BalanceResource::new(amount))?),
+            ),
+            StateOverride::Module { code } => {
+                let module = CompiledModule::deserialize(code.as_slice())
+                    .map_err(|e| format_err!("Deserialize module with error: {:?}", e))?;
+                (AccessPath::from(&module.self_id()), Some(code))
+            }
+        })
+    }
+}
+
+impl<'a> StateView for StateOverlay<'a> {
+    fn get(&self, access_path: &AccessPath) -> Result<Option<Vec<u8>>> {
+        match self.overrides.get(access_path) {
+            Some(value) => Ok(value.clone()),
+            None => self.base.get(access_path),
+        }
+    }
+
+    fn multi_get(&self, access_paths: &[AccessPath]) -> Result<Vec<Option<Vec<u8>>>> {
+        access_paths
+            .iter()
+            .map(|access_path| self.get(access_path))
+            .collect()
+    }
+
+    fn is_genesis(&self) -> bool {
+        self.base.is_genesis()
+    }
+}
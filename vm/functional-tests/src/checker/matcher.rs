@@ -276,6 +276,7 @@ where
         .iter()
         .map(|output| match output {
             EvaluationOutput::Error(e) => format!("{:?}", e.root_cause()),
+            EvaluationOutput::Output(output) => format!("Output({})", output.to_check_string()),
             _ => format!("{:?}", output),
         })
         .collect();
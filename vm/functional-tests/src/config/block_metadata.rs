@@ -11,6 +11,7 @@ use std::str::FromStr;
 pub enum Entry {
     Author(String),
     Timestamp(u64),
+    AdvanceTime(u64),
     Number(u64),
     Uncles(u64),
 }
@@ -34,6 +35,9 @@ impl FromStr for Entry {
         if let Some(s) = strip(s, "block-time:") {
             return Ok(Entry::Timestamp(s.parse::<u64>()?));
         }
+        if let Some(s) = strip(s, "advance-time:") {
+            return Ok(Entry::AdvanceTime(s.parse::<u64>()?));
+        }
         if let Some(s) = strip(s, "block-number:") {
             return Ok(Entry::Number(s.parse::<u64>()?));
         }
@@ -67,8 +71,36 @@ impl Entry {
     }
 }
 
+/// The number and timestamp of the parent block.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ParentBlock {
+    pub number: u64,
+    pub timestamp: u64,
+}
+
+impl From<&BlockMetadata> for ParentBlock {
+    fn from(block_metadata: &BlockMetadata) -> Self {
+        Self {
+            number: block_metadata.number(),
+            timestamp: block_metadata.timestamp(),
+        }
+    }
+}
+
 pub fn build_block_metadata(config: &GlobalConfig, entries: &[Entry]) -> Result<BlockMetadata> {
+    build_block_metadata_with_parent(config, entries, None)
+}
+
+/// Build block metadata, the block number defaults to the parent block number plus one, and
+/// the `advance-time` is relative to the parent block time. If there is no parent block, the
+/// `advance-time` is relative to the genesis.
+pub fn build_block_metadata_with_parent(
+    config: &GlobalConfig,
+    entries: &[Entry],
+    parent: Option<ParentBlock>,
+) -> Result<BlockMetadata> {
     let mut timestamp = None;
+    let mut advance_time = None;
     let mut author = None;
     let mut author_auth_key = None;
     let mut number = None;
@@ -81,20 +113,33 @@ pub fn build_block_metadata(config: &GlobalConfig, entries: &[Entry]) -> Result<
                 author = Some(*account.address());
                 author_auth_key = Some(account.auth_key());
             }
-            Entry::Timestamp(new_timestamp) => timestamp = Some(new_timestamp),
-            Entry::Number(new_number) => number = Some(new_number),
+            Entry::Timestamp(new_timestamp) => timestamp = Some(*new_timestamp),
+            Entry::AdvanceTime(new_advance_time) => advance_time = Some(*new_advance_time),
+            Entry::Number(new_number) => number = Some(*new_number),
             Entry::Uncles(new_uncles) => uncles = *new_uncles,
         }
     }
+    let parent = match advance_time {
+        Some(advance_time) => {
+            if timestamp.is_some() {
+                bail!("block-time and advance-time can not be both set");
+            }
+            let parent = parent.unwrap_or_default();
+            timestamp = Some(parent.timestamp + advance_time);
+            Some(parent)
+        }
+        None => parent,
+    };
+    let number = number.or_else(|| parent.map(|parent| parent.number + 1));
     //TODO support read timestamp from FakeExecutor's net.time_service()
     if let (Some(t), Some(author), Some(number)) = (timestamp, author, number) {
         Ok(BlockMetadata::new(
             HashValue::random(),
-            *t,
+            t,
             author,
             author_auth_key,
             uncles,
-            *number,
+            number,
             ChainId::test(),
             0,
         ))
@@ -4,8 +4,9 @@
 use crate::{common::strip, config::global::Config as GlobalConfig, errors::*, evaluator::Stage};
 use executor::account::Account;
 use starcoin_vm_types::{
-    language_storage::TypeTag,
-    parser::{parse_transaction_argument, parse_type_tags},
+    account_address::AccountAddress,
+    language_storage::{StructTag, TypeTag},
+    parser::{parse_struct_tag, parse_transaction_argument, parse_type_tags},
     transaction_argument::TransactionArgument,
 };
 use std::{collections::BTreeSet, str::FromStr};
@@ -42,6 +43,7 @@ pub enum Entry {
     GasPrice(u64),
     SequenceNumber(u64),
     ExpirationTime(u64),
+    ReadResource(String, StructTag),
 }
 
 impl FromStr for Entry {
@@ -92,6 +94,21 @@ impl FromStr for Entry {
         if let Some(s) = strip(s, "expiration-time:") {
             return Ok(Entry::ExpirationTime(s.parse::<u64>()?));
         }
+        if let Some(s) = strip(s, "read-resource:") {
+            let mut parts = s.splitn(2, ',');
+            return match (parts.next(), parts.next()) {
+                (Some(account), Some(struct_tag)) if !account.is_empty() => {
+                    Ok(Entry::ReadResource(
+                        account.to_ascii_lowercase(),
+                        parse_struct_tag(struct_tag)?,
+                    ))
+                }
+                _ => Err(ErrorKind::Other(
+                    "read-resource expects '<account>, <struct tag>'".to_string(),
+                )
+                .into()),
+            };
+        }
 
         Err(ErrorKind::Other(format!(
             "failed to parse '{}' as transaction config entry",
@@ -132,6 +149,8 @@ pub struct Config<'a> {
     pub gas_price: Option<u64>,
     pub sequence_number: Option<u64>,
     pub expiration_time: Option<u64>,
+    /// Resources to read after the transaction is executed.
+    pub read_resources: Vec<(AccountAddress, StructTag)>,
 }
 
 impl<'a> Config<'a> {
@@ -145,6 +164,7 @@ impl<'a> Config<'a> {
         let mut gas_price = None;
         let mut sequence_number = None;
         let mut expiration_time = None;
+        let mut read_resources = vec![];
 
         for entry in entries {
             match entry {
@@ -210,6 +230,14 @@ impl<'a> Config<'a> {
                         )
                     }
                 },
+                Entry::ReadResource(account, struct_tag) => {
+                    let address = if account.starts_with("0x") {
+                        AccountAddress::from_hex_literal(account)?
+                    } else {
+                        *config.get_account_for_name(account)?.address()
+                    };
+                    read_resources.push((address, struct_tag.clone()));
+                }
                 Entry::ExpirationTime(sn) => match expiration_time {
                     None => expiration_time = Some(*sn),
                     Some(_) => {
@@ -230,6 +258,7 @@ impl<'a> Config<'a> {
             gas_price,
             sequence_number,
             expiration_time,
+            read_resources,
         })
     }
 
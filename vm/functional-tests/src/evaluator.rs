@@ -11,6 +11,7 @@ use mirai_annotations::checked_verify;
 use once_cell::sync::Lazy;
 use starcoin_account_api::AccountPrivateKey;
 use starcoin_config::DEFAULT_GAS_CONSTANTS;
+use starcoin_resource_viewer::MoveValueAnnotator;
 use starcoin_types::{
     access_path::AccessPath,
     account_address::AccountAddress,
@@ -29,7 +30,7 @@ use starcoin_vm_types::{
     errors::{Location, VMError},
     file_format::{CompiledModule, CompiledScript},
     gas_schedule::GasAlgebra,
-    language_storage::ModuleId,
+    language_storage::{ModuleId, StructTag, TypeTag},
     state_view::StateView,
     views::ModuleView,
 };
@@ -100,11 +101,18 @@ pub enum OutputType {
     CompiledScript(Box<CompiledScript>),
     CompilerLog(String),
     TransactionOutput(Box<TransactionOutput>),
+    /// An event emitted by the transaction, with the decoded event data.
+    Event(TypeTag, String),
+    /// A resource read after the transaction, None if the resource does not exist.
+    Resource(AccountAddress, StructTag, Option<String>),
 }
 
 impl OutputType {
     pub fn to_check_string(&self) -> String {
-        format!("{:?}", self)
+        match self {
+            Self::Event(..) | Self::Resource(..) => self.to_string(),
+            _ => format!("{:?}", self),
+        }
     }
 }
 
@@ -169,6 +177,13 @@ impl fmt::Display for OutputType {
             CompiledScript(cs) => write!(f, "{:#?}", cs),
             CompilerLog(s) => write!(f, "{}", s),
             TransactionOutput(output) => write!(f, "{:#?}", output),
+            Event(type_tag, data) => write!(f, "Event {}: {}", type_tag, data),
+            Resource(address, struct_tag, Some(value)) => {
+                write!(f, "Resource {} {}: {}", address, struct_tag, value)
+            }
+            Resource(address, struct_tag, None) => {
+                write!(f, "Resource {} {}: not exists", address, struct_tag)
+            }
         }
     }
 }
@@ -381,6 +396,50 @@ fn run_transaction(
     }
 }
 
+/// Logs the transaction output, the decoded events of the output, and the resources to read.
+fn log_transaction_output(
+    exec: &FakeExecutor,
+    config: &TransactionConfig,
+    output: TransactionOutput,
+    log: &mut EvaluationLog,
+) {
+    let events = decode_events(exec, &output);
+    log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
+        Box::new(output),
+    )));
+    log.outputs.extend(events);
+    let annotator = MoveValueAnnotator::new(exec.get_state_view());
+    for (address, struct_tag) in &config.read_resources {
+        let access_path = AccessPath::resource_access_path(*address, struct_tag.clone());
+        let value = exec.read_from_access_path(&access_path).map(|blob| {
+            annotator
+                .view_struct(struct_tag.clone(), blob.as_slice())
+                .map(|value| value.to_string())
+                .unwrap_or_else(|e| format!("failed to decode resource: {}", e))
+        });
+        log.append(EvaluationOutput::Output(OutputType::Resource(
+            *address,
+            struct_tag.clone(),
+            value,
+        )));
+    }
+}
+
+fn decode_events(exec: &FakeExecutor, output: &TransactionOutput) -> Vec<EvaluationOutput> {
+    let annotator = MoveValueAnnotator::new(exec.get_state_view());
+    output
+        .events()
+        .iter()
+        .map(|event| {
+            let data = annotator
+                .view_contract_event(event)
+                .map(|value| value.to_string())
+                .unwrap_or_else(|_| format!("{:?}", event.event_data()));
+            EvaluationOutput::Output(OutputType::Event(event.type_tag().clone(), data))
+        })
+        .collect()
+}
+
 /// Serializes the script then deserializes it.
 fn serialize_and_deserialize_script(script: &CompiledScript) -> Result<()> {
     let mut script_blob = vec![];
@@ -497,9 +556,7 @@ fn eval_transaction<TComp: Compiler>(
             let script_transaction =
                 make_script_transaction(&exec, &transaction.config, compiled_script)?;
             let txn_output = unwrap_or_abort!(run_transaction(exec, script_transaction));
-            log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
-                Box::new(txn_output),
-            )));
+            log_transaction_output(exec, &transaction.config, txn_output, log);
         }
         ScriptOrModule::Module(compiled_module) => {
             log.append(EvaluationOutput::Output(OutputType::CompiledModule(
@@ -535,9 +592,7 @@ fn eval_transaction<TComp: Compiler>(
             let module_transaction =
                 make_module_transaction(&exec, &transaction.config, compiled_module)?;
             let txn_output = unwrap_or_abort!(run_transaction(exec, module_transaction));
-            log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
-                Box::new(txn_output),
-            )));
+            log_transaction_output(exec, &transaction.config, txn_output, log);
         }
     }
     Ok(Status::Success)
@@ -559,9 +614,11 @@ pub fn eval_block_metadata(
             match output.status() {
                 TransactionStatus::Keep(_status) => {
                     executor.apply_write_set(output.write_set());
+                    let events = decode_events(executor, &output);
                     log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
                         Box::new(output),
                     )));
+                    log.outputs.extend(events);
                     Ok(Status::Success)
                 }
                 TransactionStatus::Discard(status) => {
@@ -0,0 +1,183 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Gas snapshot records the `gas_used` of every transaction of a test into a sidecar file
+//! `<test>.gas`, and checks the gas used does not regress beyond a tolerance.
+//!
+//! The mode is set by env `GAS_SNAPSHOT`:
+//!     - `record`: write the gas used of the test to the sidecar file.
+//!     - `check`: compare the gas used with the sidecar file if it exists.
+//! The tolerance is set by env `GAS_SNAPSHOT_TOLERANCE` in percent, default is 5.
+
+use crate::{
+    errors::*,
+    evaluator::{EvaluationLog, EvaluationOutput, OutputType, TransactionId},
+};
+use std::{collections::BTreeMap, env, fmt, path::Path, str::FromStr};
+
+pub const GAS_SNAPSHOT: &str = "GAS_SNAPSHOT";
+pub const GAS_SNAPSHOT_TOLERANCE: &str = "GAS_SNAPSHOT_TOLERANCE";
+pub const GAS_SNAPSHOT_EXTENSION: &str = "gas";
+pub const DEFAULT_GAS_SNAPSHOT_TOLERANCE: u64 = 5;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GasSnapshotMode {
+    Record,
+    Check,
+}
+
+impl FromStr for GasSnapshotMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "record" => Ok(Self::Record),
+            "check" => Ok(Self::Check),
+            _ => bail!("unrecognized gas snapshot mode '{}'", s),
+        }
+    }
+}
+
+impl GasSnapshotMode {
+    /// Gets the gas snapshot mode from env, None if the gas snapshot is disabled.
+    pub fn from_env() -> Result<Option<Self>> {
+        match env::var(GAS_SNAPSHOT) {
+            Ok(mode) if !mode.is_empty() => Ok(Some(mode.to_ascii_lowercase().parse()?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+pub fn tolerance_from_env() -> Result<u64> {
+    match env::var(GAS_SNAPSHOT_TOLERANCE) {
+        Ok(tolerance) if !tolerance.is_empty() => Ok(tolerance.parse()?),
+        _ => Ok(DEFAULT_GAS_SNAPSHOT_TOLERANCE),
+    }
+}
+
+/// A transaction which uses more gas than the snapshot.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GasRegression {
+    pub transaction: TransactionId,
+    pub snapshot: u64,
+    pub gas_used: u64,
+}
+
+impl fmt::Display for GasRegression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "transaction {} gas used {}, snapshot {}",
+            self.transaction, self.gas_used, self.snapshot
+        )
+    }
+}
+
+/// The gas used of each executed transaction, by transaction id.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GasSnapshot(BTreeMap<TransactionId, u64>);
+
+impl GasSnapshot {
+    pub fn from_log(log: &EvaluationLog) -> Self {
+        let mut gas_used = BTreeMap::new();
+        let mut current_txn = None;
+        for output in &log.outputs {
+            match output {
+                EvaluationOutput::Transaction(idx) => current_txn = Some(*idx),
+                EvaluationOutput::Status(_) => current_txn = None,
+                EvaluationOutput::Output(OutputType::TransactionOutput(output)) => {
+                    if let Some(idx) = current_txn {
+                        gas_used.insert(idx, output.gas_used());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self(gas_used)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut gas_used = BTreeMap::new();
+        for line in std::fs::read_to_string(path)?.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(idx), Some(gas), None) => {
+                    gas_used.insert(idx.parse()?, gas.parse()?);
+                }
+                _ => bail!("invalid gas snapshot line '{}'", line),
+            }
+        }
+        Ok(Self(gas_used))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content: String = self
+            .0
+            .iter()
+            .map(|(idx, gas)| format!("{} {}\n", idx, gas))
+            .collect();
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Returns the transactions whose gas used exceeds the snapshot by more than
+    /// `tolerance` percent.
+    pub fn regressions(&self, snapshot: &GasSnapshot, tolerance: u64) -> Vec<GasRegression> {
+        self.0
+            .iter()
+            .filter_map(|(idx, gas_used)| {
+                let snapshot = *snapshot.0.get(idx)?;
+                let limit = snapshot as u128 * (100 + tolerance as u128) / 100;
+                if *gas_used as u128 > limit {
+                    Some(GasRegression {
+                        transaction: *idx,
+                        snapshot,
+                        gas_used: *gas_used,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn get(&self, transaction: TransactionId) -> Option<u64> {
+        self.0.get(&transaction).copied()
+    }
+}
+
+/// Records or checks the gas snapshot of the test file according to the env.
+pub fn process_gas_snapshot(test_path: &Path, log: &EvaluationLog) -> Result<()> {
+    let mode = match GasSnapshotMode::from_env()? {
+        Some(mode) => mode,
+        None => return Ok(()),
+    };
+    let snapshot_path = test_path.with_extension(GAS_SNAPSHOT_EXTENSION);
+    let gas_snapshot = GasSnapshot::from_log(log);
+    match mode {
+        GasSnapshotMode::Record => gas_snapshot.save(snapshot_path.as_path()),
+        GasSnapshotMode::Check => {
+            if !snapshot_path.exists() {
+                return Ok(());
+            }
+            let snapshot = GasSnapshot::load(snapshot_path.as_path())?;
+            let regressions = gas_snapshot.regressions(&snapshot, tolerance_from_env()?);
+            if regressions.is_empty() {
+                Ok(())
+            } else {
+                bail!(
+                    "gas regressions of {}:\n{}",
+                    test_path.display(),
+                    regressions
+                        .iter()
+                        .map(|regression| format!("    {}\n", regression))
+                        .collect::<String>()
+                )
+            }
+        }
+    }
+}
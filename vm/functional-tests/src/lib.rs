@@ -10,6 +10,7 @@ pub mod config;
 pub mod errors;
 pub mod evaluator;
 pub mod executor;
+pub mod gas_snapshot;
 mod genesis_accounts;
 pub mod preprocessor;
 #[cfg(test)]
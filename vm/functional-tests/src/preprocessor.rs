@@ -5,7 +5,9 @@ use crate::{
     checker::Directive,
     common::LineSp,
     config::{
-        block_metadata::{build_block_metadata, is_new_block, Entry as BlockEntry},
+        block_metadata::{
+            build_block_metadata_with_parent, is_new_block, Entry as BlockEntry, ParentBlock,
+        },
         global::{Config as GlobalConfig, Entry as GlobalConfigEntry},
         transaction::{
             is_new_transaction, Config as TransactionConfig, Entry as TransactionConfigEntry,
@@ -163,6 +165,7 @@ pub fn build_transactions<'a>(
     config: &'a GlobalConfig,
     command_inputs: &[RawCommand],
 ) -> Result<Vec<Command<'a>>> {
+    let mut parent = None;
     command_inputs
         .iter()
         .map(|command_input| match command_input {
@@ -170,9 +173,11 @@ pub fn build_transactions<'a>(
                 config: TransactionConfig::build(config, &txn_input.config_entries)?,
                 input: substitute_addresses(config, &txn_input.text.join("\n")),
             })),
-            RawCommand::BlockMetadata(entries) => Ok(Command::BlockMetadata(build_block_metadata(
-                config, &entries,
-            )?)),
+            RawCommand::BlockMetadata(entries) => {
+                let block_metadata = build_block_metadata_with_parent(config, &entries, parent)?;
+                parent = Some(ParentBlock::from(&block_metadata));
+                Ok(Command::BlockMetadata(block_metadata))
+            }
         })
         .collect()
 }
@@ -3,7 +3,10 @@
 
 use crate::{
     config::{
-        block_metadata::{build_block_metadata, is_new_block, Entry},
+        block_metadata::{
+            build_block_metadata, build_block_metadata_with_parent, is_new_block, Entry,
+            ParentBlock,
+        },
         global::Config as GlobalConfig,
     },
     errors::*,
@@ -51,6 +54,21 @@ fn parse_timestamp() {
     }
 }
 
+#[test]
+fn parse_advance_time() {
+    for s in &["//! advance-time:1000", "//!advance-time: 0"] {
+        s.parse::<Entry>().unwrap();
+    }
+
+    for s in &[
+        "//! advance-time:",
+        "//! advance-time: -1",
+        "//! advance-time: abc",
+    ] {
+        s.parse::<Entry>().unwrap_err();
+    }
+}
+
 #[test]
 fn parse_new_transaction() {
     assert!(is_new_block("//! block-prologue"));
@@ -98,3 +116,41 @@ fn build_transaction_config_3() {
         //! block-time: 6000
     ").unwrap_err();
 }
+
+#[rustfmt::skip]
+#[test]
+fn build_block_metadata_with_parent_block() {
+    let global = parse_and_build_global_config(r"
+        //! account: alice
+    ").unwrap();
+    let entries = |s: &str| parse_each_line_as::<Entry>(s).unwrap();
+
+    let block = build_block_metadata_with_parent(&global, &entries(r"
+        //! author: alice
+        //! advance-time: 1000
+    "), None).unwrap();
+    assert_eq!(block.number(), 1);
+    assert_eq!(block.timestamp(), 1000);
+
+    let parent = Some(ParentBlock::from(&block));
+    let block = build_block_metadata_with_parent(&global, &entries(r"
+        //! author: alice
+        //! advance-time: 2000
+    "), parent).unwrap();
+    assert_eq!(block.number(), 2);
+    assert_eq!(block.timestamp(), 3000);
+
+    let block = build_block_metadata_with_parent(&global, &entries(r"
+        //! author: alice
+        //! block-time: 5000
+        //! block-number: 5
+    "), parent).unwrap();
+    assert_eq!(block.number(), 5);
+    assert_eq!(block.timestamp(), 5000);
+
+    build_block_metadata_with_parent(&global, &entries(r"
+        //! author: alice
+        //! block-time: 5000
+        //! advance-time: 2000
+    "), parent).unwrap_err();
+}
@@ -0,0 +1,68 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    evaluator::{EvaluationLog, EvaluationOutput, OutputType, Status},
+    gas_snapshot::GasSnapshot,
+};
+use starcoin_types::{
+    transaction::{TransactionOutput, TransactionStatus},
+    write_set::WriteSet,
+};
+use starcoin_vm_types::vm_status::KeptVMStatus;
+
+fn txn_output(gas_used: u64) -> EvaluationOutput {
+    EvaluationOutput::Output(OutputType::TransactionOutput(Box::new(
+        TransactionOutput::new(
+            WriteSet::default(),
+            vec![],
+            gas_used,
+            TransactionStatus::Keep(KeptVMStatus::Executed),
+        ),
+    )))
+}
+
+fn make_log(txns: &[(usize, u64)]) -> EvaluationLog {
+    let mut log = EvaluationLog::new();
+    for (idx, gas_used) in txns {
+        log.append(EvaluationOutput::Transaction(*idx));
+        log.append(txn_output(*gas_used));
+        log.append(EvaluationOutput::Status(Status::Success));
+        // block metadata output should not be recorded.
+        log.append(txn_output(0));
+        log.append(EvaluationOutput::Status(Status::Success));
+    }
+    log
+}
+
+#[test]
+fn gas_snapshot_from_log() {
+    let snapshot = GasSnapshot::from_log(&make_log(&[(0, 100), (2, 200)]));
+    assert_eq!(snapshot.get(0), Some(100));
+    assert_eq!(snapshot.get(1), None);
+    assert_eq!(snapshot.get(2), Some(200));
+}
+
+#[test]
+fn gas_snapshot_save_and_load() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("test.gas");
+    let snapshot = GasSnapshot::from_log(&make_log(&[(0, 100), (2, 200)]));
+    snapshot.save(path.as_path()).unwrap();
+    assert_eq!(GasSnapshot::load(path.as_path()).unwrap(), snapshot);
+
+    std::fs::write(path.as_path(), "0 100 1\n").unwrap();
+    GasSnapshot::load(path.as_path()).unwrap_err();
+}
+
+#[test]
+fn gas_snapshot_regressions() {
+    let snapshot = GasSnapshot::from_log(&make_log(&[(0, 100), (1, 200)]));
+    let current = GasSnapshot::from_log(&make_log(&[(0, 105), (1, 220), (2, 1000)]));
+    let regressions = current.regressions(&snapshot, 5);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].transaction, 1);
+    assert_eq!(regressions[0].snapshot, 200);
+    assert_eq!(regressions[0].gas_used, 220);
+    assert!(current.regressions(&snapshot, 10).is_empty());
+}
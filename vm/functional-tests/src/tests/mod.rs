@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod block_metadata_config_tests;
+mod gas_snapshot_tests;
 mod global_config_tests;
 mod preprocessor_tests;
 mod transaction_config_tests;
@@ -95,6 +95,25 @@ fn parse_sequence_number() {
     // Rewrite the parser to handle this case properly.
 }
 
+#[test]
+fn parse_read_resource() {
+    for s in &[
+        "//! read-resource: alice, 0x1::Account::Account",
+        "//! read-resource: 0x1, 0x1::Account::Balance<0x1::STC::STC>",
+    ] {
+        s.parse::<Entry>().unwrap();
+    }
+
+    for s in &[
+        "//! read-resource:",
+        "//! read-resource: alice",
+        "//! read-resource: alice, u64",
+        "//! read-resource: , 0x1::Account::Account",
+    ] {
+        s.parse::<Entry>().unwrap_err();
+    }
+}
+
 #[test]
 fn parse_new_transaction() {
     assert!(is_new_transaction("//! new-transaction"));
@@ -145,3 +164,22 @@ fn build_transaction_config_3() {
         //! args: {{bob}}
     ").unwrap_err();
 }
+
+#[rustfmt::skip]
+#[test]
+fn build_transaction_config_read_resource() {
+    let global = parse_and_build_global_config(r"
+        //! account: alice
+    ").unwrap();
+
+    let config = parse_and_build_config(&global, r"
+        //! read-resource: alice, 0x1::Account::Account
+        //! read-resource: 0x1, 0x1::Timestamp::CurrentTimeMilliseconds
+    ").unwrap();
+    assert_eq!(config.read_resources.len(), 2);
+    assert_eq!(&config.read_resources[0].0, global.get_account_for_name("alice").unwrap().address());
+
+    parse_and_build_config(&global, r"
+        //! read-resource: bob, 0x1::Account::Account
+    ").unwrap_err();
+}
@@ -6,12 +6,18 @@ use crate::{
     compiler::Compiler,
     config::global::Config as GlobalConfig,
     evaluator::{eval, EvaluationOutput},
+    gas_snapshot::process_gas_snapshot,
     preprocessor::{build_transactions, split_input},
 };
-use std::{env, fs::read_to_string, io::Write, iter, path::Path};
+use move_coverage::trace::enable_vm_trace;
+use std::{env, fs::read_to_string, io::Write, iter, path::Path, sync::Once};
 use termcolor::{BufferWriter, Color, ColorChoice, ColorSpec, WriteColor};
 
 pub const PRETTY: &str = "PRETTY";
+/// The file to write the Move VM execution trace of all tests to, for coverage.
+pub const COVERAGE_TRACE: &str = "COVERAGE_TRACE";
+
+static ENABLE_COVERAGE_TRACE: Once = Once::new();
 
 fn at_most_n_chars(s: impl IntoIterator<Item = char>, n: usize) -> String {
     let mut it = s.into_iter();
@@ -55,11 +61,24 @@ fn pretty_mode() -> bool {
     pretty == "1" || pretty == "true"
 }
 
+/// Enables the VM execution trace if `COVERAGE_TRACE` is set. Tests run in one process,
+/// so the trace must be enabled before any of them executes.
+fn enable_coverage_trace() {
+    ENABLE_COVERAGE_TRACE.call_once(|| {
+        if let Ok(trace_path) = env::var(COVERAGE_TRACE) {
+            if !trace_path.is_empty() {
+                enable_vm_trace(Path::new(trace_path.as_str()));
+            }
+        }
+    });
+}
+
 // Runs all tests under the test/testsuite directory.
 pub fn functional_tests<TComp: Compiler>(
     compiler: TComp,
     path: &Path,
 ) -> datatest_stable::Result<()> {
+    enable_coverage_trace();
     let input = read_to_string(path)?;
 
     let lines: Vec<String> = input.lines().map(|line| line.to_string()).collect();
@@ -73,7 +92,10 @@ pub fn functional_tests<TComp: Compiler>(
     let res = match_output(&log, &directives);
 
     let errs = match res.status {
-        MatchStatus::Success => return Ok(()),
+        MatchStatus::Success => {
+            process_gas_snapshot(path, &log)?;
+            return Ok(());
+        }
         MatchStatus::Failure(errs) => errs,
     };
 
@@ -0,0 +1,41 @@
+//! account: alice
+//! account: bob
+
+//! block-prologue
+//! author: genesis
+//! block-number: 1
+//! block-time: 1000
+
+//! block-prologue
+//! author: genesis
+//! advance-time: 2000
+
+//! new-transaction
+//! sender: alice
+//! read-resource: 0x1, 0x1::Timestamp::CurrentTimeMilliseconds
+script {
+    use 0x1::Block;
+    use 0x1::Timestamp;
+
+    fun check_time(_account: signer) {
+        assert(Block::get_current_block_number() == 2, 1000);
+        assert(Timestamp::now_milliseconds() == 3000, 1001);
+    }
+}
+// check: EXECUTED
+// check: "milliseconds: 3000"
+
+//! new-transaction
+//! sender: alice
+script {
+    use 0x1::Account;
+    use 0x1::STC::STC;
+
+    fun transfer_to_bob(account: signer) {
+        Account::pay_from<STC>(&account, {{bob}}, 100);
+    }
+}
+// check: EXECUTED
+// check: "Account::WithdrawEvent"
+// check: "amount: 100u128"
+// check: "Account::DepositEvent"
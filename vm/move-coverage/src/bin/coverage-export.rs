@@ -0,0 +1,97 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use bytecode_source_map::utils::{remap_owned_loc_to_loc, source_map_from_file, OwnedLoc};
+use move_coverage::{
+    coverage_map::CoverageMap,
+    export::{export_cobertura, export_lcov, ModuleLineCoverage},
+};
+use starcoin_vm_types::file_format::CompiledModule;
+use std::{
+    fs,
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move Coverage Export",
+    about = "Export Move coverage as LCOV tracefile or Cobertura XML report"
+)]
+struct Args {
+    /// The path to the coverage map or trace file
+    #[structopt(long = "input-trace-path", short = "t")]
+    pub input_trace_path: String,
+    /// Whether the passed-in file is a raw trace file or a serialized coverage map
+    #[structopt(long = "is-raw-trace", short = "r")]
+    pub is_raw_trace_file: bool,
+    /// The paths to the module binaries, the source map `.mvsm` should be next to the binary
+    #[structopt(long = "module-path", short = "b")]
+    pub module_binary_paths: Vec<String>,
+    /// The paths to the source files, in the same order as the module binaries
+    #[structopt(long = "source-path", short = "s")]
+    pub source_file_paths: Vec<String>,
+    /// The report format, `lcov` or `cobertura`
+    #[structopt(long = "format", short = "f", default_value = "lcov", possible_values = &["lcov", "cobertura"])]
+    pub format: String,
+    /// Optional path to save the report. Printed to stdout if not present.
+    #[structopt(long = "output-path", short = "o")]
+    pub output_path: Option<String>,
+}
+
+fn main() {
+    let args = Args::from_args();
+    assert_eq!(
+        args.module_binary_paths.len(),
+        args.source_file_paths.len(),
+        "Every module binary should have a source file"
+    );
+    let source_map_extension = "mvsm";
+    let coverage_map = if args.is_raw_trace_file {
+        CoverageMap::from_trace_file(&args.input_trace_path)
+    } else {
+        CoverageMap::from_binary_file(&args.input_trace_path)
+    };
+
+    let coverages: Vec<_> = args
+        .module_binary_paths
+        .iter()
+        .zip(args.source_file_paths.iter())
+        .map(|(module_binary_path, source_file_path)| {
+            let bytecode_bytes =
+                fs::read(module_binary_path).expect("Unable to read bytecode file");
+            let compiled_module = CompiledModule::deserialize(&bytecode_bytes)
+                .expect("Module blob can't be deserialized");
+            let source_map = source_map_from_file::<OwnedLoc>(
+                &Path::new(module_binary_path).with_extension(source_map_extension),
+            )
+            .map(remap_owned_loc_to_loc)
+            .unwrap();
+            ModuleLineCoverage::new(
+                &compiled_module,
+                &source_map,
+                Path::new(source_file_path),
+                &coverage_map,
+            )
+            .expect("Unable to compute line coverage")
+        })
+        .collect();
+
+    let mut output_writer: Box<dyn Write> = match &args.output_path {
+        Some(x) => {
+            let path = Path::new(x);
+            Box::new(File::create(&path).unwrap())
+        }
+        None => Box::new(io::stdout()),
+    };
+
+    match args.format.as_str() {
+        "cobertura" => export_cobertura(&coverages, &mut output_writer),
+        _ => export_lcov(&coverages, &mut output_writer),
+    }
+    .unwrap();
+}
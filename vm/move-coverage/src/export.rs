@@ -0,0 +1,267 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! Export the coverage of Move modules as LCOV tracefile or Cobertura XML report.
+
+use crate::coverage_map::CoverageMap;
+use anyhow::{format_err, Result};
+use bytecode_source_map::source_map::SourceMap;
+use codespan::{FileId, Files};
+use starcoin_vm_types::location::Loc;
+use starcoin_vm_types::{
+    access::ModuleAccess,
+    file_format::{CodeOffset, CompiledModule, FunctionDefinitionIndex},
+    language_storage::ModuleId,
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Line coverage of a function, lines are 1-based.
+#[derive(Debug)]
+pub struct FunctionLineCoverage {
+    pub name: String,
+    pub decl_line: u32,
+    /// The execution count of the function entry.
+    pub hits: u64,
+    pub lines: BTreeMap<u32, u64>,
+}
+
+/// Line coverage of a module source file, lines are 1-based.
+#[derive(Debug)]
+pub struct ModuleLineCoverage {
+    pub module_id: ModuleId,
+    pub source_path: PathBuf,
+    pub functions: Vec<FunctionLineCoverage>,
+    pub lines: BTreeMap<u32, u64>,
+}
+
+impl ModuleLineCoverage {
+    pub fn new(
+        module: &CompiledModule,
+        source_map: &SourceMap<Loc>,
+        source_path: &Path,
+        coverage_map: &CoverageMap,
+    ) -> Result<Self> {
+        let module_id = module.self_id();
+        let unified_exec_map = coverage_map.to_unified_exec_map();
+        let module_map = unified_exec_map
+            .module_maps
+            .get(&(*module_id.address(), module_id.name().to_owned()));
+
+        let mut files = Files::new();
+        let file_id = files.add(
+            source_path.as_os_str().to_os_string(),
+            fs::read_to_string(source_path)?,
+        );
+
+        let mut functions = vec![];
+        let mut lines = BTreeMap::new();
+        for (function_def_idx, function_def) in module.function_defs().iter().enumerate() {
+            let code_unit = match &function_def.code {
+                Some(code_unit) => code_unit,
+                // native function
+                None => continue,
+            };
+            let fn_handle = module.function_handle_at(function_def.function);
+            let fn_name = module.identifier_at(fn_handle.name);
+            let function_def_idx = FunctionDefinitionIndex(function_def_idx as u16);
+            let function_coverage =
+                module_map.and_then(|module_map| module_map.function_maps.get(fn_name));
+            let function_source_map = source_map
+                .get_function_source_map(function_def_idx)
+                .map_err(|e| format_err!("{:?}", e))?;
+
+            let mut fn_lines = BTreeMap::new();
+            for code_offset in 0..code_unit.code.len() {
+                let loc = source_map
+                    .get_code_location(function_def_idx, code_offset as CodeOffset)
+                    .map_err(|e| format_err!("{:?}", e))?;
+                let line = line_of(&files, file_id, &loc)?;
+                let hits = function_coverage
+                    .and_then(|coverage| coverage.get(&(code_offset as u64)))
+                    .copied()
+                    .unwrap_or(0);
+                let line_hits = fn_lines.entry(line).or_insert(0);
+                *line_hits = std::cmp::max(*line_hits, hits);
+            }
+            for (line, hits) in &fn_lines {
+                let line_hits = lines.entry(*line).or_insert(0);
+                *line_hits = std::cmp::max(*line_hits, *hits);
+            }
+            functions.push(FunctionLineCoverage {
+                name: fn_name.to_string(),
+                decl_line: line_of(&files, file_id, &function_source_map.decl_location)?,
+                hits: function_coverage
+                    .and_then(|coverage| coverage.get(&0))
+                    .copied()
+                    .unwrap_or(0),
+                lines: fn_lines,
+            });
+        }
+
+        Ok(Self {
+            module_id,
+            source_path: source_path.to_path_buf(),
+            functions,
+            lines,
+        })
+    }
+
+    pub fn lines_covered(&self) -> usize {
+        covered(&self.lines)
+    }
+
+    pub fn lines_valid(&self) -> usize {
+        self.lines.len()
+    }
+}
+
+fn line_of(files: &Files<String>, file_id: FileId, loc: &Loc) -> Result<u32> {
+    let location = files
+        .location(file_id, loc.span().start())
+        .map_err(|e| format_err!("{:?}", e))?;
+    Ok(location.line.0 + 1)
+}
+
+fn covered(lines: &BTreeMap<u32, u64>) -> usize {
+    lines.values().filter(|hits| **hits > 0).count()
+}
+
+fn rate(covered: usize, valid: usize) -> f64 {
+    if valid == 0 {
+        1.0
+    } else {
+        covered as f64 / valid as f64
+    }
+}
+
+/// Write the coverage as LCOV tracefile, one record per module source file.
+pub fn export_lcov<W: Write>(coverages: &[ModuleLineCoverage], writer: &mut W) -> io::Result<()> {
+    for coverage in coverages {
+        writeln!(writer, "TN:{}", coverage.module_id.name())?;
+        writeln!(writer, "SF:{}", coverage.source_path.display())?;
+        for function in &coverage.functions {
+            writeln!(writer, "FN:{},{}", function.decl_line, function.name)?;
+        }
+        for function in &coverage.functions {
+            writeln!(writer, "FNDA:{},{}", function.hits, function.name)?;
+        }
+        writeln!(writer, "FNF:{}", coverage.functions.len())?;
+        writeln!(
+            writer,
+            "FNH:{}",
+            coverage.functions.iter().filter(|f| f.hits > 0).count()
+        )?;
+        for (line, hits) in &coverage.lines {
+            writeln!(writer, "DA:{},{}", line, hits)?;
+        }
+        writeln!(writer, "LF:{}", coverage.lines_valid())?;
+        writeln!(writer, "LH:{}", coverage.lines_covered())?;
+        writeln!(writer, "end_of_record")?;
+    }
+    Ok(())
+}
+
+/// Write the coverage as Cobertura XML report, modules are grouped by address as packages.
+pub fn export_cobertura<W: Write>(
+    coverages: &[ModuleLineCoverage],
+    writer: &mut W,
+) -> io::Result<()> {
+    let mut packages: BTreeMap<String, Vec<&ModuleLineCoverage>> = BTreeMap::new();
+    for coverage in coverages {
+        packages
+            .entry(format!("0x{}", coverage.module_id.address()))
+            .or_default()
+            .push(coverage);
+    }
+    let lines_covered: usize = coverages.iter().map(|c| c.lines_covered()).sum();
+    let lines_valid: usize = coverages.iter().map(|c| c.lines_valid()).sum();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    writeln!(writer, r#"<?xml version="1.0" ?>"#)?;
+    writeln!(
+        writer,
+        r#"<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">"#
+    )?;
+    writeln!(
+        writer,
+        r#"<coverage line-rate="{:.4}" branch-rate="0" lines-covered="{}" lines-valid="{}" branches-covered="0" branches-valid="0" complexity="0" version="1.9" timestamp="{}">"#,
+        rate(lines_covered, lines_valid),
+        lines_covered,
+        lines_valid,
+        timestamp
+    )?;
+    writeln!(writer, "  <sources><source>.</source></sources>")?;
+    writeln!(writer, "  <packages>")?;
+    for (package, modules) in packages {
+        let package_covered: usize = modules.iter().map(|c| c.lines_covered()).sum();
+        let package_valid: usize = modules.iter().map(|c| c.lines_valid()).sum();
+        writeln!(
+            writer,
+            r#"    <package name="{}" line-rate="{:.4}" branch-rate="0" complexity="0">"#,
+            package,
+            rate(package_covered, package_valid)
+        )?;
+        writeln!(writer, "      <classes>")?;
+        for module in modules {
+            writeln!(
+                writer,
+                r#"        <class name="{}" filename="{}" line-rate="{:.4}" branch-rate="0" complexity="0">"#,
+                module.module_id.name(),
+                escape_xml(&module.source_path.display().to_string()),
+                rate(module.lines_covered(), module.lines_valid())
+            )?;
+            writeln!(writer, "          <methods>")?;
+            for function in &module.functions {
+                writeln!(
+                    writer,
+                    r#"            <method name="{}" signature="" line-rate="{:.4}" branch-rate="0" complexity="0">"#,
+                    function.name,
+                    rate(covered(&function.lines), function.lines.len())
+                )?;
+                write_cobertura_lines(writer, &function.lines, "              ")?;
+                writeln!(writer, "            </method>")?;
+            }
+            writeln!(writer, "          </methods>")?;
+            write_cobertura_lines(writer, &module.lines, "          ")?;
+            writeln!(writer, "        </class>")?;
+        }
+        writeln!(writer, "      </classes>")?;
+        writeln!(writer, "    </package>")?;
+    }
+    writeln!(writer, "  </packages>")?;
+    writeln!(writer, "</coverage>")
+}
+
+fn write_cobertura_lines<W: Write>(
+    writer: &mut W,
+    lines: &BTreeMap<u32, u64>,
+    indent: &str,
+) -> io::Result<()> {
+    writeln!(writer, "{}<lines>", indent)?;
+    for (line, hits) in lines {
+        writeln!(
+            writer,
+            r#"{}  <line number="{}" hits="{}" branch="false"/>"#,
+            indent, line, hits
+        )?;
+    }
+    writeln!(writer, "{}</lines>", indent)
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
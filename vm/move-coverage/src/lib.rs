@@ -2,5 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod coverage_map;
+pub mod export;
 pub mod source_coverage;
 pub mod summary;
+pub mod trace;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::coverage_map::CoverageMap;
+use crate::export::{export_cobertura, export_lcov, ModuleLineCoverage};
+use anyhow::Result;
+use starcoin_move_compiler::{compile_source_string_no_report, CompiledUnit};
+use starcoin_types::account_address::AccountAddress;
+use starcoin_vm_types::identifier::Identifier;
+use std::collections::BTreeMap;
+
+// every function is in one line, so all the code offsets of a function are mapped to its line.
+const MODULE_SOURCE: &str = r#"module M {
+    public fun one(): u64 { 1 }
+    public fun two(): u64 { 2 }
+}
+"#;
+
+fn module_line_coverage() -> Result<ModuleLineCoverage> {
+    let address = AccountAddress::from_hex_literal("0x1")?;
+    let (_, units) = compile_source_string_no_report(MODULE_SOURCE, &[], address)?;
+    let units = units.map_err(|e| anyhow::format_err!("compile error: {:?}", e))?;
+    let (module, source_map) = match units.into_iter().next() {
+        Some(CompiledUnit::Module {
+            module, source_map, ..
+        }) => (module, source_map),
+        _ => anyhow::bail!("expect a compiled module"),
+    };
+
+    let temp_dir = tempfile::tempdir()?;
+    let source_path = temp_dir.path().join("M.move");
+    std::fs::write(&source_path, MODULE_SOURCE)?;
+
+    let mut coverage_map = CoverageMap {
+        exec_maps: BTreeMap::new(),
+    };
+    let module_name = Identifier::new("M")?;
+    let one = Identifier::new("one")?;
+    coverage_map.insert("txn1", address, module_name.clone(), one.clone(), 0);
+    coverage_map.insert("txn2", address, module_name, one, 0);
+    ModuleLineCoverage::new(&module, &source_map, source_path.as_path(), &coverage_map)
+}
+
+#[test]
+fn test_export_lcov() -> Result<()> {
+    let coverage = module_line_coverage()?;
+    assert_eq!(coverage.lines_valid(), 2);
+    assert_eq!(coverage.lines_covered(), 1);
+
+    let mut output = vec![];
+    export_lcov(&[coverage], &mut output)?;
+    let output = String::from_utf8(output)?;
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "TN:M");
+    assert!(lines.contains(&"FN:2,one"));
+    assert!(lines.contains(&"FN:3,two"));
+    assert!(lines.contains(&"FNDA:2,one"));
+    assert!(lines.contains(&"FNDA:0,two"));
+    assert!(lines.contains(&"FNF:2"));
+    assert!(lines.contains(&"FNH:1"));
+    let da_lines: Vec<&str> = lines
+        .iter()
+        .filter(|line| line.starts_with("DA:"))
+        .copied()
+        .collect();
+    assert_eq!(da_lines, vec!["DA:2,2", "DA:3,0"]);
+    assert!(lines.contains(&"LF:2"));
+    assert!(lines.contains(&"LH:1"));
+    assert_eq!(lines.last(), Some(&"end_of_record"));
+    Ok(())
+}
+
+#[test]
+fn test_export_cobertura() -> Result<()> {
+    let coverage = module_line_coverage()?;
+    let source_path = coverage.source_path.display().to_string();
+
+    let mut output = vec![];
+    export_cobertura(&[coverage], &mut output)?;
+    let output = String::from_utf8(output)?;
+    assert!(output.contains(
+        r#"<coverage line-rate="0.5000" branch-rate="0" lines-covered="1" lines-valid="2""#
+    ));
+    assert!(output.contains(&format!(
+        r#"<package name="0x{}" line-rate="0.5000""#,
+        AccountAddress::from_hex_literal("0x1")?
+    )));
+    assert!(output.contains(&format!(
+        r#"<class name="M" filename="{}" line-rate="0.5000""#,
+        source_path
+    )));
+    assert!(output.contains(r#"<method name="one" signature="" line-rate="1.0000""#));
+    assert!(output.contains(r#"<method name="two" signature="" line-rate="0.0000""#));
+    assert!(output.contains(r#"<line number="2" hits="2" branch="false"/>"#));
+    assert!(output.contains(r#"<line number="3" hits="0" branch="false"/>"#));
+    Ok(())
+}
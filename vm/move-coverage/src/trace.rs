@@ -0,0 +1,24 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! The Move VM (built with debug assertions) appends an execution trace to the file named by
+//! env `MOVE_VM_TRACE`, which can be loaded by `CoverageMap::from_trace_file`.
+
+use std::path::Path;
+
+pub const MOVE_VM_TRACE_ENV: &str = "MOVE_VM_TRACE";
+
+/// Enable the Move VM execution trace to `trace_path`.
+/// The VM reads the env only once, so this must be called before the first execution in the process.
+pub fn enable_vm_trace(trace_path: &Path) {
+    std::env::set_var(MOVE_VM_TRACE_ENV, trace_path.as_os_str());
+}
+
+/// Returns the trace file if the Move VM execution trace is enabled.
+pub fn vm_trace_path() -> Option<String> {
+    std::env::var(MOVE_VM_TRACE_ENV)
+        .ok()
+        .filter(|path| !path.is_empty())
+}
@@ -1,13 +1,138 @@
 // Copyright (c) The Diem Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use errmapgen::{ErrorContext, ErrorMapping};
-use move_core_types::language_storage::ModuleId;
+use anyhow::{format_err, Result};
+pub use errmapgen::{ErrorContext, ErrorMapping};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::{ModuleId, CORE_CODE_ADDRESS};
+use once_cell::sync::Lazy;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// The file extension of error map files generated by errmapgen.
+pub const ERROR_MAP_EXTENSION: &str = "errmap";
+
+static STDLIB_ERROR_MAPPING: Lazy<ErrorMapping> =
+    Lazy::new(|| bcs_ext::from_bytes(stdlib::ERROR_DESCRIPTIONS).expect("Decode err map failed"));
+
+/// Error maps of third-party modules, registered at runtime from the local error map files.
+static REGISTERED_ERROR_MAPPING: Lazy<RwLock<ErrorMapping>> =
+    Lazy::new(|| RwLock::new(ErrorMapping::default()));
+
+/// The reader of the error maps published on chain with the packages.
+static ON_CHAIN_ERROR_MAP_READER: Lazy<RwLock<Option<Arc<dyn ErrorMapReader>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// Read the error map published on chain under the module address, see the `0x1::ErrorMap` module.
+pub trait ErrorMapReader: Send + Sync {
+    fn get_error_map(&self, address: &AccountAddress) -> Result<Option<ErrorMapping>>;
+}
 
 /// Given the module ID and the abort code raised from that module, returns the human-readable
 /// explanation of that abort if possible.
+/// The stdlib error map is looked up first, then the error map published on chain under the
+/// module address, then the error maps registered from the local files.
 pub fn get_explanation(module_id: &ModuleId, abort_code: u64) -> Option<ErrorContext> {
-    let error_descriptions: ErrorMapping =
-        bcs_ext::from_bytes(stdlib::ERROR_DESCRIPTIONS).expect("Decode err map failed");
-    error_descriptions.get_explanation(module_id, abort_code)
+    STDLIB_ERROR_MAPPING
+        .get_explanation(module_id, abort_code)
+        .or_else(|| {
+            let on_chain = get_on_chain_error_map(module_id.address())?;
+            explain_by_mapping(&on_chain, module_id, abort_code)
+        })
+        .or_else(|| {
+            let registered = REGISTERED_ERROR_MAPPING
+                .read()
+                .expect("Error mapping lock should not poisoned.");
+            explain_by_mapping(&registered, module_id, abort_code)
+        })
 }
+
+fn get_on_chain_error_map(address: &AccountAddress) -> Option<ErrorMapping> {
+    if address == &CORE_CODE_ADDRESS {
+        return None;
+    }
+    let reader = ON_CHAIN_ERROR_MAP_READER
+        .read()
+        .expect("Error map reader lock should not poisoned.")
+        .clone()?;
+    // the explanation is optional, so a broken on chain error map falls back to the local ones.
+    reader.get_error_map(address).ok().flatten()
+}
+
+fn explain_by_mapping(
+    mapping: &ErrorMapping,
+    module_id: &ModuleId,
+    abort_code: u64,
+) -> Option<ErrorContext> {
+    let category_code = abort_code & 0xFFu64;
+    let reason_code = abort_code >> 8;
+    // third-party modules use the error categories of stdlib `Errors` module by default.
+    let category = mapping
+        .error_categories
+        .get(&category_code)
+        .or_else(|| STDLIB_ERROR_MAPPING.error_categories.get(&category_code))?;
+    let reason = mapping
+        .module_error_maps
+        .get(module_id)?
+        .get(&reason_code)?;
+    Some(ErrorContext {
+        category: category.clone(),
+        reason: reason.clone(),
+    })
+}
+
+/// Set the reader of the error maps published on chain, replace the previous one.
+pub fn set_error_map_reader(reader: Arc<dyn ErrorMapReader>) {
+    *ON_CHAIN_ERROR_MAP_READER
+        .write()
+        .expect("Error map reader lock should not poisoned.") = Some(reader);
+}
+
+/// Decode a BCS encoded error map, such as the content of error map files or on chain error maps.
+pub fn decode_error_mapping(bytes: &[u8]) -> Result<ErrorMapping> {
+    bcs_ext::from_bytes(bytes).map_err(|e| format_err!("Decode error map failed: {:?}", e))
+}
+
+/// Register a third-party error map, the error map of a module replaces the previous registered one.
+pub fn register_error_mapping(mapping: ErrorMapping) {
+    let mut registered = REGISTERED_ERROR_MAPPING
+        .write()
+        .expect("Error mapping lock should not poisoned.");
+    for (category_code, description) in mapping.error_categories {
+        registered
+            .error_categories
+            .entry(category_code)
+            .or_insert(description);
+    }
+    for (module_id, module_map) in mapping.module_error_maps {
+        registered.module_error_maps.insert(module_id, module_map);
+    }
+}
+
+/// Register a BCS encoded error map file.
+pub fn register_error_mapping_file<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path)?;
+    let mapping = decode_error_mapping(&bytes)
+        .map_err(|e| format_err!("Register error map {:?} failed: {:?}", path, e))?;
+    register_error_mapping(mapping);
+    Ok(())
+}
+
+/// Register all error map files in the dir, return the count of registered files.
+pub fn register_error_mapping_dir<P: AsRef<Path>>(dir: P) -> Result<usize> {
+    let mut count = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file()
+            && path.extension().and_then(|ext| ext.to_str()) == Some(ERROR_MAP_EXTENSION)
+        {
+            register_error_mapping_file(path.as_path())?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests;
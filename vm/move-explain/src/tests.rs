@@ -0,0 +1,148 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    decode_error_mapping, get_explanation, register_error_mapping, register_error_mapping_dir,
+    set_error_map_reader, ErrorMapReader, ERROR_MAP_EXTENSION,
+};
+use anyhow::Result;
+use errmapgen::{ErrorDescription, ErrorMapping};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, CORE_CODE_ADDRESS};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn description(name: &str) -> ErrorDescription {
+    ErrorDescription {
+        code_name: name.to_string(),
+        code_description: format!("{} description", name),
+    }
+}
+
+fn module_mapping(module_id: &ModuleId, reasons: &[(u64, &str)]) -> ErrorMapping {
+    let mut mapping = ErrorMapping::default();
+    mapping.module_error_maps.insert(
+        module_id.clone(),
+        reasons
+            .iter()
+            .map(|(code, name)| (*code, description(name)))
+            .collect(),
+    );
+    mapping
+}
+
+fn module_id(address: u8, name: &str) -> ModuleId {
+    ModuleId::new(
+        AccountAddress::new([address; AccountAddress::LENGTH]),
+        Identifier::new(name).unwrap(),
+    )
+}
+
+#[test]
+fn test_stdlib_explanation() {
+    let account = ModuleId::new(CORE_CODE_ADDRESS, Identifier::new("Account").unwrap());
+    // Errors::limit_exceeded(EINSUFFICIENT_BALANCE)
+    let ctx = get_explanation(&account, (10 << 8) | 8).unwrap();
+    assert_eq!(ctx.category.code_name, "LIMIT_EXCEEDED");
+    assert_eq!(ctx.reason.code_name, "EINSUFFICIENT_BALANCE");
+}
+
+#[test]
+fn test_register_error_mapping() {
+    let module_id = module_id(0x11, "Demo");
+    // Errors::invalid_argument(1)
+    let abort_code = (1 << 8) | 7;
+    assert!(get_explanation(&module_id, abort_code).is_none());
+
+    register_error_mapping(module_mapping(&module_id, &[(1, "EDEMO_ONE")]));
+    // the category falls back to the stdlib `Errors` categories.
+    let ctx = get_explanation(&module_id, abort_code).unwrap();
+    assert_eq!(ctx.category.code_name, "INVALID_ARGUMENT");
+    assert_eq!(ctx.reason.code_name, "EDEMO_ONE");
+    assert!(get_explanation(&module_id, (2 << 8) | 7).is_none());
+
+    // the registered category is used if the stdlib does not define it.
+    let mut mapping = module_mapping(&module_id, &[(2, "EDEMO_TWO")]);
+    mapping
+        .error_categories
+        .insert(200, description("DEMO_CATEGORY"));
+    register_error_mapping(mapping);
+    let ctx = get_explanation(&module_id, (2 << 8) | 200).unwrap();
+    assert_eq!(ctx.category.code_name, "DEMO_CATEGORY");
+    assert_eq!(ctx.reason.code_name, "EDEMO_TWO");
+    // the module error map is replaced by the latest registered one.
+    assert!(get_explanation(&module_id, abort_code).is_none());
+}
+
+#[test]
+fn test_register_error_mapping_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let module_id = module_id(0x22, "Demo");
+    let bytes = bcs_ext::to_bytes(&module_mapping(&module_id, &[(1, "EDEMO_ONE")])).unwrap();
+    std::fs::write(
+        dir.path().join(format!("demo.{}", ERROR_MAP_EXTENSION)),
+        bytes.as_slice(),
+    )
+    .unwrap();
+    // the files without the error map extension are ignored.
+    std::fs::write(dir.path().join("demo.txt"), b"not an error map").unwrap();
+
+    assert_eq!(register_error_mapping_dir(dir.path()).unwrap(), 1);
+    let ctx = get_explanation(&module_id, (1 << 8) | 7).unwrap();
+    assert_eq!(ctx.reason.code_name, "EDEMO_ONE");
+
+    std::fs::write(
+        dir.path().join(format!("broken.{}", ERROR_MAP_EXTENSION)),
+        b"not an error map",
+    )
+    .unwrap();
+    assert!(register_error_mapping_dir(dir.path()).is_err());
+}
+
+struct MockErrorMapReader {
+    // BCS encoded error maps by address, as they are stored on chain.
+    error_maps: HashMap<AccountAddress, Vec<u8>>,
+}
+
+impl ErrorMapReader for MockErrorMapReader {
+    fn get_error_map(&self, address: &AccountAddress) -> Result<Option<ErrorMapping>> {
+        self.error_maps
+            .get(address)
+            .map(|bytes| decode_error_mapping(bytes.as_slice()))
+            .transpose()
+    }
+}
+
+#[test]
+fn test_on_chain_error_map() {
+    let module_id = module_id(0x33, "Demo");
+    let broken_module_id = module_id(0x44, "Demo");
+    let mut error_maps = HashMap::new();
+    error_maps.insert(
+        *module_id.address(),
+        bcs_ext::to_bytes(&module_mapping(&module_id, &[(1, "EDEMO_CHAIN")])).unwrap(),
+    );
+    error_maps.insert(*broken_module_id.address(), b"not an error map".to_vec());
+    set_error_map_reader(Arc::new(MockErrorMapReader { error_maps }));
+
+    // the on chain error map is looked up by the module address.
+    let ctx = get_explanation(&module_id, (1 << 8) | 7).unwrap();
+    assert_eq!(ctx.category.code_name, "INVALID_ARGUMENT");
+    assert_eq!(ctx.reason.code_name, "EDEMO_CHAIN");
+    assert!(get_explanation(&module_id(0x33, "Other"), (1 << 8) | 7).is_none());
+
+    // the local error maps are the fallback.
+    register_error_mapping(module_mapping(
+        &module_id,
+        &[(1, "EDEMO_LOCAL"), (2, "EDEMO_LOCAL_TWO")],
+    ));
+    let ctx = get_explanation(&module_id, (1 << 8) | 7).unwrap();
+    assert_eq!(ctx.reason.code_name, "EDEMO_CHAIN");
+    let ctx = get_explanation(&module_id, (2 << 8) | 7).unwrap();
+    assert_eq!(ctx.reason.code_name, "EDEMO_LOCAL_TWO");
+
+    register_error_mapping(module_mapping(&broken_module_id, &[(1, "EBROKEN_LOCAL")]));
+    let ctx = get_explanation(&broken_module_id, (1 << 8) | 7).unwrap();
+    assert_eq!(ctx.reason.code_name, "EBROKEN_LOCAL");
+}
@@ -0,0 +1,235 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encode JSON values to the BCS arguments of a function, by the signature of the function in
+//! the on chain module. The JSON value of a type is:
+//!
+//! * `bool`: true or false.
+//! * `u8`, `u64`, `u128`: a number, or a decimal string for the number which overflows JSON.
+//! * `address`: a hex string, like "0x1".
+//! * `vector<u8>`: a hex string with `0x` prefix, an utf8 string, or an array of numbers.
+//! * `vector<T>`: an array of T.
+//! * `0x1::Option::Option<T>`: null for none, or the value of T for some.
+//! * other struct: an object with all the fields by name, or an array of the fields in order.
+
+use crate::fat_type::{FatStructType, FatType};
+use crate::resolver::Resolver;
+use anyhow::{bail, ensure, format_err, Result};
+use serde_json::Value;
+use starcoin_vm_types::access::ModuleAccess;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::file_format::SignatureToken;
+use starcoin_vm_types::identifier::IdentStr;
+use starcoin_vm_types::language_storage::{ModuleId, TypeTag, CORE_CODE_ADDRESS};
+use starcoin_vm_types::state_view::StateView;
+use starcoin_vm_types::value::{MoveStruct, MoveValue};
+use std::str::FromStr;
+
+pub struct JsonArgEncoder<'a> {
+    resolver: Resolver<'a>,
+}
+
+impl<'a> JsonArgEncoder<'a> {
+    pub fn new(view: &'a dyn StateView) -> Self {
+        Self {
+            resolver: Resolver::new(view),
+        }
+    }
+
+    /// Encode the JSON `args` to the BCS arguments of the function, the leading signer
+    /// parameters of the function are skipped, they are not passed by arguments.
+    pub fn encode_function_args(
+        &self,
+        module_id: &ModuleId,
+        function: &IdentStr,
+        ty_args: &[TypeTag],
+        args: &[Value],
+    ) -> Result<Vec<Vec<u8>>> {
+        let module = self
+            .resolver
+            .get_module(module_id.address(), module_id.name())?;
+        let handle = module
+            .function_defs()
+            .iter()
+            .map(|def| module.function_handle_at(def.function))
+            .find(|handle| module.identifier_at(handle.name) == function)
+            .ok_or_else(|| format_err!("Can not find function {} in {}", function, module_id))?;
+        ensure!(
+            handle.type_parameters.len() == ty_args.len(),
+            "Function {}::{} expect {} type args, but got {}",
+            module_id,
+            function,
+            handle.type_parameters.len(),
+            ty_args.len()
+        );
+        let parameters: Vec<_> = module
+            .signature_at(handle.parameters)
+            .0
+            .iter()
+            .skip_while(|token| is_signer(token))
+            .collect();
+        ensure!(
+            parameters.len() == args.len(),
+            "Function {}::{} expect {} args, but got {}",
+            module_id,
+            function,
+            parameters.len(),
+            args.len()
+        );
+        let ty_args = ty_args
+            .iter()
+            .map(|ty| self.resolver.resolve_type(ty))
+            .collect::<Result<Vec<_>>>()?;
+        parameters
+            .into_iter()
+            .zip(args)
+            .enumerate()
+            .map(|(idx, (token, arg))| {
+                let ty = self
+                    .resolver
+                    .resolve_signature(module.as_ref(), token)?
+                    .subst(&ty_args)
+                    .map_err(|e| format_err!("Substitution failure: {:?}", e))?;
+                let value = self
+                    .encode_value(&ty, arg)
+                    .map_err(|e| format_err!("Invalid arg{}: {}", idx, e))?;
+                value
+                    .simple_serialize()
+                    .ok_or_else(|| format_err!("Serialize arg{} failed", idx))
+            })
+            .collect()
+    }
+
+    /// Encode the JSON `value` of the type to BCS bytes.
+    pub fn encode(&self, type_tag: &TypeTag, value: &Value) -> Result<Vec<u8>> {
+        let ty = self.resolver.resolve_type(type_tag)?;
+        self.encode_value(&ty, value)?
+            .simple_serialize()
+            .ok_or_else(|| format_err!("Serialize value of {} failed", type_tag))
+    }
+
+    fn encode_value(&self, ty: &FatType, value: &Value) -> Result<MoveValue> {
+        Ok(match ty {
+            FatType::Bool => MoveValue::Bool(
+                value
+                    .as_bool()
+                    .ok_or_else(|| format_err!("expect bool, but got {}", value))?,
+            ),
+            FatType::U8 => MoveValue::U8(parse_number(value)?),
+            FatType::U64 => MoveValue::U64(parse_number(value)?),
+            FatType::U128 => MoveValue::U128(parse_number(value)?),
+            FatType::Address => MoveValue::Address(AccountAddress::from_str(
+                value
+                    .as_str()
+                    .ok_or_else(|| format_err!("expect address string, but got {}", value))?,
+            )?),
+            FatType::Vector(elem_ty) => match (elem_ty.as_ref(), value) {
+                (FatType::U8, Value::String(s)) => {
+                    MoveValue::vector_u8(match s.strip_prefix("0x") {
+                        Some(hex_str) => hex::decode(hex_str)?,
+                        None => s.as_bytes().to_vec(),
+                    })
+                }
+                (_, Value::Array(values)) => MoveValue::Vector(
+                    values
+                        .iter()
+                        .map(|v| self.encode_value(elem_ty, v))
+                        .collect::<Result<_>>()?,
+                ),
+                _ => bail!("expect array, but got {}", value),
+            },
+            FatType::Struct(struct_ty) => self.encode_struct(struct_ty, value)?,
+            FatType::Signer | FatType::Reference(_) | FatType::MutableReference(_) => {
+                bail!("type {:?} can not be passed as argument", ty)
+            }
+            FatType::TyParam(_) => bail!("unexpected type parameter"),
+        })
+    }
+
+    fn encode_struct(&self, struct_ty: &FatStructType, value: &Value) -> Result<MoveValue> {
+        if is_option(struct_ty) {
+            let elem_ty = match struct_ty.ty_args.first() {
+                Some(elem_ty) => elem_ty,
+                None => bail!("Option should have a type argument"),
+            };
+            let vec = match value {
+                Value::Null => vec![],
+                v => vec![self.encode_value(elem_ty, v)?],
+            };
+            return Ok(MoveValue::Struct(MoveStruct::new(vec![MoveValue::Vector(
+                vec,
+            )])));
+        }
+        let fields = match value {
+            Value::Array(values) => {
+                ensure!(
+                    values.len() == struct_ty.layout.len(),
+                    "struct {} expect {} fields, but got {}",
+                    struct_ty.name,
+                    struct_ty.layout.len(),
+                    values.len()
+                );
+                struct_ty
+                    .layout
+                    .iter()
+                    .zip(values)
+                    .map(|(ty, v)| self.encode_value(ty, v))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            Value::Object(values) => {
+                let names = self.resolver.get_field_names(struct_ty)?;
+                ensure!(
+                    values.len() == names.len(),
+                    "struct {} expect fields {:?}, but got {:?}",
+                    struct_ty.name,
+                    names.iter().map(|name| name.as_str()).collect::<Vec<_>>(),
+                    values.keys().collect::<Vec<_>>()
+                );
+                names
+                    .iter()
+                    .zip(struct_ty.layout.iter())
+                    .map(|(name, ty)| {
+                        let v = values.get(name.as_str()).ok_or_else(|| {
+                            format_err!("missing field {} of struct {}", name, struct_ty.name)
+                        })?;
+                        self.encode_value(ty, v)
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => bail!(
+                "expect object or array for struct {}, but got {}",
+                struct_ty.name,
+                value
+            ),
+        };
+        Ok(MoveValue::Struct(MoveStruct::new(fields)))
+    }
+}
+
+fn is_signer(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Signer => true,
+        SignatureToken::Reference(inner) => matches!(inner.as_ref(), SignatureToken::Signer),
+        _ => false,
+    }
+}
+
+fn is_option(struct_ty: &FatStructType) -> bool {
+    struct_ty.address == CORE_CODE_ADDRESS
+        && struct_ty.module.as_str() == "Option"
+        && struct_ty.name.as_str() == "Option"
+}
+
+fn parse_number<T>(value: &Value) -> Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let s = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        _ => bail!("expect number, but got {}", value),
+    };
+    s.parse::<T>()
+        .map_err(|e| format_err!("invalid number {}: {}", s, e))
+}
@@ -29,6 +29,7 @@ use std::{
 };
 
 mod fat_type;
+pub mod json_arg;
 mod module_cache;
 mod resolver;
 
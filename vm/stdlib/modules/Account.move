@@ -735,6 +735,12 @@ module Account {
         // Load the transaction sender's account
         let sender_account = borrow_global_mut<Account>(txn_sender);
 
+        // Check that the transaction's public key is of a supported authenticator scheme
+        assert(
+            Authenticator::is_supported_scheme(&txn_public_key),
+            Errors::invalid_argument(EPROLOGUE_INVALID_ACCOUNT_AUTH_KEY)
+        );
+
         // Check that the hash of the transaction's public key matches the account's auth key
         assert(
             Hash::sha3_256(txn_public_key) == *&sender_account.authentication_key,
@@ -770,6 +776,7 @@ module Account {
     spec fun txn_prologue {
         aborts_if Signer::address_of(account) != CoreAddresses::SPEC_GENESIS_ADDRESS();
         aborts_if !exists<Account>(txn_sender);
+        aborts_if !Authenticator::spec_is_supported_scheme(txn_public_key);
         aborts_if Hash::sha3_256(txn_public_key) != global<Account>(txn_sender).authentication_key;
         aborts_if txn_gas_price * txn_max_gas_units > max_u64();
         aborts_if txn_gas_price * txn_max_gas_units > 0 && !exists<Balance<TokenType>>(txn_sender);
@@ -781,6 +788,117 @@ module Account {
         aborts_if txn_sequence_number != global<Account>(txn_sender).sequence_number;
     }
 
+    /// The prologue of a sponsored transaction, the gas is paid by the `txn_fee_payer`.
+    /// It verifies:
+    /// - The sender's auth key matches the transaction's public key
+    /// - That the sequence number matches the sender's sequence number
+    /// - The fee payer's auth key matches the fee payer's public key
+    /// - That the fee payer has enough balance to pay for all of the gas
+    public fun txn_fee_payer_prologue<TokenType: store>(
+        account: &signer,
+        txn_sender: address,
+        txn_sequence_number: u64,
+        txn_public_key: vector<u8>,
+        txn_fee_payer: address,
+        txn_fee_payer_public_key: vector<u8>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+    ) acquires Account, Balance {
+        // Check the sender without gas
+        txn_prologue<TokenType>(account, txn_sender, txn_sequence_number, txn_public_key, 0, txn_max_gas_units);
+
+        // Verify that the fee payer's account exists
+        assert(exists_at(txn_fee_payer), Errors::requires_address(EPROLOGUE_ACCOUNT_DOES_NOT_EXIST));
+        let fee_payer_account = borrow_global<Account>(txn_fee_payer);
+        assert(
+            Authenticator::is_supported_scheme(&txn_fee_payer_public_key),
+            Errors::invalid_argument(EPROLOGUE_INVALID_ACCOUNT_AUTH_KEY)
+        );
+        assert(
+            Hash::sha3_256(txn_fee_payer_public_key) == *&fee_payer_account.authentication_key,
+            Errors::invalid_argument(EPROLOGUE_INVALID_ACCOUNT_AUTH_KEY)
+        );
+
+        // Check that the fee payer has enough balance for all of the gas
+        assert(
+            (txn_gas_price as u128) * (txn_max_gas_units as u128) <= MAX_U64,
+            Errors::invalid_argument(EPROLOGUE_CANT_PAY_GAS_DEPOSIT),
+        );
+        let max_transaction_fee = txn_gas_price * txn_max_gas_units;
+        if (max_transaction_fee > 0) {
+            assert(
+                STC::is_stc<TokenType>(),
+                Errors::invalid_argument(EBAD_TRANSACTION_FEE_TOKEN)
+            );
+            let balance_amount = balance<TokenType>(txn_fee_payer);
+            assert(balance_amount >= (max_transaction_fee as u128), Errors::invalid_argument(EPROLOGUE_CANT_PAY_GAS_DEPOSIT));
+        };
+    }
+
+    spec fun txn_fee_payer_prologue {
+        aborts_if Signer::address_of(account) != CoreAddresses::SPEC_GENESIS_ADDRESS();
+        aborts_if !exists<Account>(txn_sender);
+        aborts_if !Authenticator::spec_is_supported_scheme(txn_public_key);
+        aborts_if Hash::sha3_256(txn_public_key) != global<Account>(txn_sender).authentication_key;
+        aborts_if txn_sequence_number < global<Account>(txn_sender).sequence_number;
+        aborts_if txn_sequence_number != global<Account>(txn_sender).sequence_number;
+        aborts_if !exists<Account>(txn_fee_payer);
+        aborts_if !Authenticator::spec_is_supported_scheme(txn_fee_payer_public_key);
+        aborts_if Hash::sha3_256(txn_fee_payer_public_key) != global<Account>(txn_fee_payer).authentication_key;
+        aborts_if txn_gas_price * txn_max_gas_units > max_u64();
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && !exists<Balance<TokenType>>(txn_fee_payer);
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && Token::spec_token_code<TokenType>() != Token::spec_token_code<STC>();
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && global<Balance<TokenType>>(txn_fee_payer).token.value < txn_gas_price * txn_max_gas_units;
+    }
+
+    /// The epilogue of a sponsored transaction.
+    /// It collects gas from the `txn_fee_payer` and bumps the sender's sequence number
+    public fun txn_fee_payer_epilogue<TokenType: store>(
+        account: &signer,
+        txn_sender: address,
+        txn_sequence_number: u64,
+        txn_fee_payer: address,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        gas_units_remaining: u64,
+    ) acquires Account, Balance {
+        CoreAddresses::assert_genesis_address(account);
+
+        // Charge for gas from the fee payer
+        let fee_payer_balance = borrow_global_mut<Balance<TokenType>>(txn_fee_payer);
+        let transaction_fee_amount =(txn_gas_price * (txn_max_gas_units - gas_units_remaining) as u128);
+        assert(
+            balance_for(fee_payer_balance) >= transaction_fee_amount,
+            Errors::limit_exceeded(EINSUFFICIENT_BALANCE)
+        );
+
+        // Bump the sender's sequence number
+        let sender_account = borrow_global_mut<Account>(txn_sender);
+        sender_account.sequence_number = txn_sequence_number + 1;
+
+        if (transaction_fee_amount > 0) {
+            let transaction_fee = withdraw_from_balance(
+                    fee_payer_balance,
+                    transaction_fee_amount
+            );
+            TransactionFee::pay_fee(transaction_fee);
+        };
+    }
+
+    spec fun txn_fee_payer_epilogue {
+        aborts_if Signer::address_of(account) != CoreAddresses::SPEC_GENESIS_ADDRESS();
+        aborts_if !exists<Balance<TokenType>>(txn_fee_payer);
+        aborts_if txn_max_gas_units < gas_units_remaining;
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > max_u64();
+        aborts_if global<Balance<TokenType>>(txn_fee_payer).token.value < txn_gas_price * (txn_max_gas_units - gas_units_remaining);
+        aborts_if !exists<Account>(txn_sender);
+        aborts_if txn_sequence_number + 1 > max_u64();
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > 0 &&
+                !exists<TransactionFee::TransactionFee<TokenType>>(CoreAddresses::SPEC_GENESIS_ADDRESS());
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > 0 &&
+                global<TransactionFee::TransactionFee<TokenType>>(CoreAddresses::SPEC_GENESIS_ADDRESS()).fee.value + txn_gas_price * (txn_max_gas_units - gas_units_remaining) > max_u128();
+    }
+
     /// The epilogue is invoked at the end of transactions.
     /// It collects gas and bumps the sequence number
     public fun txn_epilogue<TokenType: store>(
@@ -3,6 +3,7 @@ address 0x1 {
 /// Move representation of the authenticator types
 /// - Ed25519 (single-sig)
 /// - MultiEd25519 (K-of-N multisig)
+/// - Secp256k1 (single-sig)
 module Authenticator {
     use 0x1::Hash;
     use 0x1::BCS;
@@ -18,6 +19,7 @@ module Authenticator {
 
     const ED25519_SCHEME_ID: u8 = 0;
     const MULTI_ED25519_SCHEME_ID: u8 = 1;
+    const SECP256K1_SCHEME_ID: u8 = 2;
 
     /// A multi-ed25519 public key
     struct MultiEd25519PublicKey has copy, drop, store {
@@ -85,6 +87,45 @@ module Authenticator {
     /// does not matter for the verification of callers.
     spec define spec_ed25519_authentication_key(public_key: vector<u8>): vector<u8>;
 
+    /// Compute an authentication key for the compressed secp256k1 public key `public_key`
+    public fun secp256k1_authentication_key(public_key: vector<u8>): vector<u8> {
+        Vector::push_back(&mut public_key, SECP256K1_SCHEME_ID);
+        Hash::sha3_256(public_key)
+    }
+
+    spec fun secp256k1_authentication_key {
+        pragma opaque = true;
+        aborts_if false;
+        ensures [abstract] result == spec_secp256k1_authentication_key(public_key);
+    }
+
+    /// We use an uninterpreted function to represent the result of key construction. The actual value
+    /// does not matter for the verification of callers.
+    spec define spec_secp256k1_authentication_key(public_key: vector<u8>): vector<u8>;
+
+    /// Check the authentication key preimage `preimage` ends with a supported scheme id.
+    public fun is_supported_scheme(preimage: &vector<u8>): bool {
+        let len = Vector::length(preimage);
+        if (len == 0) {
+            return false
+        };
+        let scheme_id = *Vector::borrow(preimage, len - 1);
+        scheme_id == ED25519_SCHEME_ID || scheme_id == MULTI_ED25519_SCHEME_ID || scheme_id == SECP256K1_SCHEME_ID
+    }
+
+    spec fun is_supported_scheme {
+        aborts_if false;
+        ensures result == spec_is_supported_scheme(preimage);
+    }
+
+    spec define spec_is_supported_scheme(preimage: vector<u8>): bool {
+        len(preimage) > 0 && (
+            preimage[len(preimage) - 1] == ED25519_SCHEME_ID
+                || preimage[len(preimage) - 1] == MULTI_ED25519_SCHEME_ID
+                || preimage[len(preimage) - 1] == SECP256K1_SCHEME_ID
+        )
+    }
+
     /// convert authentication key to address
     public fun derived_address(authentication_key: vector<u8>): address {
         assert(Vector::length(&authentication_key) == AUTHENTICATION_KEY_LENGTH, Errors::invalid_argument(EWRONG_AUTHENTICATION_KEY_LENGTH));
@@ -0,0 +1,36 @@
+address 0x1 {
+/// The module stores the error map of the modules published under an account.
+/// The error map is usually published by the init script of the package, and is used to explain
+/// the abort codes raised from the modules.
+module ErrorMap {
+    use 0x1::Signer;
+
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// The error map of the modules under the account.
+    struct ErrorMap has key {
+        /// BCS encoded error map generated by errmapgen.
+        bytes: vector<u8>,
+    }
+
+    /// Publish the error map of the modules under the account, replace the old one if exists.
+    public(script) fun publish(account: signer, bytes: vector<u8>) acquires ErrorMap {
+        let addr = Signer::address_of(&account);
+        if (exists<ErrorMap>(addr)) {
+            let error_map = borrow_global_mut<ErrorMap>(addr);
+            error_map.bytes = bytes;
+        } else {
+            move_to(&account, ErrorMap { bytes });
+        }
+    }
+
+    spec fun publish {
+        aborts_if false;
+        ensures exists<ErrorMap>(Signer::spec_address_of(account));
+        ensures global<ErrorMap>(Signer::spec_address_of(account)).bytes == bytes;
+    }
+}
+}
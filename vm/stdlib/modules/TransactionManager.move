@@ -69,24 +69,41 @@ module TransactionManager {
             txn_gas_price,
             txn_max_gas_units,
         );
+        txn_payload_prologue(
+            &account,
+            txn_expiration_time,
+            txn_payload_type,
+            txn_script_or_package_hash,
+            txn_package_address,
+        );
+    }
+
+    /// Check the expiration time and the payload of the transaction.
+    fun txn_payload_prologue(
+        account: &signer,
+        txn_expiration_time: u64,
+        txn_payload_type: u8,
+        txn_script_or_package_hash: vector<u8>,
+        txn_package_address: address,
+    ) {
         assert(
             TransactionTimeout::is_valid_transaction_timestamp(txn_expiration_time),
             Errors::invalid_argument(EPROLOGUE_TRANSACTION_EXPIRED),
         );
         if (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE) {
             assert(
-                TransactionPublishOption::is_module_allowed(Signer::address_of(&account)),
+                TransactionPublishOption::is_module_allowed(Signer::address_of(account)),
                 Errors::invalid_argument(EPROLOGUE_MODULE_NOT_ALLOWED),
             );
             PackageTxnManager::package_txn_prologue(
-                &account,
+                account,
                 txn_package_address,
                 txn_script_or_package_hash,
             );
         } else if (txn_payload_type == TXN_PAYLOAD_TYPE_SCRIPT) {
             assert(
                 TransactionPublishOption::is_script_allowed(
-                    Signer::address_of(&account),
+                    Signer::address_of(account),
                 ),
                 Errors::invalid_argument(EPROLOGUE_SCRIPT_NOT_ALLOWED),
             );
@@ -94,11 +111,26 @@ module TransactionManager {
         // do nothing for TXN_PAYLOAD_TYPE_SCRIPT_FUNCTION
     }
 
+    spec fun txn_payload_prologue {
+        include Timestamp::AbortsIfTimestampNotExists;
+        include Block::AbortsIfBlockMetadataNotExist;
+        include TransactionTimeout::AbortsIfTimestampNotValid;
+        aborts_if !TransactionTimeout::spec_is_valid_transaction_timestamp(txn_expiration_time);
+        include TransactionPublishOption::AbortsIfTxnPublishOptionNotExistWithBool {
+            is_script_or_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE || txn_payload_type == TXN_PAYLOAD_TYPE_SCRIPT),
+        };
+        aborts_if txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE && !TransactionPublishOption::spec_is_module_allowed(Signer::address_of(account));
+        aborts_if txn_payload_type == TXN_PAYLOAD_TYPE_SCRIPT && !TransactionPublishOption::spec_is_script_allowed(Signer::address_of(account));
+        aborts_if txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE && Signer::address_of(account) != CoreAddresses::SPEC_GENESIS_ADDRESS();
+        include PackageTxnManager::CheckPackageTxnAbortsIfWithType{is_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE), sender: Signer::address_of(account), package_address: txn_package_address, package_hash: txn_script_or_package_hash};
+    }
+
     spec fun prologue {
         aborts_if Signer::address_of(account) != CoreAddresses::GENESIS_ADDRESS();
         aborts_if !exists<ChainId::ChainId>(CoreAddresses::GENESIS_ADDRESS());
         aborts_if ChainId::get() != chain_id;
         aborts_if !exists<Account::Account>(txn_sender);
+        aborts_if !0x1::Authenticator::spec_is_supported_scheme(txn_public_key);
         aborts_if Hash::sha3_256(txn_public_key) != global<Account::Account>(txn_sender).authentication_key;
         aborts_if txn_gas_price * txn_max_gas_units > max_u64();
         include Timestamp::AbortsIfTimestampNotExists;
@@ -119,6 +151,76 @@ module TransactionManager {
         include PackageTxnManager::CheckPackageTxnAbortsIfWithType{is_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE), sender:txn_sender, package_address: txn_package_address, package_hash: txn_script_or_package_hash};
     }
 
+    /// The prologue of a sponsored transaction, the same as `prologue`,
+    /// except the gas is paid by the `txn_fee_payer`.
+    public fun fee_payer_prologue<TokenType: store>(
+        account: signer,
+        txn_sender: address,
+        txn_sequence_number: u64,
+        txn_public_key: vector<u8>,
+        txn_fee_payer: address,
+        txn_fee_payer_public_key: vector<u8>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        txn_expiration_time: u64,
+        chain_id: u8,
+        txn_payload_type: u8,
+        txn_script_or_package_hash: vector<u8>,
+        txn_package_address: address,
+    ) {
+        // Can only be invoked by genesis account
+        assert(
+            Signer::address_of(&account) == CoreAddresses::GENESIS_ADDRESS(),
+            Errors::requires_address(EPROLOGUE_ACCOUNT_DOES_NOT_EXIST),
+        );
+        assert(ChainId::get() == chain_id, Errors::invalid_argument(EPROLOGUE_BAD_CHAIN_ID));
+        Account::txn_fee_payer_prologue<TokenType>(
+            &account,
+            txn_sender,
+            txn_sequence_number,
+            txn_public_key,
+            txn_fee_payer,
+            txn_fee_payer_public_key,
+            txn_gas_price,
+            txn_max_gas_units,
+        );
+        txn_payload_prologue(
+            &account,
+            txn_expiration_time,
+            txn_payload_type,
+            txn_script_or_package_hash,
+            txn_package_address,
+        );
+    }
+
+    spec fun fee_payer_prologue {
+        aborts_if Signer::address_of(account) != CoreAddresses::GENESIS_ADDRESS();
+        aborts_if !exists<ChainId::ChainId>(CoreAddresses::GENESIS_ADDRESS());
+        aborts_if ChainId::get() != chain_id;
+        aborts_if !exists<Account::Account>(txn_sender);
+        aborts_if !0x1::Authenticator::spec_is_supported_scheme(txn_public_key);
+        aborts_if Hash::sha3_256(txn_public_key) != global<Account::Account>(txn_sender).authentication_key;
+        aborts_if txn_sequence_number < global<Account::Account>(txn_sender).sequence_number;
+        aborts_if txn_sequence_number != global<Account::Account>(txn_sender).sequence_number;
+        aborts_if !exists<Account::Account>(txn_fee_payer);
+        aborts_if !0x1::Authenticator::spec_is_supported_scheme(txn_fee_payer_public_key);
+        aborts_if Hash::sha3_256(txn_fee_payer_public_key) != global<Account::Account>(txn_fee_payer).authentication_key;
+        aborts_if txn_gas_price * txn_max_gas_units > max_u64();
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && !exists<Account::Balance<TokenType>>(txn_fee_payer);
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && 0x1::Token::spec_token_code<TokenType>() != 0x1::Token::spec_token_code<STC>();
+        aborts_if txn_gas_price * txn_max_gas_units > 0 && global<Account::Balance<TokenType>>(txn_fee_payer).token.value < txn_gas_price * txn_max_gas_units;
+        include Timestamp::AbortsIfTimestampNotExists;
+        include Block::AbortsIfBlockMetadataNotExist;
+        include TransactionTimeout::AbortsIfTimestampNotValid;
+        aborts_if !TransactionTimeout::spec_is_valid_transaction_timestamp(txn_expiration_time);
+        include TransactionPublishOption::AbortsIfTxnPublishOptionNotExistWithBool {
+            is_script_or_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE || txn_payload_type == TXN_PAYLOAD_TYPE_SCRIPT),
+        };
+        aborts_if txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE && !TransactionPublishOption::spec_is_module_allowed(Signer::address_of(account));
+        aborts_if txn_payload_type == TXN_PAYLOAD_TYPE_SCRIPT && !TransactionPublishOption::spec_is_script_allowed(Signer::address_of(account));
+        include PackageTxnManager::CheckPackageTxnAbortsIfWithType{is_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE), sender:txn_sender, package_address: txn_package_address, package_hash: txn_script_or_package_hash};
+    }
+
     /// The epilogue is invoked at the end of transactions.
     /// It collects gas and bumps the sequence number
     public fun epilogue<TokenType: store>(
@@ -169,6 +271,61 @@ module TransactionManager {
         };
     }
 
+    /// The epilogue of a sponsored transaction, the same as `epilogue`,
+    /// except the gas is collected from the `txn_fee_payer`.
+    public fun fee_payer_epilogue<TokenType: store>(
+        account: signer,
+        txn_sender: address,
+        txn_sequence_number: u64,
+        txn_fee_payer: address,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        gas_units_remaining: u64,
+        txn_payload_type: u8,
+        _txn_script_or_package_hash: vector<u8>,
+        txn_package_address: address,
+        // txn execute success or fail.
+        success: bool,
+    ) {
+        CoreAddresses::assert_genesis_address(&account);
+        Account::txn_fee_payer_epilogue<TokenType>(
+            &account,
+            txn_sender,
+            txn_sequence_number,
+            txn_fee_payer,
+            txn_gas_price,
+            txn_max_gas_units,
+            gas_units_remaining,
+        );
+        if (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE) {
+            PackageTxnManager::package_txn_epilogue(
+                &account,
+                txn_sender,
+                txn_package_address,
+                success,
+            );
+        }
+    }
+
+    spec fun fee_payer_epilogue {
+        include CoreAddresses::AbortsIfNotGenesisAddress;
+        aborts_if !exists<Account::Balance<TokenType>>(txn_fee_payer);
+        aborts_if txn_max_gas_units < gas_units_remaining;
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > max_u64();
+        aborts_if global<Account::Balance<TokenType>>(txn_fee_payer).token.value < txn_gas_price * (txn_max_gas_units - gas_units_remaining);
+        aborts_if !exists<Account::Account>(txn_sender);
+        aborts_if txn_sequence_number + 1 > max_u64();
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > 0 &&
+                !exists<TransactionFee::TransactionFee<TokenType>>(CoreAddresses::SPEC_GENESIS_ADDRESS());
+        aborts_if txn_gas_price * (txn_max_gas_units - gas_units_remaining) > 0 &&
+                global<TransactionFee::TransactionFee<TokenType>>(CoreAddresses::SPEC_GENESIS_ADDRESS()).fee.value + txn_gas_price * (txn_max_gas_units - gas_units_remaining) > max_u128();
+        include PackageTxnManager::AbortsIfPackageTxnEpilogue {
+            is_package: (txn_payload_type == TXN_PAYLOAD_TYPE_PACKAGE),
+            package_address: txn_package_address,
+            success: success,
+        };
+    }
+
     /// Set the metadata for the current block and distribute transaction fees and block rewards.
     /// The runtime always runs this before executing the transactions in a block.
     public fun block_prologue(
@@ -5,7 +5,7 @@
 
 use clap::{App, Arg};
 use starcoin_crypto::hash::PlainCryptoHash;
-use starcoin_move_compiler::check_compiled_module_compat;
+use starcoin_move_compiler::compatibility::CompatibilityReport;
 use starcoin_vm_types::file_format::CompiledModule;
 use starcoin_vm_types::{
     language_storage::ModuleId,
@@ -204,8 +204,8 @@ fn main() {
             // extract new linking/layout API and check compatibility with old
             let new_module_id = module.self_id();
             if let Some(old_module) = old_compiled_modules.get(&new_module_id) {
-                let compatibility = check_compiled_module_compat(old_module, module);
-                assert!(compatibility, "Stdlib {:?} is incompatible!", new_module_id);
+                let report = CompatibilityReport::from_compiled_module(old_module, module);
+                assert!(report.compatible, "Stdlib {}", report);
             }
         }
     }
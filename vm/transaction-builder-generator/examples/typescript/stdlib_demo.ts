@@ -0,0 +1,51 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+import * as StarcoinTypes from "./starcoin_types/mod.ts";
+import * as Stdlib from "./starcoin_stdlib/mod.ts";
+
+function makeAddress(content: number[]): StarcoinTypes.AccountAddress {
+  if (content.length !== 16) {
+    throw new Error("address must be 16 bytes");
+  }
+  return new StarcoinTypes.AccountAddress(content.map((byte) => [byte]));
+}
+
+function main(): void {
+  const token = new StarcoinTypes.TypeTagVariantStruct(
+    new StarcoinTypes.StructTag(
+      makeAddress([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+      new StarcoinTypes.Identifier("STC"),
+      new StarcoinTypes.Identifier("STC"),
+      [],
+    ),
+  );
+  const payee = makeAddress(new Array(16).fill(0x22));
+  const payeeAuthKey = new Uint8Array(32).fill(0x22);
+  const amount = BigInt(1234567);
+
+  // Now encode and decode a peer to peer transaction script function.
+  const payload = Stdlib.encodePeerToPeerWithMetadataScriptFunction(
+    token,
+    payee,
+    payeeAuthKey,
+    amount,
+    new Uint8Array(),
+  );
+  const call = Stdlib.decodeScriptFunctionPayload(payload);
+  if (!(call instanceof Stdlib.ScriptFunctionCallVariantPeerToPeerWithMetadata)) {
+    throw new Error("unexpected type of script function");
+  }
+  if (call.amount !== amount) {
+    throw new Error("unexpected amount");
+  }
+
+  const bytes = Stdlib.bcsSerializePayload(payload);
+  const decoded = Stdlib.bcsSerializePayload(Stdlib.bcsDeserializePayload(bytes));
+  if (bytes.toString() !== decoded.toString()) {
+    throw new Error("payload should round-trip through BCS");
+  }
+  console.log(Array.from(bytes).map((byte) => `${byte} `).join(""));
+}
+
+main();
@@ -21,6 +21,7 @@ enum Language {
     Cpp,
     Java,
     Dart,
+    TypeScript,
 }
 }
 
@@ -110,6 +111,7 @@ fn main() {
                     // buildgen::dart::output(&mut out, &abis, class_name).unwrap()
                     panic!("Code generation in dart requires --target_source_dir");
                 }
+                Language::TypeScript => buildgen::typescript::output(&mut out, &abis).unwrap(),
             }
             return;
         }
@@ -128,6 +130,9 @@ fn main() {
                 Language::Cpp => Box::new(serdegen::cpp::Installer::new(install_dir.clone())),
                 Language::Java => Box::new(serdegen::java::Installer::new(install_dir.clone())),
                 Language::Dart => Box::new(serdegen::dart::Installer::new(install_dir.clone())),
+                Language::TypeScript => {
+                    Box::new(serdegen::typescript::Installer::new(install_dir.clone()))
+                }
             };
 
         match options.language {
@@ -180,6 +185,7 @@ fn main() {
             Language::Cpp => Box::new(buildgen::cpp::Installer::new(install_dir)),
             Language::Java => Box::new(buildgen::java::Installer::new(install_dir)),
             Language::Dart => Box::new(buildgen::dart::Installer::new(install_dir)),
+            Language::TypeScript => Box::new(buildgen::typescript::Installer::new(install_dir)),
         };
 
     if let Some(name) = options.module_name {
@@ -0,0 +1,680 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common;
+use heck::{CamelCase, MixedCase, ShoutySnakeCase};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, TypeTag},
+};
+use serde_generate::indent::{IndentConfig, IndentedWriter};
+use starcoin_vm_types::transaction::{
+    ArgumentABI, ScriptABI, ScriptFunctionABI, TransactionScriptABI, TypeArgumentABI,
+};
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Output transaction builders and decoders in TypeScript for the given ABIs.
+/// The generated module expects the `starcoin_types` and `bcs` modules installed next to it.
+pub fn output(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    let transaction_script_abis = common::transaction_script_abis(abis);
+    let script_function_abis = common::script_function_abis(abis);
+
+    let mut emitter = TypeScriptEmitter {
+        out: IndentedWriter::new(out, IndentConfig::Space(2)),
+    };
+    emitter.output_preamble()?;
+
+    emitter.output_script_call_classes(&transaction_script_abis)?;
+    emitter.output_script_function_call_classes(&script_function_abis)?;
+
+    emitter.output_encode_method(&transaction_script_abis, &script_function_abis)?;
+    emitter.output_decode_method()?;
+    emitter.output_payload_serialization_methods()?;
+
+    for abi in abis {
+        emitter.output_script_encoder_function(abi)?;
+    }
+    for abi in abis {
+        emitter.output_script_decoder_function(abi)?;
+    }
+
+    for abi in &transaction_script_abis {
+        emitter.output_code_constant(abi)?;
+    }
+    // Must be defined after the constants.
+    emitter.output_transaction_script_decoder_map(&transaction_script_abis)?;
+    emitter.output_script_function_decoder_map(&script_function_abis)?;
+
+    emitter.output_encoding_helpers(abis)?;
+    emitter.output_decoding_helpers(abis)?;
+    Ok(())
+}
+
+/// Shared state for the TypeScript code generator.
+struct TypeScriptEmitter<T> {
+    /// Writer.
+    out: IndentedWriter<T>,
+}
+
+impl<T> TypeScriptEmitter<T>
+where
+    T: Write,
+{
+    fn output_preamble(&mut self) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"import {{ BcsDeserializer, BcsSerializer }} from "../bcs/mod.ts";
+import * as StarcoinTypes from "../starcoin_types/mod.ts";
+
+function fromHexString(hexString: string): Uint8Array {{
+  return new Uint8Array((hexString.match(/.{{1,2}}/g) || []).map((byte) => parseInt(byte, 16)));
+}}
+
+function toHexString(bytes: Uint8Array): string {{
+  return Array.from(bytes).map((byte) => byte.toString(16).padStart(2, "0")).join("");
+}}"#
+        )
+    }
+
+    fn output_script_call_classes(&mut self, abis: &[TransactionScriptABI]) -> Result<()> {
+        self.output_comment("Structured representation of a call into a known Move script.")?;
+        writeln!(self.out, "export abstract class ScriptCall {{}}")?;
+        for abi in abis {
+            self.output_call_class(
+                "ScriptCall",
+                abi.name(),
+                abi.doc(),
+                abi.ty_args(),
+                abi.args(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn output_script_function_call_classes(&mut self, abis: &[ScriptFunctionABI]) -> Result<()> {
+        self.output_comment(
+            "Structured representation of a call into a known Move script function.",
+        )?;
+        writeln!(self.out, "export abstract class ScriptFunctionCall {{}}")?;
+        for abi in abis {
+            self.output_call_class(
+                "ScriptFunctionCall",
+                abi.name(),
+                abi.doc(),
+                abi.ty_args(),
+                abi.args(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn output_call_class(
+        &mut self,
+        base_class: &str,
+        name: &str,
+        doc: &str,
+        ty_args: &[TypeArgumentABI],
+        args: &[ArgumentABI],
+    ) -> Result<()> {
+        self.output_comment(doc)?;
+        writeln!(
+            self.out,
+            "export class {} extends {} {{",
+            Self::call_class_name(base_class, name),
+            base_class
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            "constructor({}) {{",
+            [
+                Self::quote_type_parameters(ty_args),
+                Self::quote_parameters(args),
+            ]
+            .concat()
+            .iter()
+            .map(|param| format!("public {}", param))
+            .collect::<Vec<_>>()
+            .join(", ")
+        )?;
+        self.out.indent();
+        writeln!(self.out, "super();")?;
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_encode_method(
+        &mut self,
+        transaction_script_abis: &[TransactionScriptABI],
+        script_function_abis: &[ScriptFunctionABI],
+    ) -> Result<()> {
+        self.output_comment("Build a Starcoin `Script` from a structured object `ScriptCall`.")?;
+        writeln!(
+            self.out,
+            "export function encodeScript(call: ScriptCall): StarcoinTypes.Script {{"
+        )?;
+        self.out.indent();
+        for abi in transaction_script_abis {
+            self.output_encode_dispatch(
+                "ScriptCall",
+                &Self::encoder_function_name(abi.name(), "script"),
+                abi.name(),
+                abi.ty_args(),
+                abi.args(),
+            )?;
+        }
+        writeln!(self.out, "throw new Error(\"Unknown script call\");")?;
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+
+        self.output_comment(
+            "Build a Starcoin `ScriptFunction` `TransactionPayload` from a structured object `ScriptFunctionCall`.",
+        )?;
+        writeln!(
+            self.out,
+            "export function encodeScriptFunction(call: ScriptFunctionCall): StarcoinTypes.TransactionPayload {{"
+        )?;
+        self.out.indent();
+        for abi in script_function_abis {
+            self.output_encode_dispatch(
+                "ScriptFunctionCall",
+                &Self::encoder_function_name(abi.name(), "script_function"),
+                abi.name(),
+                abi.ty_args(),
+                abi.args(),
+            )?;
+        }
+        writeln!(
+            self.out,
+            "throw new Error(\"Unknown script function call\");"
+        )?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_encode_dispatch(
+        &mut self,
+        base_class: &str,
+        encoder: &str,
+        name: &str,
+        ty_args: &[TypeArgumentABI],
+        args: &[ArgumentABI],
+    ) -> Result<()> {
+        writeln!(
+            self.out,
+            "if (call instanceof {}) {{",
+            Self::call_class_name(base_class, name)
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            "return {}({});",
+            encoder,
+            ty_args
+                .iter()
+                .map(|ty_arg| ty_arg.name())
+                .chain(args.iter().map(|arg| arg.name()))
+                .map(|name| format!("call.{}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_decode_method(&mut self) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"
+/**
+ * Try to recognize a Starcoin `Script` and convert it into a structured object `ScriptCall`.
+ */
+export function decodeScript(script: StarcoinTypes.Script): ScriptCall {{
+  const helper = TRANSACTION_SCRIPT_DECODER_MAP.get(toHexString(script.code));
+  if (helper === undefined) {{
+    throw new Error("Unknown script bytecode");
+  }}
+  return helper(script);
+}}
+
+/**
+ * Try to recognize a Starcoin `TransactionPayload` and convert it into a structured object `ScriptFunctionCall`.
+ */
+export function decodeScriptFunctionPayload(payload: StarcoinTypes.TransactionPayload): ScriptFunctionCall {{
+  if (!(payload instanceof StarcoinTypes.TransactionPayloadVariantScriptFunction)) {{
+    throw new Error("Unexpected transaction payload");
+  }}
+  const script = payload.value;
+  const helper = SCRIPT_FUNCTION_DECODER_MAP.get(script.module.name.value + script.function.value);
+  if (helper === undefined) {{
+    throw new Error("Unknown script function");
+  }}
+  return helper(script);
+}}"#
+        )
+    }
+
+    fn output_payload_serialization_methods(&mut self) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"
+/**
+ * Serialize a `TransactionPayload` in BCS.
+ */
+export function bcsSerializePayload(payload: StarcoinTypes.TransactionPayload): Uint8Array {{
+  const serializer = new BcsSerializer();
+  payload.serialize(serializer);
+  return serializer.getBytes();
+}}
+
+/**
+ * Deserialize a `TransactionPayload` from BCS bytes.
+ */
+export function bcsDeserializePayload(bytes: Uint8Array): StarcoinTypes.TransactionPayload {{
+  const deserializer = new BcsDeserializer(bytes);
+  return StarcoinTypes.TransactionPayload.deserialize(deserializer);
+}}"#
+        )
+    }
+
+    fn output_script_encoder_function(&mut self, abi: &ScriptABI) -> Result<()> {
+        self.output_comment(abi.doc())?;
+        match abi {
+            ScriptABI::TransactionScript(abi) => self.emit_transaction_script_encoder_function(abi),
+            ScriptABI::ScriptFunction(abi) => self.emit_script_function_encoder_function(abi),
+        }
+    }
+
+    fn emit_transaction_script_encoder_function(
+        &mut self,
+        abi: &TransactionScriptABI,
+    ) -> Result<()> {
+        writeln!(
+            self.out,
+            "export function {}({}): StarcoinTypes.Script {{",
+            Self::encoder_function_name(abi.name(), "script"),
+            [
+                Self::quote_type_parameters(abi.ty_args()),
+                Self::quote_parameters(abi.args()),
+            ]
+            .concat()
+            .join(", ")
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            r#"return new StarcoinTypes.Script(
+  {}_CODE,
+  [{}],
+  [{}],
+);"#,
+            abi.name().to_shouty_snake_case(),
+            Self::quote_type_arguments(abi.ty_args()),
+            Self::quote_arguments(abi.args()),
+        )?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn emit_script_function_encoder_function(&mut self, abi: &ScriptFunctionABI) -> Result<()> {
+        writeln!(
+            self.out,
+            "export function {}({}): StarcoinTypes.TransactionPayload {{",
+            Self::encoder_function_name(abi.name(), "script_function"),
+            [
+                Self::quote_type_parameters(abi.ty_args()),
+                Self::quote_parameters(abi.args()),
+            ]
+            .concat()
+            .join(", ")
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            r#"return new StarcoinTypes.TransactionPayloadVariantScriptFunction(
+  new StarcoinTypes.ScriptFunction(
+    {},
+    {},
+    [{}],
+    [{}],
+  ),
+);"#,
+            Self::quote_module_id(abi.module_name()),
+            Self::quote_identifier(abi.name()),
+            Self::quote_type_arguments(abi.ty_args()),
+            Self::quote_arguments(abi.args()),
+        )?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_script_decoder_function(&mut self, abi: &ScriptABI) -> Result<()> {
+        match abi {
+            ScriptABI::TransactionScript(abi) => self.emit_decoder_function(
+                &Self::decoder_function_name(abi.name(), "script"),
+                "StarcoinTypes.Script",
+                "ScriptCall",
+                abi.name(),
+                abi.ty_args(),
+                abi.args(),
+            ),
+            ScriptABI::ScriptFunction(abi) => self.emit_decoder_function(
+                &Self::decoder_function_name(abi.name(), "script_function"),
+                "StarcoinTypes.ScriptFunction",
+                "ScriptFunctionCall",
+                abi.name(),
+                abi.ty_args(),
+                abi.args(),
+            ),
+        }
+    }
+
+    fn emit_decoder_function(
+        &mut self,
+        decoder: &str,
+        script_type: &str,
+        base_class: &str,
+        name: &str,
+        ty_args: &[TypeArgumentABI],
+        args: &[ArgumentABI],
+    ) -> Result<()> {
+        writeln!(
+            self.out,
+            "\nfunction {}({}script: {}): {} {{",
+            decoder,
+            // prevent warning "unused variable"
+            if ty_args.is_empty() && args.is_empty() {
+                "_"
+            } else {
+                ""
+            },
+            script_type,
+            base_class,
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            "return new {}(",
+            Self::call_class_name(base_class, name)
+        )?;
+        self.out.indent();
+        for (index, _ty_arg) in ty_args.iter().enumerate() {
+            writeln!(self.out, "script.ty_args[{}],", index)?;
+        }
+        for (index, arg) in args.iter().enumerate() {
+            writeln!(
+                self.out,
+                "{}(script.args[{}]),",
+                Self::decoding_helper_name(arg.type_tag()),
+                index,
+            )?;
+        }
+        self.out.unindent();
+        writeln!(self.out, ");")?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_code_constant(&mut self, abi: &TransactionScriptABI) -> Result<()> {
+        writeln!(
+            self.out,
+            "\nconst {}_CODE = fromHexString(\"{}\");",
+            abi.name().to_shouty_snake_case(),
+            abi.code()
+                .iter()
+                .map(|x| format!("{:02x}", x))
+                .collect::<Vec<_>>()
+                .join(""),
+        )
+    }
+
+    fn output_transaction_script_decoder_map(
+        &mut self,
+        abis: &[TransactionScriptABI],
+    ) -> Result<()> {
+        writeln!(
+            self.out,
+            "\nconst TRANSACTION_SCRIPT_DECODER_MAP = new Map<string, (script: StarcoinTypes.Script) => ScriptCall>(["
+        )?;
+        self.out.indent();
+        for abi in abis {
+            writeln!(
+                self.out,
+                "[toHexString({}_CODE), {}],",
+                abi.name().to_shouty_snake_case(),
+                Self::decoder_function_name(abi.name(), "script"),
+            )?;
+        }
+        self.out.unindent();
+        writeln!(self.out, "]);")
+    }
+
+    fn output_script_function_decoder_map(&mut self, abis: &[ScriptFunctionABI]) -> Result<()> {
+        writeln!(
+            self.out,
+            "\nconst SCRIPT_FUNCTION_DECODER_MAP = new Map<string, (script: StarcoinTypes.ScriptFunction) => ScriptFunctionCall>(["
+        )?;
+        self.out.indent();
+        for abi in abis {
+            writeln!(
+                self.out,
+                "[\"{}{}\", {}],",
+                abi.module_name().name(),
+                abi.name(),
+                Self::decoder_function_name(abi.name(), "script_function"),
+            )?;
+        }
+        self.out.unindent();
+        writeln!(self.out, "]);")
+    }
+
+    fn output_encoding_helpers(&mut self, abis: &[ScriptABI]) -> Result<()> {
+        let required_types = common::get_required_decoding_helper_types(abis);
+        for required_type in required_types {
+            self.output_encoding_helper(required_type)?;
+        }
+        Ok(())
+    }
+
+    fn output_encoding_helper(&mut self, type_tag: &TypeTag) -> Result<()> {
+        use TypeTag::*;
+        let expr = match type_tag {
+            Bool => "serializer.serializeBool(arg)",
+            U8 => "serializer.serializeU8(arg)",
+            U64 => "serializer.serializeU64(arg)",
+            U128 => "serializer.serializeU128(arg)",
+            Address => "arg.serialize(serializer)",
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => "serializer.serializeBytes(arg)",
+                _ => common::type_not_allowed(type_tag),
+            },
+            Struct(_) | Signer => common::type_not_allowed(type_tag),
+        };
+        writeln!(
+            self.out,
+            r#"
+function {}(arg: {}): Uint8Array {{
+  const serializer = new BcsSerializer();
+  {};
+  return serializer.getBytes();
+}}"#,
+            Self::encoding_helper_name(type_tag),
+            Self::quote_type(type_tag),
+            expr,
+        )
+    }
+
+    fn output_decoding_helpers(&mut self, abis: &[ScriptABI]) -> Result<()> {
+        let required_types = common::get_required_decoding_helper_types(abis);
+        for required_type in required_types {
+            self.output_decoding_helper(required_type)?;
+        }
+        Ok(())
+    }
+
+    fn output_decoding_helper(&mut self, type_tag: &TypeTag) -> Result<()> {
+        use TypeTag::*;
+        let expr = match type_tag {
+            Bool => "deserializer.deserializeBool()",
+            U8 => "deserializer.deserializeU8()",
+            U64 => "deserializer.deserializeU64()",
+            U128 => "deserializer.deserializeU128()",
+            Address => "StarcoinTypes.AccountAddress.deserialize(deserializer)",
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => "deserializer.deserializeBytes()",
+                _ => common::type_not_allowed(type_tag),
+            },
+            Struct(_) | Signer => common::type_not_allowed(type_tag),
+        };
+        writeln!(
+            self.out,
+            r#"
+function {}(arg: Uint8Array): {} {{
+  const deserializer = new BcsDeserializer(arg);
+  return {};
+}}"#,
+            Self::decoding_helper_name(type_tag),
+            Self::quote_type(type_tag),
+            expr,
+        )
+    }
+
+    fn output_comment(&mut self, doc: &str) -> Result<()> {
+        let doc = common::prepare_doc_string(doc).replace("*/", "*\\/");
+        writeln!(self.out, "\n/**")?;
+        for line in doc.lines() {
+            if line.is_empty() {
+                writeln!(self.out, " *")?;
+            } else {
+                writeln!(self.out, " * {}", line)?;
+            }
+        }
+        writeln!(self.out, " */")
+    }
+
+    fn call_class_name(base_class: &str, name: &str) -> String {
+        format!("{}Variant{}", base_class, name.to_camel_case())
+    }
+
+    fn encoder_function_name(name: &str, suffix: &str) -> String {
+        format!("encode_{}_{}", name, suffix).to_mixed_case()
+    }
+
+    fn decoder_function_name(name: &str, suffix: &str) -> String {
+        format!("decode_{}_{}", name, suffix).to_mixed_case()
+    }
+
+    fn encoding_helper_name(type_tag: &TypeTag) -> String {
+        format!("encode_{}_argument", common::mangle_type(type_tag)).to_mixed_case()
+    }
+
+    fn decoding_helper_name(type_tag: &TypeTag) -> String {
+        format!("decode_{}_argument", common::mangle_type(type_tag)).to_mixed_case()
+    }
+
+    fn quote_identifier(ident: &str) -> String {
+        format!("new StarcoinTypes.Identifier(\"{}\")", ident)
+    }
+
+    fn quote_address(address: &AccountAddress) -> String {
+        format!(
+            "new StarcoinTypes.AccountAddress([{}])",
+            address
+                .to_vec()
+                .iter()
+                .map(|x| format!("[{}]", x))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn quote_module_id(module_id: &ModuleId) -> String {
+        format!(
+            "new StarcoinTypes.ModuleId({}, {})",
+            Self::quote_address(module_id.address()),
+            Self::quote_identifier(module_id.name().as_str()),
+        )
+    }
+
+    fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+        ty_args
+            .iter()
+            .map(|ty_arg| format!("{}: StarcoinTypes.TypeTag", ty_arg.name()))
+            .collect()
+    }
+
+    fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+        args.iter()
+            .map(|arg| format!("{}: {}", arg.name(), Self::quote_type(arg.type_tag())))
+            .collect()
+    }
+
+    fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+        ty_args
+            .iter()
+            .map(|ty_arg| ty_arg.name().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_arguments(args: &[ArgumentABI]) -> String {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    "{}({})",
+                    Self::encoding_helper_name(arg.type_tag()),
+                    arg.name()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_type(type_tag: &TypeTag) -> String {
+        use TypeTag::*;
+        match type_tag {
+            Bool => "boolean".into(),
+            U8 => "number".into(),
+            U64 => "bigint".into(),
+            U128 => "bigint".into(),
+            Address => "StarcoinTypes.AccountAddress".into(),
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => "Uint8Array".into(),
+                _ => common::type_not_allowed(type_tag),
+            },
+
+            Struct(_) | Signer => common::type_not_allowed(type_tag),
+        }
+    }
+}
+
+pub struct Installer {
+    install_dir: PathBuf,
+}
+
+impl Installer {
+    pub fn new(install_dir: PathBuf) -> Self {
+        Installer { install_dir }
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[ScriptABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.install_dir.join(name);
+        std::fs::create_dir_all(&dir_path)?;
+        let mut file = std::fs::File::create(dir_path.join("mod.ts"))?;
+        output(&mut file, abis)?;
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@
 use serde_generate as serdegen;
 use serde_generate::SourceInstaller as _;
 use serde_reflection::Registry;
-use starcoin_vm_types::transaction::ScriptABI;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::identifier::Identifier;
+use starcoin_vm_types::language_storage::{ModuleId, StructTag, TypeTag, CORE_CODE_ADDRESS};
+use starcoin_vm_types::transaction::{ScriptABI, ScriptFunction, TransactionPayload};
 use std::{io::Write, path::Path, process::Command};
 use tempfile::tempdir;
 use transaction_builder_generator as buildgen;
@@ -16,6 +19,12 @@ fn get_starcoin_registry() -> Registry {
     serde_yaml::from_str::<Registry>(content.as_str()).unwrap()
 }
 
+fn get_starcoin_types_registry() -> Registry {
+    let path = "../../etc/starcoin_types.yml";
+    let content = std::fs::read_to_string(path).unwrap();
+    serde_yaml::from_str::<Registry>(content.as_str()).unwrap()
+}
+
 fn get_stdlib_script_abis() -> Vec<ScriptABI> {
     let path = Path::new("../stdlib/compiled/latest/transaction_scripts/abi");
     buildgen::read_abis(&path).expect("reading ABI files should not fail")
@@ -278,3 +287,75 @@ fn test_that_java_code_compiles_and_demo_runs() {
     assert!(output.status.success());
     assert_eq!(std::str::from_utf8(&output.stdout).unwrap(), OUTPUT);
 }
+
+/// The BCS bytes of the peer_to_peer_with_metadata payload built by the demos, encoded in Rust.
+fn peer_to_peer_with_metadata_payload_output() -> String {
+    let token = TypeTag::Struct(StructTag {
+        address: CORE_CODE_ADDRESS,
+        module: Identifier::new("STC").unwrap(),
+        name: Identifier::new("STC").unwrap(),
+        type_params: vec![],
+    });
+    let payload = TransactionPayload::ScriptFunction(ScriptFunction::new(
+        ModuleId::new(
+            CORE_CODE_ADDRESS,
+            Identifier::new("TransferScripts").unwrap(),
+        ),
+        Identifier::new("peer_to_peer_with_metadata").unwrap(),
+        vec![token],
+        vec![
+            bcs::to_bytes(&AccountAddress::new([0x22; 16])).unwrap(),
+            bcs::to_bytes(&vec![0x22u8; 32]).unwrap(),
+            bcs::to_bytes(&1_234_567u128).unwrap(),
+            bcs::to_bytes(&Vec::<u8>::new()).unwrap(),
+        ],
+    ));
+    let mut output = String::new();
+    for b in bcs::to_bytes(&payload).unwrap() {
+        output.push_str(&format!("{} ", b));
+    }
+    output.push('\n');
+    output
+}
+
+#[test]
+#[ignore]
+fn test_that_typescript_code_compiles_and_demo_runs() {
+    let registry = get_starcoin_types_registry();
+    let abis = get_stdlib_script_abis();
+    let dir = tempdir().unwrap();
+
+    let installer = serdegen::typescript::Installer::new(dir.path().to_path_buf());
+    let config = serdegen::CodeGeneratorConfig::new("starcoin_types".to_string())
+        .with_encodings(vec![serdegen::Encoding::Bcs]);
+    installer.install_module(&config, &registry).unwrap();
+    installer.install_serde_runtime().unwrap();
+    installer.install_bcs_runtime().unwrap();
+
+    let abi_installer = buildgen::typescript::Installer::new(dir.path().to_path_buf());
+    abi_installer
+        .install_transaction_builders("starcoin_stdlib", &abis)
+        .unwrap();
+
+    std::fs::copy(
+        "examples/typescript/stdlib_demo.ts",
+        dir.path().join("stdlib_demo.ts"),
+    )
+    .unwrap();
+
+    let output = Command::new("deno")
+        .current_dir(dir.path())
+        .arg("run")
+        .arg(dir.path().join("stdlib_demo.ts"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        std::str::from_utf8(&output.stderr).unwrap()
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap(),
+        peer_to_peer_with_metadata_payload_output()
+    );
+}
@@ -38,3 +38,7 @@ pub static PROLOGUE_NAME: Lazy<Identifier> = Lazy::new(|| Identifier::new("prolo
 pub static BLOCK_PROLOGUE_NAME: Lazy<Identifier> =
     Lazy::new(|| Identifier::new("block_prologue").unwrap());
 pub static EPILOGUE_NAME: Lazy<Identifier> = Lazy::new(|| Identifier::new("epilogue").unwrap());
+pub static FEE_PAYER_PROLOGUE_NAME: Lazy<Identifier> =
+    Lazy::new(|| Identifier::new("fee_payer_prologue").unwrap());
+pub static FEE_PAYER_EPILOGUE_NAME: Lazy<Identifier> =
+    Lazy::new(|| Identifier::new("fee_payer_epilogue").unwrap());
@@ -65,6 +65,11 @@ impl AccountResource {
         &self.authentication_key
     }
 
+    /// Rotate the authentication_key of the given AccountResource
+    pub fn rotate_authentication_key(&mut self, authentication_key: Vec<u8>) {
+        self.authentication_key = authentication_key;
+    }
+
     /// Return the deposit_events handle for the given AccountResource
     pub fn deposit_events(&self) -> &EventHandle {
         &self.deposit_events
@@ -0,0 +1,33 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::access_path::AccessPath;
+use crate::account_address::AccountAddress;
+use crate::move_resource::MoveResource;
+use serde::{Deserialize, Serialize};
+
+/// The error map of the modules published under an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorMapResource {
+    /// BCS encoded error map generated by errmapgen.
+    bytes: Vec<u8>,
+}
+
+impl ErrorMapResource {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl MoveResource for ErrorMapResource {
+    const MODULE_NAME: &'static str = "ErrorMap";
+    const STRUCT_NAME: &'static str = "ErrorMap";
+}
+
+pub fn access_path_for_error_map(address: AccountAddress) -> AccessPath {
+    AccessPath::resource_access_path(address, ErrorMapResource::struct_tag())
+}
@@ -3,6 +3,7 @@
 
 pub mod account;
 pub mod balance;
+pub mod error_map;
 pub mod key_rotation_capability;
 pub mod module_upgrade_strategy;
 pub mod withdraw_capability;
@@ -10,6 +11,7 @@ pub mod withdraw_capability;
 pub use crate::token::token_info::*;
 pub use account::*;
 pub use balance::*;
+pub use error_map::*;
 pub use key_rotation_capability::*;
 pub use module_upgrade_strategy::*;
 pub use withdraw_capability::*;
@@ -124,6 +124,7 @@ pub mod account_config;
 pub mod block_metadata;
 pub mod event;
 pub mod genesis_config;
+pub mod module_abi;
 pub mod on_chain_config;
 pub mod on_chain_resource;
 pub mod serde_helper;
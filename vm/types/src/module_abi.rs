@@ -0,0 +1,309 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The ABI of a compiled module, describes the functions, structs and script functions of
+//! the module, so a client can build and check a call without the source of the module.
+
+use crate::access::ModuleAccess;
+use crate::account_address::AccountAddress;
+use crate::file_format::{
+    Ability, AbilitySet, CompiledModule, SignatureToken, StructFieldInformation, StructHandleIndex,
+    Visibility,
+};
+use crate::identifier::{IdentStr, Identifier};
+use crate::language_storage::{ModuleId, StructTag, TypeTag};
+use crate::transaction::{ArgumentABI, ScriptFunctionABI, TypeArgumentABI};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// A type in the function signature or struct field, may contains type parameters and references.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeABI {
+    Bool,
+    U8,
+    U64,
+    U128,
+    Address,
+    Signer,
+    Vector(Box<TypeABI>),
+    Struct {
+        address: AccountAddress,
+        module: Identifier,
+        name: Identifier,
+        type_args: Vec<TypeABI>,
+    },
+    /// The type parameter at the index of the function or struct.
+    TypeParameter(u16),
+    Reference(Box<TypeABI>),
+    MutableReference(Box<TypeABI>),
+}
+
+impl TypeABI {
+    pub fn new(module: &CompiledModule, token: &SignatureToken) -> Self {
+        match token {
+            SignatureToken::Bool => Self::Bool,
+            SignatureToken::U8 => Self::U8,
+            SignatureToken::U64 => Self::U64,
+            SignatureToken::U128 => Self::U128,
+            SignatureToken::Address => Self::Address,
+            SignatureToken::Signer => Self::Signer,
+            SignatureToken::Vector(inner) => Self::Vector(Box::new(Self::new(module, inner))),
+            SignatureToken::Struct(idx) => Self::new_struct(module, *idx, &[]),
+            SignatureToken::StructInstantiation(idx, type_args) => {
+                Self::new_struct(module, *idx, type_args)
+            }
+            SignatureToken::TypeParameter(idx) => Self::TypeParameter(*idx),
+            SignatureToken::Reference(inner) => Self::Reference(Box::new(Self::new(module, inner))),
+            SignatureToken::MutableReference(inner) => {
+                Self::MutableReference(Box::new(Self::new(module, inner)))
+            }
+        }
+    }
+
+    fn new_struct(
+        module: &CompiledModule,
+        idx: StructHandleIndex,
+        type_args: &[SignatureToken],
+    ) -> Self {
+        let struct_tag = struct_tag(module, idx, vec![]);
+        Self::Struct {
+            address: struct_tag.address,
+            module: struct_tag.module,
+            name: struct_tag.name,
+            type_args: type_args
+                .iter()
+                .map(|token| Self::new(module, token))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TypeParameterABI {
+    /// The name of the type parameter, the source name is not kept in bytecode, so it is `T{idx}`.
+    pub name: String,
+    /// The abilities the type argument must have.
+    pub abilities: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FunctionABI {
+    pub name: Identifier,
+    /// One of `private`, `public`, `script`.
+    pub visibility: String,
+    pub is_native: bool,
+    pub type_parameters: Vec<TypeParameterABI>,
+    pub parameters: Vec<TypeABI>,
+    pub returns: Vec<TypeABI>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FieldABI {
+    pub name: Identifier,
+    #[serde(rename = "type")]
+    pub type_: TypeABI,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StructABI {
+    pub name: Identifier,
+    pub abilities: Vec<String>,
+    pub type_parameters: Vec<TypeParameterABI>,
+    pub is_native: bool,
+    pub fields: Vec<FieldABI>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModuleABI {
+    pub module_id: ModuleId,
+    pub functions: Vec<FunctionABI>,
+    pub structs: Vec<StructABI>,
+    /// The ABIs of the script functions, can be used to build `ScriptFunction` payload.
+    pub script_functions: Vec<ScriptFunctionABI>,
+}
+
+impl ModuleABI {
+    pub fn new(module: &CompiledModule) -> Self {
+        let functions = module
+            .function_defs()
+            .iter()
+            .map(|def| {
+                let handle = module.function_handle_at(def.function);
+                FunctionABI {
+                    name: module.identifier_at(handle.name).to_owned(),
+                    visibility: format!("{:?}", def.visibility).to_lowercase(),
+                    is_native: def.is_native(),
+                    type_parameters: type_parameters(&handle.type_parameters),
+                    parameters: module
+                        .signature_at(handle.parameters)
+                        .0
+                        .iter()
+                        .map(|token| TypeABI::new(module, token))
+                        .collect(),
+                    returns: module
+                        .signature_at(handle.return_)
+                        .0
+                        .iter()
+                        .map(|token| TypeABI::new(module, token))
+                        .collect(),
+                }
+            })
+            .collect();
+        let structs = module
+            .struct_defs()
+            .iter()
+            .map(|def| {
+                let handle = module.struct_handle_at(def.struct_handle);
+                let (is_native, fields) = match &def.field_information {
+                    StructFieldInformation::Native => (true, vec![]),
+                    StructFieldInformation::Declared(fields) => (
+                        false,
+                        fields
+                            .iter()
+                            .map(|field| FieldABI {
+                                name: module.identifier_at(field.name).to_owned(),
+                                type_: TypeABI::new(module, &field.signature.0),
+                            })
+                            .collect(),
+                    ),
+                };
+                StructABI {
+                    name: module.identifier_at(handle.name).to_owned(),
+                    abilities: abilities(handle.abilities),
+                    type_parameters: type_parameters(&handle.type_parameters),
+                    is_native,
+                    fields,
+                }
+            })
+            .collect();
+        Self {
+            module_id: module.self_id(),
+            functions,
+            structs,
+            script_functions: script_function_abis(module),
+        }
+    }
+
+    pub fn function(&self, name: &IdentStr) -> Option<&FunctionABI> {
+        self.functions
+            .iter()
+            .find(|function| function.name.as_ident_str() == name)
+    }
+
+    pub fn struct_abi(&self, name: &IdentStr) -> Option<&StructABI> {
+        self.structs
+            .iter()
+            .find(|struct_abi| struct_abi.name.as_ident_str() == name)
+    }
+}
+
+fn abilities(abilities: AbilitySet) -> Vec<String> {
+    abilities
+        .into_iter()
+        .map(|ability| match ability {
+            Ability::Copy => "copy",
+            Ability::Drop => "drop",
+            Ability::Store => "store",
+            Ability::Key => "key",
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+fn type_parameters(type_parameters: &[AbilitySet]) -> Vec<TypeParameterABI> {
+    type_parameters
+        .iter()
+        .enumerate()
+        .map(|(idx, constraints)| TypeParameterABI {
+            name: format!("T{}", idx),
+            abilities: abilities(*constraints),
+        })
+        .collect()
+}
+
+/// Generate the ABIs of the script functions in the module, the leading signer arguments are
+/// skipped, and functions with arguments which can not be represented as type tag are ignored.
+pub fn script_function_abis(module: &CompiledModule) -> Vec<ScriptFunctionABI> {
+    module
+        .function_defs()
+        .iter()
+        .filter(|def| def.visibility == Visibility::Script)
+        .filter_map(|def| {
+            let handle = module.function_handle_at(def.function);
+            let name = module.identifier_at(handle.name).to_string();
+            let args = module
+                .signature_at(handle.parameters)
+                .0
+                .iter()
+                .skip_while(|token| is_signer(token))
+                .enumerate()
+                .map(|(idx, token)| {
+                    signature_token_to_type_tag(module, token)
+                        .map(|type_tag| ArgumentABI::new(format!("arg{}", idx), type_tag))
+                })
+                .collect::<Result<Vec<_>>>()
+                .ok()?;
+            let ty_args = (0..handle.type_parameters.len())
+                .map(|idx| TypeArgumentABI::new(format!("T{}", idx)))
+                .collect();
+            Some(ScriptFunctionABI::new(
+                name,
+                module.self_id(),
+                String::new(),
+                ty_args,
+                args,
+            ))
+        })
+        .collect()
+}
+
+fn is_signer(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Signer => true,
+        SignatureToken::Reference(inner) => matches!(inner.as_ref(), SignatureToken::Signer),
+        _ => false,
+    }
+}
+
+fn signature_token_to_type_tag(module: &CompiledModule, token: &SignatureToken) -> Result<TypeTag> {
+    Ok(match token {
+        SignatureToken::Bool => TypeTag::Bool,
+        SignatureToken::U8 => TypeTag::U8,
+        SignatureToken::U64 => TypeTag::U64,
+        SignatureToken::U128 => TypeTag::U128,
+        SignatureToken::Address => TypeTag::Address,
+        SignatureToken::Signer => TypeTag::Signer,
+        SignatureToken::Vector(inner) => {
+            TypeTag::Vector(Box::new(signature_token_to_type_tag(module, inner)?))
+        }
+        SignatureToken::Struct(idx) => TypeTag::Struct(struct_tag(module, *idx, vec![])),
+        SignatureToken::StructInstantiation(idx, type_args) => {
+            let type_params = type_args
+                .iter()
+                .map(|token| signature_token_to_type_tag(module, token))
+                .collect::<Result<Vec<_>>>()?;
+            TypeTag::Struct(struct_tag(module, *idx, type_params))
+        }
+        SignatureToken::TypeParameter(_)
+        | SignatureToken::Reference(_)
+        | SignatureToken::MutableReference(_) => {
+            bail!("unsupported script function argument type {:?}", token)
+        }
+    })
+}
+
+fn struct_tag(
+    module: &CompiledModule,
+    idx: StructHandleIndex,
+    type_params: Vec<TypeTag>,
+) -> StructTag {
+    let struct_handle = module.struct_handle_at(idx);
+    let module_handle = module.module_handle_at(struct_handle.module);
+    StructTag {
+        address: *module.address_identifier_at(module_handle.address),
+        module: module.identifier_at(module_handle.name).to_owned(),
+        name: module.identifier_at(struct_handle.name).to_owned(),
+        type_params,
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::account_address::AccountAddress;
 use crate::sign_message::SigningMessage;
-use crate::transaction::{RawUserTransaction, SignedUserTransaction};
+use crate::transaction::{FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction};
 use anyhow::{ensure, Error, Result};
 #[cfg(any(test, feature = "fuzzing"))]
 use proptest_derive::Arbitrary;
@@ -16,6 +16,10 @@ use starcoin_crypto::ed25519::{
 use starcoin_crypto::multi_ed25519::multi_shard::{
     MultiEd25519KeyShard, MultiEd25519SignatureShard,
 };
+use starcoin_crypto::secp256k1::{
+    Secp256k1PrivateKey, Secp256k1PublicKey, Secp256k1Signature, SECP256K1_PRIVATE_KEY_LENGTH,
+    SECP256K1_PUBLIC_KEY_LENGTH, SECP256K1_SIGNATURE_LENGTH,
+};
 use starcoin_crypto::{
     derive::{DeserializeKey, SerializeKey},
     ed25519::{Ed25519PublicKey, Ed25519Signature},
@@ -45,6 +49,7 @@ use std::{convert::TryFrom, fmt, str::FromStr};
 pub enum Scheme {
     Ed25519 = 0,
     MultiEd25519 = 1,
+    Secp256k1 = 2,
     // ... add more schemes here
 }
 
@@ -53,6 +58,7 @@ impl fmt::Display for Scheme {
         let display = match self {
             Scheme::Ed25519 => "Ed25519",
             Scheme::MultiEd25519 => "MultiEd25519",
+            Scheme::Secp256k1 => "Secp256k1",
         };
         write!(f, "Scheme::{}", display)
     }
@@ -70,6 +76,18 @@ pub enum TransactionAuthenticator {
         public_key: MultiEd25519PublicKey,
         signature: MultiEd25519Signature,
     },
+    /// Single secp256k1 ECDSA signature
+    Secp256k1 {
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    },
+    /// Sponsored transaction, the sender and the fee payer both sign the `FeePayerRawTransaction`,
+    /// and the gas is paid by the fee payer.
+    FeePayer {
+        sender: Box<TransactionAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer: Box<TransactionAuthenticator>,
+    },
     // ... add more schemes here
 }
 
@@ -79,6 +97,8 @@ impl TransactionAuthenticator {
         match self {
             Self::Ed25519 { .. } => Scheme::Ed25519,
             Self::MultiEd25519 { .. } => Scheme::MultiEd25519,
+            Self::Secp256k1 { .. } => Scheme::Secp256k1,
+            Self::FeePayer { sender, .. } => sender.scheme(),
         }
     }
 
@@ -101,6 +121,53 @@ impl TransactionAuthenticator {
         }
     }
 
+    /// Create a single-signature secp256k1 authenticator
+    pub fn secp256k1(public_key: Secp256k1PublicKey, signature: Secp256k1Signature) -> Self {
+        Self::Secp256k1 {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a sponsored transaction authenticator
+    pub fn fee_payer(
+        sender: TransactionAuthenticator,
+        fee_payer_address: AccountAddress,
+        fee_payer: TransactionAuthenticator,
+    ) -> Self {
+        Self::FeePayer {
+            sender: Box::new(sender),
+            fee_payer_address,
+            fee_payer: Box::new(fee_payer),
+        }
+    }
+
+    /// Return the fee payer address if it is a sponsored transaction authenticator
+    pub fn fee_payer_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::FeePayer {
+                fee_payer_address, ..
+            } => Some(*fee_payer_address),
+            _ => None,
+        }
+    }
+
+    /// Return the authenticator of the fee payer if it is a sponsored transaction authenticator
+    pub fn fee_payer_authenticator(&self) -> Option<&TransactionAuthenticator> {
+        match self {
+            Self::FeePayer { fee_payer, .. } => Some(fee_payer.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Return the authenticator of the sender, it is `self` if not a sponsored transaction authenticator
+    pub fn sender_authenticator(&self) -> &TransactionAuthenticator {
+        match self {
+            Self::FeePayer { sender, .. } => sender.as_ref(),
+            _ => self,
+        }
+    }
+
     /// Return Ok if the authenticator's public key matches its signature, Err otherwise
     pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
         match self {
@@ -112,6 +179,20 @@ impl TransactionAuthenticator {
                 public_key,
                 signature,
             } => signature.verify(message, public_key),
+            Self::Secp256k1 {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
+            Self::FeePayer {
+                sender, fee_payer, ..
+            } => {
+                ensure!(
+                    sender.fee_payer_address().is_none() && fee_payer.fee_payer_address().is_none(),
+                    "Nested fee payer authenticator is not allowed"
+                );
+                sender.verify(message)?;
+                fee_payer.verify(message)
+            }
         }
     }
 
@@ -120,6 +201,8 @@ impl TransactionAuthenticator {
         match self {
             Self::Ed25519 { public_key, .. } => public_key.to_bytes().to_vec(),
             Self::MultiEd25519 { public_key, .. } => public_key.to_bytes().to_vec(),
+            Self::Secp256k1 { public_key, .. } => public_key.to_bytes(),
+            Self::FeePayer { sender, .. } => sender.public_key_bytes(),
         }
     }
 
@@ -127,6 +210,8 @@ impl TransactionAuthenticator {
         match self {
             Self::Ed25519 { public_key, .. } => AccountPublicKey::Single(public_key.clone()),
             Self::MultiEd25519 { public_key, .. } => AccountPublicKey::Multi(public_key.clone()),
+            Self::Secp256k1 { public_key, .. } => AccountPublicKey::Secp256k1(public_key.clone()),
+            Self::FeePayer { sender, .. } => sender.public_key(),
         }
     }
 
@@ -135,6 +220,8 @@ impl TransactionAuthenticator {
         match self {
             Self::Ed25519 { signature, .. } => signature.to_bytes().to_vec(),
             Self::MultiEd25519 { signature, .. } => signature.to_bytes().to_vec(),
+            Self::Secp256k1 { signature, .. } => signature.to_bytes(),
+            Self::FeePayer { sender, .. } => sender.signature_bytes(),
         }
     }
 
@@ -191,6 +278,11 @@ impl AuthenticationKey {
         Self::from_preimage(&AuthenticationKeyPreimage::multi_ed25519(public_key))
     }
 
+    /// Create an authentication key from a Secp256k1 public key
+    pub fn secp256k1(public_key: &Secp256k1PublicKey) -> Self {
+        Self::from_preimage(&AuthenticationKeyPreimage::secp256k1(public_key))
+    }
+
     /// Return an address derived from the last `AccountAddress::LENGTH` bytes of this
     /// authentication key.
     pub fn derived_address(&self) -> AccountAddress {
@@ -246,6 +338,11 @@ impl AuthenticationKeyPreimage {
         Self::new(public_key.to_bytes(), Scheme::MultiEd25519)
     }
 
+    /// Construct a preimage from a compressed Secp256k1 public key
+    pub fn secp256k1(public_key: &Secp256k1PublicKey) -> AuthenticationKeyPreimage {
+        Self::new(public_key.to_bytes(), Scheme::Secp256k1)
+    }
+
     /// Construct a vector from this authentication key
     pub fn into_vec(self) -> Vec<u8> {
         self.0
@@ -320,18 +417,21 @@ impl fmt::Display for AuthenticationKey {
 pub enum AccountPublicKey {
     Single(Ed25519PublicKey),
     Multi(MultiEd25519PublicKey),
+    Secp256k1(Secp256k1PublicKey),
 }
 
 #[derive(Eq, PartialEq, Debug, DeserializeKey, SerializeKey)]
 pub enum AccountPrivateKey {
     Single(Ed25519PrivateKey),
     Multi(MultiEd25519KeyShard),
+    Secp256k1(Secp256k1PrivateKey),
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, DeserializeKey, SerializeKey, Eq)]
 pub enum AccountSignature {
     Single(Ed25519PublicKey, Ed25519Signature),
     Multi(MultiEd25519PublicKey, MultiEd25519SignatureShard),
+    Secp256k1(Secp256k1PublicKey, Secp256k1Signature),
 }
 impl ValidCryptoMaterial for AccountSignature {
     fn to_bytes(&self) -> Vec<u8> {
@@ -346,18 +446,32 @@ impl ValidCryptoMaterial for AccountSignature {
                 bytes.extend(multi_signed_shard.to_bytes().to_vec());
                 bytes
             }
+            Self::Secp256k1(public_key, signature) => {
+                let mut bytes = public_key.to_bytes();
+                bytes.extend(signature.to_bytes());
+                bytes
+            }
         }
     }
 }
+/// The secp256k1 key bytes are prefixed by the scheme id, to distinguish them from the ed25519 keys
+/// of the same length.
 impl ValidCryptoMaterial for AccountPublicKey {
     fn to_bytes(&self) -> Vec<u8> {
         match self {
             Self::Single(key) => key.to_bytes().to_vec(),
             Self::Multi(key) => key.to_bytes(),
+            Self::Secp256k1(key) => with_scheme_prefix(Scheme::Secp256k1, key.to_bytes()),
         }
     }
 }
 
+fn with_scheme_prefix(scheme: Scheme, key_bytes: Vec<u8>) -> Vec<u8> {
+    let mut bytes = vec![scheme as u8];
+    bytes.extend(key_bytes);
+    bytes
+}
+
 impl AccountPublicKey {
     pub fn derived_address(&self) -> AccountAddress {
         self.authentication_key().derived_address()
@@ -367,6 +481,7 @@ impl AccountPublicKey {
         match self {
             Self::Single(p) => AuthenticationKeyPreimage::ed25519(p),
             Self::Multi(p) => AuthenticationKeyPreimage::multi_ed25519(p),
+            Self::Secp256k1(p) => AuthenticationKeyPreimage::secp256k1(p),
         }
     }
 
@@ -380,6 +495,7 @@ impl AccountPublicKey {
         match self {
             Self::Single(public_key) => public_key.to_bytes().to_vec(),
             Self::Multi(public_key) => public_key.to_bytes().to_vec(),
+            Self::Secp256k1(public_key) => public_key.to_bytes(),
         }
     }
 
@@ -388,6 +504,7 @@ impl AccountPublicKey {
         match self {
             Self::Single { .. } => Scheme::Ed25519,
             Self::Multi { .. } => Scheme::MultiEd25519,
+            Self::Secp256k1 { .. } => Scheme::Secp256k1,
         }
     }
 
@@ -404,6 +521,13 @@ impl AccountPublicKey {
             _ => None,
         }
     }
+
+    pub fn as_secp256k1(&self) -> Option<Secp256k1PublicKey> {
+        match self {
+            Self::Secp256k1(key) => Some(key.clone()),
+            _ => None,
+        }
+    }
 }
 
 impl TryFrom<&[u8]> for AccountPublicKey {
@@ -412,6 +536,10 @@ impl TryFrom<&[u8]> for AccountPublicKey {
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         if value.len() == ED25519_PUBLIC_KEY_LENGTH {
             Ed25519PublicKey::try_from(value).map(Self::Single)
+        } else if value.len() == SECP256K1_PUBLIC_KEY_LENGTH + 1
+            && value[0] == Scheme::Secp256k1 as u8
+        {
+            Secp256k1PublicKey::try_from(&value[1..]).map(Self::Secp256k1)
         } else {
             MultiEd25519PublicKey::try_from(value).map(Self::Multi)
         }
@@ -432,11 +560,19 @@ impl Into<AccountPublicKey> for MultiEd25519PublicKey {
     }
 }
 
+#[allow(clippy::from_over_into)]
+impl Into<AccountPublicKey> for Secp256k1PublicKey {
+    fn into(self) -> AccountPublicKey {
+        AccountPublicKey::Secp256k1(self)
+    }
+}
+
 impl ValidCryptoMaterial for AccountPrivateKey {
     fn to_bytes(&self) -> Vec<u8> {
         match self {
             Self::Single(key) => key.to_bytes().to_vec(),
             Self::Multi(key) => key.to_bytes(),
+            Self::Secp256k1(key) => with_scheme_prefix(Scheme::Secp256k1, key.to_bytes()),
         }
     }
 }
@@ -446,6 +582,7 @@ impl AccountPrivateKey {
         match self {
             Self::Single(key) => AccountPublicKey::Single(key.public_key()),
             Self::Multi(key) => AccountPublicKey::Multi(key.public_key()),
+            Self::Secp256k1(key) => AccountPublicKey::Secp256k1(key.public_key()),
         }
     }
 
@@ -453,6 +590,9 @@ impl AccountPrivateKey {
         match self {
             Self::Single(key) => AccountSignature::Single(key.public_key(), key.sign(message)),
             Self::Multi(key) => AccountSignature::Multi(key.public_key(), key.sign(message)),
+            Self::Secp256k1(key) => {
+                AccountSignature::Secp256k1(key.public_key(), key.sign(message))
+            }
         }
     }
 
@@ -475,12 +615,23 @@ impl Into<AccountPrivateKey> for MultiEd25519KeyShard {
     }
 }
 
+#[allow(clippy::from_over_into)]
+impl Into<AccountPrivateKey> for Secp256k1PrivateKey {
+    fn into(self) -> AccountPrivateKey {
+        AccountPrivateKey::Secp256k1(self)
+    }
+}
+
 impl TryFrom<&[u8]> for AccountPrivateKey {
     type Error = CryptoMaterialError;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         if value.len() == ED25519_PRIVATE_KEY_LENGTH {
             Ed25519PrivateKey::try_from(value).map(Self::Single)
+        } else if value.len() == SECP256K1_PRIVATE_KEY_LENGTH + 1
+            && value[0] == Scheme::Secp256k1 as u8
+        {
+            Secp256k1PrivateKey::try_from(&value[1..]).map(Self::Secp256k1)
         } else {
             MultiEd25519KeyShard::try_from(value).map(Self::Multi)
         }
@@ -496,6 +647,10 @@ impl TryFrom<&[u8]> for AccountSignature {
             let public_key = Ed25519PublicKey::try_from(&value[..ED25519_PUBLIC_KEY_LENGTH])?;
             let signature = Ed25519Signature::try_from(&value[ED25519_PUBLIC_KEY_LENGTH..])?;
             Ok(Self::Single(public_key, signature))
+        } else if length == SECP256K1_PUBLIC_KEY_LENGTH + SECP256K1_SIGNATURE_LENGTH {
+            let public_key = Secp256k1PublicKey::try_from(&value[..SECP256K1_PUBLIC_KEY_LENGTH])?;
+            let signature = Secp256k1Signature::try_from(&value[SECP256K1_PUBLIC_KEY_LENGTH..])?;
+            Ok(Self::Secp256k1(public_key, signature))
         } else {
             // 1 is MultiEd25519PublicKey's threshold
             // 4 is  MultiEd25519Signature's bitmap
@@ -514,14 +669,14 @@ impl TryFrom<&[u8]> for AccountSignature {
 }
 
 impl AccountSignature {
-    pub fn build_transaction(self, raw_txn: RawUserTransaction) -> Result<SignedUserTransaction> {
+    pub fn into_authenticator(self) -> Result<TransactionAuthenticator> {
         Ok(match self {
             Self::Single(public_key, signature) => {
-                SignedUserTransaction::ed25519(raw_txn, public_key, signature)
+                TransactionAuthenticator::ed25519(public_key, signature)
             }
             Self::Multi(public_key, signature) => {
                 if signature.is_enough() {
-                    SignedUserTransaction::multi_ed25519(raw_txn, public_key, signature.into())
+                    TransactionAuthenticator::multi_ed25519(public_key, signature.into())
                 } else {
                     anyhow::bail!(
                         "MultiEd25519SignatureShard do not have enough signatures, current: {}, threshold: {}",
@@ -530,24 +685,123 @@ impl AccountSignature {
                     )
                 }
             }
+            Self::Secp256k1(public_key, signature) => {
+                TransactionAuthenticator::secp256k1(public_key, signature)
+            }
         })
     }
 
+    pub fn build_transaction(self, raw_txn: RawUserTransaction) -> Result<SignedUserTransaction> {
+        Ok(SignedUserTransaction::new(
+            raw_txn,
+            self.into_authenticator()?,
+        ))
+    }
+
+    /// Build a sponsored transaction by the sender's signature `self` and the fee payer's signature
+    /// of the `fee_payer_txn`.
+    pub fn build_fee_payer_transaction(
+        self,
+        fee_payer_txn: FeePayerRawTransaction,
+        fee_payer_signature: AccountSignature,
+    ) -> Result<SignedUserTransaction> {
+        let (raw_txn, fee_payer_address) = fee_payer_txn.into_inner();
+        Ok(SignedUserTransaction::fee_payer(
+            raw_txn,
+            self.into_authenticator()?,
+            fee_payer_address,
+            fee_payer_signature.into_authenticator()?,
+        ))
+    }
+
     pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
         match self {
             Self::Single(public_key, signature) => signature.verify(message, public_key),
             Self::Multi(public_key, signature) => signature.verify(message, public_key),
+            Self::Secp256k1(public_key, signature) => signature.verify(message, public_key),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::transaction::authenticator::AuthenticationKey;
+    use crate::account_address::AccountAddress;
+    use crate::transaction::authenticator::{
+        AccountPrivateKey, AccountPublicKey, AccountSignature, AuthenticationKey, Scheme,
+    };
+    use crate::transaction::{FeePayerRawTransaction, RawUserTransaction, SignedUserTransaction};
+    use bcs_ext::Sample;
+    use starcoin_crypto::keygen::KeyGen;
+    use starcoin_crypto::ValidCryptoMaterial;
+    use std::convert::TryFrom;
     use std::str::FromStr;
 
     #[test]
     fn test_from_str_should_not_panic_by_given_empty_string() {
         assert!(AuthenticationKey::from_str("").is_err());
     }
+
+    #[test]
+    fn test_secp256k1_account_key_and_signature() {
+        let (private_key, _public_key) = KeyGen::from_os_rng().generate_secp256k1_keypair();
+        let private_key: AccountPrivateKey = private_key.into();
+        let public_key = private_key.public_key();
+        assert!(matches!(public_key.scheme(), Scheme::Secp256k1));
+
+        let private_key2 = AccountPrivateKey::try_from(private_key.to_bytes().as_slice()).unwrap();
+        assert_eq!(private_key2.public_key(), public_key);
+        let public_key2 = AccountPublicKey::try_from(public_key.to_bytes().as_slice()).unwrap();
+        assert_eq!(public_key2, public_key);
+
+        let raw_txn = RawUserTransaction::sample();
+        let signature = private_key.sign(&raw_txn);
+        assert!(signature.verify(&raw_txn).is_ok());
+        let signature2 = AccountSignature::try_from(signature.to_bytes().as_slice()).unwrap();
+        assert_eq!(signature2, signature);
+
+        let txn = signature.build_transaction(raw_txn).unwrap();
+        assert!(matches!(txn.authenticator().scheme(), Scheme::Secp256k1));
+        assert_eq!(
+            txn.authenticator().authentication_key(),
+            public_key.authentication_key()
+        );
+        assert!(txn.check_signature().is_ok());
+    }
+
+    #[test]
+    fn test_fee_payer_transaction_signature() {
+        let sender_key = AccountPrivateKey::Single(KeyGen::from_os_rng().generate_keypair().0);
+        let fee_payer_key: AccountPrivateKey =
+            KeyGen::from_os_rng().generate_secp256k1_keypair().0.into();
+        let fee_payer_address = fee_payer_key.public_key().derived_address();
+        let raw_txn = RawUserTransaction::sample();
+        let fee_payer_txn = FeePayerRawTransaction::new(raw_txn.clone(), fee_payer_address);
+
+        let txn = sender_key
+            .sign(&fee_payer_txn)
+            .build_fee_payer_transaction(fee_payer_txn.clone(), fee_payer_key.sign(&fee_payer_txn))
+            .unwrap();
+        assert_eq!(txn.fee_payer_address(), Some(fee_payer_address));
+        assert_eq!(
+            txn.authenticator().authentication_key(),
+            sender_key.public_key().authentication_key()
+        );
+        let bytes = bcs_ext::to_bytes(&txn).unwrap();
+        let txn2: SignedUserTransaction = bcs_ext::from_bytes(bytes.as_slice()).unwrap();
+        assert_eq!(txn2, txn);
+        assert!(txn.clone().check_signature().is_ok());
+
+        // the fee payer must sign the txn with its address.
+        let other_fee_payer_txn = FeePayerRawTransaction::new(raw_txn, AccountAddress::random());
+        let txn = SignedUserTransaction::fee_payer(
+            txn.raw_txn().clone(),
+            txn.authenticator().sender_authenticator().clone(),
+            other_fee_payer_txn.fee_payer(),
+            txn.authenticator()
+                .fee_payer_authenticator()
+                .unwrap()
+                .clone(),
+        );
+        assert!(txn.check_signature().is_err());
+    }
 }
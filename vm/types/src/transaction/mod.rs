@@ -20,6 +20,7 @@ use bcs_ext::Sample;
 use serde::{Deserialize, Deserializer, Serialize};
 use starcoin_accumulator::inmemory::InMemoryAccumulator;
 use starcoin_crypto::multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature};
+use starcoin_crypto::secp256k1::{Secp256k1PublicKey, Secp256k1Signature};
 use starcoin_crypto::{
     ed25519::*,
     hash::{CryptoHash, CryptoHasher, PlainCryptoHash},
@@ -303,6 +304,32 @@ impl Sample for RawUserTransaction {
     }
 }
 
+/// FeePayerRawTransaction is the message signed by both the sender and the fee payer of a
+/// sponsored transaction, the gas of the transaction is paid by the `fee_payer`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize, CryptoHasher, CryptoHash)]
+pub struct FeePayerRawTransaction {
+    raw_txn: RawUserTransaction,
+    fee_payer: AccountAddress,
+}
+
+impl FeePayerRawTransaction {
+    pub fn new(raw_txn: RawUserTransaction, fee_payer: AccountAddress) -> Self {
+        Self { raw_txn, fee_payer }
+    }
+
+    pub fn raw_txn(&self) -> &RawUserTransaction {
+        &self.raw_txn
+    }
+
+    pub fn fee_payer(&self) -> AccountAddress {
+        self.fee_payer
+    }
+
+    pub fn into_inner(self) -> (RawUserTransaction, AccountAddress) {
+        (self.raw_txn, self.fee_payer)
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TransactionPayload {
     /// A transaction that executes code.
@@ -447,10 +474,36 @@ impl SignedUserTransaction {
         Self::new(raw_txn, authenticator)
     }
 
+    pub fn secp256k1(
+        raw_txn: RawUserTransaction,
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    ) -> SignedUserTransaction {
+        let authenticator = TransactionAuthenticator::secp256k1(public_key, signature);
+        Self::new(raw_txn, authenticator)
+    }
+
+    /// Create a sponsored transaction, both authenticators sign the `FeePayerRawTransaction`.
+    pub fn fee_payer(
+        raw_txn: RawUserTransaction,
+        sender: TransactionAuthenticator,
+        fee_payer_address: AccountAddress,
+        fee_payer: TransactionAuthenticator,
+    ) -> SignedUserTransaction {
+        let authenticator =
+            TransactionAuthenticator::fee_payer(sender, fee_payer_address, fee_payer);
+        Self::new(raw_txn, authenticator)
+    }
+
     pub fn authenticator(&self) -> TransactionAuthenticator {
         self.authenticator.clone()
     }
 
+    /// Return the fee payer address if the transaction is sponsored.
+    pub fn fee_payer_address(&self) -> Option<AccountAddress> {
+        self.authenticator.fee_payer_address()
+    }
+
     pub fn raw_txn(&self) -> &RawUserTransaction {
         &self.raw_txn
     }
@@ -498,7 +551,13 @@ impl SignedUserTransaction {
     /// Checks that the signature of given transaction. Returns `Ok(SignatureCheckedTransaction)` if
     /// the signature is valid.
     pub fn check_signature(self) -> Result<SignatureCheckedTransaction> {
-        self.authenticator.verify(&self.raw_txn)?;
+        match self.authenticator.fee_payer_address() {
+            Some(fee_payer) => self.authenticator.verify(&FeePayerRawTransaction::new(
+                self.raw_txn.clone(),
+                fee_payer,
+            ))?,
+            None => self.authenticator.verify(&self.raw_txn)?,
+        }
         Ok(SignatureCheckedTransaction(self))
     }
 
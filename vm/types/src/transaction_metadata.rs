@@ -46,14 +46,27 @@ pub struct TransactionMetadata {
     pub expiration_timestamp_secs: u64,
     pub chain_id: ChainId,
     pub payload: TransactionPayloadMetadata,
+    /// The fee payer address and its authentication key preimage of a sponsored transaction.
+    pub fee_payer: Option<(AccountAddress, Vec<u8>)>,
 }
 
 impl TransactionMetadata {
     pub fn new(txn: &SignedUserTransaction) -> Result<Self, VMStatus> {
-        Self::from_raw_txn_and_preimage(
+        let authenticator = txn.authenticator();
+        let mut metadata = Self::from_raw_txn_and_preimage(
             txn.raw_txn(),
-            txn.authenticator().authentication_key_preimage(),
-        )
+            authenticator.authentication_key_preimage(),
+        )?;
+        if let (Some(fee_payer_address), Some(fee_payer)) = (
+            authenticator.fee_payer_address(),
+            authenticator.fee_payer_authenticator(),
+        ) {
+            metadata.fee_payer = Some((
+                fee_payer_address,
+                fee_payer.authentication_key_preimage().into_vec(),
+            ));
+        }
+        Ok(metadata)
     }
 
     pub fn from_raw_txn_and_preimage(
@@ -81,6 +94,7 @@ impl TransactionMetadata {
                 ),
                 TransactionPayload::ScriptFunction(_) => TransactionPayloadMetadata::ScriptFunction,
             },
+            fee_payer: None,
         })
     }
     pub fn max_gas_amount(&self) -> GasUnits<GasCarrier> {
@@ -126,4 +140,15 @@ impl TransactionMetadata {
     pub fn payload(&self) -> &TransactionPayloadMetadata {
         &self.payload
     }
+
+    /// Return the fee payer address of a sponsored transaction.
+    pub fn fee_payer(&self) -> Option<AccountAddress> {
+        self.fee_payer.as_ref().map(|(address, _)| *address)
+    }
+
+    pub fn fee_payer_authentication_key_preimage(&self) -> Option<&[u8]> {
+        self.fee_payer
+            .as_ref()
+            .map(|(_, preimage)| preimage.as_slice())
+    }
 }
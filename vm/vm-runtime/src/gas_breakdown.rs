@@ -0,0 +1,61 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use starcoin_vm_types::gas_schedule::{CostStrategy, GasAlgebra};
+
+/// The gas charged by the gas meter for a call into the Move VM.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FrameGas {
+    /// The called function, like `0x1::TransferScripts::peer_to_peer`, `Script::main` for a
+    /// transaction script, or `publish(0x1::M)` for a published module.
+    pub function: String,
+    pub gas: u64,
+}
+
+/// The gas used of a transaction by charging phase, in gas units.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GasBreakdown {
+    /// Gas charged for the transaction size.
+    pub intrinsic: u64,
+    /// Gas charged for executing the Move code.
+    pub execution: u64,
+    /// Gas charged for the mutated accounts by `charge_global_write_gas_usage`.
+    pub storage_write: u64,
+    /// Number of the accounts charged by `charge_global_write_gas_usage`.
+    pub mutated_accounts: u64,
+    /// The execution gas by call, in call order.
+    pub frames: Vec<FrameGas>,
+}
+
+impl GasBreakdown {
+    pub fn total(&self) -> u64 {
+        self.intrinsic + self.execution + self.storage_write
+    }
+
+    /// Record the gas charged by a call as execution gas.
+    pub(crate) fn charge_frame(&mut self, function: String, gas: u64) {
+        self.execution += gas;
+        self.frames.push(FrameGas { function, gas });
+    }
+}
+
+/// Tracks the remaining gas of a `CostStrategy` between charging phases.
+pub(crate) struct GasPhaseTracker {
+    gas_left: u64,
+}
+
+impl GasPhaseTracker {
+    pub fn new(cost_strategy: &CostStrategy) -> Self {
+        Self {
+            gas_left: cost_strategy.remaining_gas().get(),
+        }
+    }
+
+    /// Returns the gas consumed since the last call.
+    pub fn consumed(&mut self, cost_strategy: &CostStrategy) -> u64 {
+        let gas_left = cost_strategy.remaining_gas().get();
+        let consumed = self.gas_left.saturating_sub(gas_left);
+        self.gas_left = gas_left;
+        consumed
+    }
+}
@@ -4,6 +4,7 @@
 extern crate prometheus;
 
 pub mod data_cache;
+pub mod gas_breakdown;
 pub mod metrics;
 pub mod starcoin_vm;
 pub use move_vm_runtime::move_vm;
@@ -6,6 +6,7 @@ use crate::data_cache::{RemoteStorage, StateViewCache};
 use crate::errors::{
     convert_normal_success_epilogue_error, convert_prologue_runtime_error, error_split,
 };
+use crate::gas_breakdown::{GasBreakdown, GasPhaseTracker};
 use crate::metrics::{BLOCK_UNCLES, TXN_EXECUTION_GAS_USAGE};
 use anyhow::{format_err, Error, Result};
 use crypto::HashValue;
@@ -13,7 +14,7 @@ use move_vm_runtime::data_cache::RemoteCache;
 use move_vm_runtime::move_vm_adapter::{MoveVMAdapter, SessionAdapter};
 use starcoin_config::INITIAL_GAS_SCHEDULE;
 use starcoin_logger::prelude::*;
-use starcoin_move_compiler::check_module_compat;
+use starcoin_move_compiler::check_module_compat_report;
 use starcoin_types::account_config::access_path_for_module_upgrade_strategy;
 use starcoin_types::{
     account_config,
@@ -27,13 +28,15 @@ use starcoin_types::{
 use starcoin_vm_types::access::ModuleAccess;
 use starcoin_vm_types::account_address::AccountAddress;
 use starcoin_vm_types::account_config::{
-    genesis_address, ModuleUpgradeStrategy, EPILOGUE_NAME, PROLOGUE_NAME,
+    genesis_address, ModuleUpgradeStrategy, EPILOGUE_NAME, FEE_PAYER_EPILOGUE_NAME,
+    FEE_PAYER_PROLOGUE_NAME, PROLOGUE_NAME,
 };
 use starcoin_vm_types::contract_event::ContractEvent;
 use starcoin_vm_types::file_format::CompiledModule;
 use starcoin_vm_types::gas_schedule::{zero_cost_schedule, CostStrategy};
 use starcoin_vm_types::identifier::IdentStr;
 use starcoin_vm_types::language_storage::ModuleId;
+use starcoin_vm_types::transaction::authenticator::Scheme;
 use starcoin_vm_types::transaction::{DryRunTransaction, Module, Package, TransactionPayloadType};
 
 use starcoin_vm_types::transaction_metadata::TransactionPayloadMetadata;
@@ -55,6 +58,13 @@ use starcoin_vm_types::{
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+/// The secp256k1 authenticator scheme is only accepted since this on chain `Version`, which
+/// ships the `Authenticator::secp256k1_authentication_key` and the scheme check in the prologue.
+pub const SECP256K1_AUTHENTICATOR_VERSION: u64 = 2;
+/// The sponsored transaction is only accepted since this on chain `Version`, which ships the
+/// `TransactionManager::fee_payer_prologue` and `fee_payer_epilogue`.
+pub const FEE_PAYER_TRANSACTION_VERSION: u64 = 2;
+
 #[derive(Clone)]
 #[allow(clippy::upper_case_acronyms)]
 /// Wrapper of MoveVM
@@ -120,6 +130,37 @@ impl StarcoinVM {
             .ok_or(VMStatus::Error(StatusCode::VM_STARTUP_FAILURE))
     }
 
+    /// Check the features of the stdlib `version` are activated by the on chain `Version`.
+    /// The genesis of the latest stdlib, such as dev and test network, sets the on chain `Version`
+    /// to 0, all the features of the latest stdlib are activated on it.
+    fn is_version_activated(&self, version: u64) -> Result<bool, VMStatus> {
+        let major = self.get_version()?.major;
+        Ok(major == 0 || major >= version)
+    }
+
+    /// Reject the authenticator schemes which are not activated by the on chain `Version` yet.
+    fn check_authenticator_scheme(&self, txn_data: &TransactionMetadata) -> Result<(), VMStatus> {
+        // the last byte of the authentication key preimage is the scheme id.
+        let is_secp256k1 = |preimage: &[u8]| preimage.last() == Some(&(Scheme::Secp256k1 as u8));
+        if (is_secp256k1(txn_data.authentication_key_preimage())
+            || txn_data
+                .fee_payer_authentication_key_preimage()
+                .map(is_secp256k1)
+                .unwrap_or(false))
+            && !self.is_version_activated(SECP256K1_AUTHENTICATOR_VERSION)?
+        {
+            warn!("[VM] Secp256k1 authenticator is not activated by on chain version");
+            return Err(VMStatus::Error(StatusCode::INVALID_SIGNATURE));
+        }
+        if txn_data.fee_payer().is_some()
+            && !self.is_version_activated(FEE_PAYER_TRANSACTION_VERSION)?
+        {
+            warn!("[VM] Fee payer transaction is not activated by on chain version");
+            return Err(VMStatus::Error(StatusCode::INVALID_SIGNATURE));
+        }
+        Ok(())
+    }
+
     fn check_gas(&self, txn_data: &TransactionMetadata) -> Result<(), VMStatus> {
         let gas_constants = &self.get_gas_schedule()?.gas_constants;
         let raw_bytes_len = txn_data.transaction_size;
@@ -276,10 +317,10 @@ impl StarcoinVM {
             let pre_version = session
                 .load_module(&module_id)
                 .map_err(|e| e.into_vm_status())?;
-            let compatible = check_module_compat(pre_version.as_slice(), module.code())
+            let report = check_module_compat_report(pre_version.as_slice(), module.code())
                 .map_err(|e| e.into_vm_status())?;
-            if !compatible {
-                warn!("Check module compat error: {:?}", module_id);
+            if !report.compatible {
+                warn!("Check module compat error: {}", report);
                 return Err(errors::verification_error(
                     StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
                     IndexKind::ModuleHandle,
@@ -311,6 +352,7 @@ impl StarcoinVM {
         cost_strategy: &mut CostStrategy,
         txn_data: &TransactionMetadata,
         package: &Package,
+        gas_breakdown: &mut GasBreakdown,
     ) -> Result<(VMStatus, TransactionOutput), VMStatus> {
         let mut session = self.move_vm.new_session(remote_cache);
 
@@ -329,9 +371,11 @@ impl StarcoinVM {
             if !remote_cache.is_genesis() {
                 cost_strategy.enable_metering();
             }
+            let mut gas_tracker = GasPhaseTracker::new(cost_strategy);
             cost_strategy
                 .charge_intrinsic_gas(txn_data.transaction_size())
                 .map_err(|e| e.into_vm_status())?;
+            gas_breakdown.intrinsic = gas_tracker.consumed(cost_strategy);
 
             let package_address = package.package_address();
             match Self::only_new_module_strategy(remote_cache, package_address) {
@@ -365,27 +409,39 @@ impl StarcoinVM {
                             .verify_module(module.code())
                             .map_err(|e| e.into_vm_status())?;
 
-                        session
-                            .publish_module(module.code().to_vec(), txn_data.sender, cost_strategy)
-                            .map_err(|e| e.into_vm_status())?;
+                        let result = session.publish_module(
+                            module.code().to_vec(),
+                            txn_data.sender,
+                            cost_strategy,
+                        );
+                        gas_breakdown.charge_frame(
+                            format!("publish(0x{}::{})", module_id.address(), module_id.name()),
+                            gas_tracker.consumed(cost_strategy),
+                        );
+                        result.map_err(|e| e.into_vm_status())?;
                     }
                 }
             }
             if let Some(init_script) = package.init_script() {
                 let sender = txn_data.sender;
                 debug!("execute init script by account {:?}", sender);
-                session
-                    .execute_script_function(
-                        init_script.module(),
-                        init_script.function(),
-                        init_script.ty_args().to_vec(),
-                        init_script.args().to_vec(),
-                        vec![sender],
-                        cost_strategy,
-                    )
-                    .map_err(|e| e.into_vm_status())?
+                let result = session.execute_script_function(
+                    init_script.module(),
+                    init_script.function(),
+                    init_script.ty_args().to_vec(),
+                    init_script.args().to_vec(),
+                    vec![sender],
+                    cost_strategy,
+                );
+                gas_breakdown.charge_frame(
+                    frame_of_function(init_script.module(), init_script.function()),
+                    gas_tracker.consumed(cost_strategy),
+                );
+                result.map_err(|e| e.into_vm_status())?
             }
+            gas_breakdown.mutated_accounts = session.num_mutated_accounts(&txn_data.sender());
             charge_global_write_gas_usage(cost_strategy, &session, &txn_data.sender())?;
+            gas_breakdown.storage_write = gas_tracker.consumed(cost_strategy);
 
             cost_strategy.disable_metering();
             self.success_transaction_cleanup(
@@ -404,6 +460,7 @@ impl StarcoinVM {
         cost_strategy: &mut CostStrategy,
         txn_data: &TransactionMetadata,
         payload: &TransactionPayload,
+        gas_breakdown: &mut GasBreakdown,
     ) -> Result<(VMStatus, TransactionOutput), VMStatus> {
         let mut session = self.move_vm.new_session(remote_cache);
 
@@ -419,19 +476,25 @@ impl StarcoinVM {
         {
             //let _timer = TXN_EXECUTION_SECONDS.start_timer();
             cost_strategy.enable_metering();
+            let mut gas_tracker = GasPhaseTracker::new(cost_strategy);
             cost_strategy
                 .charge_intrinsic_gas(txn_data.transaction_size())
                 .map_err(|e| e.into_vm_status())?;
-            match payload {
-                TransactionPayload::Script(script) => session.execute_script(
-                    script.code().to_vec(),
-                    script.ty_args().to_vec(),
-                    script.args().to_vec(),
-                    vec![txn_data.sender()],
-                    cost_strategy,
+            gas_breakdown.intrinsic = gas_tracker.consumed(cost_strategy);
+            let (function, result) = match payload {
+                TransactionPayload::Script(script) => (
+                    "Script::main".to_string(),
+                    session.execute_script(
+                        script.code().to_vec(),
+                        script.ty_args().to_vec(),
+                        script.args().to_vec(),
+                        vec![txn_data.sender()],
+                        cost_strategy,
+                    ),
                 ),
-                TransactionPayload::ScriptFunction(script_function) => session
-                    .execute_script_function(
+                TransactionPayload::ScriptFunction(script_function) => (
+                    frame_of_function(script_function.module(), script_function.function()),
+                    session.execute_script_function(
                         script_function.module(),
                         script_function.function(),
                         script_function.ty_args().to_vec(),
@@ -439,13 +502,17 @@ impl StarcoinVM {
                         vec![txn_data.sender()],
                         cost_strategy,
                     ),
+                ),
                 TransactionPayload::Package(_) => {
                     return Err(VMStatus::Error(StatusCode::UNREACHABLE));
                 }
-            }
-            .map_err(|e| e.into_vm_status())?;
+            };
+            gas_breakdown.charge_frame(function, gas_tracker.consumed(cost_strategy));
+            result.map_err(|e| e.into_vm_status())?;
 
+            gas_breakdown.mutated_accounts = session.num_mutated_accounts(&txn_data.sender());
             charge_global_write_gas_usage(cost_strategy, &session, &txn_data.sender())?;
+            gas_breakdown.storage_write = gas_tracker.consumed(cost_strategy);
 
             cost_strategy.disable_metering();
             self.success_transaction_cleanup(
@@ -465,6 +532,7 @@ impl StarcoinVM {
         cost_strategy: &mut CostStrategy,
         txn_data: &TransactionMetadata,
     ) -> Result<(), VMStatus> {
+        self.check_authenticator_scheme(txn_data)?;
         let genesis_address = genesis_address();
         let gas_token_ty = txn_data.gas_token_code().into();
         let txn_sequence_number = txn_data.sequence_number();
@@ -487,13 +555,29 @@ impl StarcoinVM {
             ),
         };
 
-        // Run prologue by genesis account
-        session
-            .execute_function(
-                &account_config::TRANSACTION_MANAGER_MODULE,
-                &PROLOGUE_NAME,
-                vec![gas_token_ty],
-                serialize_values(&vec![
+        let (function_name, args) = match &txn_data.fee_payer {
+            // The gas of a sponsored transaction is paid by the fee payer
+            Some((fee_payer, fee_payer_public_key)) => (
+                &*FEE_PAYER_PROLOGUE_NAME,
+                vec![
+                    MoveValue::Signer(genesis_address),
+                    MoveValue::Address(txn_data.sender),
+                    MoveValue::U64(txn_sequence_number),
+                    MoveValue::vector_u8(txn_public_key),
+                    MoveValue::Address(*fee_payer),
+                    MoveValue::vector_u8(fee_payer_public_key.clone()),
+                    MoveValue::U64(txn_gas_price),
+                    MoveValue::U64(txn_max_gas_amount),
+                    MoveValue::U64(txn_expiration_time),
+                    MoveValue::U8(chain_id),
+                    MoveValue::U8(payload_type.into()),
+                    MoveValue::vector_u8(script_or_package_hash.to_vec()),
+                    MoveValue::Address(package_address),
+                ],
+            ),
+            None => (
+                &*PROLOGUE_NAME,
+                vec![
                     MoveValue::Signer(genesis_address),
                     MoveValue::Address(txn_data.sender),
                     MoveValue::U64(txn_sequence_number),
@@ -505,7 +589,16 @@ impl StarcoinVM {
                     MoveValue::U8(payload_type.into()),
                     MoveValue::vector_u8(script_or_package_hash.to_vec()),
                     MoveValue::Address(package_address),
-                ]),
+                ],
+            ),
+        };
+        // Run prologue by genesis account
+        session
+            .execute_function(
+                &account_config::TRANSACTION_MANAGER_MODULE,
+                function_name,
+                vec![gas_token_ty],
+                serialize_values(&args),
                 cost_strategy,
             )
             .map(|_return_vals| ())
@@ -540,16 +633,14 @@ impl StarcoinVM {
                 AccountAddress::ZERO,
             ),
         };
-        // Run epilogue by genesis account
-        session
-            .execute_function(
-                &account_config::TRANSACTION_MANAGER_MODULE,
-                &EPILOGUE_NAME,
-                vec![gas_token_ty],
-                serialize_values(&vec![
+        let (function_name, args) = match txn_data.fee_payer() {
+            Some(fee_payer) => (
+                &*FEE_PAYER_EPILOGUE_NAME,
+                vec![
                     MoveValue::Signer(genesis_address),
                     MoveValue::Address(txn_data.sender),
                     MoveValue::U64(txn_sequence_number),
+                    MoveValue::Address(fee_payer),
                     MoveValue::U64(txn_gas_price),
                     MoveValue::U64(txn_max_gas_amount),
                     MoveValue::U64(gas_remaining),
@@ -557,7 +648,31 @@ impl StarcoinVM {
                     MoveValue::vector_u8(script_or_package_hash.to_vec()),
                     MoveValue::Address(package_address),
                     MoveValue::Bool(success),
-                ]),
+                ],
+            ),
+            None => (
+                &*EPILOGUE_NAME,
+                vec![
+                    MoveValue::Signer(genesis_address),
+                    MoveValue::Address(txn_data.sender),
+                    MoveValue::U64(txn_sequence_number),
+                    MoveValue::U64(txn_gas_price),
+                    MoveValue::U64(txn_max_gas_amount),
+                    MoveValue::U64(gas_remaining),
+                    MoveValue::U8(payload_type.into()),
+                    MoveValue::vector_u8(script_or_package_hash.to_vec()),
+                    MoveValue::Address(package_address),
+                    MoveValue::Bool(success),
+                ],
+            ),
+        };
+        // Run epilogue by genesis account
+        session
+            .execute_function(
+                &account_config::TRANSACTION_MANAGER_MODULE,
+                function_name,
+                vec![gas_token_ty],
+                serialize_values(&args),
                 cost_strategy,
             )
             .map(|_return_vals| ())
@@ -660,6 +775,7 @@ impl StarcoinVM {
                             &mut cost_strategy,
                             &txn_data,
                             payload,
+                            &mut GasBreakdown::default(),
                         ),
                     TransactionPayload::Package(p) => self.execute_package(
                         remote_cache,
@@ -667,6 +783,7 @@ impl StarcoinVM {
                         &mut cost_strategy,
                         &txn_data,
                         p,
+                        &mut GasBreakdown::default(),
                     ),
                 };
                 match result {
@@ -706,6 +823,18 @@ impl StarcoinVM {
         state_view: &dyn StateView,
         txn: DryRunTransaction,
     ) -> Result<(VMStatus, TransactionOutput)> {
+        self.dry_run_transaction_with_gas_breakdown(state_view, txn)
+            .map(|(status, output, _)| (status, output))
+    }
+
+    /// Dry run the transaction, and break the gas used down by charging phase.
+    /// If the execution fails, the gas used before the failure is counted as execution gas.
+    pub fn dry_run_transaction_with_gas_breakdown(
+        &mut self,
+        state_view: &dyn StateView,
+        txn: DryRunTransaction,
+    ) -> Result<(VMStatus, TransactionOutput, GasBreakdown)> {
+        let mut gas_breakdown = GasBreakdown::default();
         let remote_cache = StateViewCache::new(state_view);
         //TODO load config by config change event.
         self.load_configs(&remote_cache)?;
@@ -716,7 +845,8 @@ impl StarcoinVM {
                 if remote_cache.is_genesis() {
                     &INITIAL_GAS_SCHEDULE
                 } else {
-                    return Ok(discard_error_vm_status(e));
+                    let (status, output) = discard_error_vm_status(e);
+                    return Ok((status, output, gas_breakdown));
                 }
             }
         };
@@ -725,7 +855,10 @@ impl StarcoinVM {
             txn.public_key.authentication_key_preimage(),
         ) {
             Ok(txn_data) => txn_data,
-            Err(e) => return Ok(discard_error_vm_status(e)),
+            Err(e) => {
+                let (status, output) = discard_error_vm_status(e);
+                return Ok((status, output, gas_breakdown));
+            }
         };
         let mut cost_strategy = CostStrategy::system(gas_schedule, txn_data.max_gas_amount());
         let result = match txn.raw_txn.payload() {
@@ -737,6 +870,7 @@ impl StarcoinVM {
                     &mut cost_strategy,
                     &txn_data,
                     payload,
+                    &mut gas_breakdown,
                 ),
             TransactionPayload::Package(p) => self.execute_package(
                 &remote_cache,
@@ -744,9 +878,10 @@ impl StarcoinVM {
                 &mut cost_strategy,
                 &txn_data,
                 p,
+                &mut gas_breakdown,
             ),
         };
-        Ok(match result {
+        let (status, output) = match result {
             Ok(status_and_output) => status_and_output,
             Err(err) => {
                 let txn_status = TransactionStatus::from(err.clone());
@@ -762,7 +897,12 @@ impl StarcoinVM {
                     )
                 }
             }
-        })
+        };
+        if gas_breakdown.total() < output.gas_used() {
+            gas_breakdown.execution =
+                output.gas_used() - gas_breakdown.intrinsic - gas_breakdown.storage_write;
+        }
+        Ok((status, output, gas_breakdown))
     }
 
     /// Execute a block transactions with gas_limit,
@@ -863,6 +1003,86 @@ impl StarcoinVM {
         Ok(result)
     }
 
+    /// Execute the payload with the signer of `sender` directly, without the transaction prologue
+    /// and epilogue, so the sender does not need to be an account with keys and balance.
+    /// This is only for local playground, the gas used is metered but not charged.
+    #[doc(hidden)]
+    #[cfg(feature = "playground")]
+    pub fn execute_payload_as(
+        &mut self,
+        state_view: &dyn StateView,
+        sender: AccountAddress,
+        payload: &TransactionPayload,
+        max_gas_amount: u64,
+    ) -> Result<(VMStatus, TransactionOutput)> {
+        let remote_cache = StateViewCache::new(state_view);
+        self.load_configs(&remote_cache)?;
+        let gas_schedule = self
+            .get_gas_schedule()
+            .map_err(|e| format_err!("load gas schedule error: {:?}", e))?;
+        let max_gas_amount = GasUnits::new(max_gas_amount);
+        let mut cost_strategy = CostStrategy::system(gas_schedule, max_gas_amount);
+        let mut session = self.move_vm.new_session(&remote_cache);
+        let result = match payload {
+            TransactionPayload::Script(script) => session.execute_script(
+                script.code().to_vec(),
+                script.ty_args().to_vec(),
+                script.args().to_vec(),
+                vec![sender],
+                &mut cost_strategy,
+            ),
+            TransactionPayload::ScriptFunction(script_function) => session.execute_script_function(
+                script_function.module(),
+                script_function.function(),
+                script_function.ty_args().to_vec(),
+                script_function.args().to_vec(),
+                vec![sender],
+                &mut cost_strategy,
+            ),
+            TransactionPayload::Package(package) => package
+                .modules()
+                .iter()
+                .try_for_each(|module| {
+                    session.verify_module(module.code())?;
+                    session.publish_module(module.code().to_vec(), sender, &mut cost_strategy)
+                })
+                .and_then(|_| match package.init_script() {
+                    Some(init_script) => session.execute_script_function(
+                        init_script.module(),
+                        init_script.function(),
+                        init_script.ty_args().to_vec(),
+                        init_script.args().to_vec(),
+                        vec![sender],
+                        &mut cost_strategy,
+                    ),
+                    None => Ok(()),
+                }),
+        };
+        Ok(match result {
+            Ok(_) => match get_transaction_output(
+                &mut (),
+                session,
+                &cost_strategy,
+                max_gas_amount,
+                KeptVMStatus::Executed,
+            ) {
+                Ok(output) => (VMStatus::Executed, output),
+                Err(e) => discard_error_vm_status(e),
+            },
+            Err(e) => {
+                let status = e.into_vm_status();
+                let gas_used = max_gas_amount.sub(cost_strategy.remaining_gas()).get();
+                let output = TransactionOutput::new(
+                    WriteSet::default(),
+                    vec![],
+                    gas_used,
+                    TransactionStatus::from(status.clone()),
+                );
+                (status, output)
+            }
+        })
+    }
+
     fn success_transaction_cleanup<R: RemoteCache>(
         &self,
         mut session: SessionAdapter<R>,
@@ -953,6 +1173,11 @@ pub fn chunk_block_transactions(txns: Vec<Transaction>) -> Vec<TransactionBlock>
     blocks
 }
 
+/// The frame name of a function call in the gas breakdown.
+fn frame_of_function(module: &ModuleId, function: &IdentStr) -> String {
+    format!("0x{}::{}::{}", module.address(), module.name(), function)
+}
+
 pub(crate) fn charge_global_write_gas_usage<R: RemoteCache>(
     cost_strategy: &mut CostStrategy,
     session: &SessionAdapter<R>,